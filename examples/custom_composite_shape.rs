@@ -6,7 +6,7 @@ extern crate ncollide;
 use na::{Isometry2, Point2, Translation2, Vector2};
 use ncollide::query::{self, Proximity};
 use ncollide::shape::{CompositeShape, CompositeShape2, Cuboid2, Shape, Shape2};
-use ncollide::partitioning::BVT;
+use ncollide::partitioning::{BVH, BVT};
 use ncollide::bounding_volume::AABB2;
 
 struct CrossedCuboids {
@@ -85,7 +85,7 @@ impl CompositeShape<Point2<f32>, Isometry2<f32>> for CrossedCuboids {
         CrossedCuboids::generate_aabb(i)
     }
 
-    fn bvt(&self) -> &BVT<usize, AABB2<f32>> {
+    fn bvh(&self) -> &BVH<f32, usize, AABB2<f32>> {
         // Reference to the acceleration structure.
         &self.bvt
     }
@@ -1,12 +1,13 @@
-use bounding_volume::{AABB, HasBoundingVolume};
-use bounding_volume::aabb_utils;
+use bounding_volume::{self, HasBoundingVolume, AABB};
 use shape::BezierCurve;
-use math::Matrix;
-use math::{Scalar, Point, Vector};
+use math::{Isometry, Point};
 
-impl HasBoundingVolume for BezierCurve {
+impl<P: Point, M: Isometry<P>> HasBoundingVolume<M, AABB<P>> for BezierCurve<P> {
     #[inline]
-    fn bounding_volume(&self, m: &Matrix) -> AABB {
-        aabb_utils::point_cloud_aabb(m, self.control_points())
+    fn bounding_volume(&self, m: &M) -> AABB<P> {
+        // The curve lies inside of the convex hull of its control points.
+        let (mins, maxs) = bounding_volume::point_cloud_aabb(m, self.control_points());
+
+        AABB::new(mins, maxs)
     }
 }
@@ -0,0 +1,13 @@
+use bounding_volume::{self, HasBoundingVolume, AABB};
+use shape::BezierSurface;
+use math::{Isometry, Point};
+
+impl<P: Point, M: Isometry<P>> HasBoundingVolume<M, AABB<P>> for BezierSurface<P> {
+    #[inline]
+    fn bounding_volume(&self, m: &M) -> AABB<P> {
+        // The surface lies inside of the convex hull of its control points.
+        let (mins, maxs) = bounding_volume::point_cloud_aabb(m, self.control_points());
+
+        AABB::new(mins, maxs)
+    }
+}
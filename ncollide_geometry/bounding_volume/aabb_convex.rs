@@ -1,6 +1,8 @@
+use alga::general::Real;
+use na::{Point2, Point3};
 use bounding_volume::{HasBoundingVolume, AABB};
 use bounding_volume::aabb_utils;
-use shape::ConvexHull;
+use shape::{ConvexHull, ConvexPolygon, ConvexPolyhedron};
 use math::{Isometry, Point};
 
 impl<P: Point, M: Isometry<P>> HasBoundingVolume<M, AABB<P>> for ConvexHull<P> {
@@ -11,3 +13,29 @@ impl<P: Point, M: Isometry<P>> HasBoundingVolume<M, AABB<P>> for ConvexHull<P> {
         AABB::new(min, max)
     }
 }
+
+impl<N, M> HasBoundingVolume<M, AABB<Point3<N>>> for ConvexPolyhedron<N>
+where
+    N: Real,
+    M: Isometry<Point3<N>>,
+{
+    #[inline]
+    fn bounding_volume(&self, m: &M) -> AABB<Point3<N>> {
+        let (min, max) = aabb_utils::point_cloud_aabb(m, self.points());
+
+        AABB::new(min, max)
+    }
+}
+
+impl<N, M> HasBoundingVolume<M, AABB<Point2<N>>> for ConvexPolygon<N>
+where
+    N: Real,
+    M: Isometry<Point2<N>>,
+{
+    #[inline]
+    fn bounding_volume(&self, m: &M) -> AABB<Point2<N>> {
+        let (min, max) = aabb_utils::point_cloud_aabb(m, self.points());
+
+        AABB::new(min, max)
+    }
+}
@@ -0,0 +1,11 @@
+use bounding_volume::{self, HasBoundingVolume, AABB};
+use shape::Ellipsoid;
+use math::{Isometry, Point};
+
+impl<P: Point, M: Isometry<P>> HasBoundingVolume<M, AABB<P>> for Ellipsoid<P::Vector> {
+    #[inline]
+    fn bounding_volume(&self, m: &M) -> AABB<P> {
+        // Exact since the support mapping of an ellipsoid is analytic.
+        bounding_volume::support_map_aabb(m, self)
+    }
+}
@@ -0,0 +1,17 @@
+use na;
+use bounding_volume::{HasBoundingVolume, AABB};
+use shape::HeightField;
+use math::{Isometry, Point};
+
+impl<P: Point, M: Isometry<P>> HasBoundingVolume<M, AABB<P>> for HeightField<P> {
+    #[inline]
+    fn bounding_volume(&self, m: &M) -> AABB<P> {
+        let bv = self.local_aabb();
+        let ls_center = bv.center();
+        let center = m.transform_point(&ls_center);
+        let half_extents = (*bv.maxs() - *bv.mins()) * na::convert::<f64, P::Real>(0.5);
+        let ws_half_extents = m.absolute_rotate_vector(&half_extents);
+
+        AABB::new(center + (-ws_half_extents), center + ws_half_extents)
+    }
+}
@@ -0,0 +1,14 @@
+use bounding_volume::{self, BoundingVolume, HasBoundingVolume, AABB};
+use shape::RoundShape;
+use math::Point;
+
+impl<P, M, S> HasBoundingVolume<M, AABB<P>> for RoundShape<S, P::Real>
+where
+    P: Point,
+    S: HasBoundingVolume<M, AABB<P>>,
+{
+    #[inline]
+    fn bounding_volume(&self, m: &M) -> AABB<P> {
+        bounding_volume::aabb(self.shape(), m).loosened(self.border_radius())
+    }
+}
@@ -0,0 +1,43 @@
+use std::ops::Deref;
+
+use na;
+use bounding_volume::{self, HasBoundingVolume, AABB};
+use shape::{Scaled, ScaledPart, Shape};
+use math::{Isometry, Point};
+
+impl<P, M, S> HasBoundingVolume<M, AABB<P>> for Scaled<S, P::Vector>
+where
+    P: Point,
+    M: Isometry<P>,
+    S: Deref<Target = Shape<P, M>>,
+{
+    #[inline]
+    fn bounding_volume(&self, m: &M) -> AABB<P> {
+        if self.shape().is_support_map() {
+            bounding_volume::support_map_aabb(m, self)
+        } else {
+            let one: M = na::one();
+            let bv = self.scale_aabb(&self.shape().aabb(&one));
+            let center = m.transform_point(&bv.center());
+            let ws_half_extents = m.absolute_rotate_vector(&bv.half_extents());
+
+            AABB::new(center + (-ws_half_extents), center + ws_half_extents)
+        }
+    }
+}
+
+impl<P: Point, M: Isometry<P>> HasBoundingVolume<M, AABB<P>> for ScaledPart<P, M> {
+    #[inline]
+    fn bounding_volume(&self, m: &M) -> AABB<P> {
+        if self.shape().is_support_map() {
+            bounding_volume::support_map_aabb(m, self)
+        } else {
+            let one: M = na::one();
+            let bv = self.transform_aabb(&self.shape().aabb(&one));
+            let center = m.transform_point(&bv.center());
+            let ws_half_extents = m.absolute_rotate_vector(&bv.half_extents());
+
+            AABB::new(center + (-ws_half_extents), center + ws_half_extents)
+        }
+    }
+}
@@ -1,7 +1,7 @@
 use bounding_volume::{HasBoundingVolume, AABB};
 use bounding_volume;
 use shape::{Capsule, Cone, Cylinder};
-use shape::{Segment, Triangle};
+use shape::{Segment, Tetrahedron, Triangle};
 use math::{Isometry, Point};
 
 impl<P: Point, M: Isometry<P>> HasBoundingVolume<M, AABB<P>> for Cone<P::Real> {
@@ -40,3 +40,11 @@ impl<P: Point, M: Isometry<P>> HasBoundingVolume<M, AABB<P>> for Segment<P> {
         bounding_volume::support_map_aabb(m, self)
     }
 }
+
+impl<P: Point, M: Isometry<P>> HasBoundingVolume<M, AABB<P>> for Tetrahedron<P> {
+    #[inline]
+    fn bounding_volume(&self, m: &M) -> AABB<P> {
+        // FIXME: optimize that
+        bounding_volume::support_map_aabb(m, self)
+    }
+}
@@ -0,0 +1,31 @@
+use alga::linear::Translation;
+use na;
+use bounding_volume::{HasBoundingVolume, AABB};
+use shape::Torus;
+use math::{Isometry, Point};
+
+impl<P: Point, M: Isometry<P>> HasBoundingVolume<M, AABB<P>> for Torus<P::Real> {
+    #[inline]
+    fn bounding_volume(&self, m: &M) -> AABB<P> {
+        let center = P::from_coordinates(m.translation().to_vector());
+        let mut axis = na::zero::<P::Vector>();
+        axis[1] = na::one();
+        let axis = m.rotate_vector(&axis);
+
+        // The extent along a world axis `e` is `major_radius * |e x axis| + minor_radius`.
+        let mut half_extents = na::zero::<P::Vector>();
+
+        for i in 0..na::dimension::<P::Vector>() {
+            let sin2 = na::one::<P::Real>() - axis[i] * axis[i];
+            let sin = if sin2 > na::zero() {
+                sin2.sqrt()
+            } else {
+                na::zero()
+            };
+
+            half_extents[i] = self.major_radius() * sin + self.minor_radius();
+        }
+
+        AABB::new(center - half_extents, center + half_extents)
+    }
+}
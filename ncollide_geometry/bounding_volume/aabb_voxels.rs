@@ -0,0 +1,26 @@
+use na;
+use bounding_volume::{HasBoundingVolume, AABB};
+use shape::Voxels;
+use math::{Isometry, Point};
+
+impl<P: Point, M: Isometry<P>> HasBoundingVolume<M, AABB<P>> for Voxels<P> {
+    #[inline]
+    fn bounding_volume(&self, m: &M) -> AABB<P> {
+        match self.local_aabb() {
+            Some(bv) => {
+                let ls_center = bv.center();
+                let center = m.transform_point(&ls_center);
+                let half_extents = (*bv.maxs() - *bv.mins()) * na::convert::<f64, P::Real>(0.5);
+                let ws_half_extents = m.absolute_rotate_vector(&half_extents);
+
+                AABB::new(center + (-ws_half_extents), center + ws_half_extents)
+            }
+            None => {
+                // A grid without any occupied voxel is reduced to its origin.
+                let center = m.transform_point(&P::origin());
+
+                AABB::new(center, center)
+            }
+        }
+    }
+}
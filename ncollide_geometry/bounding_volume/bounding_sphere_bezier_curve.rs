@@ -0,0 +1,12 @@
+use bounding_volume::{self, BoundingSphere, HasBoundingVolume};
+use shape::BezierCurve;
+use math::{Isometry, Point};
+
+impl<P: Point, M: Isometry<P>> HasBoundingVolume<M, BoundingSphere<P>> for BezierCurve<P> {
+    #[inline]
+    fn bounding_volume(&self, m: &M) -> BoundingSphere<P> {
+        let (center, radius) = bounding_volume::point_cloud_bounding_sphere(self.control_points());
+
+        BoundingSphere::new(m.transform_point(&center), radius)
+    }
+}
@@ -0,0 +1,30 @@
+use na;
+use bounding_volume::{BoundingSphere, BoundingVolume, HasBoundingVolume};
+use shape::DynamicCompound;
+use math::{Isometry, Point};
+
+impl<P, M, M2> HasBoundingVolume<M2, BoundingSphere<P>> for DynamicCompound<P, M>
+where
+    P: Point,
+    M: Isometry<P>,
+    M2: Isometry<P>,
+{
+    #[inline]
+    fn bounding_volume(&self, m: &M2) -> BoundingSphere<P> {
+        let mut res: Option<BoundingSphere<P>> = None;
+
+        for (_, delta, shape) in self.parts() {
+            let bs = shape.bounding_sphere(delta);
+
+            match res {
+                Some(ref mut res) => res.merge(&bs),
+                None => res = Some(bs),
+            }
+        }
+
+        match res {
+            Some(res) => BoundingSphere::new(m.transform_point(res.center()), res.radius()),
+            None => BoundingSphere::new(m.transform_point(&P::origin()), na::zero()),
+        }
+    }
+}
@@ -0,0 +1,14 @@
+use bounding_volume::{self, BoundingSphere, BoundingVolume, HasBoundingVolume};
+use shape::RoundShape;
+use math::Point;
+
+impl<P, M, S> HasBoundingVolume<M, BoundingSphere<P>> for RoundShape<S, P::Real>
+where
+    P: Point,
+    S: HasBoundingVolume<M, BoundingSphere<P>>,
+{
+    #[inline]
+    fn bounding_volume(&self, m: &M) -> BoundingSphere<P> {
+        bounding_volume::bounding_sphere(self.shape(), m).loosened(self.border_radius())
+    }
+}
@@ -0,0 +1,35 @@
+use std::ops::Deref;
+
+use na;
+use bounding_volume::{self, BoundingSphere, HasBoundingVolume, AABB};
+use shape::{Scaled, ScaledPart, Shape};
+use math::{Isometry, Point};
+
+impl<P, M, S> HasBoundingVolume<M, BoundingSphere<P>> for Scaled<S, P::Vector>
+where
+    P: Point,
+    M: Isometry<P>,
+    S: Deref<Target = Shape<P, M>>,
+{
+    #[inline]
+    fn bounding_volume(&self, m: &M) -> BoundingSphere<P> {
+        let one: M = na::one();
+        let bv: AABB<P> = bounding_volume::aabb(self, &one);
+        let center = m.transform_point(&bv.center());
+        let radius = na::norm(&bv.half_extents());
+
+        BoundingSphere::new(center, radius)
+    }
+}
+
+impl<P: Point, M: Isometry<P>> HasBoundingVolume<M, BoundingSphere<P>> for ScaledPart<P, M> {
+    #[inline]
+    fn bounding_volume(&self, m: &M) -> BoundingSphere<P> {
+        let one: M = na::one();
+        let bv: AABB<P> = bounding_volume::aabb(self, &one);
+        let center = m.transform_point(&bv.center());
+        let radius = na::norm(&bv.half_extents());
+
+        BoundingSphere::new(center, radius)
+    }
+}
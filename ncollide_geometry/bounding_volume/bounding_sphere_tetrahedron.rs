@@ -0,0 +1,14 @@
+use bounding_volume::{BoundingSphere, HasBoundingVolume};
+use bounding_volume;
+use shape::Tetrahedron;
+use math::{Isometry, Point};
+
+impl<P: Point, M: Isometry<P>> HasBoundingVolume<M, BoundingSphere<P>> for Tetrahedron<P> {
+    #[inline]
+    fn bounding_volume(&self, m: &M) -> BoundingSphere<P> {
+        let pts = [*self.a(), *self.b(), *self.c(), *self.d()];
+        let (center, radius) = bounding_volume::point_cloud_bounding_sphere(&pts[..]);
+
+        BoundingSphere::new(m.transform_point(&center), radius)
+    }
+}
@@ -0,0 +1,19 @@
+use na;
+use bounding_volume::{BoundingSphere, HasBoundingVolume};
+use shape::Voxels;
+use math::{Isometry, Point};
+
+impl<P: Point, M: Isometry<P>> HasBoundingVolume<M, BoundingSphere<P>> for Voxels<P> {
+    #[inline]
+    fn bounding_volume(&self, m: &M) -> BoundingSphere<P> {
+        match self.local_aabb() {
+            Some(bv) => {
+                let center = m.transform_point(&bv.center());
+                let radius = na::norm(&bv.half_extents());
+
+                BoundingSphere::new(center, radius)
+            }
+            None => BoundingSphere::new(m.transform_point(&P::origin()), na::zero()),
+        }
+    }
+}
@@ -24,9 +24,18 @@ pub mod aabb;
 mod aabb_cuboid;
 mod aabb_support_map;
 mod aabb_ball;
+mod aabb_ellipsoid;
 mod aabb_plane;
+mod aabb_torus;
+mod aabb_heightfield;
+mod aabb_voxels;
+mod aabb_bezier_curve;
+mod aabb_bezier_surface;
+mod aabb_scaled;
+mod aabb_round_shape;
 mod aabb_convex;
 mod aabb_compound;
+mod aabb_dynamic_compound;
 mod aabb_mesh;
 mod aabb_utils;
 mod aabb_shape;
@@ -36,12 +45,22 @@ pub mod bounding_sphere;
 mod bounding_sphere_cuboid;
 mod bounding_sphere_cone;
 mod bounding_sphere_ball;
+mod bounding_sphere_ellipsoid;
 mod bounding_sphere_cylinder;
 mod bounding_sphere_capsule;
 mod bounding_sphere_plane;
+mod bounding_sphere_torus;
+mod bounding_sphere_heightfield;
+mod bounding_sphere_voxels;
+mod bounding_sphere_bezier_curve;
+mod bounding_sphere_bezier_surface;
+mod bounding_sphere_scaled;
+mod bounding_sphere_round_shape;
 mod bounding_sphere_convex;
 mod bounding_sphere_compound;
+mod bounding_sphere_dynamic_compound;
 mod bounding_sphere_triangle;
+mod bounding_sphere_tetrahedron;
 mod bounding_sphere_segment;
 mod bounding_sphere_mesh;
 mod bounding_sphere_utils;
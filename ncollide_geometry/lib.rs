@@ -12,6 +12,8 @@
 extern crate alga;
 #[macro_use]
 extern crate approx;
+#[macro_use]
+extern crate lazy_static;
 extern crate nalgebra as na;
 extern crate ncollide_math as math;
 extern crate ncollide_utils as utils;
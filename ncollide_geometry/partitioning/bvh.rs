@@ -0,0 +1,118 @@
+//! Trait-object friendly interface to bounding volume hierarchies.
+
+use std::marker::PhantomData;
+
+use alga::general::Real;
+use partitioning::{BVTCostFn, BVTVisitor, BVT, DBVT};
+use bounding_volume::BoundingVolume;
+use math::Point;
+
+/// Trait implemented by bounding volume hierarchies.
+///
+/// Unlike the inherent methods of `BVT`, this trait is object-safe. This allows the acceleration
+/// structure of a composite shape to be either an explicit tree or an implicit hierarchy (e.g.,
+/// built on-the-fly from a regular grid).
+pub trait BVH<N, B, BV> {
+    /// Traverses this hierarchy using a visitor.
+    fn visit(&self, visitor: &mut BVTVisitor<B, BV>);
+
+    /// Performs a best-first search on this hierarchy.
+    ///
+    /// Returns the leaf with the smallest cost. Use `bvh_best_first_search` to retrieve the
+    /// user-data computed by the cost function as well.
+    fn best_first_search(&self, algorithm: &mut BVTCostFn<N, B, BV, UserData = ()>) -> Option<B>;
+}
+
+impl<N: Real, B: Clone, BV> BVH<N, B, BV> for BVT<B, BV> {
+    #[inline]
+    fn visit(&self, visitor: &mut BVTVisitor<B, BV>) {
+        BVT::visit(self, visitor)
+    }
+
+    #[inline]
+    fn best_first_search(&self, algorithm: &mut BVTCostFn<N, B, BV, UserData = ()>) -> Option<B> {
+        BVT::best_first_search(self, algorithm).map(|(b, _)| b.clone())
+    }
+}
+
+impl<N, P, B, BV> BVH<N, B, BV> for DBVT<P, B, BV>
+where
+    N: Real,
+    P: Point,
+    B: Clone,
+    BV: BoundingVolume<P>,
+{
+    #[inline]
+    fn visit(&self, visitor: &mut BVTVisitor<B, BV>) {
+        DBVT::visit(self, visitor)
+    }
+
+    #[inline]
+    fn best_first_search(&self, algorithm: &mut BVTCostFn<N, B, BV, UserData = ()>) -> Option<B> {
+        DBVT::best_first_search(self, algorithm).map(|(b, _)| b.clone())
+    }
+}
+
+/// Performs a best-first search on any bounding volume hierarchy.
+///
+/// This is the same as `BVT::best_first_search` but the leaf is returned by value.
+pub fn bvh_best_first_search<N, B, BV, BFS: ?Sized>(
+    bvh: &BVH<N, B, BV>,
+    algorithm: &mut BFS,
+) -> Option<(B, BFS::UserData)>
+where
+    N: Real,
+    BFS: BVTCostFn<N, B, BV>,
+{
+    let mut recorder = UserDataRecorder {
+        algorithm: algorithm,
+        best: None,
+        _data: PhantomData,
+    };
+
+    let best_leaf = bvh.best_first_search(&mut recorder);
+
+    match (best_leaf, recorder.best) {
+        (Some(b), Some((_, data))) => Some((b, data)),
+        _ => None,
+    }
+}
+
+/// Cost function wrapper keeping track of the user-data of the leaf with the smallest cost.
+struct UserDataRecorder<'a, N, B, BV, BFS: 'a + ?Sized + BVTCostFn<N, B, BV>> {
+    algorithm: &'a mut BFS,
+    best: Option<(N, BFS::UserData)>,
+    _data: PhantomData<(B, BV)>,
+}
+
+impl<'a, N, B, BV, BFS> BVTCostFn<N, B, BV> for UserDataRecorder<'a, N, B, BV, BFS>
+where
+    N: Real,
+    BFS: 'a + ?Sized + BVTCostFn<N, B, BV>,
+{
+    type UserData = ();
+
+    #[inline]
+    fn compute_bv_cost(&mut self, bv: &BV) -> Option<N> {
+        self.algorithm.compute_bv_cost(bv)
+    }
+
+    #[inline]
+    fn compute_b_cost(&mut self, b: &B) -> Option<(N, ())> {
+        match self.algorithm.compute_b_cost(b) {
+            Some((cost, data)) => {
+                let is_best = match self.best {
+                    Some((best_cost, _)) => cost < best_cost,
+                    None => true,
+                };
+
+                if is_best {
+                    self.best = Some((cost, data));
+                }
+
+                Some((cost, ()))
+            }
+            None => None,
+        }
+    }
+}
@@ -54,7 +54,7 @@ impl<B, BV> BVT<B, BV> {
     ///
     /// This will traverse the whole tree and call the visitor `.visit_internal(...)` (resp.
     /// `.visit_leaf(...)`) method on each internal (resp. leaf) node.
-    pub fn visit<Vis: BVTVisitor<B, BV>>(&self, visitor: &mut Vis) {
+    pub fn visit<Vis: ?Sized + BVTVisitor<B, BV>>(&self, visitor: &mut Vis) {
         match self.tree {
             Some(ref t) => t.visit(visitor),
             None => {}
@@ -80,7 +80,7 @@ impl<B, BV> BVT<B, BV> {
     ) -> Option<(&'a B, BFS::UserData)>
     where
         N: Real,
-        BFS: BVTCostFn<N, B, BV>,
+        BFS: ?Sized + BVTCostFn<N, B, BV>,
     {
         match self.tree {
             Some(ref t) => t.best_first_search(algorithm),
@@ -118,6 +118,22 @@ impl<B, BV> BVT<B, BV> {
         BVT::new_with_partitioner(leaves, &mut Self::median_partitioner)
     }
 
+    /// Recomputes the bounding volumes of this tree without modifying its topology.
+    ///
+    /// The bounding volume of each leaf is replaced by `leaf_bv(b)` where `b` is the leaf content.
+    /// The bounding volumes of the internal nodes are then recomputed bottom-up. This is much
+    /// cheaper than rebuilding the tree but the tree quality may degrade if the leaves move a lot.
+    pub fn refit<P, F>(&mut self, leaf_bv: &mut F)
+    where
+        P: Point,
+        BV: BoundingVolume<P> + Clone,
+        F: FnMut(&B) -> BV,
+    {
+        if let Some(ref mut t) = self.tree {
+            t.refit(leaf_bv)
+        }
+    }
+
     /// Construction function for a kdree to be used with `BVT::new_with_partitioner`.
     pub fn median_partitioner_with_centers<P, F: FnMut(&B, &BV) -> P>(
         depth: usize,
@@ -218,7 +234,23 @@ impl<B, BV> BVTNode<B, BV> {
         }
     }
 
-    fn visit<Vis: BVTVisitor<B, BV>>(&self, visitor: &mut Vis) {
+    fn refit<P, F>(&mut self, leaf_bv: &mut F)
+    where
+        P: Point,
+        BV: BoundingVolume<P> + Clone,
+        F: FnMut(&B) -> BV,
+    {
+        match *self {
+            BVTNode::Internal(ref mut bv, ref mut left, ref mut right) => {
+                left.refit(leaf_bv);
+                right.refit(leaf_bv);
+                *bv = left.bounding_volume().merged(right.bounding_volume());
+            }
+            BVTNode::Leaf(ref mut bv, ref b) => *bv = leaf_bv(b),
+        }
+    }
+
+    fn visit<Vis: ?Sized + BVTVisitor<B, BV>>(&self, visitor: &mut Vis) {
         match *self {
             BVTNode::Internal(ref bv, ref left, ref right) => {
                 if visitor.visit_internal(bv) {
@@ -269,7 +301,7 @@ impl<B, BV> BVTNode<B, BV> {
     ) -> Option<(&'a B, BFS::UserData)>
     where
         N: Real,
-        BFS: BVTCostFn<N, B, BV>,
+        BFS: ?Sized + BVTCostFn<N, B, BV>,
     {
         let mut queue: BinaryHeap<RefWithCost<'a, N, BVTNode<B, BV>>> = BinaryHeap::new();
         let mut best_cost = N::max_value();
@@ -1,10 +1,13 @@
 use std::ops::Index;
+use std::collections::BinaryHeap;
 
+use alga::general::Real;
 use na;
 
 use utils::data::SparseVec;
+use utils::data::ref_with_cost::RefWithCost;
 use math::Point;
-use partitioning::BVTVisitor;
+use partitioning::{BVTCostFn, BVTVisitor};
 use bounding_volume::BoundingVolume;
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
@@ -26,12 +29,6 @@ impl DBVTLeafId {
     }
 }
 
-#[derive(Copy, Clone, Debug)]
-enum UpdateStatus {
-    NeedsShrink,
-    UpToDate,
-}
-
 #[derive(Copy, Clone, Debug, Hash)]
 enum DBVTInternalId {
     RightChildOf(usize),
@@ -77,8 +74,6 @@ struct DBVTInternal<P, BV> {
     right: DBVTNodeId,
     /// This node parent.
     parent: DBVTInternalId,
-
-    state: UpdateStatus,
 }
 
 impl<P: Point, B, BV: BoundingVolume<P>> DBVTLeaf<P, B, BV> {
@@ -115,7 +110,6 @@ impl<P: Point, BV: BoundingVolume<P>> DBVTInternal<P, BV> {
             left: left,
             right: right,
             parent: parent,
-            state: UpdateStatus::UpToDate,
         }
     }
 }
@@ -136,6 +130,16 @@ impl<P: Point, B, BV: BoundingVolume<P>> DBVT<P, B, BV> {
         self.leaves.is_empty()
     }
 
+    /// Reference to the bounding volume of the tree root.
+    #[inline]
+    pub fn root_bounding_volume(&self) -> Option<&BV> {
+        if self.is_empty() {
+            None
+        } else {
+            Some(self.node_bounding_volume(self.root))
+        }
+    }
+
     /// Inserts a leaf into this DBVT.
     pub fn insert(&mut self, leaf: DBVTLeaf<P, B, BV>) -> DBVTLeafId {
         if self.is_empty() {
@@ -273,7 +277,7 @@ impl<P: Point, B, BV: BoundingVolume<P>> DBVT<P, B, BV> {
                     }
 
                     self.internals[pp].right = other;
-                    self.internals[pp].state = UpdateStatus::NeedsShrink;
+                    self.shrink(pp);
                 }
                 DBVTInternalId::LeftChildOf(pp) => {
                     match other {
@@ -286,7 +290,7 @@ impl<P: Point, B, BV: BoundingVolume<P>> DBVT<P, B, BV> {
                     }
 
                     self.internals[pp].left = other;
-                    self.internals[pp].state = UpdateStatus::NeedsShrink;
+                    self.shrink(pp);
                 }
                 DBVTInternalId::Root => {
                     // The root changes to the other child.
@@ -300,6 +304,8 @@ impl<P: Point, B, BV: BoundingVolume<P>> DBVT<P, B, BV> {
                     self.root = other;
                 }
             }
+
+            let _ = self.internals.remove(p);
         } else {
             // The tree is now empty.
             self.leaves.clear();
@@ -309,17 +315,103 @@ impl<P: Point, B, BV: BoundingVolume<P>> DBVT<P, B, BV> {
         leaf
     }
 
+    /// Recomputes the bounding volumes of the internal node `id` and all its ancestors so that
+    /// they tightly enclose their children.
+    fn shrink(&mut self, mut id: usize) {
+        loop {
+            let (left, right, parent) = {
+                let node = &self.internals[id];
+                (node.left, node.right, node.parent)
+            };
+
+            let bv = self.node_bounding_volume(left)
+                .merged(self.node_bounding_volume(right));
+            let node = &mut self.internals[id];
+            node.center = bv.center();
+            node.bounding_volume = bv;
+
+            match parent {
+                DBVTInternalId::LeftChildOf(pp) | DBVTInternalId::RightChildOf(pp) => id = pp,
+                DBVTInternalId::Root => break,
+            }
+        }
+    }
+
     /// Traverses this tree using an object implementing the `BVTVisitor`trait.
     ///
     /// This will traverse the whole tree and call the visitor `.visit_internal(...)` (resp.
     /// `.visit_leaf(...)`) method on each internal (resp. leaf) node.
-    pub fn visit<Vis: BVTVisitor<B, BV>>(&self, visitor: &mut Vis) {
+    pub fn visit<Vis: ?Sized + BVTVisitor<B, BV>>(&self, visitor: &mut Vis) {
         if !self.is_empty() {
             self.visit_node(visitor, self.root);
         }
     }
 
-    fn visit_node<Vis: BVTVisitor<B, BV>>(&self, visitor: &mut Vis, node: DBVTNodeId) {
+    /// Performs a best-fist-search on this tree.
+    ///
+    /// Returns the content of the leaf with the smallest associated cost, and a result of
+    /// user-defined type.
+    pub fn best_first_search<N, BFS>(&self, algorithm: &mut BFS) -> Option<(&B, BFS::UserData)>
+    where
+        N: Real,
+        BFS: ?Sized + BVTCostFn<N, B, BV>,
+    {
+        if self.is_empty() {
+            return None;
+        }
+
+        let mut queue: BinaryHeap<RefWithCost<N, DBVTNodeId>> = BinaryHeap::new();
+        let mut best_cost = N::max_value();
+        let mut result = None;
+
+        match algorithm.compute_bv_cost(self.node_bounding_volume(self.root)) {
+            Some(cost) => queue.push(RefWithCost::new(&self.root, -cost)),
+            None => return None,
+        }
+
+        while let Some(node) = queue.pop() {
+            if -node.cost >= best_cost {
+                break; // solution found.
+            }
+
+            match *node.object {
+                DBVTNodeId::Internal(i) => {
+                    let internal = &self.internals[i];
+
+                    for child in [&internal.left, &internal.right].iter() {
+                        if let Some(cost) =
+                            algorithm.compute_bv_cost(self.node_bounding_volume(**child))
+                        {
+                            if cost < best_cost {
+                                queue.push(RefWithCost::new(*child, -cost))
+                            }
+                        }
+                    }
+                }
+                DBVTNodeId::Leaf(i) => {
+                    let leaf = &self.leaves[i];
+
+                    if let Some((cost, data)) = algorithm.compute_b_cost(&leaf.data) {
+                        if cost < best_cost {
+                            best_cost = cost;
+                            result = Some((&leaf.data, data));
+                        }
+                    }
+                }
+            }
+        }
+
+        result
+    }
+
+    fn node_bounding_volume(&self, node: DBVTNodeId) -> &BV {
+        match node {
+            DBVTNodeId::Internal(i) => &self.internals[i].bounding_volume,
+            DBVTNodeId::Leaf(i) => &self.leaves[i].bounding_volume,
+        }
+    }
+
+    fn visit_node<Vis: ?Sized + BVTVisitor<B, BV>>(&self, visitor: &mut Vis, node: DBVTNodeId) {
         match node {
             DBVTNodeId::Internal(i) => {
                 let internal = &self.internals[i];
@@ -2,6 +2,7 @@
 
 pub use partitioning::dbvt::{DBVT, DBVTLeaf, DBVTLeafId};
 pub use partitioning::bvt::{BVTNode, BinaryPartition, BVT};
+pub use partitioning::bvh::{bvh_best_first_search, BVH};
 #[doc(inline)]
 pub use partitioning::bvt_visitor::{BVTVisitor, BoundingVolumeInterferencesCollector};
 #[doc(inline)]
@@ -11,6 +12,7 @@ pub use partitioning::bvt_cost_fn::BVTCostFn;
 
 mod dbvt;
 mod bvt;
+mod bvh;
 
 #[doc(hidden)]
 pub mod bvt_visitor;
@@ -13,6 +13,7 @@ use utils;
 use shape::{AnnotatedMinkowskiSum, AnnotatedPoint, Reflection, SupportMap};
 use query::algorithms::gjk;
 use query::algorithms::simplex::Simplex;
+use query::QueryError;
 use math::Point;
 
 #[derive(Copy, Clone, PartialEq)]
@@ -117,8 +118,47 @@ impl<P: Point> EPA2<P> {
 
     /// Projects the origin on a shape unsing the EPA algorithm.
     ///
-    /// The origin is assumed to be located inside of the shape.
+    /// The origin is assumed to be located inside of the shape. If the algorithm does not
+    /// converge, the best projection found so far is returned.
     pub fn project_origin<M, S, G: ?Sized>(&mut self, m: &M, shape: &G, simplex: &S) -> P
+    where
+        S: Simplex<P>,
+        G: SupportMap<P, M>,
+    {
+        match self.do_project_origin(m, shape, simplex) {
+            Ok((proj, _)) => proj,
+            Err(err) => panic!("EPA failed: {}", err),
+        }
+    }
+
+    /// Projects the origin on a shape unsing the EPA algorithm.
+    ///
+    /// The origin is assumed to be located inside of the shape. Returns
+    /// `QueryError::NoConvergence` if the EPA did not converge, and `QueryError::DegenerateInput`
+    /// if the simplex does not allow the polytope expansion.
+    pub fn try_project_origin<M, S, G: ?Sized>(
+        &mut self,
+        m: &M,
+        shape: &G,
+        simplex: &S,
+    ) -> Result<P, QueryError>
+    where
+        S: Simplex<P>,
+        G: SupportMap<P, M>,
+    {
+        match self.do_project_origin(m, shape, simplex)? {
+            (proj, true) => Ok(proj),
+            (_, false) => Err(QueryError::NoConvergence),
+        }
+    }
+
+    // Returns the projection of the origin and whether the EPA converged.
+    fn do_project_origin<M, S, G: ?Sized>(
+        &mut self,
+        m: &M,
+        shape: &G,
+        simplex: &S,
+    ) -> Result<(P, bool), QueryError>
     where
         S: Simplex<P>,
         G: SupportMap<P, M>,
@@ -136,7 +176,7 @@ impl<P: Point> EPA2<P> {
         }
 
         if simplex.dimension() == 0 {
-            return P::origin();
+            return Ok((P::origin(), true));
         } else if simplex.dimension() == 2 {
             let dp1 = self.vertices[1] - self.vertices[0];
             let dp2 = self.vertices[2] - self.vertices[0];
@@ -205,7 +245,11 @@ impl<P: Point> EPA2<P> {
 
         let mut niter = 0;
         let mut max_dist = P::Real::max_value();
-        let mut best_face_id = *self.heap.peek().unwrap();
+        let mut best_face_id = match self.heap.peek() {
+            Some(face_id) => *face_id,
+            // The simplex is degenerate.
+            None => return Err(QueryError::DegenerateInput),
+        };
 
         /*
          * Run the expansion.
@@ -232,7 +276,7 @@ impl<P: Point> EPA2<P> {
             let curr_dist = -face_id.neg_dist;
 
             if max_dist - curr_dist < _eps_tol {
-                return self.faces[best_face_id.id].proj;
+                return Ok((self.faces[best_face_id.id].proj, true));
             }
 
             let pts1 = [face.pts[0], support_point_id];
@@ -249,7 +293,7 @@ impl<P: Point> EPA2<P> {
                     if dist < curr_dist {
                         // FIXME: if we reach this point, there were issues due to
                         // numerical errors.
-                        return f.0.proj;
+                        return Ok((f.0.proj, true));
                     }
 
                     if !f.0.deleted {
@@ -262,12 +306,11 @@ impl<P: Point> EPA2<P> {
 
             niter += 1;
             if niter > 10000 {
-                println!("Internal error: EPA did not converge after 1000 iterations.");
-                break;
+                return Ok((self.faces[best_face_id.id].proj, false));
             }
         }
 
-        return self.faces[best_face_id.id].proj;
+        Ok((self.faces[best_face_id.id].proj, true))
     }
 }
 
@@ -293,6 +336,31 @@ where
     (*p.orig1(), -*p.orig2())
 }
 
+/// Computes the pair of closest points at the extremities of the minimal translational vector
+/// between `g1` and `g2`.
+///
+/// Returns an error instead of a possibly inaccurate result if the EPA fails.
+pub fn try_closest_points<P, M, S, G1: ?Sized, G2: ?Sized>(
+    epa: &mut EPA2<AnnotatedPoint<P>>,
+    m1: &M,
+    g1: &G1,
+    m2: &M,
+    g2: &G2,
+    simplex: &S,
+) -> Result<(P, P), QueryError>
+where
+    P: Point,
+    S: Simplex<AnnotatedPoint<P>>,
+    G1: SupportMap<P, M>,
+    G2: SupportMap<P, M>,
+{
+    let reflect2 = Reflection::new(g2);
+    let cso = AnnotatedMinkowskiSum::new(m1, g1, m2, &reflect2);
+
+    let p = epa.try_project_origin(&Id::new(), &cso, simplex)?;
+    Ok((*p.orig1(), -*p.orig2()))
+}
+
 fn project_origin<P: Point>(a: &P, b: &P) -> Option<P> {
     let ab = *b - *a;
     let ap = -a.coordinates();
@@ -15,6 +15,7 @@ use shape::{AnnotatedMinkowskiSum, AnnotatedPoint, Reflection, SupportMap, Trian
 use query::PointQueryWithLocation;
 use query::algorithms::gjk;
 use query::algorithms::simplex::Simplex;
+use query::QueryError;
 use math::Point;
 
 #[derive(Copy, Clone, PartialEq)]
@@ -25,9 +26,6 @@ struct FaceId<N: Real> {
 
 impl<N: Real> FaceId<N> {
     fn new(id: usize, neg_dist: N) -> Self {
-        if neg_dist > gjk::eps_tol() {
-            println!("neg_dist: {}", neg_dist);
-        }
         assert!(neg_dist <= gjk::eps_tol());
         FaceId { id, neg_dist }
     }
@@ -166,8 +164,47 @@ impl<P: Point> EPA3<P> {
 
     /// Projects the origin on a shape unsing the EPA algorithm.
     ///
-    /// The origin is assumed to be located inside of the shape.
+    /// The origin is assumed to be located inside of the shape. If the algorithm does not
+    /// converge, the best projection found so far is returned.
     pub fn project_origin<M, S, G: ?Sized>(&mut self, m: &M, shape: &G, simplex: &S) -> P
+    where
+        S: Simplex<P>,
+        G: SupportMap<P, M>,
+    {
+        match self.do_project_origin(m, shape, simplex) {
+            Ok((proj, _)) => proj,
+            Err(err) => panic!("EPA failed: {}", err),
+        }
+    }
+
+    /// Projects the origin on a shape unsing the EPA algorithm.
+    ///
+    /// The origin is assumed to be located inside of the shape. Returns
+    /// `QueryError::NoConvergence` if the EPA did not converge, and `QueryError::DegenerateInput`
+    /// if the simplex does not allow the polytope expansion.
+    pub fn try_project_origin<M, S, G: ?Sized>(
+        &mut self,
+        m: &M,
+        shape: &G,
+        simplex: &S,
+    ) -> Result<P, QueryError>
+    where
+        S: Simplex<P>,
+        G: SupportMap<P, M>,
+    {
+        match self.do_project_origin(m, shape, simplex)? {
+            (proj, true) => Ok(proj),
+            (_, false) => Err(QueryError::NoConvergence),
+        }
+    }
+
+    // Returns the projection of the origin and whether the EPA converged.
+    fn do_project_origin<M, S, G: ?Sized>(
+        &mut self,
+        m: &M,
+        shape: &G,
+        simplex: &S,
+    ) -> Result<(P, bool), QueryError>
     where
         S: Simplex<P>,
         G: SupportMap<P, M>,
@@ -185,7 +222,7 @@ impl<P: Point> EPA3<P> {
         }
 
         if simplex.dimension() == 0 {
-            return P::origin();
+            return Ok((P::origin(), true));
         } else if simplex.dimension() == 3 {
             let dp1 = self.vertices[1] - self.vertices[0];
             let dp2 = self.vertices[2] - self.vertices[0];
@@ -273,7 +310,11 @@ impl<P: Point> EPA3<P> {
 
         let mut niter = 0;
         let mut max_dist = P::Real::max_value();
-        let mut best_face_id = *self.heap.peek().unwrap();
+        let mut best_face_id = match self.heap.peek() {
+            Some(face_id) => *face_id,
+            // The simplex is degenerate.
+            None => return Err(QueryError::DegenerateInput),
+        };
 
         /*
          * Run the expansion.
@@ -300,7 +341,7 @@ impl<P: Point> EPA3<P> {
             let curr_dist = -face_id.neg_dist;
 
             if max_dist - curr_dist < _eps_tol {
-                return self.faces[best_face_id.id].proj;
+                return Ok((self.faces[best_face_id.id].proj, true));
             }
 
             self.faces[face_id.id].deleted = true;
@@ -340,7 +381,7 @@ impl<P: Point> EPA3<P> {
                         if dist < curr_dist {
                             // FIXME: if we reach this point, there were issues due to
                             // numerical errors.
-                            return face.proj;
+                            return Ok((face.proj, true));
                         }
 
                         self.heap.push(FaceId::new(new_face_id, -dist));
@@ -348,7 +389,7 @@ impl<P: Point> EPA3<P> {
                 }
             }
             if self.faces.len() <= first_new_face_id || self.faces[first_new_face_id].adj.len() < 3 {
-                return face.proj;
+                return Ok((face.proj, true));
             }
 
             self.faces[first_new_face_id].adj[2] = self.faces.len() - 1;
@@ -359,12 +400,11 @@ impl<P: Point> EPA3<P> {
 
             niter += 1;
             if niter > 10000 {
-                println!("Internal error: EPA did not converge after 1000 iterations.");
-                break;
+                return Ok((self.faces[best_face_id.id].proj, false));
             }
         }
 
-        return self.faces[best_face_id.id].proj;
+        Ok((self.faces[best_face_id.id].proj, true))
     }
 
     fn compute_silhouette(&mut self, point: usize, id: usize, opp_pt_id: usize) {
@@ -391,24 +431,6 @@ impl<P: Point> EPA3<P> {
         }
     }
 
-    #[allow(dead_code)]
-    fn print_silhouette(&self) {
-        print!("Silhouette points: ");
-        for i in 0..self.silhouette.len() {
-            let edge = &self.silhouette[i];
-            let face = &self.faces[edge.face_id];
-
-            if !face.deleted {
-                print!(
-                    "({}, {}) ",
-                    face.pts[(edge.opp_pt_id + 2) % 3],
-                    face.pts[(edge.opp_pt_id + 1) % 3]
-                );
-            }
-        }
-        println!("");
-    }
-
     #[allow(dead_code)]
     fn check_topology(&self) {
         for i in 0..self.faces.len() {
@@ -417,7 +439,6 @@ impl<P: Point> EPA3<P> {
                 continue;
             }
 
-            println!("checking {}-th face.", i);
             let adj1 = &self.faces[face.adj[0]];
             let adj2 = &self.faces[face.adj[1]];
             let adj3 = &self.faces[face.adj[2]];
@@ -475,3 +496,28 @@ where
     let p = epa.project_origin(&Id::new(), &cso, simplex);
     (*p.orig1(), -*p.orig2())
 }
+
+/// Computes the pair of closest points at the extremities of the minimal translational vector
+/// between `g1` and `g2`.
+///
+/// Returns an error instead of a possibly inaccurate result if the EPA fails.
+pub fn try_closest_points<P, M, S, G1: ?Sized, G2: ?Sized>(
+    epa: &mut EPA3<AnnotatedPoint<P>>,
+    m1: &M,
+    g1: &G1,
+    m2: &M,
+    g2: &G2,
+    simplex: &S,
+) -> Result<(P, P), QueryError>
+where
+    P: Point,
+    S: Simplex<AnnotatedPoint<P>>,
+    G1: SupportMap<P, M>,
+    G2: SupportMap<P, M>,
+{
+    let reflect2 = Reflection::new(g2);
+    let cso = AnnotatedMinkowskiSum::new(m1, g1, m2, &reflect2);
+
+    let p = epa.try_project_origin(&Id::new(), &cso, simplex)?;
+    Ok((*p.orig1(), -*p.orig2()))
+}
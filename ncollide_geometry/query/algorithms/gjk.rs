@@ -9,7 +9,7 @@ use na::{self, Unit};
 
 use shape::{AnnotatedMinkowskiSum, AnnotatedPoint, MinkowskiSum, Reflection, SupportMap};
 use query::algorithms::simplex::Simplex;
-use query::Proximity;
+use query::{Proximity, QueryError};
 use query::{ray_internal, Ray};
 use math::{Isometry, Point};
 
@@ -70,6 +70,33 @@ pub fn closest_points_with_max_dist<P, M, S, G1: ?Sized, G2: ?Sized>(
     max_dist: P::Real,
     simplex: &mut S,
 ) -> GJKResult<(P, P), P::Vector>
+where
+    P: Point,
+    S: Simplex<AnnotatedPoint<P>>,
+    G1: SupportMap<P, M>,
+    G2: SupportMap<P, M>,
+{
+    unwrap_result(try_closest_points_with_max_dist(
+        m1,
+        g1,
+        m2,
+        g2,
+        max_dist,
+        simplex,
+    ))
+}
+
+/// Computes the closest points between two convex shapes unsing the GJK algorithm.
+///
+/// Returns an error instead of panicking if the GJK algorithm fails.
+pub fn try_closest_points_with_max_dist<P, M, S, G1: ?Sized, G2: ?Sized>(
+    m1: &M,
+    g1: &G1,
+    m2: &M,
+    g2: &G2,
+    max_dist: P::Real,
+    simplex: &mut S,
+) -> Result<GJKResult<(P, P), P::Vector>, QueryError>
 where
     P: Point,
     S: Simplex<AnnotatedPoint<P>>,
@@ -79,12 +106,14 @@ where
     let reflect2 = Reflection::new(g2);
     let cso = AnnotatedMinkowskiSum::new(m1, g1, m2, &reflect2);
 
-    match project_origin_with_max_dist(&Id::new(), &cso, max_dist, true, simplex) {
+    let res = match try_project_origin_with_max_dist(&Id::new(), &cso, max_dist, true, simplex)? {
         GJKResult::Projection(p) => GJKResult::Projection((*p.orig1(), -*p.orig2())),
         GJKResult::Intersection => GJKResult::Intersection,
         GJKResult::NoIntersection(dir) => GJKResult::NoIntersection(dir),
         GJKResult::Proximity(_) => unreachable!(),
-    }
+    };
+
+    Ok(res)
 }
 
 /// Computes the exact distance separating two convex shapes unsing the GJK.
@@ -118,6 +147,33 @@ where
     }
 }
 
+/// Computes the distance between two convex shapes unsing the GJK algorithm.
+///
+/// Returns an error instead of looping indefinitely or panicking if the GJK algorithm fails.
+pub fn try_distance<P, M, S, G1: ?Sized, G2: ?Sized>(
+    m1: &M,
+    g1: &G1,
+    m2: &M,
+    g2: &G2,
+    simplex: &mut S,
+) -> Result<P::Real, QueryError>
+where
+    P: Point,
+    S: Simplex<P>,
+    G1: SupportMap<P, M>,
+    G2: SupportMap<P, M>,
+{
+    let reflect2 = Reflection::new(g2);
+    let cso = MinkowskiSum::new(m1, g1, m2, &reflect2);
+    let max_dist = P::Real::max_value();
+
+    match try_project_origin_with_max_dist(&Id::new(), &cso, max_dist, true, simplex)? {
+        GJKResult::Projection(c) => Ok(na::norm(&c.coordinates())),
+        GJKResult::Intersection => Ok(na::zero()),
+        GJKResult::NoIntersection(_) | GJKResult::Proximity(_) => unreachable!(),
+    }
+}
+
 /// Computes the closest points between two convex shapes unsing the GJK algorithm.
 ///
 /// # Arguments:
@@ -134,6 +190,26 @@ pub fn proximity<P, M, S, G1: ?Sized, G2: ?Sized>(
     max_dist: P::Real,
     simplex: &mut S,
 ) -> (Proximity, P::Vector)
+where
+    P: Point,
+    S: Simplex<AnnotatedPoint<P>>,
+    G1: SupportMap<P, M>,
+    G2: SupportMap<P, M>,
+{
+    unwrap_result(try_proximity(m1, g1, m2, g2, max_dist, simplex))
+}
+
+/// Tests the proximity of two convex shapes unsing the GJK algorithm.
+///
+/// Returns an error instead of panicking if the GJK algorithm fails.
+pub fn try_proximity<P, M, S, G1: ?Sized, G2: ?Sized>(
+    m1: &M,
+    g1: &G1,
+    m2: &M,
+    g2: &G2,
+    max_dist: P::Real,
+    simplex: &mut S,
+) -> Result<(Proximity, P::Vector), QueryError>
 where
     P: Point,
     S: Simplex<AnnotatedPoint<P>>,
@@ -143,12 +219,14 @@ where
     let reflect2 = Reflection::new(g2);
     let cso = AnnotatedMinkowskiSum::new(m1, g1, m2, &reflect2);
 
-    match project_origin_with_max_dist(&Id::new(), &cso, max_dist, false, simplex) {
+    let res = match try_project_origin_with_max_dist(&Id::new(), &cso, max_dist, false, simplex)? {
         GJKResult::NoIntersection(data) => (Proximity::Disjoint, data),
         GJKResult::Proximity(data) => (Proximity::WithinMargin, data),
         GJKResult::Intersection => (Proximity::Intersecting, na::zero()),
         GJKResult::Projection(_) => unreachable!(),
-    }
+    };
+
+    Ok(res)
 }
 
 /*
@@ -237,6 +315,31 @@ pub fn project_origin_with_max_dist<P, M, S, G: ?Sized>(
     exact_dist: bool,
     simplex: &mut S,
 ) -> GJKResult<P, P::Vector>
+where
+    P: Point,
+    S: Simplex<P>,
+    G: SupportMap<P, M>,
+{
+    unwrap_result(try_project_origin_with_max_dist(
+        m,
+        shape,
+        max_dist,
+        exact_dist,
+        simplex,
+    ))
+}
+
+/// Projects the origin on a shape using the Separating Axis GJK algorithm.
+///
+/// Returns `QueryError::NoConvergence` if the algorithm did not converge, and
+/// `QueryError::DegenerateInput` if the support points of the shape contain NaN values.
+pub fn try_project_origin_with_max_dist<P, M, S, G: ?Sized>(
+    m: &M,
+    shape: &G,
+    max_dist: P::Real,
+    exact_dist: bool,
+    simplex: &mut S,
+) -> Result<GJKResult<P, P::Vector>, QueryError>
 where
     P: Point,
     S: Simplex<P>,
@@ -263,16 +366,16 @@ where
         } else {
             // The origin is on the simplex.
             //// println!("Exiting GJK with dimesion: {} and dist bound: {}", simplex.dimension(), max_bound);
-            return GJKResult::Intersection;
+            return Ok(GJKResult::Intersection);
         }
 
         if max_bound >= old_max_bound {
             //// println!("Exit 1: {} >= {}", max_bound, old_max_bound);
             //// println!("old proj: {}", old_proj);
             if exact_dist {
-                return GJKResult::Projection(old_proj); // upper bounds inconsistencies
+                return Ok(GJKResult::Projection(old_proj)); // upper bounds inconsistencies
             } else {
-                return GJKResult::Proximity(old_proj.coordinates());
+                return Ok(GJKResult::Proximity(old_proj.coordinates()));
             }
         }
 
@@ -280,29 +383,32 @@ where
         let min_bound = na::dot(dir.as_ref(), &support_point.coordinates());
 
         //// println!("bounds: ({}, {})", min_bound, max_bound);
-        assert!(min_bound == min_bound);
+        if min_bound != min_bound {
+            return Err(QueryError::DegenerateInput);
+        }
 
         if min_bound > max_dist {
             //// println!("Exit 2");
-            return GJKResult::NoIntersection(proj.coordinates());
+            return Ok(GJKResult::NoIntersection(proj.coordinates()));
         } else if !exact_dist && min_bound > na::zero() {
             //// println!("Exit 3");
-            return GJKResult::Proximity(old_proj.coordinates());
+            return Ok(GJKResult::Proximity(old_proj.coordinates()));
         } else if max_bound - min_bound <= _eps_rel * max_bound {
             //// println!("Exit 4");
             if exact_dist {
-                return GJKResult::Projection(proj); // the distance found has a good enough precision
+                // The distance found has a good enough precision.
+                return Ok(GJKResult::Projection(proj));
             } else {
-                return GJKResult::Proximity(proj.coordinates());
+                return Ok(GJKResult::Proximity(proj.coordinates()));
             }
         }
 
         if !simplex.add_point(support_point) {
             //// println!("Exit 5");
             if exact_dist {
-                return GJKResult::Projection(proj);
+                return Ok(GJKResult::Projection(proj));
             } else {
-                return GJKResult::Proximity(proj.coordinates());
+                return Ok(GJKResult::Proximity(proj.coordinates()));
             }
         }
 
@@ -314,9 +420,9 @@ where
                 //// println!("Exit 6 with min_bound: {}", min_bound);
                 // The projection failed likely because of innacuracies.
                 if exact_dist {
-                    return GJKResult::Projection(old_proj);
+                    return Ok(GJKResult::Projection(old_proj));
                 } else {
-                    return GJKResult::Proximity(old_proj.coordinates());
+                    return Ok(GJKResult::Proximity(old_proj.coordinates()));
                 }
             } else {
                 //// println!(">> Exiting GJK with dimesion: {} and dist bound: {}", simplex.dimension(), max_bound);
@@ -324,23 +430,66 @@ where
                 //// println!(">> bound_diff: {}", max_bound - min_bound);
                 //// println!(">> bounds: min = {}, max = {}", min_bound, max_bound);
                 //// println!(">> threshold{}", _eps_rel * max_bound);
-                return GJKResult::Intersection; // Point inside of the cso.
+                return Ok(GJKResult::Intersection); // Point inside of the cso.
             }
         }
         niter += 1;
         if niter == 1000 {
-            panic!("GJK did not converge.");
+            return Err(QueryError::NoConvergence);
         }
     }
 }
 
 /// Casts a ray on a support map using the GJK algorithm.
+///
+/// If the algorithm does not converge, the best estimate found so far is returned: the last
+/// lower bound of the time of impact if the ray progressed toward the shape, `None` otherwise.
 pub fn cast_ray<P, M, S, G: ?Sized>(
     m: &M,
     shape: &G,
     simplex: &mut S,
     ray: &Ray<P>,
 ) -> Option<(P::Real, P::Vector)>
+where
+    P: Point,
+    M: Isometry<P>,
+    S: Simplex<P>,
+    G: SupportMap<P, M>,
+{
+    match do_cast_ray(m, shape, simplex, ray, false) {
+        Ok(res) => res,
+        Err(_) => unreachable!(),
+    }
+}
+
+/// Casts a ray on a support map using the GJK algorithm.
+///
+/// Returns `QueryError::NoConvergence` if the algorithm did not converge.
+pub fn try_cast_ray<P, M, S, G: ?Sized>(
+    m: &M,
+    shape: &G,
+    simplex: &mut S,
+    ray: &Ray<P>,
+) -> Result<Option<(P::Real, P::Vector)>, QueryError>
+where
+    P: Point,
+    M: Isometry<P>,
+    S: Simplex<P>,
+    G: SupportMap<P, M>,
+{
+    do_cast_ray(m, shape, simplex, ray, true)
+}
+
+// Casts a ray on a support map using the GJK algorithm.
+//
+// If `strict` is `false`, the best estimate is returned instead of `QueryError::NoConvergence`.
+fn do_cast_ray<P, M, S, G: ?Sized>(
+    m: &M,
+    shape: &G,
+    simplex: &mut S,
+    ray: &Ray<P>,
+    strict: bool,
+) -> Result<Option<(P::Real, P::Vector)>, QueryError>
 where
     P: Point,
     M: Isometry<P>,
@@ -368,8 +517,19 @@ where
     loop {
         niter = niter + 1;
 
+        if niter > 1000 {
+            if strict {
+                return Err(QueryError::NoConvergence);
+            } else if ltoi.is_zero() {
+                return Ok(None);
+            } else {
+                // The ray is known not to hit the shape before `ltoi`.
+                return Ok(Some((ltoi, ldir)));
+            }
+        }
+
         if dir.normalize_mut().is_zero() {
-            return Some((ltoi, ldir));
+            return Ok(Some((ltoi, ldir)));
         }
 
         let support_point = shape.support_point(m, &dir);
@@ -400,30 +560,39 @@ where
             None => {
                 if na::dot(&dir, &ray.dir) > na::zero() {
                     // miss
-                    return None;
+                    return Ok(None);
                 }
             }
         }
 
         if !simplex.add_point(P::origin() + (support_point - curr_ray.origin)) {
-            return Some((ltoi, dir));
+            return Ok(Some((ltoi, dir)));
         }
 
         let proj = simplex.project_origin_and_reduce().coordinates();
         let max_bound = na::norm_squared(&proj);
 
         if simplex.dimension() == _dimension {
-            return Some((ltoi, ldir));
+            return Ok(Some((ltoi, ldir)));
         } else if max_bound <= _eps_tol * simplex.max_sq_len() {
             // Return ldir: the last projection plane is tangeant to the intersected surface.
-            return Some((ltoi, ldir));
+            return Ok(Some((ltoi, ldir)));
         } else if max_bound >= old_max_bound {
             // use dir instead of proj since this situations means that the new projection is less
             // accurate than the last one (which is stored on dir).
-            return Some((ltoi, dir));
+            return Ok(Some((ltoi, dir)));
         }
 
         old_max_bound = max_bound;
         dir = -proj;
     }
 }
+
+// Panics if a GJK failed, as the infallible versions of the algorithms always did.
+fn unwrap_result<T>(res: Result<T, QueryError>) -> T {
+    match res {
+        Ok(res) => res,
+        Err(QueryError::NoConvergence) => panic!("GJK did not converge."),
+        Err(err) => panic!("GJK failed: {}", err),
+    }
+}
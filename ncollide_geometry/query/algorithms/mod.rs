@@ -15,3 +15,4 @@ pub mod gjk;
 pub mod epa3;
 pub mod epa2;
 pub mod minkowski_sampling;
+pub mod mpr;
@@ -0,0 +1,529 @@
+//! The Minkowski Portal Refinement algorithm, also known as XenoCollide.
+//!
+//! Unlike the GJK algorithm, it does not compute the distance between disjoint shapes. However, it
+//! is usually faster to determine whether two shapes intersect and to approximate their
+//! penetration depth without resorting to the EPA.
+
+use std::mem;
+
+use num::Zero;
+
+use alga::general::{Id, Real};
+use alga::linear::FiniteDimVectorSpace;
+use na::{self, Unit};
+
+use utils;
+use shape::{AnnotatedMinkowskiSum, AnnotatedPoint, MinkowskiSum, Reflection, SupportMap};
+use query::Ray;
+use query::algorithms::gjk;
+use math::Point;
+
+const MAX_ITER: usize = 100;
+
+// Outcome of the MPR algorithm.
+enum MPRResult<P: Point> {
+    // The origin is inside of the shape.
+    Inside,
+    // The origin is outside of the shape.
+    Outside,
+    // The portal crossed by the ray going from the interior point toward the origin, with its
+    // outward unit normal. In 2D, the last vertex is a copy of the second one.
+    Portal([P; 3], Unit<P::Vector>),
+}
+
+/// Tests whether the origin is inside of the given support-mapped shape, using the MPR
+/// algorithm.
+pub fn intersects<P, M, G: ?Sized>(m: &M, shape: &G) -> bool
+where
+    P: Point,
+    G: SupportMap<P, M>,
+{
+    let mut support = |dir: &P::Vector| shape.support_point(m, dir);
+    let center = interior_point(&mut support);
+
+    match mpr(&mut support, &center, true) {
+        MPRResult::Inside => true,
+        _ => false,
+    }
+}
+
+/// Approximates the projection of the origin on the boundary of a shape using the MPR algorithm.
+///
+/// Returns the point where the ray going from an interior point of the shape toward the origin
+/// leaves the shape, and the outward unit normal of the shape at this point. The origin is inside
+/// of the shape iff. it lies behind the returned point along the returned normal.
+pub fn project_origin<P, M, G: ?Sized>(m: &M, shape: &G) -> (P, Unit<P::Vector>)
+where
+    P: Point,
+    G: SupportMap<P, M>,
+{
+    let mut support = |dir: &P::Vector| shape.support_point(m, dir);
+    let center = interior_point(&mut support);
+
+    boundary_point(&mut support, center)
+}
+
+/// Casts a ray on a support-mapped shape using the MPR algorithm.
+///
+/// Returns the time of impact and the outward normal of the shape at the hit point.
+pub fn cast_ray<P, M, G: ?Sized>(m: &M, shape: &G, ray: &Ray<P>) -> Option<(P::Real, P::Vector)>
+where
+    P: Point,
+    G: SupportMap<P, M>,
+{
+    let sqlen = na::norm_squared(&ray.dir);
+
+    if sqlen.is_zero() {
+        return None;
+    }
+
+    let orig = ray.origin.coordinates();
+    let far = shape.support_point(m, &ray.dir).coordinates() - orig;
+    let max_toi = na::dot(&far, &ray.dir) / sqlen;
+
+    if max_toi < na::zero() {
+        // The shape is behind the ray.
+        return None;
+    }
+
+    // The ray hits the shape iff. the origin is inside of the shape translated by the opposite of
+    // the ray origin and swept along the opposite of the ray direction.
+    let sweep = -ray.dir * max_toi;
+    let mut swept = |dir: &P::Vector| {
+        let pt = shape.support_point(m, dir) + (-orig);
+
+        if na::dot(dir, &ray.dir) < na::zero() {
+            pt + sweep
+        } else {
+            pt
+        }
+    };
+
+    let center = interior_point(&mut swept);
+
+    match mpr(&mut swept, &center, true) {
+        MPRResult::Inside => {}
+        _ => return None,
+    }
+
+    // The hit point corresponds to the point where the swept shape is left by the ray starting at
+    // the origin and directed toward `sweep`. The swept shape is shifted so that the origin
+    // becomes a point beyond this exit point.
+    let shift = -sweep + ray.dir;
+    let mut shifted = |dir: &P::Vector| swept(dir) + shift;
+    let (exit, normal) = boundary_point(&mut shifted, shift);
+    let exit_toi = -na::dot(&(exit.coordinates() - shift), &ray.dir) / sqlen;
+    let toi = max_toi - exit_toi;
+
+    if toi > na::zero() {
+        Some((toi, normal.unwrap()))
+    } else {
+        Some((na::zero(), normal.unwrap()))
+    }
+}
+
+/// Tests whether two support-mapped shapes intersect using the MPR algorithm.
+pub fn intersection_test<P, M, G1: ?Sized, G2: ?Sized>(m1: &M, g1: &G1, m2: &M, g2: &G2) -> bool
+where
+    P: Point,
+    G1: SupportMap<P, M>,
+    G2: SupportMap<P, M>,
+{
+    let reflect2 = Reflection::new(g2);
+    let cso = MinkowskiSum::new(m1, g1, m2, &reflect2);
+
+    intersects::<P, _, _>(&Id::new(), &cso)
+}
+
+/// Approximates the penetration of two support-mapped shapes using the MPR algorithm.
+///
+/// Returns a point on each shape and the contact normal oriented toward the exterior of `g1`. The
+/// penetration depth is the dot product between the contact normal and the difference of the
+/// first point with the second one. It is negative if the shapes are disjoint, in which case its
+/// absolute value is only a lower bound of their distance.
+pub fn closest_points<P, M, G1: ?Sized, G2: ?Sized>(
+    m1: &M,
+    g1: &G1,
+    m2: &M,
+    g2: &G2,
+) -> (P, P, Unit<P::Vector>)
+where
+    P: Point,
+    G1: SupportMap<P, M>,
+    G2: SupportMap<P, M>,
+{
+    let reflect2 = Reflection::new(g2);
+    let cso = AnnotatedMinkowskiSum::new(m1, g1, m2, &reflect2);
+    let (p, normal) = project_origin::<AnnotatedPoint<P>, _, _>(&Id::new(), &cso);
+
+    (*p.orig1(), -*p.orig2(), normal)
+}
+
+// Averages extremal points of the shape given by its support function.
+fn interior_point<P, F>(support: &mut F) -> P::Vector
+where
+    P: Point,
+    F: FnMut(&P::Vector) -> P,
+{
+    let dim = na::dimension::<P::Vector>();
+    let mut basis = na::zero::<P::Vector>();
+    let mut center = na::zero::<P::Vector>();
+
+    for i in 0..dim {
+        basis[i] = na::one();
+        center += support(&basis).coordinates();
+        basis[i] = -na::one::<P::Real>();
+        center += support(&basis).coordinates();
+        basis[i] = na::zero();
+    }
+
+    center / na::convert::<f64, P::Real>((2 * dim) as f64)
+}
+
+// Finds the point where the ray going from `center` toward the origin leaves the shape.
+fn boundary_point<P, F>(support: &mut F, mut center: P::Vector) -> (P, Unit<P::Vector>)
+where
+    P: Point,
+    F: FnMut(&P::Vector) -> P,
+{
+    let _eps_tol: P::Real = gjk::eps_tol();
+
+    if na::norm_squared(&center) <= _eps_tol {
+        // Move the interior point so that the ray direction is well defined.
+        center[0] = center[0] + _eps_tol.sqrt();
+    }
+
+    match mpr(support, &center, false) {
+        MPRResult::Portal(portal, normal) => {
+            let a1 = portal[0].coordinates();
+            let dn = -na::dot(&center, normal.as_ref());
+
+            let exit = if dn > _eps_tol {
+                center * (na::one::<P::Real>() - na::dot(&(a1 - center), normal.as_ref()) / dn)
+            } else {
+                a1
+            };
+
+            (interpolate(&portal, &exit), normal)
+        }
+        _ => unreachable!(),
+    }
+}
+
+fn mpr<P, F>(support: &mut F, center: &P::Vector, early_exit: bool) -> MPRResult<P>
+where
+    P: Point,
+    F: FnMut(&P::Vector) -> P,
+{
+    let _eps_tol: P::Real = gjk::eps_tol();
+
+    if early_exit && na::norm_squared(center) <= _eps_tol {
+        return MPRResult::Inside;
+    }
+
+    match na::dimension::<P::Vector>() {
+        2 => mpr2(support, center, early_exit),
+        3 => mpr3(support, center, early_exit),
+        _ => panic!("The MPR algorithm is only implemented for 2D and 3D shapes."),
+    }
+}
+
+fn mpr2<P, F>(support: &mut F, a0: &P::Vector, early_exit: bool) -> MPRResult<P>
+where
+    P: Point,
+    F: FnMut(&P::Vector) -> P,
+{
+    let _eps_tol: P::Real = gjk::eps_tol();
+    let _eps_rel: P::Real = _eps_tol.sqrt();
+    let a0 = *a0;
+    let ray_dir = -a0;
+
+    /*
+     * Portal discovery.
+     */
+    let mut p1 = support(&ray_dir);
+
+    if early_exit && na::dot(&p1.coordinates(), &ray_dir) <= na::zero() {
+        return MPRResult::Outside;
+    }
+
+    let mut side1 = utils::perp2(&ray_dir, &(p1.coordinates() - a0));
+
+    if side1.abs() <= _eps_tol {
+        // The origin lies on the line joining the interior point to `p1`.
+        return degenerate_portal(p1, &ray_dir, early_exit);
+    }
+
+    let mut p2;
+    let mut niter = 0;
+
+    loop {
+        // Search on the side of the line joining the interior point to `p1` containing the
+        // origin.
+        let mut dir = perp(&(p1.coordinates() - a0));
+
+        if na::dot(&dir, &ray_dir) < na::zero() {
+            dir = -dir;
+        }
+
+        p2 = support(&dir);
+
+        if early_exit && na::dot(&p2.coordinates(), &dir) <= na::zero() {
+            return MPRResult::Outside;
+        }
+
+        let side2 = utils::perp2(&ray_dir, &(p2.coordinates() - a0));
+
+        if side1 * side2 <= na::zero() {
+            break;
+        }
+
+        niter += 1;
+        if niter == MAX_ITER {
+            return degenerate_portal(p2, &dir, early_exit);
+        }
+
+        p1 = p2;
+        side1 = side2;
+    }
+
+    /*
+     * Portal refinement.
+     */
+    let mut portal = [p1, p2];
+    niter = 0;
+
+    loop {
+        let a1 = portal[0].coordinates();
+        let a2 = portal[1].coordinates();
+        let mut n = perp(&(a2 - a1));
+
+        if na::dot(&n, &(a1 - a0)) < na::zero() {
+            n = -n;
+        }
+
+        let normal = match Unit::try_new(n, _eps_tol) {
+            Some(normal) => normal,
+            None => return degenerate_portal(portal[0], &ray_dir, early_exit),
+        };
+
+        if early_exit && na::dot(normal.as_ref(), &a1) >= na::zero() {
+            // The origin is behind the portal.
+            return MPRResult::Inside;
+        }
+
+        let p3 = support(normal.as_ref());
+        let a3 = p3.coordinates();
+        let d3 = na::dot(&a3, normal.as_ref());
+
+        if early_exit && d3 < na::zero() {
+            return MPRResult::Outside;
+        }
+
+        if d3 - na::dot(&a1, normal.as_ref()) <= _eps_rel || niter == MAX_ITER {
+            if early_exit {
+                return MPRResult::Outside;
+            } else {
+                return MPRResult::Portal([portal[0], portal[1], portal[1]], normal);
+            }
+        }
+
+        // Replace the vertex lying on the same side of the ray as `p3`.
+        let side1 = utils::perp2(&ray_dir, &(a1 - a0));
+        let side3 = utils::perp2(&ray_dir, &(a3 - a0));
+
+        if side1 * side3 > na::zero() {
+            portal[0] = p3;
+        } else {
+            portal[1] = p3;
+        }
+
+        niter += 1;
+    }
+}
+
+fn mpr3<P, F>(support: &mut F, a0: &P::Vector, early_exit: bool) -> MPRResult<P>
+where
+    P: Point,
+    F: FnMut(&P::Vector) -> P,
+{
+    let _eps_tol: P::Real = gjk::eps_tol();
+    let _eps_rel: P::Real = _eps_tol.sqrt();
+    let a0 = *a0;
+    let ray_dir = -a0;
+
+    /*
+     * Portal discovery.
+     */
+    let mut p1 = support(&ray_dir);
+
+    if early_exit && na::dot(&p1.coordinates(), &ray_dir) <= na::zero() {
+        return MPRResult::Outside;
+    }
+
+    let mut dir = utils::cross3(&a0, &p1.coordinates());
+
+    if na::norm_squared(&dir) <= _eps_tol {
+        // The origin lies on the line joining the interior point to `p1`.
+        return degenerate_portal(p1, &ray_dir, early_exit);
+    }
+
+    let mut p2 = support(&dir);
+
+    if early_exit && na::dot(&p2.coordinates(), &dir) <= na::zero() {
+        return MPRResult::Outside;
+    }
+
+    dir = utils::cross3(&(p1.coordinates() - a0), &(p2.coordinates() - a0));
+
+    if na::dot(&dir, &a0) > na::zero() {
+        mem::swap(&mut p1, &mut p2);
+        dir = -dir;
+    }
+
+    let mut p3;
+    let mut niter = 0;
+
+    loop {
+        if na::norm_squared(&dir) <= _eps_tol {
+            // The interior point, `p1` and `p2` are aligned.
+            return degenerate_portal(p1, &ray_dir, early_exit);
+        }
+
+        p3 = support(&dir);
+        let a3 = p3.coordinates();
+
+        if early_exit && na::dot(&a3, &dir) <= na::zero() {
+            return MPRResult::Outside;
+        }
+
+        niter += 1;
+        if niter == MAX_ITER {
+            break;
+        }
+
+        if na::dot(&utils::cross3(&p1.coordinates(), &a3), &a0) < na::zero() {
+            p2 = p3;
+            dir = utils::cross3(&(p1.coordinates() - a0), &(a3 - a0));
+        } else if na::dot(&utils::cross3(&a3, &p2.coordinates()), &a0) < na::zero() {
+            p1 = p3;
+            dir = utils::cross3(&(a3 - a0), &(p2.coordinates() - a0));
+        } else {
+            break;
+        }
+    }
+
+    /*
+     * Portal refinement.
+     */
+    let mut portal = [p1, p2, p3];
+    niter = 0;
+
+    loop {
+        let a1 = portal[0].coordinates();
+        let a2 = portal[1].coordinates();
+        let a3 = portal[2].coordinates();
+
+        let normal = match Unit::try_new(utils::cross3(&(a2 - a1), &(a3 - a1)), _eps_tol) {
+            Some(normal) => normal,
+            None => return degenerate_portal(portal[0], &ray_dir, early_exit),
+        };
+
+        if early_exit && na::dot(normal.as_ref(), &a1) >= na::zero() {
+            // The origin is behind the portal.
+            return MPRResult::Inside;
+        }
+
+        let p4 = support(normal.as_ref());
+        let a4 = p4.coordinates();
+        let d4 = na::dot(&a4, normal.as_ref());
+
+        if early_exit && d4 < na::zero() {
+            return MPRResult::Outside;
+        }
+
+        if d4 - na::dot(&a1, normal.as_ref()) <= _eps_rel || niter == MAX_ITER {
+            if early_exit {
+                return MPRResult::Outside;
+            } else {
+                return MPRResult::Portal(portal, normal);
+            }
+        }
+
+        // Replace one vertex so that the ray still goes through the portal.
+        let c = utils::cross3(&a4, &a0);
+
+        if na::dot(&a1, &c) > na::zero() {
+            if na::dot(&a2, &c) > na::zero() {
+                portal[0] = p4;
+            } else {
+                portal[2] = p4;
+            }
+        } else if na::dot(&a3, &c) > na::zero() {
+            portal[1] = p4;
+        } else {
+            portal[0] = p4;
+        }
+
+        niter += 1;
+    }
+}
+
+// The portal is reduced to the support point `p` on the direction `dir`.
+fn degenerate_portal<P: Point>(p: P, dir: &P::Vector, early_exit: bool) -> MPRResult<P> {
+    if early_exit {
+        if na::dot(&p.coordinates(), dir) >= na::zero() {
+            MPRResult::Inside
+        } else {
+            MPRResult::Outside
+        }
+    } else {
+        MPRResult::Portal([p, p, p], Unit::new_normalize(*dir))
+    }
+}
+
+// Interpolates the vertices of the portal at the given point of the portal.
+fn interpolate<P: Point>(portal: &[P; 3], pt: &P::Vector) -> P {
+    let _eps_tol: P::Real = gjk::eps_tol();
+    let _1: P::Real = na::one();
+    let a = portal[0].coordinates();
+    let ab = portal[1].coordinates() - a;
+    let ap = *pt - a;
+    let mut res = portal[0];
+
+    if na::dimension::<P::Vector>() == 2 {
+        let sqlen = na::norm_squared(&ab);
+
+        if sqlen > _eps_tol {
+            let t = na::clamp(na::dot(&ap, &ab) / sqlen, na::zero(), _1);
+            res.axpy(t, &portal[1], _1 - t);
+        }
+    } else {
+        let ac = portal[2].coordinates() - a;
+        let d00 = na::dot(&ab, &ab);
+        let d01 = na::dot(&ab, &ac);
+        let d11 = na::dot(&ac, &ac);
+        let d20 = na::dot(&ap, &ab);
+        let d21 = na::dot(&ap, &ac);
+        let denom = d00 * d11 - d01 * d01;
+
+        if denom > _eps_tol {
+            let v = (d11 * d20 - d01 * d21) / denom;
+            let w = (d00 * d21 - d01 * d20) / denom;
+
+            res.axpy(v, &portal[1], _1 - v - w);
+            res.axpy(w, &portal[2], _1);
+        }
+    }
+
+    res
+}
+
+// A vector orthogonal to the 2D vector `v`.
+fn perp<V: FiniteDimVectorSpace>(v: &V) -> V {
+    let mut res = na::zero::<V>();
+    res[0] = -v[1];
+    res[1] = v[0];
+
+    res
+}
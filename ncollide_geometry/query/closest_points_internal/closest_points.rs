@@ -0,0 +1,26 @@
+use std::mem;
+
+/// Closest points information.
+#[derive(Debug, PartialEq, Clone)]
+pub enum ClosestPoints<P> {
+    /// The two objects are intersecting.
+    Intersecting,
+    /// The two objects are non-intersecting but closer than a given distance.
+    ///
+    /// Holds the closest point on the first object and the closest point on the second object,
+    /// both expressed in world space.
+    WithinMargin(P, P),
+    /// The two objects are non-intersecting and further than a given distance.
+    Disjoint,
+}
+
+impl<P: Copy> Copy for ClosestPoints<P> {}
+
+impl<P> ClosestPoints<P> {
+    /// Swaps the two closest points.
+    pub fn flip(&mut self) {
+        if let ClosestPoints::WithinMargin(ref mut p1, ref mut p2) = *self {
+            mem::swap(p1, p2)
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use na::Unit;
+use math::{Isometry, Point};
+use query::{closest_points_internal, ClosestPoints};
+use shape::SupportMap;
+
+/// Closest points between two support-mapped shapes with rounded borders.
+///
+/// The closest points are computed between the core shapes `g1` and `g2` before being moved
+/// toward each other by the border radii `r1` and `r2`. A non-rounded shape has a zero border
+/// radius.
+pub fn round_shape_against_round_shape<P, M, G1: ?Sized, G2: ?Sized>(
+    m1: &M,
+    g1: &G1,
+    r1: P::Real,
+    m2: &M,
+    g2: &G2,
+    r2: P::Real,
+    margin: P::Real,
+) -> ClosestPoints<P>
+where
+    P: Point,
+    M: Isometry<P>,
+    G1: SupportMap<P, M>,
+    G2: SupportMap<P, M>,
+{
+    let margins = r1 + r2;
+
+    match closest_points_internal::support_map_against_support_map(
+        m1,
+        g1,
+        m2,
+        g2,
+        margin + margins,
+    ) {
+        ClosestPoints::WithinMargin(p1, p2) => {
+            let (dir, dist) = Unit::new_and_get(p2 - p1);
+
+            // The cores are disjoint: check whether the borders intersect.
+            if dist <= margins {
+                ClosestPoints::Intersecting
+            } else {
+                ClosestPoints::WithinMargin(p1 + *dir * r1, p2 + (-*dir * r2))
+            }
+        }
+        res => res,
+    }
+}
@@ -0,0 +1,106 @@
+use alga::linear::Translation;
+use na;
+
+use shape::{self, AnnotatedPoint, SupportMap};
+use query::algorithms::gjk::{self, GJKResult};
+use query::algorithms::{Simplex, JohnsonSimplex, VoronoiSimplex2, VoronoiSimplex3};
+use query::ClosestPoints;
+use math::{Isometry, Point};
+
+/// Closest points between support-mapped shapes (`Cuboid`, `ConvexHull`, etc.)
+pub fn support_map_against_support_map<P, M, G1: ?Sized, G2: ?Sized>(
+    m1: &M,
+    g1: &G1,
+    m2: &M,
+    g2: &G2,
+    margin: P::Real,
+) -> ClosestPoints<P>
+where
+    P: Point,
+    M: Isometry<P>,
+    G1: SupportMap<P, M>,
+    G2: SupportMap<P, M>,
+{
+    if na::dimension::<P::Vector>() == 2 {
+        support_map_against_support_map_with_params(
+            m1,
+            g1,
+            m2,
+            g2,
+            margin,
+            &mut VoronoiSimplex2::new(),
+            None,
+        )
+    } else if na::dimension::<P::Vector>() == 3 {
+        support_map_against_support_map_with_params(
+            m1,
+            g1,
+            m2,
+            g2,
+            margin,
+            &mut VoronoiSimplex3::new(),
+            None,
+        )
+    } else {
+        support_map_against_support_map_with_params(
+            m1,
+            g1,
+            m2,
+            g2,
+            margin,
+            &mut JohnsonSimplex::new_w_tls(),
+            None,
+        )
+    }
+}
+
+/// Closest points between support-mapped shapes (`Cuboid`, `ConvexHull`, etc.)
+///
+/// This allows a more fine grained control other the underlying GJK algorigtm.
+pub fn support_map_against_support_map_with_params<P, M, S, G1: ?Sized, G2: ?Sized>(
+    m1: &M,
+    g1: &G1,
+    m2: &M,
+    g2: &G2,
+    margin: P::Real,
+    simplex: &mut S,
+    init_dir: Option<P::Vector>,
+) -> ClosestPoints<P>
+where
+    P: Point,
+    M: Isometry<P>,
+    S: Simplex<AnnotatedPoint<P>>,
+    G1: SupportMap<P, M>,
+    G2: SupportMap<P, M>,
+{
+    assert!(
+        margin >= na::zero(),
+        "The proximity margin must be positive or null."
+    );
+
+    let mut dir = match init_dir {
+        // FIXME: or m2.translation - m1.translation ?
+        None => m1.translation().to_vector() - m2.translation().to_vector(),
+        Some(dir) => dir,
+    };
+
+    if dir == na::zero() {
+        dir[0] = na::one();
+    }
+
+    simplex.reset(shape::cso_support_point(m1, g1, m2, g2, dir));
+
+    match gjk::closest_points_with_max_dist(m1, g1, m2, g2, margin, simplex) {
+        GJKResult::Projection((p1, p2)) => {
+            // The GJK may stop before proving that the shapes are further than `margin`.
+            if na::norm_squared(&(p2 - p1)) <= margin * margin {
+                ClosestPoints::WithinMargin(p1, p2)
+            } else {
+                ClosestPoints::Disjoint
+            }
+        }
+        GJKResult::Intersection => ClosestPoints::Intersecting,
+        GJKResult::NoIntersection(_) => ClosestPoints::Disjoint,
+        GJKResult::Proximity(_) => unreachable!(),
+    }
+}
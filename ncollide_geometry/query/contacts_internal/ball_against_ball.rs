@@ -5,7 +5,7 @@ use alga::linear::FiniteDimVectorSpace;
 use na::{self, Unit};
 use math::Point;
 use query::Contact;
-use shape::Ball;
+use shape::{Ball, FeatureId};
 
 /// Contact between balls.
 #[inline]
@@ -33,11 +33,13 @@ where
             normal = Unit::new_unchecked(P::Vector::canonical_basis_element(0));
         }
 
-        Some(Contact::new(
+        Some(Contact::new_with_features(
             *center1 + *normal * r1,
             *center2 + (-*normal * r2),
             normal,
             sum_radius - distance_squared.sqrt(),
+            FeatureId::Vertex(0),
+            FeatureId::Vertex(0),
         ))
     } else {
         None
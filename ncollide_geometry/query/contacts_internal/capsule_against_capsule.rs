@@ -0,0 +1,196 @@
+use num::Zero;
+use approx::ApproxEq;
+
+use alga::general::Real;
+use alga::linear::{FiniteDimVectorSpace, Translation};
+use na::{self, Unit};
+use math::{Isometry, Point};
+use query::Contact;
+use shape::{Ball, Capsule};
+
+/// Contact between two capsules.
+#[inline]
+pub fn capsule_against_capsule<P, M>(
+    m1: &M,
+    c1: &Capsule<P::Real>,
+    m2: &M,
+    c2: &Capsule<P::Real>,
+    prediction: P::Real,
+) -> Option<Contact<P>>
+where
+    P: Point,
+    M: Isometry<P>,
+{
+    let (a1, b1) = capsule_segment(m1, c1);
+    let (a2, b2) = capsule_segment(m2, c2);
+    let (p1, p2) = closest_points_segment_segment(&a1, &b1, &a2, &b2);
+
+    rounded_points_contact(&p1, c1.radius(), &p2, c2.radius(), prediction)
+}
+
+/// Contact between a capsule and a ball.
+#[inline]
+pub fn capsule_against_ball<P, M>(
+    m1: &M,
+    c1: &Capsule<P::Real>,
+    m2: &M,
+    b2: &Ball<P::Real>,
+    prediction: P::Real,
+) -> Option<Contact<P>>
+where
+    P: Point,
+    M: Isometry<P>,
+{
+    let (a1, b1) = capsule_segment(m1, c1);
+    let center2 = P::from_coordinates(m2.translation().to_vector());
+    let p1 = closest_point_on_segment(&a1, &b1, &center2);
+
+    rounded_points_contact(&p1, c1.radius(), &center2, b2.radius(), prediction)
+}
+
+/// Contact between a ball and a capsule.
+#[inline]
+pub fn ball_against_capsule<P, M>(
+    m1: &M,
+    b1: &Ball<P::Real>,
+    m2: &M,
+    c2: &Capsule<P::Real>,
+    prediction: P::Real,
+) -> Option<Contact<P>>
+where
+    P: Point,
+    M: Isometry<P>,
+{
+    let mut res = capsule_against_ball(m2, c2, m1, b1, prediction);
+
+    if let Some(ref mut c) = res {
+        c.flip()
+    }
+
+    res
+}
+
+/// The world-space extremities of the segment at the core of a capsule.
+fn capsule_segment<P, M>(m: &M, capsule: &Capsule<P::Real>) -> (P, P)
+where
+    P: Point,
+    M: Isometry<P>,
+{
+    let mut a = P::origin();
+    let mut b = P::origin();
+
+    a[1] = -capsule.half_height();
+    b[1] = capsule.half_height();
+
+    (m.transform_point(&a), m.transform_point(&b))
+}
+
+/// Contact between two balls of radii `r1` and `r2` centered at `p1` and `p2`.
+fn rounded_points_contact<P: Point>(
+    p1: &P,
+    r1: P::Real,
+    p2: &P,
+    r2: P::Real,
+    prediction: P::Real,
+) -> Option<Contact<P>> {
+    let delta_pos = *p2 - *p1;
+    let distance_squared = na::norm_squared(&delta_pos);
+    let sum_radius = r1 + r2;
+    let sum_radius_with_error = sum_radius + prediction;
+
+    if distance_squared < sum_radius_with_error * sum_radius_with_error {
+        let mut normal = Unit::new_normalize(delta_pos);
+
+        if distance_squared.is_zero() {
+            normal = Unit::new_unchecked(P::Vector::canonical_basis_element(0));
+        }
+
+        Some(Contact::new(
+            *p1 + *normal * r1,
+            *p2 + (-*normal * r2),
+            normal,
+            sum_radius - distance_squared.sqrt(),
+        ))
+    } else {
+        None
+    }
+}
+
+/// Projects `pt` on the segment `[a, b]`.
+fn closest_point_on_segment<P: Point>(a: &P, b: &P, pt: &P) -> P {
+    let ab = *b - *a;
+    let sqnab = na::norm_squared(&ab);
+
+    if sqnab.is_zero() {
+        return *a;
+    }
+
+    let t = clamp01(na::dot(&ab, &(*pt - *a)) / sqnab);
+
+    *a + ab * t
+}
+
+/// Computes the pair of closest points between the segments `[a1, b1]` and `[a2, b2]`.
+///
+/// If the segments are parallel, the closest points located at the middle of their overlapping
+/// part are returned.
+fn closest_points_segment_segment<P: Point>(a1: &P, b1: &P, a2: &P, b2: &P) -> (P, P) {
+    let _eps = P::Real::default_epsilon();
+    let _0 = na::zero::<P::Real>();
+    let _1 = na::one::<P::Real>();
+
+    let d1 = *b1 - *a1;
+    let d2 = *b2 - *a2;
+    let r = *a1 - *a2;
+
+    let a = na::norm_squared(&d1);
+    let e = na::norm_squared(&d2);
+    let f = na::dot(&d2, &r);
+
+    let mut s;
+    let mut t;
+
+    if a <= _eps && e <= _eps {
+        s = _0;
+        t = _0;
+    } else if a <= _eps {
+        s = _0;
+        t = clamp01(f / e);
+    } else {
+        let c = na::dot(&d1, &r);
+
+        if e <= _eps {
+            t = _0;
+            s = clamp01(-c / a);
+        } else {
+            let b = na::dot(&d1, &d2);
+            let denom = a * e - b * b;
+
+            if denom > _eps * a * e {
+                s = clamp01((b * f - c * e) / denom);
+            } else {
+                // Parallel segments: pick the middle of the overlapping range, if any.
+                let s0 = clamp01(-c / a);
+                let s1 = clamp01((b - c) / a);
+                s = (s0 + s1) * na::convert(0.5f64);
+            }
+
+            t = (b * s + f) / e;
+
+            if t < _0 {
+                t = _0;
+                s = clamp01(-c / a);
+            } else if t > _1 {
+                t = _1;
+                s = clamp01((b - c) / a);
+            }
+        }
+    }
+
+    (*a1 + d1 * s, *a2 + d2 * t)
+}
+
+#[inline]
+fn clamp01<N: Real>(x: N) -> N {
+    na::clamp(x, na::zero(), na::one())
+}
@@ -2,7 +2,7 @@ use na;
 use partitioning::BoundingVolumeInterferencesCollector;
 use bounding_volume::BoundingVolume;
 use shape::{CompositeShape, Shape};
-use query::Contact;
+use query::{Contact, QueryError};
 use query::contacts_internal;
 use math::{Isometry, Point};
 
@@ -29,7 +29,7 @@ pub fn manifold_composite_shape_against_shape<N, P, V, AV, M, G1, G2>(
 
     {
         let mut visitor = BoundingVolumeInterferencesCollector::new(&ls_aabb2, &mut interferences);
-        g1.bvt().visit(&mut visitor);
+        g1.bvh().visit(&mut visitor);
     }
 
     for i in interferences.into_iter() {
@@ -83,7 +83,7 @@ where
 
     {
         let mut visitor = BoundingVolumeInterferencesCollector::new(&ls_aabb2, &mut interferences);
-        g1.bvt().visit(&mut visitor);
+        g1.bvh().visit(&mut visitor);
     }
 
     let mut res = None::<Contact<P>>;
@@ -96,7 +96,9 @@ where
             g2,
             prediction,
         ) {
-            Some(c) => {
+            Some(mut c) => {
+                c.part1 = Some(i);
+
                 let replace = match res {
                     Some(ref cbest) => c.depth > cbest.depth,
                     None => true,
@@ -134,3 +136,83 @@ where
 
     res
 }
+
+/// Best contact between a composite shape (`Mesh`, `Compound`) and any other shape.
+///
+/// Returns the first error reported by the contact computation between a part of `g1` and `g2`.
+pub fn try_composite_shape_against_shape<P, M, G1: ?Sized>(
+    m1: &M,
+    g1: &G1,
+    m2: &M,
+    g2: &Shape<P, M>,
+    prediction: P::Real,
+) -> Result<Option<Contact<P>>, QueryError>
+where
+    P: Point,
+    M: Isometry<P>,
+    G1: CompositeShape<P, M>,
+{
+    let ls_m2 = na::inverse(m1) * m2.clone();
+    let ls_aabb2 = g2.aabb(&ls_m2).loosened(prediction);
+
+    let mut interferences = Vec::new();
+
+    {
+        let mut visitor = BoundingVolumeInterferencesCollector::new(&ls_aabb2, &mut interferences);
+        g1.bvh().visit(&mut visitor);
+    }
+
+    let mut res = None::<Contact<P>>;
+    let mut error = None;
+
+    for i in interferences.into_iter() {
+        g1.map_part_at(i, &mut |_, part| {
+            match contacts_internal::try_contact_internal(m1, part, m2, g2, prediction) {
+                Ok(Some(mut c)) => {
+                    c.part1 = Some(i);
+
+                    let replace = match res {
+                        Some(ref cbest) => c.depth > cbest.depth,
+                        None => true,
+                    };
+
+                    if replace {
+                        res = Some(c)
+                    }
+                }
+                Ok(None) => {}
+                Err(err) => error = Some(err),
+            }
+        });
+
+        if let Some(err) = error {
+            return Err(err);
+        }
+    }
+
+    Ok(res)
+}
+
+/// Best contact between a shape and a composite (`Mesh`, `Compound`) shape.
+///
+/// Returns the first error reported by the contact computation between `g1` and a part of `g2`.
+pub fn try_shape_against_composite_shape<P, M, G2: ?Sized>(
+    m1: &M,
+    g1: &Shape<P, M>,
+    m2: &M,
+    g2: &G2,
+    prediction: P::Real,
+) -> Result<Option<Contact<P>>, QueryError>
+where
+    P: Point,
+    M: Isometry<P>,
+    G2: CompositeShape<P, M>,
+{
+    let mut res = try_composite_shape_against_shape(m2, g2, m1, g1, prediction)?;
+
+    for c in res.iter_mut() {
+        c.flip()
+    }
+
+    Ok(res)
+}
@@ -1,5 +1,6 @@
 use std::mem;
 use math::Point;
+use shape::FeatureId;
 
 use na::{Real, Unit};
 
@@ -17,26 +18,97 @@ pub struct Contact<P: Point> {
 
     /// Penetration depth
     pub depth: P::Real,
+
+    /// The feature of the first object containing `world1`.
+    ///
+    /// Together with `feature2`, `part1`, and `part2`, this identifies the contact so that it can
+    /// be matched with the contacts of the previous frames.
+    ///
+    /// This is `FeatureId::Unknown` if both features may touch at several points, e.g., if they
+    /// are two faces, since they do not identify the contact then.
+    pub feature1: FeatureId,
+
+    /// The feature of the second object containing `world2`.
+    pub feature2: FeatureId,
+
+    /// The index of the part of the first object in contact, if it is a composite shape.
+    pub part1: Option<usize>,
+
+    /// The index of the part of the second object in contact, if it is a composite shape.
+    pub part2: Option<usize>,
 }
 
 impl<P: Point> Contact<P> {
     /// Creates a new contact.
     #[inline]
     pub fn new(world1: P, world2: P, normal: Unit<P::Vector>, depth: P::Real) -> Contact<P> {
+        Contact::new_with_features(
+            world1,
+            world2,
+            normal,
+            depth,
+            FeatureId::Unknown,
+            FeatureId::Unknown,
+        )
+    }
+
+    /// Creates a new contact between the given features.
+    #[inline]
+    pub fn new_with_features(
+        world1: P,
+        world2: P,
+        normal: Unit<P::Vector>,
+        depth: P::Real,
+        feature1: FeatureId,
+        feature2: FeatureId,
+    ) -> Contact<P> {
         Contact {
             world1: world1,
             world2: world2,
             normal: normal,
             depth: depth,
+            feature1: feature1,
+            feature2: feature2,
+            part1: None,
+            part2: None,
         }
     }
+
+    /// Whether this contact and `other` have the same features and parts.
+    ///
+    /// Contacts with unknown features are never considered identical.
+    #[inline]
+    pub fn has_same_features(&self, other: &Contact<P>) -> bool {
+        self.feature1 != FeatureId::Unknown && self.feature2 != FeatureId::Unknown
+            && self.feature1 == other.feature1 && self.feature2 == other.feature2
+            && self.part1 == other.part1 && self.part2 == other.part2
+    }
 }
 
 impl<P: Point> Contact<P> {
-    /// Reverts the contact normal and swaps `world1` and `world2`.
+    // Sets the features of this contact if they touch at a single point, i.e., if one of them is
+    // a vertex. Other pairs of features, e.g., two faces, may touch at several points so they do
+    // not identify a contact and the features are left unchanged.
+    #[inline]
+    pub(crate) fn set_features_if_unique(&mut self, feature1: FeatureId, feature2: FeatureId) {
+        let is_vertex = |f: FeatureId| match f {
+            FeatureId::Vertex(_) => true,
+            _ => false,
+        };
+
+        if is_vertex(feature1) || is_vertex(feature2) {
+            self.feature1 = feature1;
+            self.feature2 = feature2;
+        }
+    }
+
+    /// Reverts the contact normal and swaps `world1` and `world2`, along with the features and
+    /// parts.
     #[inline]
     pub fn flip(&mut self) {
         mem::swap(&mut self.world1, &mut self.world2);
+        mem::swap(&mut self.feature1, &mut self.feature2);
+        mem::swap(&mut self.part1, &mut self.part2);
         self.normal = -self.normal;
     }
 }
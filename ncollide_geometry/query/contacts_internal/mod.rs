@@ -2,18 +2,37 @@
 
 pub use self::contact::{Contact, ContactPrediction};
 pub use self::ball_against_ball::ball_against_ball;
-pub use self::support_map_against_support_map::support_map_against_support_map;
-pub use self::support_map_against_support_map::support_map_against_support_map_with_params;
+pub use self::capsule_against_capsule::{ball_against_capsule, capsule_against_ball,
+                                        capsule_against_capsule};
+pub use self::support_map_against_support_map::{support_map_against_support_map,
+                                                try_support_map_against_support_map};
+pub use self::support_map_against_support_map::{support_map_against_support_map_with_params,
+                                                try_support_map_against_support_map_with_params};
+pub use self::support_map_against_support_map::support_map_against_support_map_mpr;
 pub use self::plane_against_support_map::{plane_against_support_map, support_map_against_plane};
+pub use self::polytope_against_polytope::{polytope_against_polytope,
+                                          polytope_against_polytope_with_cache, SeparatingAxis};
+pub use self::support_area_against_support_area::support_area_against_support_area;
+pub use self::torus_against_support_map::{support_map_against_torus, torus_against_support_map};
+pub use self::round_shape_against_round_shape::{round_shape_against_round_shape,
+                                                try_round_shape_against_round_shape};
 pub use self::shape_against_shape::shape_against_shape as contact_internal;
+pub use self::shape_against_shape::try_shape_against_shape as try_contact_internal;
 pub use self::composite_shape_against_shape::{composite_shape_against_shape,
-                                              shape_against_composite_shape};
+                                              shape_against_composite_shape,
+                                              try_composite_shape_against_shape,
+                                              try_shape_against_composite_shape};
 // pub use self::generate_contact_manifold::generate_contact_manifold;
 
 mod contact;
 mod ball_against_ball;
+mod capsule_against_capsule;
 mod support_map_against_support_map;
 mod plane_against_support_map;
+mod polytope_against_polytope;
+mod support_area_against_support_area;
+pub(crate) mod torus_against_support_map;
+mod round_shape_against_round_shape;
 mod shape_against_shape;
 mod composite_shape_against_shape;
 // mod generate_contact_manifold;
@@ -1,7 +1,7 @@
 use alga::linear::Translation;
 use na::{self, Unit};
 use query::Contact;
-use shape::{Plane, SupportMap};
+use shape::{FeatureId, Plane, SupportMap};
 use math::{Isometry, Point};
 
 /// Contact between a plane and a support-mapped shape (Cuboid, ConvexHull, etc.)
@@ -25,8 +25,12 @@ where
 
     if distance > -prediction {
         let c1 = deepest + plane_normal * distance;
+        let normal = Unit::new_unchecked(plane_normal);
+        let feature2 = other.support_feature_id_toward(mother, &-normal);
+        let mut contact = Contact::new(c1, deepest, normal, distance);
+        contact.set_features_if_unique(FeatureId::Face(0), feature2);
 
-        Some(Contact::new(c1, deepest, Unit::new_unchecked(plane_normal), distance))
+        Some(contact)
     } else {
         None
     }
@@ -0,0 +1,298 @@
+use na::{self, Unit};
+use utils;
+use query::Contact;
+use query::algorithms::gjk;
+use query::contacts_internal;
+use query::contacts_internal::support_area_against_support_area::{clip_support_area,
+                                                                   set_clipped_features};
+use shape::{ConvexPolytope, FeatureId, SupportAreaFeatures};
+use math::{Isometry, Point, Vector};
+
+/// Contact manifold between two convex polytopes (`Cuboid`, `ConvexPolyhedron`, etc.) based on
+/// the separating axis theorem.
+///
+/// The face normals and, in 3D, the cross products of the edge directions of both polytopes are
+/// tested as separating axes. If the axis of minimal penetration is a face normal, the most
+/// anti-parallel face of the other polytope is clipped against this reference face and each
+/// vertex of the clipped face yields a contact. Otherwise, the polytopes touch along two edges
+/// and a single contact is computed with the GJK and EPA algorithms.
+///
+/// The contacts are pushed to `out`. Nothing is pushed if the polytopes are separated by more
+/// than `prediction`.
+pub fn polytope_against_polytope<P, M>(
+    m1: &M,
+    g1: &ConvexPolytope<P, M>,
+    m2: &M,
+    g2: &ConvexPolytope<P, M>,
+    prediction: P::Real,
+    out: &mut Vec<Contact<P>>,
+) where
+    P: Point,
+    M: Isometry<P>,
+{
+    let mut axis = SeparatingAxis::Unknown;
+    polytope_against_polytope_with_cache(m1, g1, m2, g2, prediction, &mut axis, out)
+}
+
+/// Same as `polytope_against_polytope` but tests the separating axis `axis` first.
+///
+/// If the polytopes are separated by more than `prediction` along `axis`, no other axis is
+/// tested. Otherwise, `axis` is set to the axis of largest separation, which is likely to still
+/// separate the polytopes at the next call if they move continuously.
+pub fn polytope_against_polytope_with_cache<P, M>(
+    m1: &M,
+    g1: &ConvexPolytope<P, M>,
+    m2: &M,
+    g2: &ConvexPolytope<P, M>,
+    prediction: P::Real,
+    axis: &mut SeparatingAxis,
+    out: &mut Vec<Contact<P>>,
+) where
+    P: Point,
+    M: Isometry<P>,
+{
+    if let Some(sep) = axis_separation(m1, g1, m2, g2, *axis) {
+        if sep > prediction {
+            return;
+        }
+    }
+
+    let (face1, sep1) = max_face_separation(m1, g1, m2, g2);
+    *axis = SeparatingAxis::Face1(face1);
+
+    if sep1 > prediction {
+        return;
+    }
+
+    let (face2, sep2) = max_face_separation(m2, g2, m1, g1);
+
+    if sep2 > sep1 {
+        *axis = SeparatingAxis::Face2(face2);
+    }
+
+    if sep2 > prediction {
+        return;
+    }
+
+    let (edges, sep_edges) = max_edge_separation(m1, g1, m2, g2);
+
+    if sep_edges > sep1 && sep_edges > sep2 {
+        *axis = edges;
+    }
+
+    if sep_edges > prediction {
+        return;
+    }
+
+    // Favor face contacts which yield more stable manifolds.
+    let _eps_tol: P::Real = gjk::eps_tol();
+
+    if sep_edges > sep1 + _eps_tol && sep_edges > sep2 + _eps_tol {
+        let contact =
+            contacts_internal::support_map_against_support_map(m1, g1, m2, g2, prediction);
+
+        if let Some(contact) = contact {
+            out.push(contact)
+        }
+    } else if sep2 > sep1 + _eps_tol {
+        let start = out.len();
+
+        clip_faces(m2, g2, face2, m1, g1, prediction, out);
+
+        for contact in out[start..].iter_mut() {
+            contact.flip();
+        }
+    } else {
+        clip_faces(m1, g1, face1, m2, g2, prediction, out)
+    }
+}
+
+/// An axis tested for the separation of two polytopes, cached between calls to
+/// `polytope_against_polytope_with_cache`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SeparatingAxis {
+    /// No axis is known.
+    Unknown,
+    /// The normal of the given face of the first polytope.
+    Face1(usize),
+    /// The opposite of the normal of the given face of the second polytope.
+    Face2(usize),
+    /// The cross product of the given edge directions of the first and second polytopes, negated
+    /// if the boolean is `true`.
+    Edges(usize, usize, bool),
+}
+
+// Separation of the polytopes along the given cached axis, if it is still valid.
+fn axis_separation<P, M>(
+    m1: &M,
+    g1: &ConvexPolytope<P, M>,
+    m2: &M,
+    g2: &ConvexPolytope<P, M>,
+    axis: SeparatingAxis,
+) -> Option<P::Real>
+where
+    P: Point,
+    M: Isometry<P>,
+{
+    match axis {
+        SeparatingAxis::Face1(i) if i < g1.nfaces() => {
+            Some(separation(m1, g1, m2, g2, g1.face_normal(m1, i).as_ref()))
+        }
+        SeparatingAxis::Face2(i) if i < g2.nfaces() => {
+            Some(separation(m2, g2, m1, g1, g2.face_normal(m2, i).as_ref()))
+        }
+        SeparatingAxis::Edges(i, j, negate) if i < g1.nedge_dirs() && j < g2.nedge_dirs() => {
+            let dir1 = g1.edge_dir(m1, i);
+            let dir2 = g2.edge_dir(m2, j);
+
+            edges_axis(&dir1, &dir2, negate).map(|axis| separation(m1, g1, m2, g2, &axis))
+        }
+        _ => None,
+    }
+}
+
+// The unit cross product of two edge directions, negated if `negate` is `true`. Returns `None` if
+// those edges are parallel.
+#[inline]
+fn edges_axis<V: Vector>(dir1: &Unit<V>, dir2: &Unit<V>, negate: bool) -> Option<V> {
+    let _eps_tol: V::Real = gjk::eps_tol();
+    let axis = utils::cross3(dir1.as_ref(), dir2.as_ref());
+
+    Unit::try_new(axis, _eps_tol).map(|axis| if negate { -*axis } else { *axis })
+}
+
+// Separation of the polytopes along `axis`, oriented from `g1` toward `g2`.
+#[inline]
+fn separation<P, M>(
+    m1: &M,
+    g1: &ConvexPolytope<P, M>,
+    m2: &M,
+    g2: &ConvexPolytope<P, M>,
+    axis: &P::Vector,
+) -> P::Real
+where
+    P: Point,
+    M: Isometry<P>,
+{
+    let support1 = g1.support_point(m1, axis);
+    let support2 = g2.support_point(m2, &-*axis);
+
+    na::dot(axis, &(support2 - support1))
+}
+
+// The face of `g1` with the largest separation along its normal.
+fn max_face_separation<P, M>(
+    m1: &M,
+    g1: &ConvexPolytope<P, M>,
+    m2: &M,
+    g2: &ConvexPolytope<P, M>,
+) -> (usize, P::Real)
+where
+    P: Point,
+    M: Isometry<P>,
+{
+    let mut best = 0;
+    let mut best_sep = separation(m1, g1, m2, g2, g1.face_normal(m1, 0).as_ref());
+
+    for i in 1..g1.nfaces() {
+        let sep = separation(m1, g1, m2, g2, g1.face_normal(m1, i).as_ref());
+
+        if sep > best_sep {
+            best = i;
+            best_sep = sep;
+        }
+    }
+
+    (best, best_sep)
+}
+
+// The largest separation along the cross products of the edge directions of both polytopes.
+fn max_edge_separation<P, M>(
+    m1: &M,
+    g1: &ConvexPolytope<P, M>,
+    m2: &M,
+    g2: &ConvexPolytope<P, M>,
+) -> (SeparatingAxis, P::Real)
+where
+    P: Point,
+    M: Isometry<P>,
+{
+    let mut best = SeparatingAxis::Unknown;
+    let mut best_sep = -P::Real::max_value();
+
+    for i in 0..g1.nedge_dirs() {
+        let dir1 = g1.edge_dir(m1, i);
+
+        for j in 0..g2.nedge_dirs() {
+            let dir2 = g2.edge_dir(m2, j);
+
+            // Skip the parallel edges.
+            if let Some(axis) = edges_axis(&dir1, &dir2, false) {
+                for &(axis, negate) in [(axis, false), (-axis, true)].iter() {
+                    let sep = separation(m1, g1, m2, g2, &axis);
+
+                    if sep > best_sep {
+                        best = SeparatingAxis::Edges(i, j, negate);
+                        best_sep = sep;
+                    }
+                }
+            }
+        }
+    }
+
+    (best, best_sep)
+}
+
+// Clips the face of `gi` the most anti-parallel to the face `ref_face` of `gr` against the side
+// planes of the latter. `gr` is the first shape of the contacts pushed to `out`.
+//
+// Each contact is identified by the features it originates from: a vertex of the incident face
+// and the reference face, an edge of each face, or a vertex of the reference face and the incident
+// face. In 2D, the faces are edges so the contacts created by the clipping lie on the incident face
+// below a vertex of the reference face.
+fn clip_faces<P, M>(
+    mr: &M,
+    gr: &ConvexPolytope<P, M>,
+    ref_face: usize,
+    mi: &M,
+    gi: &ConvexPolytope<P, M>,
+    prediction: P::Real,
+    out: &mut Vec<Contact<P>>,
+) where
+    P: Point,
+    M: Isometry<P>,
+{
+    let normal = gr.face_normal(mr, ref_face);
+    let inc_face = gi.support_face_toward(mi, &-normal);
+    let start = out.len();
+
+    let mut ref_pts = Vec::new();
+    let mut inc_pts = Vec::new();
+    let mut features = Vec::new();
+
+    gr.face(mr, ref_face, &mut ref_pts);
+    gi.face(mi, inc_face, &mut inc_pts);
+
+    let origin = ref_pts[0];
+    clip_support_area(
+        &ref_pts,
+        &origin,
+        &normal,
+        &inc_pts,
+        prediction,
+        out,
+        &mut features,
+    );
+
+    let mut ref_features = SupportAreaFeatures::new();
+    let mut inc_features = SupportAreaFeatures::new();
+
+    gr.face_vertex_ids(ref_face, &mut ref_features.vertices);
+    gr.face_edge_ids(ref_face, &mut ref_features.edges);
+    ref_features.area = FeatureId::Face(ref_face);
+    gi.face_vertex_ids(inc_face, &mut inc_features.vertices);
+    gi.face_edge_ids(inc_face, &mut inc_features.edges);
+    inc_features.area = FeatureId::Face(inc_face);
+
+    set_clipped_features(&ref_features, &inc_features, &features, &mut out[start..]);
+}
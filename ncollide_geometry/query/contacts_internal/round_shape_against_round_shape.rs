@@ -0,0 +1,62 @@
+use math::{Isometry, Point};
+use query::{Contact, QueryError};
+use query::contacts_internal;
+use shape::SupportMap;
+
+/// Contact between two support-mapped shapes with rounded borders.
+///
+/// The contact is computed between the core shapes `g1` and `g2` before being shifted by the
+/// border radii `r1` and `r2`. A non-rounded shape has a zero border radius.
+pub fn round_shape_against_round_shape<P, M, G1: ?Sized, G2: ?Sized>(
+    m1: &M,
+    g1: &G1,
+    r1: P::Real,
+    m2: &M,
+    g2: &G2,
+    r2: P::Real,
+    prediction: P::Real,
+) -> Option<Contact<P>>
+where
+    P: Point,
+    M: Isometry<P>,
+    G1: SupportMap<P, M>,
+    G2: SupportMap<P, M>,
+{
+    let margins = r1 + r2;
+
+    contacts_internal::support_map_against_support_map(m1, g1, m2, g2, prediction + margins)
+        .map(|c| add_borders(c, r1, r2))
+}
+
+/// Contact between two support-mapped shapes with rounded borders.
+///
+/// Returns an error if the contact between the core shapes could not be computed.
+pub fn try_round_shape_against_round_shape<P, M, G1: ?Sized, G2: ?Sized>(
+    m1: &M,
+    g1: &G1,
+    r1: P::Real,
+    m2: &M,
+    g2: &G2,
+    r2: P::Real,
+    prediction: P::Real,
+) -> Result<Option<Contact<P>>, QueryError>
+where
+    P: Point,
+    M: Isometry<P>,
+    G1: SupportMap<P, M>,
+    G2: SupportMap<P, M>,
+{
+    let margins = r1 + r2;
+    let prediction = prediction + margins;
+    let res = contacts_internal::try_support_map_against_support_map(m1, g1, m2, g2, prediction)?;
+
+    Ok(res.map(|c| add_borders(c, r1, r2)))
+}
+
+fn add_borders<P: Point>(mut c: Contact<P>, r1: P::Real, r2: P::Real) -> Contact<P> {
+    c.world1 = c.world1 + *c.normal * r1;
+    c.world2 = c.world2 + (-*c.normal * r2);
+    c.depth = c.depth + r1 + r2;
+
+    c
+}
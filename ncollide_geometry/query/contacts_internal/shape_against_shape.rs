@@ -1,7 +1,7 @@
 use alga::linear::Translation;
 use math::{Isometry, Point};
-use shape::{Ball, Plane, Shape};
-use query::contacts_internal;
+use shape::{Ball, Capsule, Plane, Shape, Torus};
+use query::{contacts_internal, query_error, query_registry, QueryError};
 use query::contacts_internal::Contact;
 
 /// Computes one contact point between two shapes.
@@ -18,25 +18,131 @@ where
     P: Point,
     M: Isometry<P>,
 {
-    if let (Some(b1), Some(b2)) = (
+    match dispatch(m1, g1, m2, g2, prediction, false) {
+        Ok(res) => res,
+        Err(QueryError::UnsupportedShapePair) => {
+            panic!(
+                "No algorithm known to compute a contact point between the given pair of shapes."
+            )
+        }
+        Err(err) => panic!("Contact computation failed: {}", err),
+    }
+}
+
+/// Computes one contact point between two shapes.
+///
+/// Returns `Ok(None)` if the objects are separated by a distance greater than `prediction`, and
+/// an error if the contact could not be computed.
+pub fn try_shape_against_shape<P, M>(
+    m1: &M,
+    g1: &Shape<P, M>,
+    m2: &M,
+    g2: &Shape<P, M>,
+    prediction: P::Real,
+) -> Result<Option<Contact<P>>, QueryError>
+where
+    P: Point,
+    M: Isometry<P>,
+{
+    query_error::check_isometries::<P, M>(m1, m2)?;
+    query_error::check_real::<P>(prediction)?;
+
+    dispatch(m1, g1, m2, g2, prediction, true)
+}
+
+// If `strict` is `false`, the inaccurate results of algorithms that did not converge are used
+// instead of being reported as errors.
+fn dispatch<P, M>(
+    m1: &M,
+    g1: &Shape<P, M>,
+    m2: &M,
+    g2: &Shape<P, M>,
+    prediction: P::Real,
+    strict: bool,
+) -> Result<Option<Contact<P>>, QueryError>
+where
+    P: Point,
+    M: Isometry<P>,
+{
+    if let Some(res) = query_registry::registered_contact(m1, g1, m2, g2, prediction) {
+        Ok(res)
+    } else if let (Some(b1), Some(b2)) = (
         g1.as_shape::<Ball<P::Real>>(),
         g2.as_shape::<Ball<P::Real>>(),
     ) {
         let p1 = P::from_coordinates(m1.translation().to_vector());
         let p2 = P::from_coordinates(m2.translation().to_vector());
 
-        contacts_internal::ball_against_ball(&p1, b1, &p2, b2, prediction)
+        Ok(contacts_internal::ball_against_ball(&p1, b1, &p2, b2, prediction))
+    } else if let (Some(c1), Some(c2)) = (
+        g1.as_shape::<Capsule<P::Real>>(),
+        g2.as_shape::<Capsule<P::Real>>(),
+    ) {
+        Ok(contacts_internal::capsule_against_capsule(m1, c1, m2, c2, prediction))
+    } else if let (Some(c1), Some(b2)) = (
+        g1.as_shape::<Capsule<P::Real>>(),
+        g2.as_shape::<Ball<P::Real>>(),
+    ) {
+        Ok(contacts_internal::capsule_against_ball(m1, c1, m2, b2, prediction))
+    } else if let (Some(b1), Some(c2)) = (
+        g1.as_shape::<Ball<P::Real>>(),
+        g2.as_shape::<Capsule<P::Real>>(),
+    ) {
+        Ok(contacts_internal::ball_against_capsule(m1, b1, m2, c2, prediction))
     } else if let (Some(p1), Some(s2)) = (g1.as_shape::<Plane<P::Vector>>(), g2.as_support_map()) {
-        contacts_internal::plane_against_support_map(m1, p1, m2, s2, prediction)
+        Ok(contacts_internal::plane_against_support_map(m1, p1, m2, s2, prediction))
     } else if let (Some(s1), Some(p2)) = (g1.as_support_map(), g2.as_shape::<Plane<P::Vector>>()) {
-        contacts_internal::support_map_against_plane(m1, s1, m2, p2, prediction)
+        Ok(contacts_internal::support_map_against_plane(m1, s1, m2, p2, prediction))
+    } else if let (Some(t1), Some(s2)) = (g1.as_shape::<Torus<P::Real>>(), g2.as_support_map()) {
+        Ok(contacts_internal::torus_against_support_map(m1, t1, m2, s2, prediction))
+    } else if let (Some(s1), Some(t2)) = (g1.as_support_map(), g2.as_shape::<Torus<P::Real>>()) {
+        Ok(contacts_internal::support_map_against_torus(m1, s1, m2, t2, prediction))
+    } else if (g1.is_round_shape() && g2.is_support_map())
+        || (g1.is_support_map() && g2.is_round_shape())
+    {
+        let (s1, r1) = g1.as_support_map_with_border().unwrap();
+        let (s2, r2) = g2.as_support_map_with_border().unwrap();
+
+        if strict {
+            contacts_internal::try_round_shape_against_round_shape(
+                m1,
+                s1,
+                r1,
+                m2,
+                s2,
+                r2,
+                prediction,
+            )
+        } else {
+            Ok(contacts_internal::round_shape_against_round_shape(
+                m1,
+                s1,
+                r1,
+                m2,
+                s2,
+                r2,
+                prediction,
+            ))
+        }
     } else if let (Some(s1), Some(s2)) = (g1.as_support_map(), g2.as_support_map()) {
-        contacts_internal::support_map_against_support_map(m1, s1, m2, s2, prediction)
+        if strict {
+            contacts_internal::try_support_map_against_support_map(m1, s1, m2, s2, prediction)
+        } else {
+            Ok(contacts_internal::support_map_against_support_map(m1, s1, m2, s2, prediction))
+        }
     } else if let Some(c1) = g1.as_composite_shape() {
-        contacts_internal::composite_shape_against_shape(m1, c1, m2, g2, prediction)
+        if strict {
+            contacts_internal::try_composite_shape_against_shape(m1, c1, m2, g2, prediction)
+        } else {
+            Ok(contacts_internal::composite_shape_against_shape(m1, c1, m2, g2, prediction))
+        }
     } else if let Some(c2) = g2.as_composite_shape() {
-        contacts_internal::shape_against_composite_shape(m1, g1, m2, c2, prediction)
+        if strict {
+            contacts_internal::try_shape_against_composite_shape(m1, g1, m2, c2, prediction)
+        } else {
+            Ok(contacts_internal::shape_against_composite_shape(m1, g1, m2, c2, prediction))
+        }
     } else {
-        panic!("No algorithm known to compute a contact point between the given pair of shapes.")
+        Err(QueryError::UnsupportedShapePair)
     }
 }
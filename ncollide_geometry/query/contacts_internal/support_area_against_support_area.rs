@@ -0,0 +1,296 @@
+use std::mem;
+
+use na::{self, Unit};
+use utils;
+use query::Contact;
+use shape::{FeatureId, SupportAreaFeatures};
+use math::Point;
+
+/// Contact manifold between the support areas of two shapes, given one of their contacts.
+///
+/// The areas are the sets of points returned by `SupportMap::support_area_features_toward` for
+/// both shapes along the contact normal (resp. its opposite), in world-space, and `features1`
+/// (resp. `features2`) are the identifiers of their features. An empty area is interpreted as an
+/// unbounded plane passing through the corresponding contact point, e.g., for a `Plane`.
+///
+/// The incident area is clipped against the side planes of the reference area, i.e., the one which
+/// is a face (or a plane), and each remaining point yields a contact. Two segments are clipped
+/// against each other only if they are parallel. The contacts are pushed to `out` and `false` is
+/// returned if the areas cannot be clipped, e.g., if one of them is a single point. Each contact
+/// is identified by the pair of features of both areas it originates from, or by unknown features
+/// if those are not given.
+pub fn support_area_against_support_area<P: Point>(
+    contact: &Contact<P>,
+    area1: &[P],
+    features1: &SupportAreaFeatures,
+    area2: &[P],
+    features2: &SupportAreaFeatures,
+    prediction: P::Real,
+    out: &mut Vec<Contact<P>>,
+) -> bool {
+    let dim = na::dimension::<P::Vector>();
+    let is_face = |area: &[P]| area.is_empty() || area.len() >= dim;
+    let start = out.len();
+    let mut features = Vec::new();
+
+    if is_face(area1) && area2.len() >= 2 {
+        clip_support_area(
+            area1,
+            &contact.world1,
+            &contact.normal,
+            area2,
+            prediction,
+            out,
+            &mut features,
+        );
+        set_clipped_features(features1, features2, &features, &mut out[start..]);
+    } else if is_face(area2) && area1.len() >= 2 {
+        clip_support_area(
+            area2,
+            &contact.world2,
+            &-contact.normal,
+            area1,
+            prediction,
+            out,
+            &mut features,
+        );
+        set_clipped_features(features2, features1, &features, &mut out[start..]);
+
+        for contact in out[start..].iter_mut() {
+            contact.flip();
+        }
+    } else if dim == 3 && area1.len() == 2 && area2.len() == 2 {
+        let d1 = area1[1] - area1[0];
+        let d2 = area2[1] - area2[0];
+        let cross = utils::cross3(&d1, &d2);
+        let _eps: P::Real = na::convert(1.0e-4);
+
+        // Non-parallel edges touch at a single point.
+        if na::norm_squared(&cross) > _eps * na::norm_squared(&d1) * na::norm_squared(&d2) {
+            return false;
+        }
+
+        clip_support_area(
+            area1,
+            &contact.world1,
+            &contact.normal,
+            area2,
+            prediction,
+            out,
+            &mut features,
+        );
+        set_clipped_features(features1, features2, &features, &mut out[start..]);
+    } else {
+        return false;
+    }
+
+    true
+}
+
+// Sets the features of the contacts resulting from the clipping of the incident area identified
+// by `incident` against the reference area identified by `reference`. `features` are the
+// `ClippedFeature`s the contacts originate from, as output by `clip_support_area`.
+pub(crate) fn set_clipped_features<P: Point>(
+    reference: &SupportAreaFeatures,
+    incident: &SupportAreaFeatures,
+    features: &[ClippedFeature],
+    contacts: &mut [Contact<P>],
+) {
+    let vertex = |area: &SupportAreaFeatures, k: usize| {
+        area.vertices.get(k).cloned().unwrap_or(FeatureId::Unknown)
+    };
+
+    // The sides of an area with two vertices are located at these vertices.
+    let side = |area: &SupportAreaFeatures, k: usize| {
+        if area.vertices.len() == 2 {
+            vertex(area, k)
+        } else {
+            area.edges.get(k).cloned().unwrap_or(FeatureId::Unknown)
+        }
+    };
+
+    // An area with two vertices is its own single edge.
+    let edge = |area: &SupportAreaFeatures, j: usize| {
+        if area.vertices.len() == 2 {
+            area.area
+        } else {
+            area.edges.get(j).cloned().unwrap_or(FeatureId::Unknown)
+        }
+    };
+
+    for (contact, feature) in contacts.iter_mut().zip(features.iter()) {
+        let (feature1, feature2) = match *feature {
+            ClippedFeature::IncidentVertex(k) => (reference.area, vertex(incident, k)),
+            ClippedFeature::ReferenceVertex(k) => (vertex(reference, k), incident.area),
+            ClippedFeature::SideEdge(k, j) => (side(reference, k), edge(incident, j)),
+        };
+
+        contact.feature1 = feature1;
+        contact.feature2 = feature2;
+    }
+}
+
+/// The feature from which a point of a clipped support area originates.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) enum ClippedFeature {
+    /// The `k`-th vertex of the incident area.
+    IncidentVertex(usize),
+    /// The `k`-th vertex of the reference area, projected on the incident area.
+    ReferenceVertex(usize),
+    /// The intersection of the `k`-th side of the reference area with the `j`-th edge of the
+    /// incident area, given as `(k, j)`.
+    ///
+    /// The sides of a reference area with two vertices are located at these vertices. Otherwise,
+    /// the `k`-th side contains the edge joining its `k`-th and `(k + 1)`-th vertices. The `j`-th
+    /// edge of the incident area joins its `j`-th and `(j + 1)`-th vertices.
+    SideEdge(usize, usize),
+}
+
+/// Clips the incident area `inc_area` against the side planes of the reference area `ref_area`.
+///
+/// The reference area is orthogonal to `normal` and its plane passes through `origin`. It is
+/// unbounded if it is empty. A contact is pushed to `out` for each point of the clipped incident
+/// area with a distance to the reference plane smaller than `prediction`, and the feature this
+/// point originates from is pushed to `features`. The reference area is the first shape of these
+/// contacts.
+pub(crate) fn clip_support_area<P: Point>(
+    ref_area: &[P],
+    origin: &P,
+    normal: &Unit<P::Vector>,
+    inc_area: &[P],
+    prediction: P::Real,
+    out: &mut Vec<Contact<P>>,
+    features: &mut Vec<ClippedFeature>,
+) {
+    let mut inc_pts: Vec<_> = inc_area
+        .iter()
+        .enumerate()
+        .map(|(k, pt)| ClipVertex {
+            point: *pt,
+            feature: ClippedFeature::IncidentVertex(k),
+            next_edge: ClipEdge::Incident(k),
+        })
+        .collect();
+    let mut clipped = Vec::new();
+
+    if ref_area.len() == 2 {
+        // The side planes of a segment are located at its endpoints.
+        let dir = ref_area[1] - ref_area[0];
+
+        clip_polygon(&inc_pts, &ref_area[0], &-dir, 0, 2, &mut clipped);
+        mem::swap(&mut inc_pts, &mut clipped);
+        clipped.clear();
+        clip_polygon(&inc_pts, &ref_area[1], &dir, 1, 2, &mut clipped);
+        mem::swap(&mut inc_pts, &mut clipped);
+    } else if ref_area.len() > 2 {
+        let mut center = ref_area[0];
+
+        for pt in ref_area[1..].iter() {
+            center = center + pt.coordinates();
+        }
+
+        center = center * na::convert::<f64, P::Real>(1.0 / (ref_area.len() as f64));
+
+        for k in 0..ref_area.len() {
+            let a = ref_area[k];
+            let b = ref_area[(k + 1) % ref_area.len()];
+            let mut side = utils::cross3(&(b - a), normal.as_ref());
+
+            // The vertices of the reference area may be in any order.
+            if na::dot(&side, &(center - a)) > na::zero() {
+                side = -side;
+            }
+
+            clipped.clear();
+            clip_polygon(&inc_pts, &a, &side, k, ref_area.len(), &mut clipped);
+            mem::swap(&mut inc_pts, &mut clipped);
+        }
+    }
+
+    for vtx in inc_pts.iter() {
+        let dist = na::dot(&(vtx.point - *origin), normal.as_ref());
+
+        if dist <= prediction {
+            let proj = vtx.point + **normal * (-dist);
+
+            out.push(Contact::new(proj, vtx.point, *normal, -dist));
+            features.push(vtx.feature);
+        }
+    }
+}
+
+// The part of the incident area an edge of the clipped polygon lies on.
+#[derive(Copy, Clone)]
+enum ClipEdge {
+    // The `j`-th edge of the incident area.
+    Incident(usize),
+    // The `k`-th side plane of the reference area.
+    Side(usize),
+}
+
+// A vertex of the clipped polygon, with the edge joining it to the next vertex.
+#[derive(Copy, Clone)]
+struct ClipVertex<P> {
+    point: P,
+    feature: ClippedFeature,
+    next_edge: ClipEdge,
+}
+
+// Clips `poly` against the half-space of the points `x` such that `dot(x - origin, side) <= 0`.
+// This half-space is bounded by the `side_id`-th of the `nsides` side planes of the reference
+// area. A polygon with two vertices is handled as a segment.
+fn clip_polygon<P: Point>(
+    poly: &[ClipVertex<P>],
+    origin: &P,
+    side: &P::Vector,
+    side_id: usize,
+    nsides: usize,
+    out: &mut Vec<ClipVertex<P>>,
+) {
+    if poly.is_empty() {
+        return;
+    }
+
+    let nedges = if poly.len() == 2 { 1 } else { poly.len() };
+    let _0 = na::zero::<P::Real>();
+
+    for i in 0..nedges {
+        let a = poly[i];
+        let b = poly[(i + 1) % poly.len()];
+        let da = na::dot(&(a.point - *origin), side);
+        let db = na::dot(&(b.point - *origin), side);
+
+        if da <= _0 {
+            out.push(a);
+        }
+
+        if (da < _0 && db > _0) || (da > _0 && db < _0) {
+            let feature = match a.next_edge {
+                ClipEdge::Incident(j) => ClippedFeature::SideEdge(side_id, j),
+                // Two consecutive side planes meet at the vertex of the reference area they
+                // share.
+                ClipEdge::Side(k) if (side_id + 1) % nsides == k => {
+                    ClippedFeature::ReferenceVertex(k)
+                }
+                ClipEdge::Side(_) => ClippedFeature::ReferenceVertex(side_id),
+            };
+
+            // The polygon follows the side plane from the point where it leaves the half-space.
+            let next_edge = if da < _0 {
+                ClipEdge::Side(side_id)
+            } else {
+                a.next_edge
+            };
+
+            out.push(ClipVertex {
+                point: a.point + (b.point - a.point) * (da / (da - db)),
+                feature: feature,
+                next_edge: next_edge,
+            });
+        }
+
+        if nedges == 1 && db <= _0 {
+            out.push(b);
+        }
+    }
+}
@@ -6,10 +6,11 @@ use shape::{self, AnnotatedPoint, SupportMap};
 use query::algorithms::gjk::GJKResult;
 use query::algorithms::gjk;
 use query::algorithms::minkowski_sampling;
+use query::algorithms::mpr;
 use query::algorithms::epa3;
 use query::algorithms::epa2;
 use query::algorithms::{EPA2, EPA3, Simplex, JohnsonSimplex, VoronoiSimplex2, VoronoiSimplex3};
-use query::Contact;
+use query::{Contact, QueryError};
 use math::{Isometry, Point};
 
 /// Contact between support-mapped shapes (`Cuboid`, `ConvexHull`, etc.)
@@ -74,6 +75,41 @@ where
     }
 }
 
+/// Contact between support-mapped shapes (`Cuboid`, `ConvexHull`, etc.)
+///
+/// Returns an error if the GJK or the EPA fails.
+pub fn try_support_map_against_support_map<P, M, G1: ?Sized, G2: ?Sized>(
+    m1: &M,
+    g1: &G1,
+    m2: &M,
+    g2: &G2,
+    prediction: P::Real,
+) -> Result<Option<Contact<P>>, QueryError>
+where
+    P: Point,
+    M: Isometry<P>,
+    G1: SupportMap<P, M>,
+    G2: SupportMap<P, M>,
+{
+    let res = if na::dimension::<P::Vector>() == 2 {
+        let simplex = &mut VoronoiSimplex2::new();
+        try_support_map_against_support_map_with_params(m1, g1, m2, g2, prediction, simplex, None)?
+    } else if na::dimension::<P::Vector>() == 3 {
+        let simplex = &mut VoronoiSimplex3::new();
+        try_support_map_against_support_map_with_params(m1, g1, m2, g2, prediction, simplex, None)?
+    } else {
+        let simplex = &mut JohnsonSimplex::new_w_tls();
+        try_support_map_against_support_map_with_params(m1, g1, m2, g2, prediction, simplex, None)?
+    };
+
+    match res {
+        GJKResult::Projection(c) => Ok(Some(c)),
+        GJKResult::NoIntersection(_) => Ok(None),
+        GJKResult::Intersection => unreachable!(),
+        GJKResult::Proximity(_) => unreachable!(),
+    }
+}
+
 /// Contact between support-mapped shapes (`Cuboid`, `ConvexHull`, etc.)
 ///
 /// This allows a more fine grained control other the underlying GJK algorigtm.
@@ -86,6 +122,53 @@ pub fn support_map_against_support_map_with_params<P, M, S, G1: ?Sized, G2: ?Siz
     simplex: &mut S,
     init_dir: Option<P::Vector>,
 ) -> GJKResult<Contact<P>, P::Vector>
+where
+    P: Point,
+    M: Isometry<P>,
+    S: Simplex<AnnotatedPoint<P>>,
+    G1: SupportMap<P, M>,
+    G2: SupportMap<P, M>,
+{
+    match do_support_map_against_support_map(m1, g1, m2, g2, prediction, simplex, init_dir, false) {
+        Ok(res) => res,
+        Err(err) => panic!("Contact computation failed: {}", err),
+    }
+}
+
+/// Contact between support-mapped shapes (`Cuboid`, `ConvexHull`, etc.)
+///
+/// This allows a more fine grained control other the underlying GJK algorigtm. Returns an error
+/// if the GJK or the EPA fails.
+pub fn try_support_map_against_support_map_with_params<P, M, S, G1: ?Sized, G2: ?Sized>(
+    m1: &M,
+    g1: &G1,
+    m2: &M,
+    g2: &G2,
+    prediction: P::Real,
+    simplex: &mut S,
+    init_dir: Option<P::Vector>,
+) -> Result<GJKResult<Contact<P>, P::Vector>, QueryError>
+where
+    P: Point,
+    M: Isometry<P>,
+    S: Simplex<AnnotatedPoint<P>>,
+    G1: SupportMap<P, M>,
+    G2: SupportMap<P, M>,
+{
+    do_support_map_against_support_map(m1, g1, m2, g2, prediction, simplex, init_dir, true)
+}
+
+// If `strict` is `false`, the result of an EPA that did not converge is used anyway.
+fn do_support_map_against_support_map<P, M, S, G1: ?Sized, G2: ?Sized>(
+    m1: &M,
+    g1: &G1,
+    m2: &M,
+    g2: &G2,
+    prediction: P::Real,
+    simplex: &mut S,
+    init_dir: Option<P::Vector>,
+    strict: bool,
+) -> Result<GJKResult<Contact<P>, P::Vector>, QueryError>
 where
     P: Point,
     M: Isometry<P>,
@@ -105,17 +188,17 @@ where
 
     simplex.reset(shape::cso_support_point(m1, g1, m2, g2, dir));
 
-    match gjk::closest_points_with_max_dist(m1, g1, m2, g2, prediction, simplex) {
+    match gjk::try_closest_points_with_max_dist(m1, g1, m2, g2, prediction, simplex)? {
         GJKResult::Projection((p1, p2)) => {
             let p1p2 = p2 - p1;
             let sqn = na::norm_squared(&p1p2);
 
             if !sqn.is_zero() {
                 let (normal, depth) = Unit::new_and_get(p1p2);
-                return GJKResult::Projection(Contact::new(p1, p2, normal, -depth));
+                return Ok(projection(m1, g1, m2, g2, Contact::new(p1, p2, normal, -depth)));
             }
         }
-        GJKResult::NoIntersection(dir) => return GJKResult::NoIntersection(dir),
+        GJKResult::NoIntersection(dir) => return Ok(GJKResult::NoIntersection(dir)),
         GJKResult::Intersection => {} // fallback
         GJKResult::Proximity(_) => unreachable!(),
     }
@@ -123,27 +206,86 @@ where
     // The point is inside of the CSO: use the fallback algorithm
     if na::dimension::<P::Vector>() == 2 {
         let mut epa = EPA2::new();
-        let (p1, p2) = epa2::closest_points(&mut epa, m1, g1, m2, g2, simplex);
+        let (p1, p2) = if strict {
+            epa2::try_closest_points(&mut epa, m1, g1, m2, g2, simplex)?
+        } else {
+            epa2::closest_points(&mut epa, m1, g1, m2, g2, simplex)
+        };
         let (normal, depth) = Unit::new_and_get(p1 - p2);
 
         if depth.is_zero() {
-            return GJKResult::NoIntersection(na::zero());
+            Ok(GJKResult::NoIntersection(na::zero()))
         } else {
-            return GJKResult::Projection(Contact::new(p1, p2, normal, depth));
+            Ok(projection(m1, g1, m2, g2, Contact::new(p1, p2, normal, depth)))
         }
     } else if na::dimension::<P::Vector>() == 3 {
         let mut epa = EPA3::new();
-        let (p1, p2) = epa3::closest_points(&mut epa, m1, g1, m2, g2, simplex);
+        let (p1, p2) = if strict {
+            epa3::try_closest_points(&mut epa, m1, g1, m2, g2, simplex)?
+        } else {
+            epa3::closest_points(&mut epa, m1, g1, m2, g2, simplex)
+        };
         let (normal, depth) = Unit::new_and_get(p1 - p2);
 
-        return GJKResult::Projection(Contact::new(p1, p2, normal, depth));
+        Ok(projection(m1, g1, m2, g2, Contact::new(p1, p2, normal, depth)))
     } else {
         match minkowski_sampling::closest_points(m1, g1, m2, g2, simplex) {
             Some((p1, p2, normal)) => {
                 let depth = na::dot(&(p1 - p2), &normal);
-                GJKResult::Projection(Contact::new(p1, p2, normal, depth))
+                Ok(projection(m1, g1, m2, g2, Contact::new(p1, p2, normal, depth)))
             }
-            None => GJKResult::NoIntersection(na::zero()), // panic!("Both GJK and fallback algorithm failed.")
+            None if strict => Err(QueryError::NoConvergence),
+            // panic!("Both GJK and fallback algorithm failed.")
+            None => Ok(GJKResult::NoIntersection(na::zero())),
         }
     }
 }
+
+/// Contact between support-mapped shapes (`Cuboid`, `ConvexHull`, etc.) using the MPR algorithm.
+///
+/// This is usually faster than the GJK and EPA algorithms but the penetration depth is only
+/// approximated. If the shapes are disjoint, the contact is only reported if the approximate
+/// separation along the contact normal is smaller than `prediction`.
+pub fn support_map_against_support_map_mpr<P, M, G1: ?Sized, G2: ?Sized>(
+    m1: &M,
+    g1: &G1,
+    m2: &M,
+    g2: &G2,
+    prediction: P::Real,
+) -> GJKResult<Contact<P>, P::Vector>
+where
+    P: Point,
+    M: Isometry<P>,
+    G1: SupportMap<P, M>,
+    G2: SupportMap<P, M>,
+{
+    let (p1, p2, normal) = mpr::closest_points(m1, g1, m2, g2);
+    let depth = na::dot(&(p1 - p2), normal.as_ref());
+
+    if depth >= -prediction {
+        projection(m1, g1, m2, g2, Contact::new(p1, p2, normal, depth))
+    } else {
+        GJKResult::NoIntersection(normal.unwrap())
+    }
+}
+
+// Identifies the features of both shapes containing the contact points.
+fn projection<P, M, G1: ?Sized, G2: ?Sized>(
+    m1: &M,
+    g1: &G1,
+    m2: &M,
+    g2: &G2,
+    mut contact: Contact<P>,
+) -> GJKResult<Contact<P>, P::Vector>
+where
+    P: Point,
+    M: Isometry<P>,
+    G1: SupportMap<P, M>,
+    G2: SupportMap<P, M>,
+{
+    let feature1 = g1.support_feature_id_toward(m1, &contact.normal);
+    let feature2 = g2.support_feature_id_toward(m2, &-contact.normal);
+    contact.set_features_if_unique(feature1, feature2);
+
+    GJKResult::Projection(contact)
+}
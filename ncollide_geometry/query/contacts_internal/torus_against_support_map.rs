@@ -0,0 +1,175 @@
+use alga::general::Real;
+use alga::linear::Translation;
+use na;
+use math::{Isometry, Point};
+use query::Contact;
+use query::contacts_internal;
+use shape::{Ball, SupportMap, Torus};
+
+/// Number of samples taken along the torus core circle before refinement.
+const NSAMPLES: usize = 16;
+/// Number of golden-section iterations used to refine the best sample.
+const NREFINEMENTS: usize = 16;
+
+/// Contact between a torus and a support-mapped shape.
+///
+/// The torus is seen as the union of the balls of radius `minor_radius` centered on its core
+/// circle. The deepest of those balls is found by sampling the core circle and refining the best
+/// sample with a golden-section search.
+pub fn torus_against_support_map<P, M, G: ?Sized>(
+    m1: &M,
+    t1: &Torus<P::Real>,
+    m2: &M,
+    g2: &G,
+    prediction: P::Real,
+) -> Option<Contact<P>>
+where
+    P: Point,
+    M: Isometry<P>,
+    G: SupportMap<P, M>,
+{
+    let ball = Ball::new(t1.minor_radius());
+
+    minimize_on_core(m1, t1, |m| {
+        contacts_internal::support_map_against_support_map(m, &ball, m2, g2, prediction)
+            .map(|c| (-c.depth, c))
+    })
+}
+
+/// Contact between a support-mapped shape and a torus.
+pub fn support_map_against_torus<P, M, G: ?Sized>(
+    m1: &M,
+    g1: &G,
+    m2: &M,
+    t2: &Torus<P::Real>,
+    prediction: P::Real,
+) -> Option<Contact<P>>
+where
+    P: Point,
+    M: Isometry<P>,
+    G: SupportMap<P, M>,
+{
+    let mut res = torus_against_support_map(m2, t2, m1, g1, prediction);
+
+    if let Some(ref mut c) = res {
+        c.flip()
+    }
+
+    res
+}
+
+/// Finds the ball of the torus `t` transformed by `m` which minimizes `cost`.
+///
+/// The torus is seen as the union of the balls of radius `minor_radius` centered on its core
+/// circle. The closure `cost` is given the position of such a ball and returns its cost with the
+/// corresponding result, or `None` if the cost is infinite. The core circle is sampled and the
+/// best sample is refined with a golden-section search.
+pub(crate) fn minimize_on_core<P, M, T, F>(m: &M, t: &Torus<P::Real>, mut cost: F) -> Option<T>
+where
+    P: Point,
+    M: Isometry<P>,
+    T: Clone,
+    F: FnMut(&M) -> Option<(P::Real, T)>,
+{
+    let center = m.translation().to_vector();
+    let mut cost_at = |theta: P::Real| {
+        let core = m.transform_point(&core_point::<P>(t.major_radius(), theta));
+        let shift = M::Translation::from_vector(core.coordinates() - center).unwrap();
+
+        cost(&m.append_translation(&shift))
+    };
+
+    // In 2D, the torus is reduced to two discs.
+    let nsamples = if na::dimension::<P::Vector>() == 2 {
+        2
+    } else {
+        NSAMPLES
+    };
+    let dtheta = P::Real::two_pi() / na::convert(nsamples as f64);
+
+    let mut best_theta = na::zero::<P::Real>();
+    let mut best = None;
+
+    for i in 0..nsamples {
+        let theta = dtheta * na::convert(i as f64);
+        let res = cost_at(theta);
+
+        if is_better(&res, &best) {
+            best_theta = theta;
+            best = res;
+        }
+    }
+
+    if best.is_none() || na::dimension::<P::Vector>() == 2 {
+        return best.map(|res| res.1);
+    }
+
+    /*
+     * Golden-section refinement around the best sample.
+     */
+    let inv_phi: P::Real = na::convert(0.618033988749895f64);
+    let mut lo = best_theta - dtheta;
+    let mut hi = best_theta + dtheta;
+    let mut x1 = hi - (hi - lo) * inv_phi;
+    let mut x2 = lo + (hi - lo) * inv_phi;
+    let mut c1 = cost_at(x1);
+    let mut c2 = cost_at(x2);
+
+    for _ in 0..NREFINEMENTS {
+        if is_better(&c1, &best) {
+            best = c1.clone();
+        }
+
+        if is_better(&c2, &best) {
+            best = c2.clone();
+        }
+
+        if is_better(&c1, &c2) {
+            hi = x2;
+            x2 = x1;
+            c2 = c1;
+            x1 = hi - (hi - lo) * inv_phi;
+            c1 = cost_at(x1);
+        } else {
+            lo = x1;
+            x1 = x2;
+            c1 = c2;
+            x2 = lo + (hi - lo) * inv_phi;
+            c2 = cost_at(x2);
+        }
+    }
+
+    if is_better(&c1, &best) {
+        best = c1;
+    }
+
+    if is_better(&c2, &best) {
+        best = c2;
+    }
+
+    best.map(|res| res.1)
+}
+
+// Whether `a` has a smaller cost than `b`, a `None` having an infinite cost.
+#[inline]
+fn is_better<N: Real, T>(a: &Option<(N, T)>, b: &Option<(N, T)>) -> bool {
+    match (a, b) {
+        (&Some((ca, _)), &Some((cb, _))) => ca < cb,
+        (&Some(_), &None) => true,
+        _ => false,
+    }
+}
+
+/// The point of the core circle of a torus at the angle `theta`, in the torus local frame.
+#[inline]
+fn core_point<P: Point>(major_radius: P::Real, theta: P::Real) -> P {
+    let mut res = P::origin();
+
+    res[0] = theta.cos() * major_radius;
+
+    if na::dimension::<P::Vector>() > 2 {
+        res[2] = theta.sin() * major_radius;
+    }
+
+    res
+}
@@ -4,10 +4,10 @@ use alga::general::Id;
 
 use na;
 use bounding_volume::AABB;
-use partitioning::BVTCostFn;
+use partitioning::{self, BVTCostFn};
 use shape::{CompositeShape, Shape};
 use query::distance_internal;
-use query::PointQuery;
+use query::{PointQuery, QueryError};
 use math::{Isometry, Point};
 
 /// Smallest distance between a composite shape and any other shape.
@@ -22,10 +22,9 @@ where
     M: Isometry<P>,
     G1: CompositeShape<P, M>,
 {
-    let mut cost_fn = CompositeShapeAgainstAnyDistCostFn::new(m1, g1, m2, g2);
+    let mut cost_fn = CompositeShapeAgainstAnyDistCostFn::new(m1, g1, m2, g2, false);
 
-    g1.bvt()
-        .best_first_search(&mut cost_fn)
+    partitioning::bvh_best_first_search(g1.bvh(), &mut cost_fn)
         .map(|(_, res)| res)
         .expect("The composite shape must not be empty.")
 }
@@ -45,6 +44,48 @@ where
     composite_shape_against_shape(m2, g2, m1, g1)
 }
 
+/// Smallest distance between a composite shape and any other shape.
+///
+/// Returns the first error reported by the distance computation between a part of `g1` and `g2`,
+/// or `QueryError::DegenerateInput` if `g1` has no part.
+pub fn try_composite_shape_against_shape<P, M, G1: ?Sized>(
+    m1: &M,
+    g1: &G1,
+    m2: &M,
+    g2: &Shape<P, M>,
+) -> Result<P::Real, QueryError>
+where
+    P: Point,
+    M: Isometry<P>,
+    G1: CompositeShape<P, M>,
+{
+    let mut cost_fn = CompositeShapeAgainstAnyDistCostFn::new(m1, g1, m2, g2, true);
+    let res = partitioning::bvh_best_first_search(g1.bvh(), &mut cost_fn).map(|(_, res)| res);
+
+    match cost_fn.error {
+        Some(err) => Err(err),
+        None => res.ok_or(QueryError::DegenerateInput),
+    }
+}
+
+/// Smallest distance between a shape and a composite shape.
+///
+/// Returns the first error reported by the distance computation between `g1` and a part of `g2`,
+/// or `QueryError::DegenerateInput` if `g2` has no part.
+pub fn try_shape_against_composite_shape<P, M, G2: ?Sized>(
+    m1: &M,
+    g1: &Shape<P, M>,
+    m2: &M,
+    g2: &G2,
+) -> Result<P::Real, QueryError>
+where
+    P: Point,
+    M: Isometry<P>,
+    G2: CompositeShape<P, M>,
+{
+    try_composite_shape_against_shape(m2, g2, m1, g1)
+}
+
 struct CompositeShapeAgainstAnyDistCostFn<'a, P: 'a + Point, M: 'a, G1: ?Sized + 'a> {
     msum_shift: P::Vector,
     msum_margin: P::Vector,
@@ -54,6 +95,11 @@ struct CompositeShapeAgainstAnyDistCostFn<'a, P: 'a + Point, M: 'a, G1: ?Sized +
     m2: &'a M,
     g2: &'a Shape<P, M>,
 
+    // If `true`, the errors of the distance computations between parts are recorded instead of
+    // causing a panic.
+    strict: bool,
+    error: Option<QueryError>,
+
     point_type: PhantomData<P>,
 }
 
@@ -68,6 +114,7 @@ where
         g1: &'a G1,
         m2: &'a M,
         g2: &'a Shape<P, M>,
+        strict: bool,
     ) -> CompositeShapeAgainstAnyDistCostFn<'a, P, M, G1> {
         let ls_m2 = na::inverse(m1) * m2.clone();
         let ls_aabb2 = g2.aabb(&ls_m2);
@@ -79,6 +126,8 @@ where
             g1: g1,
             m2: m2,
             g2: g2,
+            strict: strict,
+            error: None,
             point_type: PhantomData,
         }
     }
@@ -94,6 +143,11 @@ where
     type UserData = P::Real;
     #[inline]
     fn compute_bv_cost(&mut self, bv: &AABB<P>) -> Option<P::Real> {
+        // Stop the traversal as soon as an error occurred.
+        if self.error.is_some() {
+            return None;
+        }
+
         // Compute the minkowski sum of the two AABBs.
         let msum = AABB::new(
             *bv.mins() + self.msum_shift + (-self.msum_margin),
@@ -106,14 +160,27 @@ where
 
     #[inline]
     fn compute_b_cost(&mut self, b: &usize) -> Option<(P::Real, P::Real)> {
+        if self.error.is_some() {
+            return None;
+        }
+
         let mut res = None;
+        let mut error = None;
 
         self.g1.map_transformed_part_at(*b, self.m1, &mut |m1, g1| {
-            let distance = distance_internal::distance(m1, g1, self.m2, self.g2);
-
-            res = Some((distance, distance))
+            if !self.strict {
+                let distance = distance_internal::distance(m1, g1, self.m2, self.g2);
+                res = Some((distance, distance))
+            } else {
+                match distance_internal::try_distance(m1, g1, self.m2, self.g2) {
+                    Ok(distance) => res = Some((distance, distance)),
+                    Err(err) => error = Some(err),
+                }
+            }
         });
 
+        self.error = error;
+
         res
     }
 }
@@ -1,15 +1,24 @@
 //! Implementation details of the `distance` function.
 
 pub use self::ball_against_ball::ball_against_ball;
-pub use self::support_map_against_support_map::support_map_against_support_map;
+pub use self::support_map_against_support_map::{support_map_against_support_map,
+                                                try_support_map_against_support_map};
 pub use self::support_map_against_support_map::support_map_against_support_map_with_params;
 pub use self::plane_against_support_map::{plane_against_support_map, support_map_against_plane};
+pub use self::torus_against_support_map::{support_map_against_torus, torus_against_support_map};
+pub use self::round_shape_against_round_shape::{round_shape_against_round_shape,
+                                                try_round_shape_against_round_shape};
 pub use self::shape_against_shape::shape_against_shape as distance;
+pub use self::shape_against_shape::try_shape_against_shape as try_distance;
 pub use self::composite_shape_against_shape::{composite_shape_against_shape,
-                                              shape_against_composite_shape};
+                                              shape_against_composite_shape,
+                                              try_composite_shape_against_shape,
+                                              try_shape_against_composite_shape};
 
 mod ball_against_ball;
 mod support_map_against_support_map;
 mod plane_against_support_map;
+mod torus_against_support_map;
+mod round_shape_against_round_shape;
 mod shape_against_shape;
 mod composite_shape_against_shape;
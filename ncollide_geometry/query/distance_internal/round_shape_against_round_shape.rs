@@ -0,0 +1,57 @@
+use na;
+use math::{Isometry, Point};
+use query::{distance_internal, QueryError};
+use shape::SupportMap;
+
+/// Distance between two support-mapped shapes with rounded borders.
+///
+/// The distance is computed between the core shapes `g1` and `g2` before subtracting the border
+/// radii `r1` and `r2`. A non-rounded shape has a zero border radius.
+pub fn round_shape_against_round_shape<P, M, G1: ?Sized, G2: ?Sized>(
+    m1: &M,
+    g1: &G1,
+    r1: P::Real,
+    m2: &M,
+    g2: &G2,
+    r2: P::Real,
+) -> P::Real
+where
+    P: Point,
+    M: Isometry<P>,
+    G1: SupportMap<P, M>,
+    G2: SupportMap<P, M>,
+{
+    let dist = distance_internal::support_map_against_support_map(m1, g1, m2, g2) - r1 - r2;
+
+    if dist > na::zero() {
+        dist
+    } else {
+        na::zero()
+    }
+}
+
+/// Distance between two support-mapped shapes with rounded borders.
+///
+/// Returns an error if the distance between the core shapes could not be computed.
+pub fn try_round_shape_against_round_shape<P, M, G1: ?Sized, G2: ?Sized>(
+    m1: &M,
+    g1: &G1,
+    r1: P::Real,
+    m2: &M,
+    g2: &G2,
+    r2: P::Real,
+) -> Result<P::Real, QueryError>
+where
+    P: Point,
+    M: Isometry<P>,
+    G1: SupportMap<P, M>,
+    G2: SupportMap<P, M>,
+{
+    let dist = distance_internal::try_support_map_against_support_map(m1, g1, m2, g2)? - r1 - r2;
+
+    if dist > na::zero() {
+        Ok(dist)
+    } else {
+        Ok(na::zero())
+    }
+}
@@ -1,7 +1,7 @@
 use alga::linear::Translation;
 use math::{Isometry, Point};
-use shape::{Ball, Plane, Shape};
-use query::distance_internal;
+use shape::{Ball, Plane, Shape, Torus};
+use query::{distance_internal, query_error, query_registry, QueryError};
 
 /// Computes the minimum distance separating two shapes.
 ///
@@ -11,25 +11,100 @@ where
     P: Point,
     M: Isometry<P>,
 {
-    if let (Some(b1), Some(b2)) = (
+    match dispatch(m1, g1, m2, g2, false) {
+        Ok(res) => res,
+        Err(QueryError::UnsupportedShapePair) => {
+            panic!(
+                "No algorithm known to compute a contact point between the given pair of shapes."
+            )
+        }
+        Err(err) => panic!("Distance computation failed: {}", err),
+    }
+}
+
+/// Computes the minimum distance separating two shapes.
+///
+/// Returns `Ok(0.0)` if the objects are touching or penetrating, and an error if the distance
+/// could not be computed.
+pub fn try_shape_against_shape<P, M>(
+    m1: &M,
+    g1: &Shape<P, M>,
+    m2: &M,
+    g2: &Shape<P, M>,
+) -> Result<P::Real, QueryError>
+where
+    P: Point,
+    M: Isometry<P>,
+{
+    query_error::check_isometries::<P, M>(m1, m2)?;
+
+    dispatch(m1, g1, m2, g2, true)
+}
+
+// If `strict` is `false`, the algorithms that never report errors are used whenever possible.
+fn dispatch<P, M>(
+    m1: &M,
+    g1: &Shape<P, M>,
+    m2: &M,
+    g2: &Shape<P, M>,
+    strict: bool,
+) -> Result<P::Real, QueryError>
+where
+    P: Point,
+    M: Isometry<P>,
+{
+    if let Some(res) = query_registry::registered_distance(m1, g1, m2, g2) {
+        Ok(res)
+    } else if let (Some(b1), Some(b2)) = (
         g1.as_shape::<Ball<P::Real>>(),
         g2.as_shape::<Ball<P::Real>>(),
     ) {
         let p1 = P::from_coordinates(m1.translation().to_vector());
         let p2 = P::from_coordinates(m2.translation().to_vector());
 
-        distance_internal::ball_against_ball(&p1, b1, &p2, b2)
+        Ok(distance_internal::ball_against_ball(&p1, b1, &p2, b2))
     } else if let (Some(p1), Some(s2)) = (g1.as_shape::<Plane<P::Vector>>(), g2.as_support_map()) {
-        distance_internal::plane_against_support_map(m1, p1, m2, s2)
+        Ok(distance_internal::plane_against_support_map(m1, p1, m2, s2))
     } else if let (Some(s1), Some(p2)) = (g1.as_support_map(), g2.as_shape::<Plane<P::Vector>>()) {
-        distance_internal::support_map_against_plane(m1, s1, m2, p2)
+        Ok(distance_internal::support_map_against_plane(m1, s1, m2, p2))
+    } else if let (Some(t1), Some(s2)) = (g1.as_shape::<Torus<P::Real>>(), g2.as_support_map()) {
+        Ok(distance_internal::torus_against_support_map(m1, t1, m2, s2))
+    } else if let (Some(s1), Some(t2)) = (g1.as_support_map(), g2.as_shape::<Torus<P::Real>>()) {
+        Ok(distance_internal::support_map_against_torus(m1, s1, m2, t2))
+    } else if (g1.is_round_shape() && g2.is_support_map())
+        || (g1.is_support_map() && g2.is_round_shape())
+    {
+        let (s1, r1) = g1.as_support_map_with_border().unwrap();
+        let (s2, r2) = g2.as_support_map_with_border().unwrap();
+
+        if strict {
+            distance_internal::try_round_shape_against_round_shape::<P, _, _, _>(
+                m1, s1, r1, m2, s2, r2,
+            )
+        } else {
+            Ok(distance_internal::round_shape_against_round_shape::<P, _, _, _>(
+                m1, s1, r1, m2, s2, r2,
+            ))
+        }
     } else if let (Some(s1), Some(s2)) = (g1.as_support_map(), g2.as_support_map()) {
-        distance_internal::support_map_against_support_map::<P, _, _, _>(m1, s1, m2, s2)
+        if strict {
+            distance_internal::try_support_map_against_support_map::<P, _, _, _>(m1, s1, m2, s2)
+        } else {
+            Ok(distance_internal::support_map_against_support_map::<P, _, _, _>(m1, s1, m2, s2))
+        }
     } else if let Some(c1) = g1.as_composite_shape() {
-        distance_internal::composite_shape_against_shape(m1, c1, m2, g2)
+        if strict {
+            distance_internal::try_composite_shape_against_shape(m1, c1, m2, g2)
+        } else {
+            Ok(distance_internal::composite_shape_against_shape(m1, c1, m2, g2))
+        }
     } else if let Some(c2) = g2.as_composite_shape() {
-        distance_internal::shape_against_composite_shape(m1, g1, m2, c2)
+        if strict {
+            distance_internal::try_shape_against_composite_shape(m1, g1, m2, c2)
+        } else {
+            Ok(distance_internal::shape_against_composite_shape(m1, g1, m2, c2))
+        }
     } else {
-        panic!("No algorithm known to compute a contact point between the given pair of shapes.")
+        Err(QueryError::UnsupportedShapePair)
     }
 }
@@ -5,6 +5,7 @@ use na;
 use query::algorithms::gjk;
 use query::algorithms::{Simplex, JohnsonSimplex, VoronoiSimplex2, VoronoiSimplex3};
 use shape::{self, SupportMap};
+use query::QueryError;
 use math::{Isometry, Point};
 
 /// Distance between support-mapped shapes.
@@ -82,3 +83,41 @@ where
 
     gjk::distance(m1, g1, m2, g2, simplex)
 }
+
+/// Distance between support-mapped shapes.
+///
+/// Returns an error if the GJK algorithm fails.
+pub fn try_support_map_against_support_map<P, M, G1: ?Sized, G2: ?Sized>(
+    m1: &M,
+    g1: &G1,
+    m2: &M,
+    g2: &G2,
+) -> Result<P::Real, QueryError>
+where
+    P: Point,
+    M: Isometry<P>,
+    G1: SupportMap<P, M>,
+    G2: SupportMap<P, M>,
+{
+    let mut dir = m1.translation().to_vector() - m2.translation().to_vector();
+
+    if dir.is_zero() {
+        dir[0] = na::one();
+    }
+
+    let support_point = *shape::cso_support_point(m1, g1, m2, g2, dir).point();
+
+    if na::dimension::<P::Vector>() == 2 {
+        let simplex = &mut VoronoiSimplex2::new();
+        simplex.reset(support_point);
+        gjk::try_distance(m1, g1, m2, g2, simplex)
+    } else if na::dimension::<P::Vector>() == 3 {
+        let simplex = &mut VoronoiSimplex3::new();
+        simplex.reset(support_point);
+        gjk::try_distance(m1, g1, m2, g2, simplex)
+    } else {
+        let simplex = &mut JohnsonSimplex::new_w_tls();
+        simplex.reset(support_point);
+        gjk::try_distance(m1, g1, m2, g2, simplex)
+    }
+}
@@ -0,0 +1,46 @@
+use num::Bounded;
+
+use math::{Isometry, Point};
+use query::distance_internal;
+use query::contacts_internal::torus_against_support_map::minimize_on_core;
+use shape::{Ball, SupportMap, Torus};
+
+/// Distance between a torus and a support-mapped shape.
+///
+/// The torus is seen as the union of the balls of radius `minor_radius` centered on its core
+/// circle. The closest of those balls is found by sampling the core circle and refining the best
+/// sample with a golden-section search.
+pub fn torus_against_support_map<P, M, G: ?Sized>(
+    m1: &M,
+    t1: &Torus<P::Real>,
+    m2: &M,
+    g2: &G,
+) -> P::Real
+where
+    P: Point,
+    M: Isometry<P>,
+    G: SupportMap<P, M>,
+{
+    let ball = Ball::new(t1.minor_radius());
+
+    minimize_on_core(m1, t1, |m| {
+        let dist = distance_internal::support_map_against_support_map(m, &ball, m2, g2);
+
+        Some((dist, dist))
+    }).unwrap_or_else(P::Real::max_value)
+}
+
+/// Distance between a support-mapped shape and a torus.
+pub fn support_map_against_torus<P, M, G: ?Sized>(
+    m1: &M,
+    g1: &G,
+    m2: &M,
+    t2: &Torus<P::Real>,
+) -> P::Real
+where
+    P: Point,
+    M: Isometry<P>,
+    G: SupportMap<P, M>,
+{
+    torus_against_support_map(m2, t2, m1, g1)
+}
@@ -5,13 +5,34 @@ pub use self::contacts_internal::{Contact, ContactPrediction};
 #[doc(inline)]
 pub use self::contacts_internal::contact_internal as contact;
 #[doc(inline)]
+pub use self::contacts_internal::try_contact_internal as try_contact;
+#[doc(inline)]
 pub use self::proximity_internal::Proximity;
 #[doc(inline)]
 pub use self::proximity_internal::proximity_internal as proximity;
 #[doc(inline)]
-pub use self::distance_internal::distance;
+pub use self::proximity_internal::try_proximity_internal as try_proximity;
+#[doc(inline)]
+pub use self::distance_internal::{distance, try_distance};
+#[doc(inline)]
+pub use self::closest_points_internal::{closest_points, ClosestPoints};
+#[doc(inline)]
+pub use self::signed_distance_internal::{signed_distance, SignedDistance};
+#[doc(inline)]
+pub use self::time_of_impact_internal::{time_of_impact, time_of_impact_and_witnesses,
+                                        try_time_of_impact, try_time_of_impact_and_witnesses,
+                                        TOIStatus, TOI};
+#[doc(inline)]
+pub use self::nonlinear_time_of_impact_internal::{nonlinear_time_of_impact,
+                                                  nonlinear_time_of_impact_and_witnesses,
+                                                  ConstantLinearVelocityRigidMotion,
+                                                  ConstantVelocityRigidMotion,
+                                                  ConstantVelocityRigidMotion2,
+                                                  ConstantVelocityRigidMotion3, RigidMotion};
+#[doc(inline)]
+pub use self::query_error::QueryError;
 #[doc(inline)]
-pub use self::time_of_impact_internal::time_of_impact;
+pub use self::query_registry::{modify_query_registry, read_query_registry, QueryRegistry};
 #[doc(inline)]
 pub use self::ray_internal::{Ray, Ray2, Ray3, RayCast, RayInterferencesCollector, RayIntersection,
                              RayIntersection2, RayIntersection3, RayIntersectionCostFn};
@@ -23,7 +44,12 @@ pub use self::point_internal::{PointInterferencesCollector, PointProjection, Poi
 pub mod algorithms;
 pub mod contacts_internal;
 pub mod distance_internal;
+pub mod closest_points_internal;
+pub mod signed_distance_internal;
 pub mod proximity_internal;
 pub mod time_of_impact_internal;
+pub mod nonlinear_time_of_impact_internal;
 pub mod ray_internal;
 pub mod point_internal;
+mod query_error;
+mod query_registry;
@@ -0,0 +1,197 @@
+use alga::general::{Identity, Real};
+use alga::linear::Translation;
+use approx::ApproxEq;
+use na::{self, Unit};
+use math::{Isometry, Point};
+use bounding_volume::AABB;
+use partitioning::{self, BVTCostFn};
+use shape::{CompositeShape, Cuboid, Shape};
+use query::{closest_points_internal, nonlinear_time_of_impact_internal, ClosestPoints, RigidMotion,
+            TOI};
+use query::nonlinear_time_of_impact_internal::conservative_advancement;
+
+/// Time of impact of a composite shape with any other shape, under a rigid motion.
+pub fn composite_shape_against_shape<P, M, G1: ?Sized>(
+    motion1: &RigidMotion<P, M>,
+    g1: &G1,
+    motion2: &RigidMotion<P, M>,
+    g2: &Shape<P, M>,
+    max_toi: P::Real,
+    tolerance: P::Real,
+    max_iter: usize,
+) -> Option<TOI<P>>
+where
+    P: Point,
+    M: Isometry<P>,
+    G1: CompositeShape<P, M>,
+{
+    let mut cost_fn = CompositeShapeAgainstAnyNonlinearTOICostFn {
+        motion1: motion1,
+        g1: g1,
+        motion2: motion2,
+        g2: g2,
+        max_toi: max_toi,
+        tolerance: tolerance,
+        max_iter: max_iter,
+    };
+
+    partitioning::bvh_best_first_search(g1.bvh(), &mut cost_fn).map(|(_, res)| res)
+}
+
+/// Time of impact of any shape with a composite shape, under a rigid motion.
+pub fn shape_against_composite_shape<P, M, G2: ?Sized>(
+    motion1: &RigidMotion<P, M>,
+    g1: &Shape<P, M>,
+    motion2: &RigidMotion<P, M>,
+    g2: &G2,
+    max_toi: P::Real,
+    tolerance: P::Real,
+    max_iter: usize,
+) -> Option<TOI<P>>
+where
+    P: Point,
+    M: Isometry<P>,
+    G2: CompositeShape<P, M>,
+{
+    let mut res =
+        composite_shape_against_shape(motion2, g2, motion1, g1, max_toi, tolerance, max_iter);
+
+    for toi in res.iter_mut() {
+        toi.flip()
+    }
+
+    res
+}
+
+// The motion of a shape rigidly attached to another moving object.
+struct AttachedRigidMotion<'a, P: 'a + Point, M: 'a> {
+    motion: &'a RigidMotion<P, M>,
+    local_position: M,
+}
+
+impl<'a, P: Point, M: Isometry<P>> RigidMotion<P, M> for AttachedRigidMotion<'a, P, M> {
+    fn position_at_time(&self, t: P::Real) -> M {
+        self.motion.position_at_time(t) * self.local_position.clone()
+    }
+
+    fn max_point_speed(&self, radius: P::Real) -> P::Real {
+        let shift = na::norm(&self.local_position.translation().to_vector());
+        self.motion.max_point_speed(shift + radius)
+    }
+
+    fn max_point_speed_along(&self, dir: &Unit<P::Vector>, radius: P::Real) -> P::Real {
+        let shift = na::norm(&self.local_position.translation().to_vector());
+        self.motion.max_point_speed_along(dir, shift + radius)
+    }
+}
+
+struct CompositeShapeAgainstAnyNonlinearTOICostFn<'a, P: 'a + Point, M: 'a, G1: ?Sized + 'a> {
+    motion1: &'a RigidMotion<P, M>,
+    g1: &'a G1,
+    motion2: &'a RigidMotion<P, M>,
+    g2: &'a Shape<P, M>,
+    max_toi: P::Real,
+    tolerance: P::Real,
+    max_iter: usize,
+}
+
+impl<'a, P, M, G1: ?Sized> BVTCostFn<P::Real, usize, AABB<P>>
+    for CompositeShapeAgainstAnyNonlinearTOICostFn<'a, P, M, G1>
+where
+    P: Point,
+    M: Isometry<P>,
+    G1: CompositeShape<P, M>,
+{
+    type UserData = TOI<P>;
+
+    #[inline]
+    fn compute_bv_cost(&mut self, bv: &AABB<P>) -> Option<P::Real> {
+        // The time of impact with the bounding volume is a lower bound of the time of impact with
+        // the parts it contains. If the advancement does not converge, the time it reached is still
+        // such a lower bound so the bounding volume is not pruned.
+        let cuboid = Cuboid::new(bv.half_extents());
+        let center = M::Translation::from_vector(bv.center().coordinates()).unwrap();
+        let motion = AttachedRigidMotion {
+            motion: self.motion1,
+            local_position: M::identity().append_translation(&center),
+        };
+
+        if let Some(s2) = self.g2.as_support_map() {
+            // Both shapes are convex so their distance cannot decrease faster than the speed of
+            // their points along the direction separating them.
+            let motion2 = self.motion2;
+            let start1 = motion.position_at_time(na::zero());
+            let start2 = motion2.position_at_time(na::zero());
+            let r1 = conservative_advancement::support_map_radius::<P, _, _>(&start1, &cuboid);
+            let r2 = conservative_advancement::support_map_radius::<P, _, _>(&start2, s2);
+            let speed = motion.max_point_speed(r1) + motion2.max_point_speed(r2);
+
+            conservative_advancement::conservative_advancement(
+                &motion,
+                motion2,
+                self.max_toi,
+                self.tolerance,
+                self.max_iter,
+                |m1, m2| {
+                    let pts = closest_points_internal::support_map_against_support_map(
+                        m1,
+                        &cuboid,
+                        m2,
+                        s2,
+                        P::Real::max_value(),
+                    );
+
+                    match pts {
+                        ClosestPoints::WithinMargin(p1, p2) => {
+                            let eps = P::Real::default_epsilon();
+
+                            match Unit::try_new_and_get(p2 - p1, eps) {
+                                Some((dir, dist)) => {
+                                    let speed = motion.max_point_speed_along(&dir, r1)
+                                        + motion2.max_point_speed_along(&dir, r2);
+                                    (dist, speed)
+                                }
+                                None => (na::zero(), speed),
+                            }
+                        }
+                        _ => (na::zero(), speed),
+                    }
+                },
+            ).map(|(toi, _, _, _)| toi)
+        } else {
+            nonlinear_time_of_impact_internal::nonlinear_time_of_impact_and_witnesses(
+                &motion,
+                &cuboid,
+                self.motion2,
+                self.g2,
+                self.max_toi,
+                self.tolerance,
+                self.max_iter,
+            ).map(|toi| toi.toi)
+        }
+    }
+
+    #[inline]
+    fn compute_b_cost(&mut self, b: &usize) -> Option<(P::Real, TOI<P>)> {
+        let mut res = None;
+
+        self.g1.map_part_at(*b, &mut |m, g1| {
+            let motion = AttachedRigidMotion {
+                motion: self.motion1,
+                local_position: m.clone(),
+            };
+
+            res = nonlinear_time_of_impact_internal::nonlinear_time_of_impact_and_witnesses(
+                &motion,
+                g1,
+                self.motion2,
+                self.g2,
+                self.max_toi,
+                self.tolerance,
+                self.max_iter,
+            ).map(|toi| (toi.toi, toi))
+        });
+
+        res
+    }
+}
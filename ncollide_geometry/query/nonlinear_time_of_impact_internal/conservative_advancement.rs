@@ -0,0 +1,94 @@
+use num::Zero;
+
+use alga::general::Real;
+use alga::linear::Translation;
+use na;
+use query::{RigidMotion, TOIStatus};
+use shape::SupportMap;
+use math::{Isometry, Point};
+
+/// Computes the time of impact of two moving objects using conservative advancement.
+///
+/// The closure `distance` is given the positions of both objects at the current time and must
+/// return their distance and an upper bound of the speed at which they may get closer. Returns
+/// the time of impact with the positions of both objects at this time.
+pub fn conservative_advancement<P, M, F>(
+    motion1: &RigidMotion<P, M>,
+    motion2: &RigidMotion<P, M>,
+    max_toi: P::Real,
+    tolerance: P::Real,
+    max_iter: usize,
+    mut distance: F,
+) -> Option<(P::Real, M, M, TOIStatus)>
+where
+    P: Point,
+    M: Isometry<P>,
+    F: FnMut(&M, &M) -> (P::Real, P::Real),
+{
+    assert!(
+        tolerance > na::zero(),
+        "The time of impact tolerance must be strictly positive."
+    );
+
+    let mut toi = na::zero();
+    let mut m1 = motion1.position_at_time(toi);
+    let mut m2 = motion2.position_at_time(toi);
+
+    for i in 0..max_iter {
+        let (dist, speed) = distance(&m1, &m2);
+
+        if dist <= tolerance {
+            let status = if i == 0 && dist.is_zero() {
+                TOIStatus::Penetrating
+            } else {
+                TOIStatus::Converged
+            };
+
+            return Some((toi, m1, m2, status));
+        }
+
+        if speed <= na::zero() {
+            // The objects cannot get any closer.
+            return None;
+        }
+
+        // The objects cannot collide before they traveled `dist` toward each other.
+        toi += dist / speed;
+
+        if toi > max_toi {
+            return None;
+        }
+
+        m1 = motion1.position_at_time(toi);
+        m2 = motion2.position_at_time(toi);
+    }
+
+    Some((toi, m1, m2, TOIStatus::Failed))
+}
+
+/// An upper bound of the distance between the points of `g` and the origin of its local frame.
+pub fn support_map_radius<P, M, G: ?Sized>(m: &M, g: &G) -> P::Real
+where
+    P: Point,
+    M: Isometry<P>,
+    G: SupportMap<P, M>,
+{
+    let origin = m.translation().to_vector();
+    let mut basis = na::zero::<P::Vector>();
+    let mut sq_radius = na::zero::<P::Real>();
+
+    for d in 0..na::dimension::<P::Vector>() {
+        basis[d] = na::one();
+        let max = g.support_point(m, &basis)[d] - origin[d];
+
+        basis[d] = -na::one::<P::Real>();
+        let min = g.support_point(m, &basis)[d] - origin[d];
+
+        basis[d] = na::zero();
+
+        let extent = max.abs().max(min.abs());
+        sq_radius += extent * extent;
+    }
+
+    sq_radius.sqrt()
+}
@@ -0,0 +1,20 @@
+//! Implementation details of the `nonlinear_time_of_impact` function.
+
+pub use self::rigid_motion::{ConstantLinearVelocityRigidMotion, ConstantVelocityRigidMotion,
+                             ConstantVelocityRigidMotion2, ConstantVelocityRigidMotion3,
+                             RigidMotion};
+pub use self::support_map_against_support_map::support_map_against_support_map;
+pub use self::plane_against_support_map::{plane_against_support_map, support_map_against_plane};
+pub use self::round_shape_against_round_shape::round_shape_against_round_shape;
+pub use self::shape_against_shape::shape_against_shape as nonlinear_time_of_impact_and_witnesses;
+pub use self::shape_against_shape::nonlinear_time_of_impact;
+pub use self::composite_shape_against_shape::{composite_shape_against_shape,
+                                              shape_against_composite_shape};
+
+mod rigid_motion;
+mod conservative_advancement;
+mod support_map_against_support_map;
+mod plane_against_support_map;
+mod round_shape_against_round_shape;
+mod shape_against_shape;
+mod composite_shape_against_shape;
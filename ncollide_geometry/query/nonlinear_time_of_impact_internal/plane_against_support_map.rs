@@ -0,0 +1,86 @@
+use alga::linear::Translation;
+use na;
+use math::{Isometry, Point};
+use query::{distance_internal, RigidMotion, TOI};
+use query::time_of_impact_internal::toi;
+use query::nonlinear_time_of_impact_internal::conservative_advancement;
+use shape::{Plane, SupportMap};
+
+/// Time of impact of a plane with a support-mapped shape under a rigid motion.
+///
+/// The time of impact is computed by conservative advancement until the shapes are closer than
+/// `tolerance`. Returns `None` if no impact is found before `max_toi`. The status of the result is
+/// `TOIStatus::Failed` if the time of impact could not be found after `max_iter` iterations.
+pub fn plane_against_support_map<P, M, G: ?Sized>(
+    motion_plane: &RigidMotion<P, M>,
+    plane: &Plane<P::Vector>,
+    motion_other: &RigidMotion<P, M>,
+    other: &G,
+    max_toi: P::Real,
+    tolerance: P::Real,
+    max_iter: usize,
+) -> Option<TOI<P>>
+where
+    P: Point,
+    M: Isometry<P>,
+    G: SupportMap<P, M>,
+{
+    let start_other = motion_other.position_at_time(na::zero());
+    let r_other = conservative_advancement::support_map_radius::<P, _, _>(&start_other, other);
+
+    conservative_advancement::conservative_advancement(
+        motion_plane,
+        motion_other,
+        max_toi,
+        tolerance,
+        max_iter,
+        |mplane, mother| {
+            let dist = distance_internal::plane_against_support_map::<P, _, _>(
+                mplane, plane, mother, other,
+            );
+
+            // Only the part of the plane facing the other shape may be hit before the next step.
+            let shift = mother.translation().to_vector() - mplane.translation().to_vector();
+            let r_plane = na::norm(&shift) + r_other;
+            let speed =
+                motion_plane.max_point_speed(r_plane) + motion_other.max_point_speed(r_other);
+
+            (dist, speed)
+        },
+    ).map(|(t, mplane, mother, status)| {
+        let (w1, w2, normal) = toi::plane_witnesses(&mplane, plane, &mother, other);
+        TOI::new(t, w1, w2, normal, status)
+    })
+}
+
+/// Time of impact of a support-mapped shape with a plane under a rigid motion.
+pub fn support_map_against_plane<P, M, G: ?Sized>(
+    motion_other: &RigidMotion<P, M>,
+    other: &G,
+    motion_plane: &RigidMotion<P, M>,
+    plane: &Plane<P::Vector>,
+    max_toi: P::Real,
+    tolerance: P::Real,
+    max_iter: usize,
+) -> Option<TOI<P>>
+where
+    P: Point,
+    M: Isometry<P>,
+    G: SupportMap<P, M>,
+{
+    let mut res = plane_against_support_map(
+        motion_plane,
+        plane,
+        motion_other,
+        other,
+        max_toi,
+        tolerance,
+        max_iter,
+    );
+
+    for toi in res.iter_mut() {
+        toi.flip()
+    }
+
+    res
+}
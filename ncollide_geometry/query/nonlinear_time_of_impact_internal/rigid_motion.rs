@@ -0,0 +1,137 @@
+use alga::general::Real;
+use alga::linear::Translation;
+use na::{self, Isometry2, Isometry3, Point2, Point3, Unit, Vector2, Vector3};
+use math::{Isometry, Point};
+
+/// Trait implemented by the description of the continuous motion of a rigid object.
+pub trait RigidMotion<P: Point, M> {
+    /// Gets the position of the moving object at the time `t`.
+    fn position_at_time(&self, t: P::Real) -> M;
+
+    /// An upper bound of the speed of the points of the moving object.
+    ///
+    /// Only the points at a distance smaller than `radius` from the origin of the local frame of
+    /// the moving object are taken into account.
+    fn max_point_speed(&self, radius: P::Real) -> P::Real;
+
+    /// An upper bound of the absolute value of the speed of the points of the moving object along
+    /// the direction `dir`.
+    ///
+    /// Only the points at a distance smaller than `radius` from the origin of the local frame of
+    /// the moving object are taken into account. Defaults to `self.max_point_speed(radius)`.
+    fn max_point_speed_along(&self, _dir: &Unit<P::Vector>, radius: P::Real) -> P::Real {
+        self.max_point_speed(radius)
+    }
+}
+
+/// A translational motion at a constant velocity.
+pub struct ConstantLinearVelocityRigidMotion<P: Point, M> {
+    /// The position of the object at the time `0`.
+    pub start: M,
+    /// The linear velocity of the object.
+    pub velocity: P::Vector,
+}
+
+impl<P: Point, M> ConstantLinearVelocityRigidMotion<P, M> {
+    /// Creates a translational motion starting at the position `start`.
+    pub fn new(start: M, velocity: P::Vector) -> Self {
+        ConstantLinearVelocityRigidMotion {
+            start: start,
+            velocity: velocity,
+        }
+    }
+}
+
+impl<P: Point, M: Isometry<P>> RigidMotion<P, M> for ConstantLinearVelocityRigidMotion<P, M> {
+    #[inline]
+    fn position_at_time(&self, t: P::Real) -> M {
+        let shift = M::Translation::from_vector(self.velocity * t).unwrap();
+        self.start.append_translation(&shift)
+    }
+
+    #[inline]
+    fn max_point_speed(&self, _: P::Real) -> P::Real {
+        na::norm(&self.velocity)
+    }
+
+    #[inline]
+    fn max_point_speed_along(&self, dir: &Unit<P::Vector>, _: P::Real) -> P::Real {
+        na::dot(&self.velocity, dir.as_ref()).abs()
+    }
+}
+
+/// A motion at constant linear and angular velocities.
+///
+/// The object rotates around the point `local_center`, expressed in its local frame, while this
+/// point moves at the linear velocity `linvel`. The angular velocity `angvel` is a scalar in 2D,
+/// and the rotation axis multiplied by the rotation speed in 3D.
+pub struct ConstantVelocityRigidMotion<P: Point, M, A> {
+    /// The position of the object at the time `0`.
+    pub start: M,
+    /// The center of rotation, expressed in the local frame of the object.
+    pub local_center: P,
+    /// The linear velocity of the center of rotation.
+    pub linvel: P::Vector,
+    /// The angular velocity of the object.
+    pub angvel: A,
+}
+
+impl<P: Point, M, A> ConstantVelocityRigidMotion<P, M, A> {
+    /// Creates a motion starting at the position `start`.
+    pub fn new(start: M, local_center: P, linvel: P::Vector, angvel: A) -> Self {
+        ConstantVelocityRigidMotion {
+            start: start,
+            local_center: local_center,
+            linvel: linvel,
+            angvel: angvel,
+        }
+    }
+}
+
+impl<N: Real> RigidMotion<Point2<N>, Isometry2<N>>
+    for ConstantVelocityRigidMotion<Point2<N>, Isometry2<N>, N> {
+    fn position_at_time(&self, t: N) -> Isometry2<N> {
+        let center = self.start * self.local_center;
+        let to_center = Isometry2::new(-center.coords, na::zero());
+        let motion = Isometry2::new(center.coords + self.linvel * t, self.angvel * t);
+
+        motion * to_center * self.start
+    }
+
+    fn max_point_speed(&self, radius: N) -> N {
+        let arm = na::norm(&self.local_center.coords) + radius;
+        na::norm(&self.linvel) + self.angvel.abs() * arm
+    }
+
+    fn max_point_speed_along(&self, dir: &Unit<Vector2<N>>, radius: N) -> N {
+        let arm = na::norm(&self.local_center.coords) + radius;
+        na::dot(&self.linvel, dir.as_ref()).abs() + self.angvel.abs() * arm
+    }
+}
+
+impl<N: Real> RigidMotion<Point3<N>, Isometry3<N>>
+    for ConstantVelocityRigidMotion<Point3<N>, Isometry3<N>, Vector3<N>> {
+    fn position_at_time(&self, t: N) -> Isometry3<N> {
+        let center = self.start * self.local_center;
+        let to_center = Isometry3::new(-center.coords, na::zero());
+        let motion = Isometry3::new(center.coords + self.linvel * t, self.angvel * t);
+
+        motion * to_center * self.start
+    }
+
+    fn max_point_speed(&self, radius: N) -> N {
+        let arm = na::norm(&self.local_center.coords) + radius;
+        na::norm(&self.linvel) + na::norm(&self.angvel) * arm
+    }
+
+    fn max_point_speed_along(&self, dir: &Unit<Vector3<N>>, radius: N) -> N {
+        let arm = na::norm(&self.local_center.coords) + radius;
+        na::dot(&self.linvel, dir.as_ref()).abs() + na::norm(&self.angvel) * arm
+    }
+}
+
+/// A 2D motion at constant linear and angular velocities.
+pub type ConstantVelocityRigidMotion2<N> = ConstantVelocityRigidMotion<Point2<N>, Isometry2<N>, N>;
+/// A 3D motion at constant linear and angular velocities.
+pub type ConstantVelocityRigidMotion3<N> =
+    ConstantVelocityRigidMotion<Point3<N>, Isometry3<N>, Vector3<N>>;
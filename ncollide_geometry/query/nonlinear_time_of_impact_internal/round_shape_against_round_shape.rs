@@ -0,0 +1,54 @@
+use alga::linear::Translation;
+use na;
+use math::{Isometry, Point};
+use query::{distance_internal, RigidMotion, TOI};
+use query::time_of_impact_internal::toi;
+use query::nonlinear_time_of_impact_internal::conservative_advancement;
+use shape::SupportMap;
+
+/// Time of impact of two support-mapped shapes with rounded borders, under a rigid motion.
+///
+/// The distances are computed between the core shapes `g1` and `g2` before subtracting the border
+/// radii `r1` and `r2`. A non-rounded shape has a zero border radius.
+pub fn round_shape_against_round_shape<P, M, G1: ?Sized, G2: ?Sized>(
+    motion1: &RigidMotion<P, M>,
+    g1: &G1,
+    r1: P::Real,
+    motion2: &RigidMotion<P, M>,
+    g2: &G2,
+    r2: P::Real,
+    max_toi: P::Real,
+    tolerance: P::Real,
+    max_iter: usize,
+) -> Option<TOI<P>>
+where
+    P: Point,
+    M: Isometry<P>,
+    G1: SupportMap<P, M>,
+    G2: SupportMap<P, M>,
+{
+    let start1 = motion1.position_at_time(na::zero());
+    let start2 = motion2.position_at_time(na::zero());
+    let radius1 = conservative_advancement::support_map_radius::<P, _, _>(&start1, g1) + r1;
+    let radius2 = conservative_advancement::support_map_radius::<P, _, _>(&start2, g2) + r2;
+    let speed = motion1.max_point_speed(radius1) + motion2.max_point_speed(radius2);
+
+    conservative_advancement::conservative_advancement(
+        motion1,
+        motion2,
+        max_toi,
+        tolerance,
+        max_iter,
+        |m1, m2| {
+            let dist = distance_internal::round_shape_against_round_shape::<P, _, _, _>(
+                m1, g1, r1, m2, g2, r2,
+            );
+            (dist, speed)
+        },
+    ).map(|(t, m1, m2, status)| {
+        let dir = m2.translation().to_vector() - m1.translation().to_vector();
+        let (w1, w2, normal) = toi::support_map_witnesses(&m1, g1, &m2, g2, &dir);
+
+        TOI::new(t, w1 + *normal * r1, w2 + (-*normal * r2), normal, status)
+    })
+}
@@ -0,0 +1,82 @@
+use math::{Isometry, Point};
+use shape::{Plane, Shape};
+use query::{RigidMotion, TOIStatus, TOI};
+use query::nonlinear_time_of_impact_internal;
+
+/// Computes the smallest time of impact of two shapes under a rigid motion.
+///
+/// The time of impact is computed by conservative advancement: the shapes are moved along their
+/// motions by steps that cannot make them penetrate, until they are closer than `tolerance`.
+/// Returns `None` if no impact is found before the time `max_toi`, or after `max_iter`
+/// iterations. Returns `0.0` if the objects are initially touching or penetrating.
+pub fn nonlinear_time_of_impact<P, M>(
+    motion1: &RigidMotion<P, M>,
+    g1: &Shape<P, M>,
+    motion2: &RigidMotion<P, M>,
+    g2: &Shape<P, M>,
+    max_toi: P::Real,
+    tolerance: P::Real,
+    max_iter: usize,
+) -> Option<P::Real>
+where
+    P: Point,
+    M: Isometry<P>,
+{
+    match shape_against_shape(motion1, g1, motion2, g2, max_toi, tolerance, max_iter) {
+        Some(ref res) if res.status != TOIStatus::Failed => Some(res.toi),
+        _ => None,
+    }
+}
+
+/// Computes the smallest time of impact of two shapes under a rigid motion, with the contact
+/// points and normal at this time.
+///
+/// Returns `None` if no impact is found before the time `max_toi`. The status of the result is
+/// `TOIStatus::Failed` if the time of impact could not be found after `max_iter` iterations, and
+/// `TOIStatus::Penetrating` if the objects are initially touching or penetrating.
+pub fn shape_against_shape<P, M>(
+    motion1: &RigidMotion<P, M>,
+    g1: &Shape<P, M>,
+    motion2: &RigidMotion<P, M>,
+    g2: &Shape<P, M>,
+    max_toi: P::Real,
+    tolerance: P::Real,
+    max_iter: usize,
+) -> Option<TOI<P>>
+where
+    P: Point,
+    M: Isometry<P>,
+{
+    if let (Some(p1), Some(s2)) = (g1.as_shape::<Plane<P::Vector>>(), g2.as_support_map()) {
+        nonlinear_time_of_impact_internal::plane_against_support_map(
+            motion1, p1, motion2, s2, max_toi, tolerance, max_iter,
+        )
+    } else if let (Some(s1), Some(p2)) = (g1.as_support_map(), g2.as_shape::<Plane<P::Vector>>()) {
+        nonlinear_time_of_impact_internal::support_map_against_plane(
+            motion1, s1, motion2, p2, max_toi, tolerance, max_iter,
+        )
+    } else if (g1.is_round_shape() && g2.is_support_map())
+        || (g1.is_support_map() && g2.is_round_shape())
+    {
+        let (s1, r1) = g1.as_support_map_with_border().unwrap();
+        let (s2, r2) = g2.as_support_map_with_border().unwrap();
+
+        nonlinear_time_of_impact_internal::round_shape_against_round_shape(
+            motion1, s1, r1, motion2, s2, r2, max_toi, tolerance, max_iter,
+        )
+    } else if let (Some(s1), Some(s2)) = (g1.as_support_map(), g2.as_support_map()) {
+        nonlinear_time_of_impact_internal::support_map_against_support_map(
+            motion1, s1, motion2, s2, max_toi, tolerance, max_iter,
+        )
+    } else if let Some(c1) = g1.as_composite_shape() {
+        nonlinear_time_of_impact_internal::composite_shape_against_shape(
+            motion1, c1, motion2, g2, max_toi, tolerance, max_iter,
+        )
+    } else if let Some(c2) = g2.as_composite_shape() {
+        nonlinear_time_of_impact_internal::shape_against_composite_shape(
+            motion1, g1, motion2, c2, max_toi, tolerance, max_iter,
+        )
+    } else {
+        panic!("No algorithm known to compute a time of impact between the given pair of shapes.")
+    }
+}
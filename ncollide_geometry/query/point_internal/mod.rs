@@ -11,6 +11,13 @@ pub use self::point_tetrahedron::TetrahedronPointLocation;
 pub mod point_query;
 mod point_plane;
 mod point_ball;
+mod point_ellipsoid;
+mod point_torus;
+mod point_heightfield;
+mod point_voxels;
+mod point_bezier;
+mod point_scaled;
+mod point_round_shape;
 mod point_cuboid;
 mod point_aabb;
 mod point_bounding_sphere;
@@ -19,6 +26,7 @@ mod point_segment;
 mod point_triangle;
 mod point_tetrahedron;
 mod point_compound;
+mod point_dynamic_compound;
 mod point_mesh;
 mod point_shape;
 mod point_bvt;
@@ -0,0 +1,277 @@
+use alga::general::{Id, Real};
+use na::{self, Matrix1, Matrix2, Vector1, Vector2};
+
+use utils::{self, BacktrackingLineSearch};
+use query::{PointProjection, PointQuery};
+use shape::{BezierCurve, BezierSurface};
+use bounding_volume::AABB;
+use partitioning::{self, BVTCostFn};
+use math::{Isometry, Point};
+
+/// Maximum number of iterations of the refinement of a projection on a Bezier shape.
+const MAX_REFINEMENT_ITERATIONS: usize = 20;
+
+/// Bezier curves and surfaces have no interior: points are projected on the curve or surface
+/// itself.
+///
+/// The projection on the piecewise-linear approximation of the shape is used as an initial guess
+/// for the closest parameters. Those are then refined with the BFGS method on the exact shape.
+impl<P: Point, M: Isometry<P>> PointQuery<P, M> for BezierCurve<P> {
+    #[inline]
+    fn project_point(&self, m: &M, point: &P, _: bool) -> PointProjection<P> {
+        let ls_pt = m.inverse_transform_point(point);
+        let mut cost_fn = BezierCurvePointProjCostFn {
+            curve: self,
+            point: &ls_pt,
+        };
+
+        let (part, proj) = partitioning::bvh_best_first_search(self, &mut cost_fn).unwrap();
+
+        // Parameter of the linear projection on the curve.
+        let segment = self.segment_at(part);
+        let ab = *segment.b() - *segment.a();
+        let sqnab = na::norm_squared(&ab);
+        let s = if sqnab > na::zero() {
+            na::dot(&(proj.point - *segment.a()), &ab) / sqnab
+        } else {
+            na::zero()
+        };
+        let (t0, t1) = self.part_range(part);
+        let t = refine_curve_projection(self, &ls_pt, t0 + (t1 - t0) * s);
+
+        let refined = self.point_at(t);
+        let best = if na::distance_squared(&refined, &ls_pt)
+            < na::distance_squared(&proj.point, &ls_pt)
+        {
+            refined
+        } else {
+            proj.point
+        };
+
+        PointProjection::new(false, m.transform_point(&best))
+    }
+
+    #[inline]
+    fn contains_point(&self, _: &M, _: &P) -> bool {
+        false
+    }
+}
+
+impl<P: Point, M: Isometry<P>> PointQuery<P, M> for BezierSurface<P> {
+    #[inline]
+    fn project_point(&self, m: &M, point: &P, _: bool) -> PointProjection<P> {
+        let ls_pt = m.inverse_transform_point(point);
+        let mut cost_fn = BezierSurfacePointProjCostFn {
+            surface: self,
+            point: &ls_pt,
+        };
+
+        let (part, proj) = partitioning::bvh_best_first_search(self, &mut cost_fn).unwrap();
+
+        // Parameters of the linear projection on the surface.
+        let triangle = self.triangle_at(part);
+        let (w0, w1, w2) = barycentric_coordinates(
+            triangle.a(),
+            triangle.b(),
+            triangle.c(),
+            &proj.point,
+        );
+        let ((u0, u1), (v0, v1)) = self.part_range(part);
+        let (u, v) = if part % 2 == 0 {
+            // Corners (u0, v0), (u1, v0), (u1, v1).
+            (u0 * w0 + u1 * (w1 + w2), v0 * (w0 + w1) + v1 * w2)
+        } else {
+            // Corners (u0, v0), (u1, v1), (u0, v1).
+            (u0 * (w0 + w2) + u1 * w1, v0 * w0 + v1 * (w1 + w2))
+        };
+        let (u, v) = refine_surface_projection(self, &ls_pt, u, v);
+
+        let refined = self.point_at(u, v);
+        let best = if na::distance_squared(&refined, &ls_pt)
+            < na::distance_squared(&proj.point, &ls_pt)
+        {
+            refined
+        } else {
+            proj.point
+        };
+
+        PointProjection::new(false, m.transform_point(&best))
+    }
+
+    #[inline]
+    fn contains_point(&self, _: &M, _: &P) -> bool {
+        false
+    }
+}
+
+/// Minimizes the squared distance between `point` and the curve, starting at the parameter
+/// `guess`. The result is clamped to `[0, 1]`.
+fn refine_curve_projection<P: Point>(
+    curve: &BezierCurve<P>,
+    point: &P,
+    guess: P::Real,
+) -> P::Real {
+    let _2: P::Real = na::convert(2.0);
+    let clamp = |x: &Vector1<P::Real>| na::clamp(x.x, na::zero(), na::one());
+
+    let tangent = curve.derivative_at(na::clamp(guess, na::zero(), na::one()));
+    let hessian = Matrix1::new(na::norm_squared(&tangent) * _2);
+    let ss = BacktrackingLineSearch::new(na::one(), na::convert(0.5), na::convert(0.5), 20);
+
+    let res = utils::bfgs(
+        MAX_REFINEMENT_ITERATIONS,
+        &ss,
+        Vector1::new(guess),
+        hessian,
+        &mut |x| na::distance_squared(&curve.point_at(clamp(x)), point),
+        &mut |x| {
+            let t = clamp(x);
+            let diff = curve.point_at(t) - *point;
+            let grad = na::dot(&diff, &curve.derivative_at(t)) * _2;
+
+            Vector1::new(clamp_gradient(x.x, grad))
+        },
+    );
+
+    clamp(&res)
+}
+
+/// Minimizes the squared distance between `point` and the surface, starting at the parameters
+/// `(guess_u, guess_v)`. The result is clamped to `[0, 1] x [0, 1]`.
+fn refine_surface_projection<P: Point>(
+    surface: &BezierSurface<P>,
+    point: &P,
+    guess_u: P::Real,
+    guess_v: P::Real,
+) -> (P::Real, P::Real) {
+    let _2: P::Real = na::convert(2.0);
+    let clamp = |x: &Vector2<P::Real>| {
+        (
+            na::clamp(x.x, na::zero(), na::one()),
+            na::clamp(x.y, na::zero(), na::one()),
+        )
+    };
+
+    let (du, dv) = surface.derivatives_at(
+        na::clamp(guess_u, na::zero(), na::one()),
+        na::clamp(guess_v, na::zero(), na::one()),
+    );
+    let duv = na::dot(&du, &dv) * _2;
+    let hessian = Matrix2::new(
+        na::norm_squared(&du) * _2,
+        duv,
+        duv,
+        na::norm_squared(&dv) * _2,
+    );
+    let ss = BacktrackingLineSearch::new(na::one(), na::convert(0.5), na::convert(0.5), 20);
+
+    let res = utils::bfgs(
+        MAX_REFINEMENT_ITERATIONS,
+        &ss,
+        Vector2::new(guess_u, guess_v),
+        hessian,
+        &mut |x| {
+            let (u, v) = clamp(x);
+            na::distance_squared(&surface.point_at(u, v), point)
+        },
+        &mut |x| {
+            let (u, v) = clamp(x);
+            let diff = surface.point_at(u, v) - *point;
+            let (du, dv) = surface.derivatives_at(u, v);
+
+            Vector2::new(
+                clamp_gradient(x.x, na::dot(&diff, &du) * _2),
+                clamp_gradient(x.y, na::dot(&diff, &dv) * _2),
+            )
+        },
+    );
+
+    clamp(&res)
+}
+
+/// Cancels a gradient component that would make a descent move further outside of `[0, 1]`.
+#[inline]
+fn clamp_gradient<N: Real>(x: N, grad: N) -> N {
+    if (x <= na::zero() && grad > na::zero()) || (x >= na::one() && grad < na::zero()) {
+        na::zero()
+    } else {
+        grad
+    }
+}
+
+/// The barycentric coordinates of the projection of `p` on the plane of the triangle `abc`.
+fn barycentric_coordinates<P: Point>(
+    a: &P,
+    b: &P,
+    c: &P,
+    p: &P,
+) -> (P::Real, P::Real, P::Real) {
+    let _1: P::Real = na::one();
+    let ab = *b - *a;
+    let ac = *c - *a;
+    let ap = *p - *a;
+    let d00 = na::dot(&ab, &ab);
+    let d01 = na::dot(&ab, &ac);
+    let d11 = na::dot(&ac, &ac);
+    let d20 = na::dot(&ap, &ab);
+    let d21 = na::dot(&ap, &ac);
+    let denom = d00 * d11 - d01 * d01;
+
+    if denom == na::zero() {
+        return (_1, na::zero(), na::zero());
+    }
+
+    let v = (d11 * d20 - d01 * d21) / denom;
+    let w = (d00 * d21 - d01 * d20) / denom;
+
+    (_1 - v - w, v, w)
+}
+
+/*
+ * Costs functions.
+ */
+struct BezierCurvePointProjCostFn<'a, P: 'a + Point> {
+    curve: &'a BezierCurve<P>,
+    point: &'a P,
+}
+
+impl<'a, P: Point> BVTCostFn<P::Real, usize, AABB<P>> for BezierCurvePointProjCostFn<'a, P> {
+    type UserData = PointProjection<P>;
+
+    #[inline]
+    fn compute_bv_cost(&mut self, aabb: &AABB<P>) -> Option<P::Real> {
+        Some(aabb.distance_to_point(&Id::new(), self.point, true))
+    }
+
+    #[inline]
+    fn compute_b_cost(&mut self, b: &usize) -> Option<(P::Real, Self::UserData)> {
+        let proj = self.curve
+            .segment_at(*b)
+            .project_point(&Id::new(), self.point, true);
+
+        Some((na::distance(self.point, &proj.point), proj))
+    }
+}
+
+struct BezierSurfacePointProjCostFn<'a, P: 'a + Point> {
+    surface: &'a BezierSurface<P>,
+    point: &'a P,
+}
+
+impl<'a, P: Point> BVTCostFn<P::Real, usize, AABB<P>> for BezierSurfacePointProjCostFn<'a, P> {
+    type UserData = PointProjection<P>;
+
+    #[inline]
+    fn compute_bv_cost(&mut self, aabb: &AABB<P>) -> Option<P::Real> {
+        Some(aabb.distance_to_point(&Id::new(), self.point, true))
+    }
+
+    #[inline]
+    fn compute_b_cost(&mut self, b: &usize) -> Option<(P::Real, Self::UserData)> {
+        let proj = self.surface
+            .triangle_at(*b)
+            .project_point(&Id::new(), self.point, true);
+
+        Some((na::distance(self.point, &proj.point), proj))
+    }
+}
@@ -0,0 +1,141 @@
+use approx::ApproxEq;
+use alga::general::Real;
+use na;
+
+use query::{PointProjection, PointQuery};
+use shape::Ellipsoid;
+use math::{Isometry, Point, Vector};
+
+/// Maximum number of bisection steps performed to project a point on an ellipsoid.
+const MAX_BISECTIONS: usize = 200;
+
+impl<P: Point, M: Isometry<P>> PointQuery<P, M> for Ellipsoid<P::Vector> {
+    #[inline]
+    fn project_point(&self, m: &M, pt: &P, solid: bool) -> PointProjection<P> {
+        let ls_pt = m.inverse_transform_point(pt).coordinates();
+        let inside = contains_local_point(self.radii(), &ls_pt);
+
+        if inside && solid {
+            PointProjection::new(true, *pt)
+        } else {
+            let ls_proj = project_local_point(self.radii(), &ls_pt, inside);
+
+            PointProjection::new(inside, m.transform_point(&P::from_coordinates(ls_proj)))
+        }
+    }
+
+    #[inline]
+    fn contains_point(&self, m: &M, pt: &P) -> bool {
+        let ls_pt = m.inverse_transform_point(pt).coordinates();
+
+        contains_local_point(self.radii(), &ls_pt)
+    }
+}
+
+#[inline]
+fn contains_local_point<V: Vector>(radii: &V, pt: &V) -> bool {
+    let mut sum = na::zero::<V::Real>();
+
+    for i in 0..na::dimension::<V>() {
+        let coord = pt[i] / radii[i];
+        sum = sum + coord * coord;
+    }
+
+    sum <= na::one()
+}
+
+/// Projects a point on the boundary of an ellipsoid centered at the origin.
+///
+/// The closest point is `y_i = r_i² x_i / (t + r_i²)` where `t` is the root of
+/// `f(t) = sum_i (r_i x_i / (t + r_i²))² - 1` lying in `]-min_i r_i², +inf[`. This root is found
+/// by bisection since `f` is strictly decreasing on this interval.
+fn project_local_point<V: Vector>(radii: &V, pt: &V, inside: bool) -> V {
+    let dim = na::dimension::<V>();
+    let eps = V::Real::default_epsilon();
+    let f = |t: V::Real| {
+        let mut sum = -na::one::<V::Real>();
+
+        for i in 0..dim {
+            let r2 = radii[i] * radii[i];
+            let coord = radii[i] * pt[i] / (t + r2);
+            sum = sum + coord * coord;
+        }
+
+        sum
+    };
+
+    let mut rmin = radii[0];
+    let mut rmax = radii[0];
+
+    for i in 1..dim {
+        rmin = rmin.min(radii[i]);
+        rmax = rmax.max(radii[i]);
+    }
+
+    let (mut tmin, mut tmax) = if inside {
+        // When the point lies on the plane orthogonal to the smallest axis, `f` may remain
+        // bounded near `-rmin²`. In that case, the closest point is on the intersection of the
+        // ellipsoid with this plane.
+        let mut blows_up = false;
+        let mut sum = na::zero::<V::Real>();
+
+        for i in 0..dim {
+            let dr2 = radii[i] * radii[i] - rmin * rmin;
+
+            if dr2 <= eps {
+                blows_up = blows_up || pt[i].abs() > eps;
+            } else {
+                let coord = radii[i] * pt[i] / dr2;
+                sum = sum + coord * coord;
+            }
+        }
+
+        if !blows_up && sum <= na::one() {
+            let mut res = *pt;
+            let mut on_min_axis = false;
+
+            for i in 0..dim {
+                let dr2 = radii[i] * radii[i] - rmin * rmin;
+
+                if dr2 > eps {
+                    res[i] = radii[i] * radii[i] * pt[i] / dr2;
+                } else if !on_min_axis {
+                    res[i] = rmin * (na::one::<V::Real>() - sum).sqrt();
+                    on_min_axis = true;
+                } else {
+                    res[i] = na::zero();
+                }
+            }
+
+            return res;
+        }
+
+        (-rmin * rmin, na::zero())
+    } else {
+        (na::zero(), rmax * na::norm(pt))
+    };
+
+    for _ in 0..MAX_BISECTIONS {
+        let t = (tmin + tmax) * na::convert(0.5);
+
+        if t <= tmin || t >= tmax {
+            break;
+        }
+
+        if f(t) > na::zero() {
+            tmin = t
+        } else {
+            tmax = t
+        }
+    }
+
+    let t = (tmin + tmax) * na::convert(0.5);
+    let mut res = *pt;
+
+    for i in 0..dim {
+        let r2 = radii[i] * radii[i];
+        res[i] = r2 * pt[i] / (t + r2);
+    }
+
+    res
+}
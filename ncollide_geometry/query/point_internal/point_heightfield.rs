@@ -0,0 +1,78 @@
+use alga::general::Id;
+use na;
+use query::{PointProjection, PointQuery};
+use shape::HeightField;
+use bounding_volume::AABB;
+use partitioning::{self, BVTCostFn};
+use math::{Isometry, Point};
+
+impl<P: Point, M: Isometry<P>> PointQuery<P, M> for HeightField<P> {
+    #[inline]
+    fn project_point(&self, m: &M, point: &P, solid: bool) -> PointProjection<P> {
+        let ls_pt = m.inverse_transform_point(point);
+        let inside = contains_local_point(self, &ls_pt);
+
+        if inside && solid {
+            return PointProjection::new(true, *point);
+        }
+
+        let mut cost_fn = HeightFieldPointProjCostFn {
+            heightfield: self,
+            point: &ls_pt,
+        };
+
+        let mut proj = partitioning::bvh_best_first_search(self, &mut cost_fn).unwrap().1;
+        proj.is_inside = inside;
+        proj.point = m.transform_point(&proj.point);
+
+        proj
+    }
+
+    #[inline]
+    fn contains_point(&self, m: &M, point: &P) -> bool {
+        let ls_pt = m.inverse_transform_point(point);
+
+        contains_local_point(self, &ls_pt)
+    }
+}
+
+/// A point is inside of a height field if it lies below its surface.
+#[inline]
+fn contains_local_point<P: Point>(heightfield: &HeightField<P>, point: &P) -> bool {
+    match heightfield.height_at_point(point) {
+        Some(height) => point[1] <= height,
+        None => false,
+    }
+}
+
+/*
+ * Costs function.
+ */
+struct HeightFieldPointProjCostFn<'a, P: 'a + Point> {
+    heightfield: &'a HeightField<P>,
+    point: &'a P,
+}
+
+impl<'a, P: Point> BVTCostFn<P::Real, usize, AABB<P>> for HeightFieldPointProjCostFn<'a, P> {
+    type UserData = PointProjection<P>;
+
+    #[inline]
+    fn compute_bv_cost(&mut self, aabb: &AABB<P>) -> Option<P::Real> {
+        Some(aabb.distance_to_point(&Id::new(), self.point, true))
+    }
+
+    #[inline]
+    fn compute_b_cost(&mut self, b: &usize) -> Option<(P::Real, Self::UserData)> {
+        let proj = if na::dimension::<P::Vector>() > 2 {
+            self.heightfield
+                .triangle_at(*b)
+                .project_point(&Id::new(), self.point, true)
+        } else {
+            self.heightfield
+                .segment_at(*b)
+                .project_point(&Id::new(), self.point, true)
+        };
+
+        Some((na::distance(self.point, &proj.point), proj))
+    }
+}
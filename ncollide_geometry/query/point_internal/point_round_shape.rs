@@ -0,0 +1,73 @@
+use approx::ApproxEq;
+
+use na::{self, Unit};
+
+use query::{PointProjection, PointQuery};
+use query::algorithms::{JohnsonSimplex, VoronoiSimplex2, VoronoiSimplex3};
+use query::point_internal::point_support_map::support_map_point_projection;
+use shape::{RoundShape, SupportMap};
+use math::{Isometry, Point};
+
+impl<P, M, S> PointQuery<P, M> for RoundShape<S, P::Real>
+where
+    P: Point,
+    M: Isometry<P>,
+    S: SupportMap<P, M>,
+{
+    #[inline]
+    fn project_point(&self, m: &M, point: &P, solid: bool) -> PointProjection<P> {
+        let eps = P::Real::default_epsilon();
+        let border_radius = self.border_radius();
+        let core_proj = project_on_core(m, self.shape(), point);
+
+        if core_proj.is_inside {
+            if solid {
+                return PointProjection::new(true, *point);
+            }
+
+            // The point is inside of the core shape: move its projection on the core boundary
+            // outward.
+            return match Unit::try_new(core_proj.point - *point, eps) {
+                Some(dir) => PointProjection::new(true, core_proj.point + *dir * border_radius),
+                None => core_proj,
+            };
+        }
+
+        let (dir, dist) = match Unit::try_new_and_get(*point - core_proj.point, eps) {
+            Some(res) => res,
+            None => return PointProjection::new(true, *point),
+        };
+
+        if dist <= border_radius && solid {
+            PointProjection::new(true, *point)
+        } else {
+            PointProjection::new(
+                dist <= border_radius,
+                core_proj.point + *dir * border_radius,
+            )
+        }
+    }
+}
+
+/// Projects a point on the boundary of the core shape of a round shape.
+#[inline]
+fn project_on_core<P, M, S>(m: &M, shape: &S, point: &P) -> PointProjection<P>
+where
+    P: Point,
+    M: Isometry<P>,
+    S: SupportMap<P, M>,
+{
+    if na::dimension::<P::Vector>() == 2 {
+        support_map_point_projection(m, shape, &mut VoronoiSimplex2::<P>::new(), point, false)
+    } else if na::dimension::<P::Vector>() == 3 {
+        support_map_point_projection(m, shape, &mut VoronoiSimplex3::<P>::new(), point, false)
+    } else {
+        support_map_point_projection(
+            m,
+            shape,
+            &mut JohnsonSimplex::<P>::new_w_tls(),
+            point,
+            false,
+        )
+    }
+}
@@ -0,0 +1,162 @@
+use std::ops::Deref;
+
+use alga::general::Id;
+use na;
+use query::{PointProjection, PointQuery};
+use query::algorithms::{JohnsonSimplex, VoronoiSimplex2, VoronoiSimplex3};
+use query::point_internal::point_support_map::support_map_point_projection;
+use shape::{CompositeShape, Scaled, ScaledPart, Shape, SupportMap};
+use bounding_volume::AABB;
+use partitioning::{self, BVTCostFn};
+use math::{Isometry, Point};
+
+impl<P, M, S> PointQuery<P, M> for Scaled<S, P::Vector>
+where
+    P: Point,
+    M: Isometry<P>,
+    S: Deref<Target = Shape<P, M>>,
+{
+    #[inline]
+    fn project_point(&self, m: &M, point: &P, solid: bool) -> PointProjection<P> {
+        if self.shape().is_support_map() {
+            return support_map_projection(m, self, point, solid);
+        }
+
+        if self.shape().is_composite_shape() {
+            // This fails only if the composite shape has no part.
+            if let Some(proj) = composite_projection(m, self, point) {
+                return proj;
+            }
+        }
+
+        // NOTE: this is not an exact projection since scaling does not preserve distances.
+        let one: M = na::one();
+        let ls_pt = self.unscale_point(&m.inverse_transform_point(point));
+        let mut proj = self.shape()
+            .as_point_query()
+            .expect("The scaled shape does not support point queries.")
+            .project_point(&one, &ls_pt, solid);
+
+        proj.point = m.transform_point(&self.scale_point(&proj.point));
+
+        proj
+    }
+
+    #[inline]
+    fn contains_point(&self, m: &M, point: &P) -> bool {
+        let one: M = na::one();
+        let ls_pt = self.unscale_point(&m.inverse_transform_point(point));
+
+        self.shape()
+            .as_point_query()
+            .expect("The scaled shape does not support point queries.")
+            .contains_point(&one, &ls_pt)
+    }
+}
+
+impl<P: Point, M: Isometry<P>> PointQuery<P, M> for ScaledPart<P, M> {
+    #[inline]
+    fn project_point(&self, m: &M, point: &P, solid: bool) -> PointProjection<P> {
+        if self.shape().is_support_map() {
+            return support_map_projection(m, self, point, solid);
+        }
+
+        if self.shape().is_composite_shape() {
+            if let Some(proj) = composite_projection(m, self, point) {
+                return proj;
+            }
+        }
+
+        // NOTE: this is not an exact projection since scaling does not preserve distances.
+        let ls_pt = self.scaled().unscale_point(&m.inverse_transform_point(point));
+        let mut proj = self.shape()
+            .as_point_query()
+            .expect("The scaled part does not support point queries.")
+            .project_point(self.delta(), &ls_pt, solid);
+
+        proj.point = m.transform_point(&self.scaled().scale_point(&proj.point));
+
+        proj
+    }
+
+    #[inline]
+    fn contains_point(&self, m: &M, point: &P) -> bool {
+        let ls_pt = self.scaled().unscale_point(&m.inverse_transform_point(point));
+
+        self.shape()
+            .as_point_query()
+            .expect("The scaled part does not support point queries.")
+            .contains_point(self.delta(), &ls_pt)
+    }
+}
+
+fn support_map_projection<P, M, G>(m: &M, g: &G, point: &P, solid: bool) -> PointProjection<P>
+where
+    P: Point,
+    M: Isometry<P>,
+    G: SupportMap<P, M>,
+{
+    if na::dimension::<P::Vector>() == 2 {
+        support_map_point_projection(m, g, &mut VoronoiSimplex2::<P>::new(), point, solid)
+    } else if na::dimension::<P::Vector>() == 3 {
+        support_map_point_projection(m, g, &mut VoronoiSimplex3::<P>::new(), point, solid)
+    } else {
+        support_map_point_projection(m, g, &mut JohnsonSimplex::<P>::new_w_tls(), point, solid)
+    }
+}
+
+// Projects `point` on the closest part of `g`. Returns `None` if `g` has no part.
+fn composite_projection<P, M, G>(m: &M, g: &G, point: &P) -> Option<PointProjection<P>>
+where
+    P: Point,
+    M: Isometry<P>,
+    G: CompositeShape<P, M>,
+{
+    let ls_pt = m.inverse_transform_point(point);
+    let mut cost_fn = ScaledPointProjCostFn {
+        scaled: g,
+        point: &ls_pt,
+    };
+
+    partitioning::bvh_best_first_search(g.bvh(), &mut cost_fn).map(|(_, mut proj)| {
+        proj.point = m.transform_point(&proj.point);
+        proj
+    })
+}
+
+/*
+ * Costs function.
+ */
+struct ScaledPointProjCostFn<'a, P: 'a + Point, M: 'a> {
+    scaled: &'a CompositeShape<P, M>,
+    point: &'a P,
+}
+
+impl<'a, P, M> BVTCostFn<P::Real, usize, AABB<P>> for ScaledPointProjCostFn<'a, P, M>
+where
+    P: Point,
+    M: Isometry<P>,
+{
+    type UserData = PointProjection<P>;
+
+    #[inline]
+    fn compute_bv_cost(&mut self, aabb: &AABB<P>) -> Option<P::Real> {
+        Some(aabb.distance_to_point(&Id::new(), self.point, true))
+    }
+
+    #[inline]
+    fn compute_b_cost(&mut self, b: &usize) -> Option<(P::Real, Self::UserData)> {
+        let mut res = None;
+        let point = self.point;
+
+        self.scaled.map_part_at(*b, &mut |m, part| {
+            let proj = part.as_point_query()
+                .expect("The scaled shape does not support point queries.")
+                .project_point(m, point, true);
+
+            res = Some((na::distance(point, &proj.point), proj))
+        });
+
+        res
+    }
+}
@@ -0,0 +1,68 @@
+use alga::linear::FiniteDimVectorSpace;
+use na;
+
+use query::{PointProjection, PointQuery};
+use shape::Torus;
+use math::{Isometry, Point};
+
+impl<P: Point, M: Isometry<P>> PointQuery<P, M> for Torus<P::Real> {
+    #[inline]
+    fn project_point(&self, m: &M, pt: &P, solid: bool) -> PointProjection<P> {
+        let ls_pt = m.inverse_transform_point(pt);
+        let core = torus_core_point(self, &ls_pt);
+        let dcore = ls_pt - core;
+        let distance_squared = na::norm_squared(&dcore);
+        let inside = distance_squared <= self.minor_radius() * self.minor_radius();
+
+        if inside && solid {
+            PointProjection::new(true, *pt)
+        } else {
+            let dir = if distance_squared > na::zero() {
+                dcore / distance_squared.sqrt()
+            } else {
+                // The point lies on the core circle: any direction orthogonal to the circle works.
+                core.coordinates() / self.major_radius()
+            };
+
+            let ls_proj = core + dir * self.minor_radius();
+
+            PointProjection::new(inside, m.transform_point(&ls_proj))
+        }
+    }
+
+    #[inline]
+    fn distance_to_point(&self, m: &M, pt: &P, solid: bool) -> P::Real {
+        let ls_pt = m.inverse_transform_point(pt);
+        let core = torus_core_point(self, &ls_pt);
+        let dist = na::distance(&ls_pt, &core) - self.minor_radius();
+
+        if solid && dist < na::zero() {
+            na::zero()
+        } else {
+            dist
+        }
+    }
+
+    #[inline]
+    fn contains_point(&self, m: &M, pt: &P) -> bool {
+        let ls_pt = m.inverse_transform_point(pt);
+        let core = torus_core_point(self, &ls_pt);
+
+        na::distance_squared(&ls_pt, &core) <= self.minor_radius() * self.minor_radius()
+    }
+}
+
+/// The point of the torus core circle closest to `pt`, expressed in the torus local frame.
+fn torus_core_point<P: Point>(torus: &Torus<P::Real>, pt: &P) -> P {
+    let mut radial = pt.coordinates();
+    radial[1] = na::zero();
+
+    let radial_len = na::norm(&radial);
+
+    if radial_len > na::zero() {
+        P::from_coordinates(radial * (torus.major_radius() / radial_len))
+    } else {
+        // All the points of the core circle are equidistant.
+        P::from_coordinates(P::Vector::canonical_basis_element(0) * torus.major_radius())
+    }
+}
@@ -0,0 +1,78 @@
+use alga::general::Id;
+use na;
+use query::{PointProjection, PointQuery};
+use shape::Voxels;
+use bounding_volume::AABB;
+use partitioning::{self, BVTCostFn};
+use math::{Isometry, Point};
+
+impl<P: Point, M: Isometry<P>> PointQuery<P, M> for Voxels<P> {
+    // XXX: if solid == false, this might return a projection on a face shared by two voxels.
+    // NOTE: if no voxel is occupied, the point is projected on the AABB of the whole grid.
+    #[inline]
+    fn project_point(&self, m: &M, point: &P, solid: bool) -> PointProjection<P> {
+        let ls_pt = m.inverse_transform_point(point);
+        let inside = contains_local_point(self, &ls_pt);
+
+        if inside && solid {
+            return PointProjection::new(true, *point);
+        }
+
+        let mut cost_fn = VoxelsPointProjCostFn {
+            voxels: self,
+            point: &ls_pt,
+            solid: solid,
+        };
+
+        let mut proj = match partitioning::bvh_best_first_search(self, &mut cost_fn) {
+            Some((_, proj)) => proj,
+            None => self.grid_aabb().project_point(&Id::new(), &ls_pt, solid),
+        };
+        proj.is_inside = inside;
+        proj.point = m.transform_point(&proj.point);
+
+        proj
+    }
+
+    #[inline]
+    fn contains_point(&self, m: &M, point: &P) -> bool {
+        let ls_pt = m.inverse_transform_point(point);
+
+        contains_local_point(self, &ls_pt)
+    }
+}
+
+#[inline]
+fn contains_local_point<P: Point>(voxels: &Voxels<P>, point: &P) -> bool {
+    match voxels.voxel_at_point(point) {
+        Some(coords) => voxels.is_occupied(voxels.voxel_id(&coords)),
+        None => false,
+    }
+}
+
+/*
+ * Costs function.
+ */
+struct VoxelsPointProjCostFn<'a, P: 'a + Point> {
+    voxels: &'a Voxels<P>,
+    point: &'a P,
+    solid: bool,
+}
+
+impl<'a, P: Point> BVTCostFn<P::Real, usize, AABB<P>> for VoxelsPointProjCostFn<'a, P> {
+    type UserData = PointProjection<P>;
+
+    #[inline]
+    fn compute_bv_cost(&mut self, aabb: &AABB<P>) -> Option<P::Real> {
+        Some(aabb.distance_to_point(&Id::new(), self.point, true))
+    }
+
+    #[inline]
+    fn compute_b_cost(&mut self, b: &usize) -> Option<(P::Real, Self::UserData)> {
+        let proj = self.voxels
+            .voxel_aabb(*b)
+            .project_point(&Id::new(), self.point, self.solid);
+
+        Some((na::distance(self.point, &proj.point), proj))
+    }
+}
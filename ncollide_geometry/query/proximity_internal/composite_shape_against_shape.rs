@@ -4,9 +4,9 @@ use alga::general::Id;
 use na;
 
 use bounding_volume::AABB;
-use partitioning::BVTCostFn;
+use partitioning::{self, BVTCostFn};
 use shape::{CompositeShape, Shape};
-use query::{PointQuery, Proximity};
+use query::{PointQuery, Proximity, QueryError};
 use query::proximity_internal;
 use math::{Isometry, Point};
 
@@ -28,9 +28,9 @@ where
         "The proximity margin must be positive or null."
     );
 
-    let mut cost_fn = CompositeShapeAgainstAnyInterfCostFn::new(m1, g1, m2, g2, margin);
+    let mut cost_fn = CompositeShapeAgainstAnyInterfCostFn::new(m1, g1, m2, g2, margin, false);
 
-    match g1.bvt().best_first_search(&mut cost_fn).map(|(_, res)| res) {
+    match partitioning::bvh_best_first_search(g1.bvh(), &mut cost_fn).map(|(_, res)| res) {
         None => Proximity::Disjoint,
         Some(prox) => prox,
     }
@@ -52,6 +52,54 @@ where
     composite_shape_against_shape(m2, g2, m1, g1, margin)
 }
 
+/// Proximity between a composite shape (`Mesh`, `Compound`) and any other shape.
+///
+/// Returns the first error reported by the proximity computation between a part of `g1` and `g2`.
+pub fn try_composite_shape_against_shape<P, M, G1: ?Sized>(
+    m1: &M,
+    g1: &G1,
+    m2: &M,
+    g2: &Shape<P, M>,
+    margin: P::Real,
+) -> Result<Proximity, QueryError>
+where
+    P: Point,
+    M: Isometry<P>,
+    G1: CompositeShape<P, M>,
+{
+    assert!(
+        margin >= na::zero(),
+        "The proximity margin must be positive or null."
+    );
+
+    let mut cost_fn = CompositeShapeAgainstAnyInterfCostFn::new(m1, g1, m2, g2, margin, true);
+    let res = partitioning::bvh_best_first_search(g1.bvh(), &mut cost_fn).map(|(_, res)| res);
+
+    match (cost_fn.error, res) {
+        (Some(err), _) => Err(err),
+        (None, None) => Ok(Proximity::Disjoint),
+        (None, Some(prox)) => Ok(prox),
+    }
+}
+
+/// Proximity between a shape and a composite (`Mesh`, `Compound`) shape.
+///
+/// Returns the first error reported by the proximity computation between `g1` and a part of `g2`.
+pub fn try_shape_against_composite_shape<P, M, G2: ?Sized>(
+    m1: &M,
+    g1: &Shape<P, M>,
+    m2: &M,
+    g2: &G2,
+    margin: P::Real,
+) -> Result<Proximity, QueryError>
+where
+    P: Point,
+    M: Isometry<P>,
+    G2: CompositeShape<P, M>,
+{
+    try_composite_shape_against_shape(m2, g2, m1, g1, margin)
+}
+
 struct CompositeShapeAgainstAnyInterfCostFn<'a, P: 'a + Point, M: 'a, G1: ?Sized + 'a> {
     msum_shift: P::Vector,
     msum_margin: P::Vector,
@@ -63,6 +111,10 @@ struct CompositeShapeAgainstAnyInterfCostFn<'a, P: 'a + Point, M: 'a, G1: ?Sized
     margin: P::Real,
 
     found_intersection: bool,
+    // If `true`, the errors of the proximity computations between parts are recorded instead of
+    // causing a panic.
+    strict: bool,
+    error: Option<QueryError>,
 
     point_type: PhantomData<P>,
 }
@@ -79,6 +131,7 @@ where
         m2: &'a M,
         g2: &'a Shape<P, M>,
         margin: P::Real,
+        strict: bool,
     ) -> CompositeShapeAgainstAnyInterfCostFn<'a, P, M, G1> {
         let ls_m2 = na::inverse(m1) * m2.clone();
         let ls_aabb2 = g2.aabb(&ls_m2);
@@ -92,6 +145,8 @@ where
             g2: g2,
             margin: margin,
             found_intersection: false,
+            strict: strict,
+            error: None,
             point_type: PhantomData,
         }
     }
@@ -108,8 +163,8 @@ where
 
     #[inline]
     fn compute_bv_cost(&mut self, bv: &AABB<P>) -> Option<P::Real> {
-        // No need to continue if some parts intersect.
-        if self.found_intersection {
+        // No need to continue if some parts intersect or if an error occurred.
+        if self.found_intersection || self.error.is_some() {
             return None;
         }
 
@@ -130,21 +185,44 @@ where
 
     #[inline]
     fn compute_b_cost(&mut self, b: &usize) -> Option<(P::Real, Proximity)> {
+        if self.error.is_some() {
+            return None;
+        }
+
         let mut res = None;
+        let mut found_intersection = false;
+        let mut error = None;
 
         self.g1.map_transformed_part_at(*b, self.m1, &mut |m1, g1| {
-            res =
-                match proximity_internal::proximity_internal(m1, g1, self.m2, self.g2, self.margin)
-                {
-                    Proximity::Disjoint => None,
-                    Proximity::WithinMargin => Some((self.margin, Proximity::WithinMargin)),
-                    Proximity::Intersecting => {
-                        self.found_intersection = true;
-                        Some((na::zero(), Proximity::Intersecting))
-                    }
+            let prox = if self.strict {
+                proximity_internal::try_proximity_internal(m1, g1, self.m2, self.g2, self.margin)
+            } else {
+                Ok(proximity_internal::proximity_internal(
+                    m1,
+                    g1,
+                    self.m2,
+                    self.g2,
+                    self.margin,
+                ))
+            };
+
+            res = match prox {
+                Ok(Proximity::Disjoint) => None,
+                Ok(Proximity::WithinMargin) => Some((self.margin, Proximity::WithinMargin)),
+                Ok(Proximity::Intersecting) => {
+                    found_intersection = true;
+                    Some((na::zero(), Proximity::Intersecting))
                 }
+                Err(err) => {
+                    error = Some(err);
+                    None
+                }
+            }
         });
 
+        self.found_intersection = self.found_intersection || found_intersection;
+        self.error = error;
+
         res
     }
 }
@@ -0,0 +1,75 @@
+use math::{Isometry, Point};
+use query::{distance_internal, proximity_internal, Proximity, QueryError};
+use shape::SupportMap;
+
+/// Proximity between two support-mapped shapes with rounded borders.
+///
+/// The proximity is computed between the core shapes `g1` and `g2` taking the border radii `r1`
+/// and `r2` into account. A non-rounded shape has a zero border radius.
+pub fn round_shape_against_round_shape<P, M, G1: ?Sized, G2: ?Sized>(
+    m1: &M,
+    g1: &G1,
+    r1: P::Real,
+    m2: &M,
+    g2: &G2,
+    r2: P::Real,
+    margin: P::Real,
+) -> Proximity
+where
+    P: Point,
+    M: Isometry<P>,
+    G1: SupportMap<P, M>,
+    G2: SupportMap<P, M>,
+{
+    let margins = r1 + r2;
+
+    match proximity_internal::support_map_against_support_map(m1, g1, m2, g2, margin + margins) {
+        Proximity::WithinMargin => {
+            // The cores are disjoint: check whether the borders intersect.
+            let dist = distance_internal::support_map_against_support_map(m1, g1, m2, g2);
+
+            if dist <= margins {
+                Proximity::Intersecting
+            } else {
+                Proximity::WithinMargin
+            }
+        }
+        prox => prox,
+    }
+}
+
+/// Proximity between two support-mapped shapes with rounded borders.
+///
+/// Returns an error if the proximity or the distance between the core shapes could not be
+/// computed.
+pub fn try_round_shape_against_round_shape<P, M, G1: ?Sized, G2: ?Sized>(
+    m1: &M,
+    g1: &G1,
+    r1: P::Real,
+    m2: &M,
+    g2: &G2,
+    r2: P::Real,
+    margin: P::Real,
+) -> Result<Proximity, QueryError>
+where
+    P: Point,
+    M: Isometry<P>,
+    G1: SupportMap<P, M>,
+    G2: SupportMap<P, M>,
+{
+    let margins = r1 + r2;
+    let margin = margin + margins;
+
+    match proximity_internal::try_support_map_against_support_map(m1, g1, m2, g2, margin)? {
+        Proximity::WithinMargin => {
+            let dist = distance_internal::try_support_map_against_support_map(m1, g1, m2, g2)?;
+
+            if dist <= margins {
+                Ok(Proximity::Intersecting)
+            } else {
+                Ok(Proximity::WithinMargin)
+            }
+        }
+        prox => Ok(prox),
+    }
+}
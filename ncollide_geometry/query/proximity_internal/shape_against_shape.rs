@@ -1,8 +1,8 @@
 use alga::linear::Translation;
 use math::{Isometry, Point};
-use shape::{Ball, Plane, Shape};
-use query::Proximity;
-use query::proximity_internal;
+use shape::{Ball, Plane, Shape, Torus};
+use query::{Proximity, QueryError};
+use query::{proximity_internal, query_error, query_registry};
 
 /// Tests whether two shapes are in intersecting or separated by a distance smaller than `margin`.
 pub fn shape_against_shape<P, M>(
@@ -16,25 +16,98 @@ where
     P: Point,
     M: Isometry<P>,
 {
-    if let (Some(b1), Some(b2)) = (
+    match dispatch(m1, g1, m2, g2, margin, false) {
+        Ok(res) => res,
+        Err(QueryError::UnsupportedShapePair) => {
+            panic!("No algorithm known to compute proximity between the given pair of shapes.")
+        }
+        Err(err) => panic!("Proximity computation failed: {}", err),
+    }
+}
+
+/// Tests whether two shapes are in intersecting or separated by a distance smaller than `margin`.
+///
+/// Returns an error if the proximity could not be determined.
+pub fn try_shape_against_shape<P, M>(
+    m1: &M,
+    g1: &Shape<P, M>,
+    m2: &M,
+    g2: &Shape<P, M>,
+    margin: P::Real,
+) -> Result<Proximity, QueryError>
+where
+    P: Point,
+    M: Isometry<P>,
+{
+    query_error::check_isometries::<P, M>(m1, m2)?;
+    query_error::check_real::<P>(margin)?;
+
+    dispatch(m1, g1, m2, g2, margin, true)
+}
+
+// If `strict` is `false`, the algorithms that never report errors are used whenever possible.
+fn dispatch<P, M>(
+    m1: &M,
+    g1: &Shape<P, M>,
+    m2: &M,
+    g2: &Shape<P, M>,
+    margin: P::Real,
+    strict: bool,
+) -> Result<Proximity, QueryError>
+where
+    P: Point,
+    M: Isometry<P>,
+{
+    if let Some(res) = query_registry::registered_proximity(m1, g1, m2, g2, margin) {
+        Ok(res)
+    } else if let (Some(b1), Some(b2)) = (
         g1.as_shape::<Ball<P::Real>>(),
         g2.as_shape::<Ball<P::Real>>(),
     ) {
         let p1 = P::from_coordinates(m1.translation().to_vector());
         let p2 = P::from_coordinates(m2.translation().to_vector());
 
-        proximity_internal::ball_against_ball(&p1, b1, &p2, b2, margin)
+        Ok(proximity_internal::ball_against_ball(&p1, b1, &p2, b2, margin))
     } else if let (Some(p1), Some(s2)) = (g1.as_shape::<Plane<P::Vector>>(), g2.as_support_map()) {
-        proximity_internal::plane_against_support_map(m1, p1, m2, s2, margin)
+        Ok(proximity_internal::plane_against_support_map(m1, p1, m2, s2, margin))
     } else if let (Some(s1), Some(p2)) = (g1.as_support_map(), g2.as_shape::<Plane<P::Vector>>()) {
-        proximity_internal::support_map_against_plane(m1, s1, m2, p2, margin)
+        Ok(proximity_internal::support_map_against_plane(m1, s1, m2, p2, margin))
+    } else if let (Some(t1), Some(s2)) = (g1.as_shape::<Torus<P::Real>>(), g2.as_support_map()) {
+        Ok(proximity_internal::torus_against_support_map(m1, t1, m2, s2, margin))
+    } else if let (Some(s1), Some(t2)) = (g1.as_support_map(), g2.as_shape::<Torus<P::Real>>()) {
+        Ok(proximity_internal::support_map_against_torus(m1, s1, m2, t2, margin))
+    } else if (g1.is_round_shape() && g2.is_support_map())
+        || (g1.is_support_map() && g2.is_round_shape())
+    {
+        let (s1, r1) = g1.as_support_map_with_border().unwrap();
+        let (s2, r2) = g2.as_support_map_with_border().unwrap();
+
+        if strict {
+            proximity_internal::try_round_shape_against_round_shape::<P, _, _, _>(
+                m1, s1, r1, m2, s2, r2, margin,
+            )
+        } else {
+            Ok(proximity_internal::round_shape_against_round_shape::<P, _, _, _>(
+                m1, s1, r1, m2, s2, r2, margin,
+            ))
+        }
     } else if let (Some(s1), Some(s2)) = (g1.as_support_map(), g2.as_support_map()) {
-        proximity_internal::support_map_against_support_map::<P, _, _, _>(m1, s1, m2, s2, margin)
+        proximity_internal::try_support_map_against_support_map::<P, _, _, _>(
+            m1, s1, m2, s2, margin,
+        )
     } else if let Some(c1) = g1.as_composite_shape() {
-        proximity_internal::composite_shape_against_shape(m1, c1, m2, g2, margin)
+        if strict {
+            proximity_internal::try_composite_shape_against_shape(m1, c1, m2, g2, margin)
+        } else {
+            Ok(proximity_internal::composite_shape_against_shape(m1, c1, m2, g2, margin))
+        }
     } else if let Some(c2) = g2.as_composite_shape() {
-        proximity_internal::shape_against_composite_shape(m1, g1, m2, c2, margin)
+        if strict {
+            proximity_internal::try_shape_against_composite_shape(m1, g1, m2, c2, margin)
+        } else {
+            Ok(proximity_internal::shape_against_composite_shape(m1, g1, m2, c2, margin))
+        }
     } else {
-        panic!("No algorithm known to compute proximity between the given pair of shapes.")
+        Err(QueryError::UnsupportedShapePair)
     }
 }
@@ -1,10 +1,12 @@
+use num::Zero;
+
 use alga::linear::Translation;
 use na;
 
 use shape::{self, AnnotatedPoint, SupportMap};
-use query::algorithms::gjk;
+use query::algorithms::{gjk, mpr};
 use query::algorithms::{Simplex, JohnsonSimplex, VoronoiSimplex2, VoronoiSimplex3};
-use query::Proximity;
+use query::{Proximity, QueryError};
 use math::{Isometry, Point};
 
 /// Proximity between support-mapped shapes (`Cuboid`, `ConvexHull`, etc.)
@@ -66,6 +68,71 @@ pub fn support_map_against_support_map_with_params<P, M, S, G1: ?Sized, G2: ?Siz
     simplex: &mut S,
     init_dir: Option<P::Vector>,
 ) -> (Proximity, P::Vector)
+where
+    P: Point,
+    M: Isometry<P>,
+    S: Simplex<AnnotatedPoint<P>>,
+    G1: SupportMap<P, M>,
+    G2: SupportMap<P, M>,
+{
+    match try_support_map_against_support_map_with_params(
+        m1,
+        g1,
+        m2,
+        g2,
+        margin,
+        simplex,
+        init_dir,
+    ) {
+        Ok(res) => res,
+        Err(QueryError::NoConvergence) => panic!("GJK did not converge."),
+        Err(err) => panic!("GJK failed: {}", err),
+    }
+}
+
+/// Proximity between support-mapped shapes (`Cuboid`, `ConvexHull`, etc.)
+///
+/// Returns an error if the GJK algorithm fails.
+pub fn try_support_map_against_support_map<P, M, G1: ?Sized, G2: ?Sized>(
+    m1: &M,
+    g1: &G1,
+    m2: &M,
+    g2: &G2,
+    margin: P::Real,
+) -> Result<Proximity, QueryError>
+where
+    P: Point,
+    M: Isometry<P>,
+    G1: SupportMap<P, M>,
+    G2: SupportMap<P, M>,
+{
+    let res = if na::dimension::<P::Vector>() == 2 {
+        let simplex = &mut VoronoiSimplex2::new();
+        try_support_map_against_support_map_with_params(m1, g1, m2, g2, margin, simplex, None)?
+    } else if na::dimension::<P::Vector>() == 3 {
+        let simplex = &mut VoronoiSimplex3::new();
+        try_support_map_against_support_map_with_params(m1, g1, m2, g2, margin, simplex, None)?
+    } else {
+        let simplex = &mut JohnsonSimplex::new_w_tls();
+        try_support_map_against_support_map_with_params(m1, g1, m2, g2, margin, simplex, None)?
+    };
+
+    Ok(res.0)
+}
+
+/// Proximity between support-mapped shapes (`Cuboid`, `ConvexHull`, etc.)
+///
+/// This allows a more fine grained control other the underlying GJK algorigtm. Returns an error
+/// if the GJK algorithm fails.
+pub fn try_support_map_against_support_map_with_params<P, M, S, G1: ?Sized, G2: ?Sized>(
+    m1: &M,
+    g1: &G1,
+    m2: &M,
+    g2: &G2,
+    margin: P::Real,
+    simplex: &mut S,
+    init_dir: Option<P::Vector>,
+) -> Result<(Proximity, P::Vector), QueryError>
 where
     P: Point,
     M: Isometry<P>,
@@ -90,5 +157,44 @@ where
 
     simplex.reset(shape::cso_support_point(m1, g1, m2, g2, dir));
 
-    gjk::proximity(m1, g1, m2, g2, margin, simplex)
+    gjk::try_proximity(m1, g1, m2, g2, margin, simplex)
+}
+
+/// Proximity between support-mapped shapes (`Cuboid`, `ConvexHull`, etc.) using the MPR
+/// algorithm.
+///
+/// This is usually faster than the GJK algorithm. However, the distance between disjoint shapes is
+/// only approximated so `Proximity::WithinMargin` may be reported for shapes slightly farther
+/// than `margin`.
+pub fn support_map_against_support_map_mpr<P, M, G1: ?Sized, G2: ?Sized>(
+    m1: &M,
+    g1: &G1,
+    m2: &M,
+    g2: &G2,
+    margin: P::Real,
+) -> Proximity
+where
+    P: Point,
+    M: Isometry<P>,
+    G1: SupportMap<P, M>,
+    G2: SupportMap<P, M>,
+{
+    assert!(
+        margin >= na::zero(),
+        "The proximity margin must be positive or null."
+    );
+
+    if mpr::intersection_test(m1, g1, m2, g2) {
+        Proximity::Intersecting
+    } else if margin.is_zero() {
+        Proximity::Disjoint
+    } else {
+        let (p1, p2, normal) = mpr::closest_points(m1, g1, m2, g2);
+
+        if na::dot(&(p1 - p2), normal.as_ref()) >= -margin {
+            Proximity::WithinMargin
+        } else {
+            Proximity::Disjoint
+        }
+    }
 }
@@ -0,0 +1,53 @@
+use num::Zero;
+
+use math::{Isometry, Point};
+use query::{distance_internal, Proximity};
+use shape::{SupportMap, Torus};
+
+/// Proximity between a torus and a support-mapped shape.
+///
+/// This is deduced from the distance between both shapes (see
+/// `distance_internal::torus_against_support_map`).
+pub fn torus_against_support_map<P, M, G: ?Sized>(
+    m1: &M,
+    t1: &Torus<P::Real>,
+    m2: &M,
+    g2: &G,
+    margin: P::Real,
+) -> Proximity
+where
+    P: Point,
+    M: Isometry<P>,
+    G: SupportMap<P, M>,
+{
+    assert!(
+        margin >= P::Real::zero(),
+        "The proximity margin must be positive or null."
+    );
+
+    let dist = distance_internal::torus_against_support_map(m1, t1, m2, g2);
+
+    if dist.is_zero() {
+        Proximity::Intersecting
+    } else if dist <= margin {
+        Proximity::WithinMargin
+    } else {
+        Proximity::Disjoint
+    }
+}
+
+/// Proximity between a support-mapped shape and a torus.
+pub fn support_map_against_torus<P, M, G: ?Sized>(
+    m1: &M,
+    g1: &G,
+    m2: &M,
+    t2: &Torus<P::Real>,
+    margin: P::Real,
+) -> Proximity
+where
+    P: Point,
+    M: Isometry<P>,
+    G: SupportMap<P, M>,
+{
+    torus_against_support_map(m2, t2, m1, g1, margin)
+}
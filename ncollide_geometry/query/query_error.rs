@@ -0,0 +1,80 @@
+use std::error::Error;
+use std::fmt;
+
+use alga::linear::{FiniteDimVectorSpace, Translation};
+use na;
+use math::{Isometry, Point};
+
+/// Errors reported by the fallible geometric queries.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum QueryError {
+    /// No algorithm is known to perform the query on the given pair of shapes.
+    UnsupportedShapePair,
+    /// An iterative algorithm, e.g., the GJK or the EPA, did not converge.
+    NoConvergence,
+    /// The input of the query is degenerate, e.g., it contains NaN values.
+    DegenerateInput,
+    /// A handle given to the query does not identify any object, e.g., of a collision world.
+    InvalidHandle,
+}
+
+impl fmt::Display for QueryError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.description())
+    }
+}
+
+impl Error for QueryError {
+    fn description(&self) -> &str {
+        match *self {
+            QueryError::UnsupportedShapePair => {
+                "no algorithm known for the given pair of shapes"
+            }
+            QueryError::NoConvergence => "the geometric algorithm did not converge",
+            QueryError::DegenerateInput => "degenerate input given to a geometric query",
+            QueryError::InvalidHandle => "the handle does not identify any object",
+        }
+    }
+}
+
+// Checks that the given transformations do not contain NaN values.
+pub(crate) fn check_isometries<P, M>(m1: &M, m2: &M) -> Result<(), QueryError>
+where
+    P: Point,
+    M: Isometry<P>,
+{
+    let axis = P::Vector::canonical_basis_element(0);
+
+    for m in &[m1, m2] {
+        let t = m.translation().to_vector();
+        let r = m.rotate_vector(&axis);
+
+        for i in 0..na::dimension::<P::Vector>() {
+            if t[i] != t[i] || r[i] != r[i] {
+                return Err(QueryError::DegenerateInput);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+// Checks that the given vector does not contain NaN values.
+pub(crate) fn check_vector<P: Point>(v: &P::Vector) -> Result<(), QueryError> {
+    for i in 0..na::dimension::<P::Vector>() {
+        if v[i] != v[i] {
+            return Err(QueryError::DegenerateInput);
+        }
+    }
+
+    Ok(())
+}
+
+// Checks that the given scalar is not NaN.
+pub(crate) fn check_real<P: Point>(x: P::Real) -> Result<(), QueryError> {
+    if x != x {
+        Err(QueryError::DegenerateInput)
+    } else {
+        Ok(())
+    }
+}
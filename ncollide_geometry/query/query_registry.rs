@@ -0,0 +1,356 @@
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+use std::sync::atomic::{AtomicBool, Ordering, ATOMIC_BOOL_INIT};
+
+use math::{Isometry, Point};
+use shape::Shape;
+use query::{Contact, Proximity, TOI};
+
+type DistanceFn<P, M> =
+    Arc<Fn(&M, &Shape<P, M>, &M, &Shape<P, M>) -> <P as Point>::Real + Send + Sync>;
+type ContactFn<P, M> = Arc<
+    Fn(&M, &Shape<P, M>, &M, &Shape<P, M>, <P as Point>::Real) -> Option<Contact<P>> + Send + Sync,
+>;
+type ProximityFn<P, M> =
+    Arc<Fn(&M, &Shape<P, M>, &M, &Shape<P, M>, <P as Point>::Real) -> Proximity + Send + Sync>;
+type TimeOfImpactFn<P, M> = Arc<
+    Fn(&M, &<P as Point>::Vector, &Shape<P, M>, &M, &<P as Point>::Vector, &Shape<P, M>)
+        -> Option<TOI<P>>
+        + Send
+        + Sync,
+>;
+
+/// A set of user-defined geometric queries for specific pairs of shape types.
+///
+/// The query functions registered for a pair of shapes take precedence over the algorithms
+/// implemented by this crate. They are consulted by `query::distance`, `query::contact`,
+/// `query::proximity`, and `query::time_of_impact`, as well as by the default contact and
+/// proximity dispatchers of the collision pipeline. This makes it possible to use shapes that are
+/// neither support maps nor composite shapes.
+///
+/// The registry used by those queries is global and can be modified with
+/// `query::modify_query_registry`.
+pub struct QueryRegistry<P: Point, M> {
+    distance: HashMap<(TypeId, TypeId), DistanceFn<P, M>>,
+    contact: HashMap<(TypeId, TypeId), ContactFn<P, M>>,
+    proximity: HashMap<(TypeId, TypeId), ProximityFn<P, M>>,
+    time_of_impact: HashMap<(TypeId, TypeId), TimeOfImpactFn<P, M>>,
+}
+
+impl<P: Point, M: Isometry<P>> QueryRegistry<P, M> {
+    /// Creates an empty query registry.
+    pub fn new() -> QueryRegistry<P, M> {
+        QueryRegistry {
+            distance: HashMap::new(),
+            contact: HashMap::new(),
+            proximity: HashMap::new(),
+            time_of_impact: HashMap::new(),
+        }
+    }
+
+    /// Registers the function computing the distance between shapes of types `G1` and `G2`.
+    ///
+    /// The function is also used for shapes of types `G2` and `G1`, in this order.
+    pub fn register_distance<G1, G2, F>(&mut self, f: F)
+    where
+        G1: Shape<P, M>,
+        G2: Shape<P, M>,
+        F: 'static + Send + Sync + Fn(&M, &G1, &M, &G2) -> P::Real,
+    {
+        let f = Arc::new(f);
+        let flipped = f.clone();
+
+        let _ = self.distance.insert(
+            key::<P, M, G2, G1>(),
+            Arc::new(
+                move |m1: &M, g1: &Shape<P, M>, m2: &M, g2: &Shape<P, M>| {
+                    flipped(m2, downcast(g2), m1, downcast(g1))
+                },
+            ),
+        );
+        let _ = self.distance.insert(
+            key::<P, M, G1, G2>(),
+            Arc::new(
+                move |m1: &M, g1: &Shape<P, M>, m2: &M, g2: &Shape<P, M>| {
+                    f(m1, downcast(g1), m2, downcast(g2))
+                },
+            ),
+        );
+    }
+
+    /// Registers the function computing one contact point between shapes of types `G1` and `G2`.
+    ///
+    /// The function is also used for shapes of types `G2` and `G1`, in this order, by flipping
+    /// the contacts it returns.
+    pub fn register_contact<G1, G2, F>(&mut self, f: F)
+    where
+        G1: Shape<P, M>,
+        G2: Shape<P, M>,
+        F: 'static + Send + Sync + Fn(&M, &G1, &M, &G2, P::Real) -> Option<Contact<P>>,
+    {
+        let f = Arc::new(f);
+        let flipped = f.clone();
+
+        let _ = self.contact.insert(
+            key::<P, M, G2, G1>(),
+            Arc::new(
+                move |m1: &M, g1: &Shape<P, M>, m2: &M, g2: &Shape<P, M>, prediction| {
+                    let mut res = flipped(m2, downcast(g2), m1, downcast(g1), prediction);
+
+                    for c in res.iter_mut() {
+                        c.flip()
+                    }
+
+                    res
+                },
+            ),
+        );
+        let _ = self.contact.insert(
+            key::<P, M, G1, G2>(),
+            Arc::new(
+                move |m1: &M, g1: &Shape<P, M>, m2: &M, g2: &Shape<P, M>, prediction| {
+                    f(m1, downcast(g1), m2, downcast(g2), prediction)
+                },
+            ),
+        );
+    }
+
+    /// Registers the function computing the proximity between shapes of types `G1` and `G2`.
+    ///
+    /// The function is also used for shapes of types `G2` and `G1`, in this order.
+    pub fn register_proximity<G1, G2, F>(&mut self, f: F)
+    where
+        G1: Shape<P, M>,
+        G2: Shape<P, M>,
+        F: 'static + Send + Sync + Fn(&M, &G1, &M, &G2, P::Real) -> Proximity,
+    {
+        let f = Arc::new(f);
+        let flipped = f.clone();
+
+        let _ = self.proximity.insert(
+            key::<P, M, G2, G1>(),
+            Arc::new(
+                move |m1: &M, g1: &Shape<P, M>, m2: &M, g2: &Shape<P, M>, margin| {
+                    flipped(m2, downcast(g2), m1, downcast(g1), margin)
+                },
+            ),
+        );
+        let _ = self.proximity.insert(
+            key::<P, M, G1, G2>(),
+            Arc::new(
+                move |m1: &M, g1: &Shape<P, M>, m2: &M, g2: &Shape<P, M>, margin| {
+                    f(m1, downcast(g1), m2, downcast(g2), margin)
+                },
+            ),
+        );
+    }
+
+    /// Registers the function computing the time of impact between shapes of types `G1` and
+    /// `G2` under translational movement.
+    ///
+    /// The function is also used for shapes of types `G2` and `G1`, in this order, by flipping
+    /// the results it returns.
+    pub fn register_time_of_impact<G1, G2, F>(&mut self, f: F)
+    where
+        G1: Shape<P, M>,
+        G2: Shape<P, M>,
+        F: 'static
+            + Send
+            + Sync
+            + Fn(&M, &P::Vector, &G1, &M, &P::Vector, &G2) -> Option<TOI<P>>,
+    {
+        let f = Arc::new(f);
+        let flipped = f.clone();
+
+        let _ = self.time_of_impact.insert(
+            key::<P, M, G2, G1>(),
+            Arc::new(
+                move |m1: &M,
+                      vel1: &P::Vector,
+                      g1: &Shape<P, M>,
+                      m2: &M,
+                      vel2: &P::Vector,
+                      g2: &Shape<P, M>| {
+                    let mut res = flipped(m2, vel2, downcast(g2), m1, vel1, downcast(g1));
+
+                    for toi in res.iter_mut() {
+                        toi.flip()
+                    }
+
+                    res
+                },
+            ),
+        );
+        let _ = self.time_of_impact.insert(
+            key::<P, M, G1, G2>(),
+            Arc::new(
+                move |m1: &M,
+                      vel1: &P::Vector,
+                      g1: &Shape<P, M>,
+                      m2: &M,
+                      vel2: &P::Vector,
+                      g2: &Shape<P, M>| {
+                    f(m1, vel1, downcast(g1), m2, vel2, downcast(g2))
+                },
+            ),
+        );
+    }
+
+    /// Tests if a distance function has been registered for the given pair of shapes.
+    pub fn has_distance(&self, g1: &Shape<P, M>, g2: &Shape<P, M>) -> bool {
+        self.distance.contains_key(&shape_key(g1, g2))
+    }
+
+    /// Tests if a contact function has been registered for the given pair of shapes.
+    pub fn has_contact(&self, g1: &Shape<P, M>, g2: &Shape<P, M>) -> bool {
+        self.contact.contains_key(&shape_key(g1, g2))
+    }
+
+    /// Tests if a proximity function has been registered for the given pair of shapes.
+    pub fn has_proximity(&self, g1: &Shape<P, M>, g2: &Shape<P, M>) -> bool {
+        self.proximity.contains_key(&shape_key(g1, g2))
+    }
+
+    /// Tests if a time of impact function has been registered for the given pair of shapes.
+    pub fn has_time_of_impact(&self, g1: &Shape<P, M>, g2: &Shape<P, M>) -> bool {
+        self.time_of_impact.contains_key(&shape_key(g1, g2))
+    }
+}
+
+type Registries = RwLock<HashMap<TypeId, Box<Any + Send + Sync>>>;
+
+lazy_static! {
+    static ref REGISTRIES: Registries = RwLock::new(HashMap::new());
+}
+
+// Set as soon as a registry is modified so that queries do not need to lock when none is used.
+static REGISTRIES_USED: AtomicBool = ATOMIC_BOOL_INIT;
+
+/// Modifies the global query registry for shapes with points of type `P` and transformations of
+/// type `M`.
+pub fn modify_query_registry<P, M, F>(f: F)
+where
+    P: Point,
+    M: Isometry<P>,
+    F: FnOnce(&mut QueryRegistry<P, M>),
+{
+    let mut registries = REGISTRIES.write().unwrap();
+    let registry = registries
+        .entry(TypeId::of::<QueryRegistry<P, M>>())
+        .or_insert_with(|| Box::new(QueryRegistry::<P, M>::new()));
+
+    f(registry.downcast_mut().unwrap());
+    REGISTRIES_USED.store(true, Ordering::Release);
+}
+
+/// Reads the global query registry for shapes with points of type `P` and transformations of
+/// type `M`.
+///
+/// Returns `None` if this registry has never been modified.
+pub fn read_query_registry<P, M, T, F>(f: F) -> Option<T>
+where
+    P: Point,
+    M: Isometry<P>,
+    F: FnOnce(&QueryRegistry<P, M>) -> T,
+{
+    if !REGISTRIES_USED.load(Ordering::Acquire) {
+        return None;
+    }
+
+    let registries = REGISTRIES.read().unwrap();
+
+    registries
+        .get(&TypeId::of::<QueryRegistry<P, M>>())
+        .map(|registry| f(registry.downcast_ref().unwrap()))
+}
+
+// The registered functions are cloned out of the registry before being called so that they may
+// call other queries, or modify the registry, without deadlocking.
+
+pub(crate) fn registered_distance<P, M>(
+    m1: &M,
+    g1: &Shape<P, M>,
+    m2: &M,
+    g2: &Shape<P, M>,
+) -> Option<P::Real>
+where
+    P: Point,
+    M: Isometry<P>,
+{
+    read_query_registry(|r: &QueryRegistry<P, M>| r.distance.get(&shape_key(g1, g2)).cloned())
+        .and_then(|f| f)
+        .map(|f| f(m1, g1, m2, g2))
+}
+
+pub(crate) fn registered_contact<P, M>(
+    m1: &M,
+    g1: &Shape<P, M>,
+    m2: &M,
+    g2: &Shape<P, M>,
+    prediction: P::Real,
+) -> Option<Option<Contact<P>>>
+where
+    P: Point,
+    M: Isometry<P>,
+{
+    read_query_registry(|r: &QueryRegistry<P, M>| r.contact.get(&shape_key(g1, g2)).cloned())
+        .and_then(|f| f)
+        .map(|f| f(m1, g1, m2, g2, prediction))
+}
+
+pub(crate) fn registered_proximity<P, M>(
+    m1: &M,
+    g1: &Shape<P, M>,
+    m2: &M,
+    g2: &Shape<P, M>,
+    margin: P::Real,
+) -> Option<Proximity>
+where
+    P: Point,
+    M: Isometry<P>,
+{
+    read_query_registry(|r: &QueryRegistry<P, M>| r.proximity.get(&shape_key(g1, g2)).cloned())
+        .and_then(|f| f)
+        .map(|f| f(m1, g1, m2, g2, margin))
+}
+
+pub(crate) fn registered_time_of_impact<P, M>(
+    m1: &M,
+    vel1: &P::Vector,
+    g1: &Shape<P, M>,
+    m2: &M,
+    vel2: &P::Vector,
+    g2: &Shape<P, M>,
+) -> Option<Option<TOI<P>>>
+where
+    P: Point,
+    M: Isometry<P>,
+{
+    let f = read_query_registry(|r: &QueryRegistry<P, M>| {
+        r.time_of_impact.get(&shape_key(g1, g2)).cloned()
+    });
+
+    f.and_then(|f| f).map(|f| f(m1, vel1, g1, m2, vel2, g2))
+}
+
+#[inline]
+fn key<P, M, G1, G2>() -> (TypeId, TypeId)
+where
+    P: Point,
+    G1: Shape<P, M>,
+    G2: Shape<P, M>,
+{
+    (TypeId::of::<G1>(), TypeId::of::<G2>())
+}
+
+#[inline]
+fn shape_key<P: Point, M: 'static>(g1: &Shape<P, M>, g2: &Shape<P, M>) -> (TypeId, TypeId) {
+    (g1.shape_type_id(), g2.shape_type_id())
+}
+
+#[inline]
+fn downcast<P: Point, M: 'static, G: Shape<P, M>>(g: &Shape<P, M>) -> &G {
+    g.as_shape::<G>()
+        .expect("The shape type does not match its query registry entry.")
+}
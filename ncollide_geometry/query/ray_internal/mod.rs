@@ -5,6 +5,7 @@ pub use self::ray_plane::plane_toi_with_ray;
 pub use self::ray_triangle::triangle_ray_intersection;
 pub use self::ray_support_map::implicit_toi_and_normal_with_ray;
 pub use self::ray_ball::ball_toi_with_ray;
+pub use self::ray_torus::torus_toi_and_normal_with_ray;
 pub use self::ray_bvt::{RayInterferencesCollector, RayIntersectionCostFn};
 
 use na::{Point2, Point3, Vector2, Vector3};
@@ -13,12 +14,19 @@ use na::{Point2, Point3, Vector2, Vector3};
 pub mod ray;
 mod ray_plane;
 mod ray_ball;
+mod ray_ellipsoid;
+mod ray_torus;
+mod ray_heightfield;
+mod ray_voxels;
+mod ray_bezier;
+mod ray_scaled;
 mod ray_cuboid;
 mod ray_aabb;
 mod ray_bounding_sphere;
 mod ray_support_map;
 mod ray_triangle;
 mod ray_compound;
+mod ray_dynamic_compound;
 mod ray_mesh;
 mod ray_shape;
 mod ray_bvt;
@@ -2,6 +2,7 @@
 
 use na::Point2;
 
+use shape::FeatureId;
 use math::{Isometry, Point, Vector};
 
 /// A Ray.
@@ -63,6 +64,22 @@ pub struct RayIntersection<V: Vector> {
     /// The textures coordinates at the intersection point.  This is an `Option` because some shape
     /// do not support texture coordinates.
     pub uvs: Option<Point2<V::Real>>,
+
+    /// The feature of the shape hit by the ray.
+    ///
+    /// This is `FeatureId::Unknown` if the shape cannot identify it.
+    pub feature: FeatureId,
+
+    /// The index of the part hit by the ray if the shape is a composite shape with sub-shapes,
+    /// e.g., a `Compound`.
+    ///
+    /// If parts are themselves composite shapes, this is the index of the part of the outermost
+    /// one while `feature` is the feature of the innermost shape hit.
+    pub part: Option<usize>,
+
+    /// The index of the part hit by the ray within the part `part`, if the latter is itself a
+    /// composite shape, e.g., the triangle of a `TriMesh` part of a `Compound`.
+    pub sub_part: Option<usize>,
 }
 
 impl<V: Vector> RayIntersection<V> {
@@ -77,6 +94,9 @@ impl<V: Vector> RayIntersection<V> {
             toi: toi,
             normal: normal,
             uvs: uvs,
+            feature: FeatureId::Unknown,
+            part: None,
+            sub_part: None,
         }
     }
 
@@ -87,6 +107,22 @@ impl<V: Vector> RayIntersection<V> {
             toi: toi,
             normal: normal,
             uvs: None,
+            feature: FeatureId::Unknown,
+            part: None,
+            sub_part: None,
+        }
+    }
+
+    #[inline]
+    /// Creates a new `RayIntersection` with the given hit feature.
+    pub fn new_with_feature(toi: V::Real, normal: V, feature: FeatureId) -> RayIntersection<V> {
+        RayIntersection {
+            toi: toi,
+            normal: normal,
+            uvs: None,
+            feature: feature,
+            part: None,
+            sub_part: None,
         }
     }
 }
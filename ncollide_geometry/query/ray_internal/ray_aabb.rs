@@ -6,6 +6,7 @@ use na::{self, Point2};
 
 use query::{Ray, RayCast, RayIntersection};
 use bounding_volume::AABB;
+use shape::FeatureId;
 use math::{Isometry, Point};
 
 impl<P: Point, M: Isometry<P>> RayCast<P, M> for AABB<P> {
@@ -55,7 +56,8 @@ impl<P: Point, M: Isometry<P>> RayCast<P, M> for AABB<P> {
     ) -> Option<RayIntersection<P::Vector>> {
         let ls_ray = ray.inverse_transform_by(m);
 
-        ray_aabb(self, &ls_ray, solid).map(|(t, n, _)| RayIntersection::new(t, m.rotate_vector(&n)))
+        ray_aabb(self, &ls_ray, solid)
+            .map(|(t, n, _, f)| RayIntersection::new_with_feature(t, m.rotate_vector(&n), f))
     }
 
     fn toi_and_normal_and_uv_with_ray(
@@ -83,14 +85,14 @@ where
     } else {
         let ls_ray = ray.inverse_transform_by(m);
 
-        ray_aabb(aabb, &ls_ray, solid).map(|(t, n, s)| {
+        ray_aabb(aabb, &ls_ray, solid).map(|(t, n, s, f)| {
             let pt = ls_ray.origin + ls_ray.dir * t;
             let dpt = pt - *aabb.mins();
             let scale = *aabb.maxs() - *aabb.mins();
             let id = na::abs(&s);
             let gs_n = m.rotate_vector(&n);
 
-            if id == 1 {
+            let mut res = if id == 1 {
                 RayIntersection::new_with_uvs(
                     t,
                     gs_n,
@@ -108,12 +110,29 @@ where
                     gs_n,
                     Some(Point2::new(dpt[0] / scale[0], dpt[1] / scale[1])),
                 )
-            }
+            };
+
+            res.feature = f;
+            res
         })
     }
 }
 
-fn ray_aabb<P>(aabb: &AABB<P>, ray: &Ray<P>, solid: bool) -> Option<(P::Real, P::Vector, isize)>
+// The face of an AABB with the given normal direction `+e_i` (if `positive`) or `-e_i`.
+#[inline]
+fn face_id<P: Point>(i: usize, positive: bool) -> FeatureId {
+    if positive {
+        FeatureId::Face(i)
+    } else {
+        FeatureId::Face(i + na::dimension::<P::Vector>())
+    }
+}
+
+fn ray_aabb<P>(
+    aabb: &AABB<P>,
+    ray: &Ray<P>,
+    solid: bool,
+) -> Option<(P::Real, P::Vector, isize, FeatureId)>
 where
     P: Point,
 {
@@ -176,34 +195,40 @@ where
     if tmin < na::convert(0.0f64) {
         // the ray starts inside of the box
         if solid {
-            Some((na::zero(), na::zero(), far_side))
+            Some((na::zero(), na::zero(), far_side, FeatureId::Unknown))
         } else {
             if far_diag {
-                Some((tmax, -na::normalize(&ray.dir), far_side))
+                Some((tmax, -na::normalize(&ray.dir), far_side, FeatureId::Unknown))
             } else {
                 let mut normal = na::zero::<P::Vector>();
+                let feature;
 
                 if far_side < 0 {
                     normal[(-far_side - 1) as usize] = -na::one::<P::Real>();
+                    feature = face_id::<P>((-far_side - 1) as usize, false);
                 } else {
                     normal[(far_side - 1) as usize] = na::one::<P::Real>();
+                    feature = face_id::<P>((far_side - 1) as usize, true);
                 }
 
-                Some((tmax, normal, far_side))
+                Some((tmax, normal, far_side, feature))
             }
         }
     } else {
         if near_diag {
-            Some((tmin, -na::normalize(&ray.dir), near_side))
+            Some((tmin, -na::normalize(&ray.dir), near_side, FeatureId::Unknown))
         } else {
             let mut normal = na::zero::<P::Vector>();
+            let feature;
 
             if near_side < 0 {
                 normal[(-near_side - 1) as usize] = na::one::<P::Real>();
+                feature = face_id::<P>((-near_side - 1) as usize, true);
             } else {
                 normal[(near_side - 1) as usize] = -na::one::<P::Real>();
+                feature = face_id::<P>((near_side - 1) as usize, false);
             }
-            Some((tmin, normal, near_side))
+            Some((tmin, normal, near_side, feature))
         }
     }
 }
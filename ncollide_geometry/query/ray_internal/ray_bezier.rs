@@ -0,0 +1,105 @@
+use alga::general::Id;
+
+use bounding_volume::AABB;
+use partitioning::{self, BVTCostFn};
+use query::{Ray, RayCast, RayIntersection};
+use shape::{BezierCurve, BezierSurface};
+use math::{Isometry, Point};
+
+/// The ray is cast on the piecewise-linear approximation of the curve. Only the curve pieces
+/// with a bounding box hit by the ray are subdivided.
+impl<P: Point, M: Isometry<P>> RayCast<P, M> for BezierCurve<P> {
+    fn toi_and_normal_with_ray(
+        &self,
+        m: &M,
+        ray: &Ray<P>,
+        solid: bool,
+    ) -> Option<RayIntersection<P::Vector>> {
+        let ls_ray = ray.inverse_transform_by(m);
+
+        let mut cost_fn = BezierCurveRayToiAndNormalCostFn {
+            curve: self,
+            ray: &ls_ray,
+            solid: solid,
+        };
+
+        partitioning::bvh_best_first_search(self, &mut cost_fn).map(|(_, mut res)| {
+            res.normal = m.rotate_vector(&res.normal);
+            res
+        })
+    }
+}
+
+/// The ray is cast on the piecewise-linear approximation of the surface. Only the surface pieces
+/// with a bounding box hit by the ray are subdivided.
+impl<P: Point, M: Isometry<P>> RayCast<P, M> for BezierSurface<P> {
+    fn toi_and_normal_with_ray(
+        &self,
+        m: &M,
+        ray: &Ray<P>,
+        solid: bool,
+    ) -> Option<RayIntersection<P::Vector>> {
+        let ls_ray = ray.inverse_transform_by(m);
+
+        let mut cost_fn = BezierSurfaceRayToiAndNormalCostFn {
+            surface: self,
+            ray: &ls_ray,
+            solid: solid,
+        };
+
+        partitioning::bvh_best_first_search(self, &mut cost_fn).map(|(_, mut res)| {
+            res.normal = m.rotate_vector(&res.normal);
+            res
+        })
+    }
+}
+
+/*
+ * Costs functions.
+ */
+struct BezierCurveRayToiAndNormalCostFn<'a, P: 'a + Point> {
+    curve: &'a BezierCurve<P>,
+    ray: &'a Ray<P>,
+    solid: bool,
+}
+
+impl<'a, P: Point> BVTCostFn<P::Real, usize, AABB<P>> for BezierCurveRayToiAndNormalCostFn<'a, P> {
+    type UserData = RayIntersection<P::Vector>;
+
+    #[inline]
+    fn compute_bv_cost(&mut self, aabb: &AABB<P>) -> Option<P::Real> {
+        aabb.toi_with_ray(&Id::new(), self.ray, self.solid)
+    }
+
+    #[inline]
+    fn compute_b_cost(&mut self, b: &usize) -> Option<(P::Real, RayIntersection<P::Vector>)> {
+        self.curve
+            .segment_at(*b)
+            .toi_and_normal_with_ray(&Id::new(), self.ray, self.solid)
+            .map(|inter| (inter.toi, inter))
+    }
+}
+
+struct BezierSurfaceRayToiAndNormalCostFn<'a, P: 'a + Point> {
+    surface: &'a BezierSurface<P>,
+    ray: &'a Ray<P>,
+    solid: bool,
+}
+
+impl<'a, P: Point> BVTCostFn<P::Real, usize, AABB<P>>
+    for BezierSurfaceRayToiAndNormalCostFn<'a, P> {
+    type UserData = RayIntersection<P::Vector>;
+
+    #[inline]
+    fn compute_bv_cost(&mut self, aabb: &AABB<P>) -> Option<P::Real> {
+        aabb.toi_with_ray(&Id::new(), self.ray, self.solid)
+    }
+
+    #[inline]
+    fn compute_b_cost(&mut self, b: &usize) -> Option<(P::Real, RayIntersection<P::Vector>)> {
+        self.surface
+            .triangle_at(*b)
+            .toi_and_normal_with_ray(&Id::new(), self.ray, self.solid)
+            .map(|inter| (inter.toi, inter))
+    }
+}
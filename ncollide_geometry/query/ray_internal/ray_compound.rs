@@ -96,6 +96,10 @@ impl<'a, P: Point, M: Isometry<P>> BVTCostFn<P::Real, usize, AABB<P>>
         let elt = &self.compound.shapes()[*b];
         elt.1
             .toi_and_normal_with_ray(&elt.0, self.ray, self.solid)
-            .map(|inter| (inter.toi, inter))
+            .map(|mut inter| {
+                inter.sub_part = inter.part;
+                inter.part = Some(*b);
+                (inter.toi, inter)
+            })
     }
 }
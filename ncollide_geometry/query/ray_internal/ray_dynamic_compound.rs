@@ -0,0 +1,102 @@
+use alga::general::Id;
+use bounding_volume::AABB;
+use shape::DynamicCompound;
+use partitioning::BVTCostFn;
+use query::{Ray, RayCast, RayIntersection};
+use math::{Isometry, Point};
+
+// XXX: if solid == false, this might return internal intersection.
+impl<P: Point, M: Isometry<P>> RayCast<P, M> for DynamicCompound<P, M> {
+    fn toi_with_ray(&self, m: &M, ray: &Ray<P>, solid: bool) -> Option<P::Real> {
+        let ls_ray = ray.inverse_transform_by(m);
+
+        let mut cost_fn = DynamicCompoundRayToiCostFn {
+            compound: self,
+            ray: &ls_ray,
+            solid: solid,
+        };
+
+        self.dbvt()
+            .best_first_search(&mut cost_fn)
+            .map(|(_, res)| res)
+    }
+
+    fn toi_and_normal_with_ray(
+        &self,
+        m: &M,
+        ray: &Ray<P>,
+        solid: bool,
+    ) -> Option<RayIntersection<P::Vector>> {
+        let ls_ray = ray.inverse_transform_by(m);
+
+        let mut cost_fn = DynamicCompoundRayToiAndNormalCostFn {
+            compound: self,
+            ray: &ls_ray,
+            solid: solid,
+        };
+
+        self.dbvt()
+            .best_first_search(&mut cost_fn)
+            .map(|(_, mut res)| {
+                res.normal = m.rotate_vector(&res.normal);
+                res
+            })
+    }
+}
+
+/*
+ * Costs functions.
+ */
+struct DynamicCompoundRayToiCostFn<'a, P: 'a + Point, M: 'a> {
+    compound: &'a DynamicCompound<P, M>,
+    ray: &'a Ray<P>,
+    solid: bool,
+}
+
+impl<'a, P, M> BVTCostFn<P::Real, usize, AABB<P>> for DynamicCompoundRayToiCostFn<'a, P, M>
+where
+    P: Point,
+    M: Isometry<P>,
+{
+    type UserData = P::Real;
+    #[inline]
+    fn compute_bv_cost(&mut self, aabb: &AABB<P>) -> Option<P::Real> {
+        aabb.toi_with_ray(&Id::new(), self.ray, self.solid)
+    }
+
+    #[inline]
+    fn compute_b_cost(&mut self, b: &usize) -> Option<(P::Real, P::Real)> {
+        let (delta, shape) = self.compound.part(*b).unwrap();
+        shape
+            .toi_with_ray(delta, self.ray, self.solid)
+            .map(|toi| (toi, toi))
+    }
+}
+
+struct DynamicCompoundRayToiAndNormalCostFn<'a, P: 'a + Point, M: 'a> {
+    compound: &'a DynamicCompound<P, M>,
+    ray: &'a Ray<P>,
+    solid: bool,
+}
+
+impl<'a, P: Point, M: Isometry<P>> BVTCostFn<P::Real, usize, AABB<P>>
+    for DynamicCompoundRayToiAndNormalCostFn<'a, P, M> {
+    type UserData = RayIntersection<P::Vector>;
+
+    #[inline]
+    fn compute_bv_cost(&mut self, aabb: &AABB<P>) -> Option<P::Real> {
+        aabb.toi_with_ray(&Id::new(), self.ray, self.solid)
+    }
+
+    #[inline]
+    fn compute_b_cost(&mut self, b: &usize) -> Option<(P::Real, RayIntersection<P::Vector>)> {
+        let (delta, shape) = self.compound.part(*b).unwrap();
+        shape
+            .toi_and_normal_with_ray(delta, self.ray, self.solid)
+            .map(|mut inter| {
+                inter.sub_part = inter.part;
+                inter.part = Some(*b);
+                (inter.toi, inter)
+            })
+    }
+}
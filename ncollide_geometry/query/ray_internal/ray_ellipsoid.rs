@@ -0,0 +1,37 @@
+use na;
+
+use query::{Ray, RayCast, RayIntersection};
+use query::ray_internal::ball_toi_with_ray;
+use shape::Ellipsoid;
+use math::{Isometry, Point};
+
+impl<P: Point, M: Isometry<P>> RayCast<P, M> for Ellipsoid<P::Vector> {
+    #[inline]
+    fn toi_and_normal_with_ray(
+        &self,
+        m: &M,
+        ray: &Ray<P>,
+        solid: bool,
+    ) -> Option<RayIntersection<P::Vector>> {
+        let ls_ray = ray.inverse_transform_by(m);
+
+        // The ellipsoid is a unit ball once its local frame is divided by its radii. The time of
+        // impact is left unchanged by this linear mapping.
+        let mut unit_ray = ls_ray;
+
+        for i in 0..na::dimension::<P::Vector>() {
+            unit_ray.origin[i] = unit_ray.origin[i] / self.radii()[i];
+            unit_ray.dir[i] = unit_ray.dir[i] / self.radii()[i];
+        }
+
+        let (inside, inter) = ball_toi_with_ray(&P::origin(), na::one(), &unit_ray, solid);
+
+        inter.map(|toi| {
+            let pos = ls_ray.origin + ls_ray.dir * toi;
+            let normal = self.normal_at(&pos.coordinates());
+            let normal = m.rotate_vector(&normal);
+
+            RayIntersection::new(toi, if inside { -normal } else { normal })
+        })
+    }
+}
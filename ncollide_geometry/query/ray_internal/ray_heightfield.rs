@@ -0,0 +1,191 @@
+use std::mem;
+use num::{Bounded, Zero};
+
+use alga::general::{Id, Real};
+use na;
+
+use query::{Ray, RayCast, RayIntersection};
+use shape::{FeatureId, HeightField};
+use math::{Isometry, Point};
+
+impl<P: Point, M: Isometry<P>> RayCast<P, M> for HeightField<P> {
+    #[inline]
+    fn toi_and_normal_with_ray(
+        &self,
+        m: &M,
+        ray: &Ray<P>,
+        solid: bool,
+    ) -> Option<RayIntersection<P::Vector>> {
+        let ls_ray = ray.inverse_transform_by(m);
+
+        heightfield_toi_and_normal_with_ray(self, &ls_ray, solid).map(|mut res| {
+            res.normal = m.rotate_vector(&res.normal);
+            res
+        })
+    }
+}
+
+/// Casts a ray expressed in the local frame of a height field.
+///
+/// The ray is first clipped by the height field AABB. Then, the cells crossed by the projection
+/// of the ray on the `xz` plane are traversed in order until a hit is found.
+fn heightfield_toi_and_normal_with_ray<P: Point>(
+    hf: &HeightField<P>,
+    ray: &Ray<P>,
+    solid: bool,
+) -> Option<RayIntersection<P::Vector>> {
+    let (tmin, tmax) = match clip_ray(hf, ray) {
+        Some(range) => range,
+        None => return None,
+    };
+
+    // Compute the cell containing the clipped ray origin.
+    let aabb = hf.local_aabb();
+    let start = na::sup(aabb.mins(), &na::inf(aabb.maxs(), &(ray.origin + ray.dir * tmin)));
+    let (mut i, mut j) = match hf.cell_at_point(&start) {
+        Some(cell) => cell,
+        None => return None,
+    };
+
+    // Initialize the traversal along each axis of the grid.
+    let (step_j, mut next_tj, delta_tj) =
+        traversal_params(ray, 0, aabb.mins()[0], hf.cell_width(), j);
+    let (step_i, mut next_ti, delta_ti) = if na::dimension::<P::Vector>() > 2 {
+        traversal_params(ray, 2, aabb.mins()[2], hf.cell_depth(), i)
+    } else {
+        (0, P::Real::max_value(), P::Real::max_value())
+    };
+
+    loop {
+        let (part1, part2) = hf.parts_at_cell(i, j);
+        let mut best = cast_on_part(hf, part1, ray, solid);
+
+        if let Some(part2) = part2 {
+            if let Some(inter) = cast_on_part(hf, part2, ray, solid) {
+                let is_better = match best {
+                    Some(ref b) => inter.toi < b.toi,
+                    None => true,
+                };
+
+                if is_better {
+                    best = Some(inter);
+                }
+            }
+        }
+
+        if best.is_some() {
+            return best;
+        }
+
+        // Move to the next cell.
+        if next_tj < next_ti {
+            if next_tj > tmax || !advance(&mut j, step_j, hf.ncells_x()) {
+                return None;
+            }
+
+            next_tj = next_tj + delta_tj;
+        } else {
+            if next_ti > tmax || !advance(&mut i, step_i, hf.ncells_z()) {
+                return None;
+            }
+
+            next_ti = next_ti + delta_ti;
+        }
+    }
+}
+
+#[inline]
+fn cast_on_part<P: Point>(
+    hf: &HeightField<P>,
+    part: usize,
+    ray: &Ray<P>,
+    solid: bool,
+) -> Option<RayIntersection<P::Vector>> {
+    let res = if na::dimension::<P::Vector>() > 2 {
+        hf.triangle_at(part)
+            .toi_and_normal_with_ray(&Id::new(), ray, solid)
+    } else {
+        hf.segment_at(part)
+            .toi_and_normal_with_ray(&Id::new(), ray, solid)
+    };
+
+    res.map(|mut inter| {
+        inter.feature = FeatureId::Face(part);
+        inter
+    })
+}
+
+/// Computes the parameters of the ray entering and leaving the height field AABB.
+fn clip_ray<P: Point>(hf: &HeightField<P>, ray: &Ray<P>) -> Option<(P::Real, P::Real)> {
+    let aabb = hf.local_aabb();
+    let mut tmin: P::Real = na::zero();
+    let mut tmax: P::Real = Bounded::max_value();
+
+    for i in 0usize..na::dimension::<P::Vector>() {
+        if ray.dir[i].is_zero() {
+            if ray.origin[i] < aabb.mins()[i] || ray.origin[i] > aabb.maxs()[i] {
+                return None;
+            }
+        } else {
+            let _1: P::Real = na::one();
+            let denom = _1 / ray.dir[i];
+            let mut near = (aabb.mins()[i] - ray.origin[i]) * denom;
+            let mut far = (aabb.maxs()[i] - ray.origin[i]) * denom;
+
+            if near > far {
+                mem::swap(&mut near, &mut far)
+            }
+
+            tmin = tmin.max(near);
+            tmax = tmax.min(far);
+
+            if tmin > tmax {
+                return None;
+            }
+        }
+    }
+
+    Some((tmin, tmax))
+}
+
+/// Computes the step direction, the parameter of the first cell boundary crossing, and the
+/// parameter increment between two boundary crossings along the axis `axis`.
+pub(crate) fn traversal_params<P: Point>(
+    ray: &Ray<P>,
+    axis: usize,
+    min: P::Real,
+    cell_size: P::Real,
+    cell: usize,
+) -> (isize, P::Real, P::Real) {
+    let dir = ray.dir[axis];
+
+    if dir.is_zero() {
+        return (0, P::Real::max_value(), P::Real::max_value());
+    }
+
+    let (step, boundary) = if dir > na::zero() {
+        (1, cell + 1)
+    } else {
+        (-1, cell)
+    };
+
+    let boundary_coord = min + cell_size * na::convert(boundary as f64);
+    let next_t = (boundary_coord - ray.origin[axis]) / dir;
+    let delta_t = cell_size / dir.abs();
+
+    (step, next_t, delta_t)
+}
+
+/// Moves `cell` by `step`. Returns `false` if this leaves the range `[0, ncells[`.
+#[inline]
+pub(crate) fn advance(cell: &mut usize, step: isize, ncells: usize) -> bool {
+    if step > 0 {
+        *cell += 1;
+        *cell < ncells
+    } else if step < 0 && *cell > 0 {
+        *cell -= 1;
+        true
+    } else {
+        false
+    }
+}
@@ -6,10 +6,10 @@ use alga::linear::NormedSpace;
 use na::{self, Point2, Vector3};
 
 use query::{ray_internal, Ray, RayCast, RayIntersection};
-use shape::{BaseMesh, BaseMeshElement, Polyline, TriMesh};
+use shape::{BaseMesh, BaseMeshElement, FeatureId, Polyline, TriMesh};
 use bounding_volume::AABB;
 use partitioning::BVTCostFn;
-use math::{Isometry, Point};
+use math::{Isometry, Point, Vector};
 
 impl<P, M, I, E> RayCast<P, M> for BaseMesh<P, I, E>
 where
@@ -90,7 +90,7 @@ where
                 let uvy = uv1.y * uv.x + uv2.y * uv.y + uv3.y * uv.z;
 
                 // XXX: this interpolation should be done on the two other ray cast too!
-                match *self.normals() {
+                let res = match *self.normals() {
                     None => Some(RayIntersection::new_with_uvs(
                         toi,
                         m.rotate_vector(&n),
@@ -125,12 +125,30 @@ where
                             }
                         }
                     }
-                }
+                };
+
+                res.map(|mut res| {
+                    res.feature = FeatureId::Face(*best);
+                    res
+                })
             }
         }
     }
 }
 
+// Whether the ray hits the back face of the i-th element of a one-sided mesh.
+#[inline]
+fn is_back_face<P, I, E>(mesh: &BaseMesh<P, I, E>, i: usize, ray: &Ray<P>) -> bool
+where
+    P: Point,
+    E: BaseMeshElement<I, P>,
+{
+    match mesh.front_normal_at(i) {
+        Some(n) => na::dot(&*n, &ray.dir) > na::zero(),
+        None => false,
+    }
+}
+
 /*
  * Costs functions.
  */
@@ -153,6 +171,10 @@ where
 
     #[inline]
     fn compute_b_cost(&mut self, b: &usize) -> Option<(P::Real, P::Real)> {
+        if is_back_face(self.mesh, *b, self.ray) {
+            return None;
+        }
+
         self.mesh
             .element_at(*b)
             .toi_with_ray(&Id::new(), self.ray, true)
@@ -179,10 +201,17 @@ where
 
     #[inline]
     fn compute_b_cost(&mut self, b: &usize) -> Option<(P::Real, RayIntersection<P::Vector>)> {
+        if is_back_face(self.mesh, *b, self.ray) {
+            return None;
+        }
+
         self.mesh
             .element_at(*b)
             .toi_and_normal_with_ray(&Id::new(), self.ray, true)
-            .map(|inter| (inter.toi, inter))
+            .map(|mut inter| {
+                inter.feature = FeatureId::Face(*b);
+                (inter.toi, inter)
+            })
     }
 }
 
@@ -210,6 +239,10 @@ where
         &mut self,
         b: &usize,
     ) -> Option<(P::Real, (RayIntersection<P::Vector>, Vector3<P::Real>))> {
+        if is_back_face(self.mesh, *b, self.ray) {
+            return None;
+        }
+
         let vs = &self.mesh.vertices()[..];
         let idx = &self.mesh.indices()[*b];
 
@@ -265,7 +298,9 @@ impl<P: Point, M: Isometry<P>> RayCast<P, M> for Polyline<P> {
         ray: &Ray<P>,
         solid: bool,
     ) -> Option<RayIntersection<P::Vector>> {
-        self.base_mesh().toi_and_normal_with_ray(m, ray, solid)
+        self.base_mesh()
+            .toi_and_normal_with_ray(m, ray, solid)
+            .map(segment_feature)
     }
 
     #[inline]
@@ -277,5 +312,18 @@ impl<P: Point, M: Isometry<P>> RayCast<P, M> for Polyline<P> {
     ) -> Option<RayIntersection<P::Vector>> {
         self.base_mesh()
             .toi_and_normal_and_uv_with_ray(m, ray, solid)
+            .map(segment_feature)
     }
 }
+
+// The segments of a polyline are its faces in 2D, but its edges in higher dimensions.
+#[inline]
+fn segment_feature<V: Vector>(mut inter: RayIntersection<V>) -> RayIntersection<V> {
+    if na::dimension::<V>() != 2 {
+        if let FeatureId::Face(i) = inter.feature {
+            inter.feature = FeatureId::Edge(i)
+        }
+    }
+
+    inter
+}
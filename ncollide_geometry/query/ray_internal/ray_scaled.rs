@@ -0,0 +1,141 @@
+use std::ops::Deref;
+
+use na;
+use query::{Ray, RayCast, RayIntersection};
+use shape::{Scaled, ScaledPart, Shape};
+use math::{Isometry, Point};
+
+impl<P, M, S> RayCast<P, M> for Scaled<S, P::Vector>
+where
+    P: Point,
+    M: Isometry<P>,
+    S: Deref<Target = Shape<P, M>>,
+{
+    #[inline]
+    fn toi_with_ray(&self, m: &M, ray: &Ray<P>, solid: bool) -> Option<P::Real> {
+        let one: M = na::one();
+
+        // The time of impact is invariant by scaling both the ray and the shape.
+        self.shape()
+            .as_ray_cast()
+            .expect("The scaled shape does not support ray casting.")
+            .toi_with_ray(&one, &unscale_ray(self, m, ray), solid)
+    }
+
+    #[inline]
+    fn toi_and_normal_with_ray(
+        &self,
+        m: &M,
+        ray: &Ray<P>,
+        solid: bool,
+    ) -> Option<RayIntersection<P::Vector>> {
+        let one: M = na::one();
+
+        self.shape()
+            .as_ray_cast()
+            .expect("The scaled shape does not support ray casting.")
+            .toi_and_normal_with_ray(&one, &unscale_ray(self, m, ray), solid)
+            .map(|mut res| {
+                res.normal = m.rotate_vector(&scale_normal(self, &res.normal));
+                res
+            })
+    }
+
+    #[inline]
+    fn toi_and_normal_and_uv_with_ray(
+        &self,
+        m: &M,
+        ray: &Ray<P>,
+        solid: bool,
+    ) -> Option<RayIntersection<P::Vector>> {
+        let one: M = na::one();
+
+        self.shape()
+            .as_ray_cast()
+            .expect("The scaled shape does not support ray casting.")
+            .toi_and_normal_and_uv_with_ray(&one, &unscale_ray(self, m, ray), solid)
+            .map(|mut res| {
+                res.normal = m.rotate_vector(&scale_normal(self, &res.normal));
+                res
+            })
+    }
+}
+
+impl<P: Point, M: Isometry<P>> RayCast<P, M> for ScaledPart<P, M> {
+    #[inline]
+    fn toi_with_ray(&self, m: &M, ray: &Ray<P>, solid: bool) -> Option<P::Real> {
+        self.shape()
+            .as_ray_cast()
+            .expect("The scaled part does not support ray casting.")
+            .toi_with_ray(self.delta(), &unscale_ray(self.scaled(), m, ray), solid)
+    }
+
+    #[inline]
+    fn toi_and_normal_with_ray(
+        &self,
+        m: &M,
+        ray: &Ray<P>,
+        solid: bool,
+    ) -> Option<RayIntersection<P::Vector>> {
+        self.shape()
+            .as_ray_cast()
+            .expect("The scaled part does not support ray casting.")
+            .toi_and_normal_with_ray(self.delta(), &unscale_ray(self.scaled(), m, ray), solid)
+            .map(|mut res| {
+                res.normal = m.rotate_vector(&scale_normal(self.scaled(), &res.normal));
+                res
+            })
+    }
+
+    #[inline]
+    fn toi_and_normal_and_uv_with_ray(
+        &self,
+        m: &M,
+        ray: &Ray<P>,
+        solid: bool,
+    ) -> Option<RayIntersection<P::Vector>> {
+        self.shape()
+            .as_ray_cast()
+            .expect("The scaled part does not support ray casting.")
+            .toi_and_normal_and_uv_with_ray(
+                self.delta(),
+                &unscale_ray(self.scaled(), m, ray),
+                solid,
+            )
+            .map(|mut res| {
+                res.normal = m.rotate_vector(&scale_normal(self.scaled(), &res.normal));
+                res
+            })
+    }
+}
+
+/// Expresses a ray in the local frame of the shape wrapped by `scaled`.
+#[inline]
+fn unscale_ray<P: Point, M: Isometry<P>, S>(
+    scaled: &Scaled<S, P::Vector>,
+    m: &M,
+    ray: &Ray<P>,
+) -> Ray<P> {
+    let ls_ray = ray.inverse_transform_by(m);
+
+    Ray::new(
+        scaled.unscale_point(&ls_ray.origin),
+        scaled.unscale_vector(&ls_ray.dir),
+    )
+}
+
+/// Maps a normal from the frame of the wrapped shape to the frame of the scaled shape.
+///
+/// Normals are transformed by the inverse transpose of the scaling, i.e., divided by the scale
+/// factors.
+#[inline]
+fn scale_normal<P: Point, S>(scaled: &Scaled<S, P::Vector>, normal: &P::Vector) -> P::Vector {
+    let normal = scaled.unscale_vector(normal);
+    let norm = na::norm(&normal);
+
+    if norm > na::zero() {
+        normal / norm
+    } else {
+        normal
+    }
+}
@@ -1,12 +1,14 @@
 use num::Zero;
 
-use alga::general::Id;
-use na;
+use approx::ApproxEq;
+use alga::general::{Id, Real};
+use na::{self, Point2, Point3, Unit, Vector2, Vector3};
 
 use query::algorithms::gjk;
 use query::algorithms::{Simplex, JohnsonSimplex, VoronoiSimplex2, VoronoiSimplex3};
 use query::{Ray, RayCast, RayIntersection};
-use shape::{Capsule, Cone, ConvexHull, Cylinder, MinkowskiSum, Segment, SupportMap};
+use shape::{Capsule, Cone, ConvexHull, ConvexPolygon, ConvexPolyhedron, ConvexPolytope, Cylinder,
+            FeatureId, MinkowskiSum, RoundShape, Segment, SupportMap, Tetrahedron};
 use math::{Isometry, Point};
 
 /// Cast a ray on a shape using the GJK algorithm.
@@ -51,6 +53,69 @@ where
     }
 }
 
+// Casts a ray on a support map defined in its local-space and transformed by `m`.
+fn local_support_map_toi_and_normal_with_ray<P, M, G>(
+    m: &M,
+    shape: &G,
+    ray: &Ray<P>,
+    solid: bool,
+) -> Option<RayIntersection<P::Vector>>
+where
+    P: Point,
+    M: Isometry<P>,
+    G: SupportMap<P, Id>,
+{
+    let ls_ray = ray.inverse_transform_by(m);
+
+    let res = if na::dimension::<P::Vector>() == 2 {
+        implicit_toi_and_normal_with_ray(
+            &Id::new(),
+            shape,
+            &mut VoronoiSimplex2::<P>::new(),
+            &ls_ray,
+            solid,
+        )
+    } else if na::dimension::<P::Vector>() == 3 {
+        implicit_toi_and_normal_with_ray(
+            &Id::new(),
+            shape,
+            &mut VoronoiSimplex3::<P>::new(),
+            &ls_ray,
+            solid,
+        )
+    } else {
+        implicit_toi_and_normal_with_ray(
+            &Id::new(),
+            shape,
+            &mut JohnsonSimplex::<P>::new_w_tls(),
+            &ls_ray,
+            solid,
+        )
+    };
+
+    res.map(|mut res| {
+        res.normal = m.rotate_vector(&res.normal);
+        res
+    })
+}
+
+// Sets the feature of an intersection with a polytope, with a local-space normal, to the face
+// hit by the ray. The feature is left unknown if the ray hits an edge or a vertex.
+fn set_polytope_face_feature<P, G>(shape: &G, inter: &mut RayIntersection<P::Vector>)
+where
+    P: Point,
+    G: ConvexPolytope<P, Id>,
+{
+    if let Some(normal) = Unit::try_new(inter.normal, P::Real::default_epsilon()) {
+        let face = shape.support_face_toward(&Id::new(), &normal);
+        let dot = na::dot(shape.face_normal(&Id::new(), face).as_ref(), normal.as_ref());
+
+        if dot >= na::one::<P::Real>() - P::Real::default_epsilon().sqrt() {
+            inter.feature = FeatureId::Face(face);
+        }
+    }
+}
+
 impl<P, M> RayCast<P, M> for Cylinder<P::Real>
 where
     P: Point,
@@ -62,42 +127,7 @@ where
         ray: &Ray<P>,
         solid: bool,
     ) -> Option<RayIntersection<P::Vector>> {
-        let ls_ray = ray.inverse_transform_by(m);
-
-        if na::dimension::<P::Vector>() == 2 {
-            implicit_toi_and_normal_with_ray(
-                &Id::new(),
-                self,
-                &mut VoronoiSimplex2::<P>::new(),
-                &ls_ray,
-                solid,
-            ).map(|mut res| {
-                res.normal = m.rotate_vector(&res.normal);
-                res
-            })
-        } else if na::dimension::<P::Vector>() == 3 {
-            implicit_toi_and_normal_with_ray(
-                &Id::new(),
-                self,
-                &mut VoronoiSimplex3::<P>::new(),
-                &ls_ray,
-                solid,
-            ).map(|mut res| {
-                res.normal = m.rotate_vector(&res.normal);
-                res
-            })
-        } else {
-            implicit_toi_and_normal_with_ray(
-                &Id::new(),
-                self,
-                &mut JohnsonSimplex::<P>::new_w_tls(),
-                &ls_ray,
-                solid,
-            ).map(|mut res| {
-                res.normal = m.rotate_vector(&res.normal);
-                res
-            })
-        }
+        local_support_map_toi_and_normal_with_ray(m, self, ray, solid)
     }
 }
 
@@ -112,42 +142,7 @@ where
         ray: &Ray<P>,
         solid: bool,
     ) -> Option<RayIntersection<P::Vector>> {
-        let ls_ray = ray.inverse_transform_by(m);
-
-        if na::dimension::<P::Vector>() == 2 {
-            implicit_toi_and_normal_with_ray(
-                &Id::new(),
-                self,
-                &mut VoronoiSimplex2::<P>::new(),
-                &ls_ray,
-                solid,
-            ).map(|mut res| {
-                res.normal = m.rotate_vector(&res.normal);
-                res
-            })
-        } else if na::dimension::<P::Vector>() == 3 {
-            implicit_toi_and_normal_with_ray(
-                &Id::new(),
-                self,
-                &mut VoronoiSimplex3::<P>::new(),
-                &ls_ray,
-                solid,
-            ).map(|mut res| {
-                res.normal = m.rotate_vector(&res.normal);
-                res
-            })
-        } else {
-            implicit_toi_and_normal_with_ray(
-                &Id::new(),
-                self,
-                &mut JohnsonSimplex::<P>::new_w_tls(),
-                &ls_ray,
-                solid,
-            ).map(|mut res| {
-                res.normal = m.rotate_vector(&res.normal);
-                res
-            })
-        }
+        local_support_map_toi_and_normal_with_ray(m, self, ray, solid)
     }
 }
 
@@ -162,42 +157,7 @@ where
         ray: &Ray<P>,
         solid: bool,
     ) -> Option<RayIntersection<P::Vector>> {
-        let ls_ray = ray.inverse_transform_by(m);
-
-        if na::dimension::<P::Vector>() == 2 {
-            implicit_toi_and_normal_with_ray(
-                &Id::new(),
-                self,
-                &mut VoronoiSimplex2::<P>::new(),
-                &ls_ray,
-                solid,
-            ).map(|mut res| {
-                res.normal = m.rotate_vector(&res.normal);
-                res
-            })
-        } else if na::dimension::<P::Vector>() == 3 {
-            implicit_toi_and_normal_with_ray(
-                &Id::new(),
-                self,
-                &mut VoronoiSimplex3::<P>::new(),
-                &ls_ray,
-                solid,
-            ).map(|mut res| {
-                res.normal = m.rotate_vector(&res.normal);
-                res
-            })
-        } else {
-            implicit_toi_and_normal_with_ray(
-                &Id::new(),
-                self,
-                &mut JohnsonSimplex::<P>::new_w_tls(),
-                &ls_ray,
-                solid,
-            ).map(|mut res| {
-                res.normal = m.rotate_vector(&res.normal);
-                res
-            })
-        }
+        local_support_map_toi_and_normal_with_ray(m, self, ray, solid)
     }
 }
 
@@ -212,42 +172,61 @@ where
         ray: &Ray<P>,
         solid: bool,
     ) -> Option<RayIntersection<P::Vector>> {
+        local_support_map_toi_and_normal_with_ray(m, self, ray, solid)
+    }
+}
+
+impl<N, M> RayCast<Point3<N>, M> for ConvexPolyhedron<N>
+where
+    N: Real,
+    M: Isometry<Point3<N>>,
+{
+    fn toi_and_normal_with_ray(
+        &self,
+        m: &M,
+        ray: &Ray<Point3<N>>,
+        solid: bool,
+    ) -> Option<RayIntersection<Vector3<N>>> {
         let ls_ray = ray.inverse_transform_by(m);
 
-        if na::dimension::<P::Vector>() == 2 {
-            implicit_toi_and_normal_with_ray(
-                &Id::new(),
-                self,
-                &mut VoronoiSimplex2::<P>::new(),
-                &ls_ray,
-                solid,
-            ).map(|mut res| {
-                res.normal = m.rotate_vector(&res.normal);
-                res
-            })
-        } else if na::dimension::<P::Vector>() == 3 {
-            implicit_toi_and_normal_with_ray(
-                &Id::new(),
-                self,
-                &mut VoronoiSimplex3::<P>::new(),
-                &ls_ray,
-                solid,
-            ).map(|mut res| {
-                res.normal = m.rotate_vector(&res.normal);
-                res
-            })
-        } else {
-            implicit_toi_and_normal_with_ray(
-                &Id::new(),
-                self,
-                &mut JohnsonSimplex::<P>::new_w_tls(),
-                &ls_ray,
-                solid,
-            ).map(|mut res| {
-                res.normal = m.rotate_vector(&res.normal);
-                res
-            })
-        }
+        implicit_toi_and_normal_with_ray(
+            &Id::new(),
+            self,
+            &mut VoronoiSimplex3::<Point3<N>>::new(),
+            &ls_ray,
+            solid,
+        ).map(|mut res| {
+            set_polytope_face_feature(self, &mut res);
+            res.normal = m.rotate_vector(&res.normal);
+            res
+        })
+    }
+}
+
+impl<N, M> RayCast<Point2<N>, M> for ConvexPolygon<N>
+where
+    N: Real,
+    M: Isometry<Point2<N>>,
+{
+    fn toi_and_normal_with_ray(
+        &self,
+        m: &M,
+        ray: &Ray<Point2<N>>,
+        solid: bool,
+    ) -> Option<RayIntersection<Vector2<N>>> {
+        let ls_ray = ray.inverse_transform_by(m);
+
+        implicit_toi_and_normal_with_ray(
+            &Id::new(),
+            self,
+            &mut VoronoiSimplex2::<Point2<N>>::new(),
+            &ls_ray,
+            solid,
+        ).map(|mut res| {
+            set_polytope_face_feature(self, &mut res);
+            res.normal = m.rotate_vector(&res.normal);
+            res
+        })
     }
 }
 
@@ -261,6 +240,39 @@ where
         m: &M,
         ray: &Ray<P>,
         solid: bool,
+    ) -> Option<RayIntersection<P::Vector>> {
+        // XXX: optimize if na::dimension::<P>() == 2
+        local_support_map_toi_and_normal_with_ray(m, self, ray, solid)
+    }
+}
+
+impl<P, M> RayCast<P, M> for Tetrahedron<P>
+where
+    P: Point,
+    M: Isometry<P>,
+{
+    fn toi_and_normal_with_ray(
+        &self,
+        m: &M,
+        ray: &Ray<P>,
+        solid: bool,
+    ) -> Option<RayIntersection<P::Vector>> {
+        local_support_map_toi_and_normal_with_ray(m, self, ray, solid)
+    }
+}
+
+impl<'a, P, M, M2, G1: ?Sized, G2: ?Sized> RayCast<P, M2> for MinkowskiSum<'a, M, G1, G2>
+where
+    P: Point,
+    M2: Isometry<P>,
+    G1: SupportMap<P, M>,
+    G2: SupportMap<P, M>,
+{
+    fn toi_and_normal_with_ray(
+        &self,
+        m: &M2,
+        ray: &Ray<P>,
+        solid: bool,
     ) -> Option<RayIntersection<P::Vector>> {
         // XXX: optimize if na::dimension::<P>() == 2
         let ls_ray = ray.inverse_transform_by(m);
@@ -302,55 +314,30 @@ where
     }
 }
 
-impl<'a, P, M, M2, G1: ?Sized, G2: ?Sized> RayCast<P, M2> for MinkowskiSum<'a, M, G1, G2>
+impl<P, M, S> RayCast<P, M> for RoundShape<S, P::Real>
 where
     P: Point,
-    M2: Isometry<P>,
-    G1: SupportMap<P, M>,
-    G2: SupportMap<P, M>,
+    M: Isometry<P>,
+    S: SupportMap<P, M>,
 {
     fn toi_and_normal_with_ray(
         &self,
-        m: &M2,
+        m: &M,
         ray: &Ray<P>,
         solid: bool,
     ) -> Option<RayIntersection<P::Vector>> {
-        // XXX: optimize if na::dimension::<P>() == 2
-        let ls_ray = ray.inverse_transform_by(m);
-
         if na::dimension::<P::Vector>() == 2 {
-            implicit_toi_and_normal_with_ray(
-                &Id::new(),
-                self,
-                &mut VoronoiSimplex2::<P>::new(),
-                &ls_ray,
-                solid,
-            ).map(|mut res| {
-                res.normal = m.rotate_vector(&res.normal);
-                res
-            })
+            implicit_toi_and_normal_with_ray(m, self, &mut VoronoiSimplex2::<P>::new(), ray, solid)
         } else if na::dimension::<P::Vector>() == 3 {
-            implicit_toi_and_normal_with_ray(
-                &Id::new(),
-                self,
-                &mut VoronoiSimplex3::<P>::new(),
-                &ls_ray,
-                solid,
-            ).map(|mut res| {
-                res.normal = m.rotate_vector(&res.normal);
-                res
-            })
+            implicit_toi_and_normal_with_ray(m, self, &mut VoronoiSimplex3::<P>::new(), ray, solid)
         } else {
             implicit_toi_and_normal_with_ray(
-                &Id::new(),
+                m,
                 self,
                 &mut JohnsonSimplex::<P>::new_w_tls(),
-                &ls_ray,
+                ray,
                 solid,
-            ).map(|mut res| {
-                res.normal = m.rotate_vector(&res.normal);
-                res
-            })
+            )
         }
     }
 }
@@ -0,0 +1,154 @@
+use num::Zero;
+
+use na;
+
+use utils;
+use query::{Ray, RayCast, RayIntersection};
+use shape::Torus;
+use math::{Isometry, Point};
+
+impl<P: Point, M: Isometry<P>> RayCast<P, M> for Torus<P::Real> {
+    #[inline]
+    fn toi_and_normal_with_ray(
+        &self,
+        m: &M,
+        ray: &Ray<P>,
+        solid: bool,
+    ) -> Option<RayIntersection<P::Vector>> {
+        let ls_ray = ray.inverse_transform_by(m);
+
+        torus_toi_and_normal_with_ray(self, &ls_ray, solid).map(|(toi, normal)| {
+            RayIntersection::new(toi, m.rotate_vector(&normal))
+        })
+    }
+}
+
+/// Computes the time of impact and the normal of a ray on a torus expressed in its local frame.
+///
+/// The intersection is computed exactly by solving the quartic equation of the torus surface
+/// restricted to the ray.
+pub fn torus_toi_and_normal_with_ray<P: Point>(
+    torus: &Torus<P::Real>,
+    ray: &Ray<P>,
+    solid: bool,
+) -> Option<(P::Real, P::Vector)> {
+    let _0 = na::zero::<P::Real>();
+    let _2: P::Real = na::convert(2.0f64);
+    let _4: P::Real = na::convert(4.0f64);
+
+    let major = torus.major_radius();
+    let minor = torus.minor_radius();
+
+    /*
+     * Restrict the search to the bounding sphere of the torus.
+     */
+    let a = na::norm_squared(&ray.dir);
+
+    if a.is_zero() {
+        return None;
+    }
+
+    let b = na::dot(&ray.origin.coordinates(), &ray.dir);
+    let c = na::norm_squared(&ray.origin.coordinates()) - (major + minor) * (major + minor);
+    let delta = b * b - a * c;
+
+    if delta < _0 {
+        return None;
+    }
+
+    let tmax = (-b + delta.sqrt()) / a;
+
+    if tmax < _0 {
+        return None;
+    }
+
+    let tmin = (-b - delta.sqrt()) / a;
+    let tmin = if tmin > _0 { tmin } else { _0 };
+
+    let inside = torus_contains_local_point(torus, &ray.origin);
+
+    if inside && solid {
+        let normal = -torus_local_normal(torus, &ray.origin);
+        return Some((_0, normal));
+    }
+
+    /*
+     * Express the ray from the entry point of the bounding sphere to improve the accuracy,
+     * then solve:
+     * (|p|² + R² - r²)² - 4R²(|p|² - p_y²) = 0
+     * with p = o + t * d.
+     */
+    let o = ray.origin + ray.dir * tmin;
+    let d = ray.dir;
+    let oc = o.coordinates();
+
+    let g = a;
+    let h = _2 * na::dot(&oc, &d);
+    let i = na::norm_squared(&oc) + major * major - minor * minor;
+    let j = a - d[1] * d[1];
+    let k = _2 * (na::dot(&oc, &d) - oc[1] * d[1]);
+    let l = na::norm_squared(&oc) - oc[1] * oc[1];
+    let r2 = _4 * major * major;
+
+    let coeffs = [
+        i * i - r2 * l,
+        _2 * h * i - r2 * k,
+        h * h + _2 * g * i - r2 * j,
+        _2 * g * h,
+        g * g,
+    ];
+
+    let mut roots = Vec::with_capacity(4);
+    utils::polynomial_roots(&coeffs, _0, tmax - tmin, &mut roots);
+
+    for t in roots {
+        // Skip a root at the origin of the ray when starting from the inside (non-solid) since
+        // we want the exit point.
+        if inside && t.is_zero() && tmin.is_zero() {
+            continue;
+        }
+
+        let pt = o + d * t;
+        let normal = torus_local_normal(torus, &pt);
+
+        if inside {
+            return Some((tmin + t, -normal));
+        } else {
+            return Some((tmin + t, normal));
+        }
+    }
+
+    None
+}
+
+fn torus_contains_local_point<P: Point>(torus: &Torus<P::Real>, pt: &P) -> bool {
+    let mut radial = pt.coordinates();
+    radial[1] = na::zero();
+
+    let dr = na::norm(&radial) - torus.major_radius();
+
+    dr * dr + pt[1] * pt[1] <= torus.minor_radius() * torus.minor_radius()
+}
+
+/// The outward unit normal of the torus at a point assumed to be on its boundary.
+fn torus_local_normal<P: Point>(torus: &Torus<P::Real>, pt: &P) -> P::Vector {
+    let _2: P::Real = na::convert(2.0f64);
+    let major = torus.major_radius();
+    let minor = torus.minor_radius();
+
+    let coords = pt.coordinates();
+    let mut radial = coords;
+    radial[1] = na::zero();
+
+    let s = na::norm_squared(&coords) + major * major - minor * minor;
+    let gradient = coords * s - radial * (_2 * major * major);
+
+    match na::try_normalize(&gradient, na::zero()) {
+        Some(n) => n,
+        None => {
+            let mut n = na::zero::<P::Vector>();
+            n[1] = na::one();
+            n
+        }
+    }
+}
@@ -6,7 +6,7 @@ use na::{self, Vector3};
 use query::algorithms::JohnsonSimplex;
 use query::{Ray, RayCast, RayIntersection};
 use query::ray_internal;
-use shape::Triangle;
+use shape::{FeatureId, Triangle};
 use math::{Isometry, Point};
 
 use utils;
@@ -81,6 +81,7 @@ pub fn triangle_ray_intersection<P: Point>(
     let mut w;
     let toi;
     let normal;
+    let feature;
 
     if t < na::zero() {
         v = -na::dot(&ac, &e);
@@ -98,6 +99,7 @@ pub fn triangle_ray_intersection<P: Point>(
         let invd = na::one::<P::Real>() / d;
         toi = -t * invd;
         normal = -na::normalize(&n);
+        feature = FeatureId::Face(1);
         v = v * invd;
         w = w * invd;
     } else {
@@ -116,12 +118,13 @@ pub fn triangle_ray_intersection<P: Point>(
         let invd = na::one::<P::Real>() / d;
         toi = t * invd;
         normal = na::normalize(&n);
+        feature = FeatureId::Face(0);
         v = v * invd;
         w = w * invd;
     }
 
     Some((
-        RayIntersection::new(toi, normal),
+        RayIntersection::new_with_feature(toi, normal, feature),
         Vector3::new(-v - w + na::one(), v, w),
     ))
 }
@@ -0,0 +1,182 @@
+use std::mem;
+use num::{Bounded, Zero};
+
+use alga::general::Real;
+use na;
+
+use query::{Ray, RayCast, RayIntersection};
+use query::ray_internal::ray_heightfield::{advance, traversal_params};
+use bounding_volume::AABB;
+use shape::{FeatureId, Voxels};
+use math::{Isometry, Point, Vector};
+
+impl<P: Point, M: Isometry<P>> RayCast<P, M> for Voxels<P> {
+    #[inline]
+    fn toi_and_normal_with_ray(
+        &self,
+        m: &M,
+        ray: &Ray<P>,
+        solid: bool,
+    ) -> Option<RayIntersection<P::Vector>> {
+        let ls_ray = ray.inverse_transform_by(m);
+
+        voxels_toi_and_normal_with_ray(self, &ls_ray, solid).map(|mut res| {
+            res.normal = m.rotate_vector(&res.normal);
+            res
+        })
+    }
+}
+
+/// Casts a ray expressed in the local frame of a voxel grid.
+///
+/// The ray is first clipped by the grid AABB. Then, the voxels it crosses are traversed in
+/// order (using a DDA) until one of them does not have the same occupancy as the first one.
+fn voxels_toi_and_normal_with_ray<P: Point>(
+    voxels: &Voxels<P>,
+    ray: &Ray<P>,
+    solid: bool,
+) -> Option<RayIntersection<P::Vector>> {
+    let aabb = voxels.grid_aabb();
+    let (tmin, tmax, entry_axis) = match clip_ray(&aabb, ray) {
+        Some(range) => range,
+        None => return None,
+    };
+
+    // Compute the voxel containing the clipped ray origin.
+    let start = na::sup(aabb.mins(), &na::inf(aabb.maxs(), &(ray.origin + ray.dir * tmin)));
+    let mut coords = match voxels.voxel_at_point(&start) {
+        Some(coords) => coords,
+        None => return None,
+    };
+    let inside = voxels.is_occupied(voxels.voxel_id(&coords));
+
+    if inside {
+        if let Some(axis) = entry_axis {
+            // The ray enters the grid through an occupied voxel.
+            let id = voxels.voxel_id(&coords);
+            return Some(voxel_hit(tmin, axis, -ray.dir[axis], id));
+        } else if solid {
+            let mut res = RayIntersection::new(na::zero(), na::zero());
+            res.part = Some(voxels.voxel_id(&coords));
+            return Some(res);
+        }
+    }
+
+    // Initialize the traversal along each axis of the grid.
+    let dim = na::dimension::<P::Vector>();
+    let mut steps = [0; 3];
+    let mut next_t = [P::Real::max_value(); 3];
+    let mut delta_t = [P::Real::max_value(); 3];
+
+    for i in 0..dim {
+        let (step, next, delta) =
+            traversal_params(ray, i, na::zero(), voxels.voxel_size(), coords[i]);
+        steps[i] = step;
+        next_t[i] = next;
+        delta_t[i] = delta;
+    }
+
+    loop {
+        let mut axis = 0;
+
+        for i in 1..dim {
+            if next_t[i] < next_t[axis] {
+                axis = i;
+            }
+        }
+
+        let t = next_t[axis];
+        let prev_id = voxels.voxel_id(&coords);
+
+        if t > tmax || !advance(&mut coords[axis], steps[axis], voxels.dimensions()[axis]) {
+            // The ray leaves the grid. If it started inside of the occupied voxels, this is where
+            // it leaves them.
+            return if inside {
+                Some(voxel_hit(tmax, axis, ray.dir[axis], prev_id))
+            } else {
+                None
+            };
+        }
+
+        next_t[axis] = next_t[axis] + delta_t[axis];
+
+        let id = voxels.voxel_id(&coords);
+
+        if voxels.is_occupied(id) != inside {
+            return if inside {
+                Some(voxel_hit(t, axis, ray.dir[axis], prev_id))
+            } else {
+                Some(voxel_hit(t, axis, -ray.dir[axis], id))
+            };
+        }
+    }
+}
+
+/// The intersection with the face orthogonal to `axis`, with the same sign as `sign`, of the
+/// occupied voxel `id`.
+#[inline]
+fn voxel_hit<V: Vector>(toi: V::Real, axis: usize, sign: V::Real, id: usize) -> RayIntersection<V> {
+    let feature = if sign > na::zero() {
+        FeatureId::Face(axis)
+    } else {
+        FeatureId::Face(axis + na::dimension::<V>())
+    };
+
+    let mut res = RayIntersection::new_with_feature(toi, axis_normal(axis, sign), feature);
+    res.part = Some(id);
+    res
+}
+
+/// The unit vector along the axis `axis`, with the same sign as `sign`.
+#[inline]
+fn axis_normal<V: Vector>(axis: usize, sign: V::Real) -> V {
+    let mut res = na::zero::<V>();
+
+    res[axis] = if sign > na::zero() {
+        na::one()
+    } else {
+        -na::one::<V::Real>()
+    };
+
+    res
+}
+
+/// Computes the parameters of the ray entering and leaving the grid AABB.
+///
+/// Also returns the axis orthogonal to the face through which the ray enters the AABB, if the
+/// ray origin is not already inside of it.
+fn clip_ray<P: Point>(aabb: &AABB<P>, ray: &Ray<P>) -> Option<(P::Real, P::Real, Option<usize>)> {
+    let mut tmin: P::Real = na::zero();
+    let mut tmax: P::Real = Bounded::max_value();
+    let mut entry_axis = None;
+
+    for i in 0usize..na::dimension::<P::Vector>() {
+        if ray.dir[i].is_zero() {
+            if ray.origin[i] < aabb.mins()[i] || ray.origin[i] > aabb.maxs()[i] {
+                return None;
+            }
+        } else {
+            let _1: P::Real = na::one();
+            let denom = _1 / ray.dir[i];
+            let mut near = (aabb.mins()[i] - ray.origin[i]) * denom;
+            let mut far = (aabb.maxs()[i] - ray.origin[i]) * denom;
+
+            if near > far {
+                mem::swap(&mut near, &mut far)
+            }
+
+            if near > tmin {
+                tmin = near;
+                entry_axis = Some(i);
+            }
+
+            tmax = tmax.min(far);
+
+            if tmin > tmax {
+                return None;
+            }
+        }
+    }
+
+    Some((tmin, tmax, entry_axis))
+}
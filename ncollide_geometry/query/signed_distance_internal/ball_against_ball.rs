@@ -0,0 +1,38 @@
+use approx::ApproxEq;
+
+use alga::linear::FiniteDimVectorSpace;
+use na::{self, Unit};
+use math::Point;
+use query::SignedDistance;
+use shape::Ball;
+
+/// Signed distance between balls.
+#[inline]
+pub fn ball_against_ball<P>(
+    center1: &P,
+    b1: &Ball<P::Real>,
+    center2: &P,
+    b2: &Ball<P::Real>,
+) -> SignedDistance<P>
+where
+    P: Point,
+{
+    let r1 = b1.radius();
+    let r2 = b2.radius();
+    let delta_pos = *center2 - *center1;
+    let (normal, dist) = match Unit::try_new_and_get(delta_pos, P::Real::default_epsilon()) {
+        Some(res) => res,
+        // The centers coincide: any direction will do.
+        None => (
+            Unit::new_unchecked(P::Vector::canonical_basis_element(0)),
+            na::zero(),
+        ),
+    };
+
+    SignedDistance::new(
+        dist - r1 - r2,
+        *center1 + *normal * r1,
+        *center2 + (-*normal * r2),
+        normal,
+    )
+}
@@ -0,0 +1,120 @@
+use alga::general::Id;
+use na;
+
+use bounding_volume::AABB;
+use partitioning::{self, BVTCostFn};
+use shape::{CompositeShape, Shape};
+use query::{PointQuery, SignedDistance};
+use query::signed_distance_internal;
+use math::{Isometry, Point};
+
+/// Signed distance between a composite shape (`Mesh`, `Compound`) and any other shape.
+///
+/// This is the smallest signed distance between the other shape and the parts of the composite
+/// shape, i.e., the deepest penetration if some parts intersect the other shape.
+pub fn composite_shape_against_shape<P, M, G1: ?Sized>(
+    m1: &M,
+    g1: &G1,
+    m2: &M,
+    g2: &Shape<P, M>,
+) -> SignedDistance<P>
+where
+    P: Point,
+    M: Isometry<P>,
+    G1: CompositeShape<P, M>,
+{
+    let mut cost_fn = CompositeShapeAgainstAnySignedDistCostFn::new(m1, g1, m2, g2);
+
+    partitioning::bvh_best_first_search(g1.bvh(), &mut cost_fn)
+        .expect("The composite shape must not be empty.")
+        .1
+}
+
+/// Signed distance between a shape and a composite (`Mesh`, `Compound`) shape.
+pub fn shape_against_composite_shape<P, M, G2: ?Sized>(
+    m1: &M,
+    g1: &Shape<P, M>,
+    m2: &M,
+    g2: &G2,
+) -> SignedDistance<P>
+where
+    P: Point,
+    M: Isometry<P>,
+    G2: CompositeShape<P, M>,
+{
+    let mut res = composite_shape_against_shape(m2, g2, m1, g1);
+    res.flip();
+    res
+}
+
+struct CompositeShapeAgainstAnySignedDistCostFn<'a, P: 'a + Point, M: 'a, G1: ?Sized + 'a> {
+    msum_shift: P::Vector,
+    msum_margin: P::Vector,
+
+    m1: &'a M,
+    g1: &'a G1,
+    m2: &'a M,
+    g2: &'a Shape<P, M>,
+}
+
+impl<'a, P, M, G1: ?Sized> CompositeShapeAgainstAnySignedDistCostFn<'a, P, M, G1>
+where
+    P: Point,
+    M: Isometry<P>,
+    G1: CompositeShape<P, M>,
+{
+    pub fn new(
+        m1: &'a M,
+        g1: &'a G1,
+        m2: &'a M,
+        g2: &'a Shape<P, M>,
+    ) -> CompositeShapeAgainstAnySignedDistCostFn<'a, P, M, G1> {
+        let ls_m2 = na::inverse(m1) * m2.clone();
+        let ls_aabb2 = g2.aabb(&ls_m2);
+
+        CompositeShapeAgainstAnySignedDistCostFn {
+            msum_shift: -ls_aabb2.center().coordinates(),
+            msum_margin: ls_aabb2.half_extents(),
+            m1: m1,
+            g1: g1,
+            m2: m2,
+            g2: g2,
+        }
+    }
+}
+
+impl<'a, P, M, G1: ?Sized> BVTCostFn<P::Real, usize, AABB<P>>
+    for CompositeShapeAgainstAnySignedDistCostFn<'a, P, M, G1>
+where
+    P: Point,
+    M: Isometry<P>,
+    G1: CompositeShape<P, M>,
+{
+    type UserData = SignedDistance<P>;
+
+    #[inline]
+    fn compute_bv_cost(&mut self, bv: &AABB<P>) -> Option<P::Real> {
+        // Compute the minkowski sum of the two AABBs.
+        let msum = AABB::new(
+            *bv.mins() + self.msum_shift + (-self.msum_margin),
+            *bv.maxs() + self.msum_shift + self.msum_margin,
+        );
+
+        // The signed distance to the origin is a lower bound of the signed distance between the
+        // shapes since the penetration depth of two AABBs is greater than the penetration depth
+        // of the shapes they contain.
+        Some(msum.distance_to_point(&Id::new(), &P::origin(), false))
+    }
+
+    #[inline]
+    fn compute_b_cost(&mut self, b: &usize) -> Option<(P::Real, SignedDistance<P>)> {
+        let mut res = None;
+
+        self.g1.map_transformed_part_at(*b, self.m1, &mut |m1, g1| {
+            let dist = signed_distance_internal::signed_distance(m1, g1, self.m2, self.g2);
+            res = Some((dist.dist, dist))
+        });
+
+        res
+    }
+}
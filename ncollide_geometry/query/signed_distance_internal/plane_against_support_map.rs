@@ -0,0 +1,50 @@
+use alga::linear::Translation;
+use na::{self, Unit};
+
+use query::SignedDistance;
+use shape::SupportMap;
+use shape::Plane;
+use math::{Isometry, Point};
+
+/// Signed distance between a plane and a support-mapped shape (Cuboid, ConvexHull, etc.)
+pub fn plane_against_support_map<P, M, G: ?Sized>(
+    mplane: &M,
+    plane: &Plane<P::Vector>,
+    mother: &M,
+    other: &G,
+) -> SignedDistance<P>
+where
+    P: Point,
+    M: Isometry<P>,
+    G: SupportMap<P, M>,
+{
+    let plane_normal = mplane.rotate_vector(&*plane.normal());
+    let plane_center = P::from_coordinates(mplane.translation().to_vector());
+    let deepest = other.support_point(mother, &-plane_normal);
+
+    let dist = na::dot(&plane_normal, &(deepest - plane_center));
+
+    SignedDistance::new(
+        dist,
+        deepest + (-plane_normal * dist),
+        deepest,
+        Unit::new_unchecked(plane_normal),
+    )
+}
+
+/// Signed distance between a support-mapped shape (Cuboid, ConvexHull, etc.) and a plane.
+pub fn support_map_against_plane<P, M, G: ?Sized>(
+    mother: &M,
+    other: &G,
+    mplane: &M,
+    plane: &Plane<P::Vector>,
+) -> SignedDistance<P>
+where
+    P: Point,
+    M: Isometry<P>,
+    G: SupportMap<P, M>,
+{
+    let mut res = plane_against_support_map(mplane, plane, mother, other);
+    res.flip();
+    res
+}
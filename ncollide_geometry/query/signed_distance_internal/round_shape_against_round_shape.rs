@@ -0,0 +1,31 @@
+use math::{Isometry, Point};
+use query::{signed_distance_internal, SignedDistance};
+use shape::SupportMap;
+
+/// Signed distance between two support-mapped shapes with rounded borders.
+///
+/// The signed distance is computed between the core shapes `g1` and `g2` before being reduced
+/// by the border radii `r1` and `r2`. A non-rounded shape has a zero border radius.
+pub fn round_shape_against_round_shape<P, M, G1: ?Sized, G2: ?Sized>(
+    m1: &M,
+    g1: &G1,
+    r1: P::Real,
+    m2: &M,
+    g2: &G2,
+    r2: P::Real,
+) -> SignedDistance<P>
+where
+    P: Point,
+    M: Isometry<P>,
+    G1: SupportMap<P, M>,
+    G2: SupportMap<P, M>,
+{
+    let res = signed_distance_internal::support_map_against_support_map(m1, g1, m2, g2);
+
+    SignedDistance::new(
+        res.dist - r1 - r2,
+        res.point1 + *res.normal * r1,
+        res.point2 + (-*res.normal * r2),
+        res.normal,
+    )
+}
@@ -0,0 +1,45 @@
+use std::mem;
+
+use na::Unit;
+use math::Point;
+
+/// The signed distance between two shapes.
+///
+/// The distance is positive if the shapes are disjoint and negative if they are penetrating.
+/// In both cases, `point2 = point1 + normal * dist`.
+#[derive(Debug, PartialEq, Clone)]
+pub struct SignedDistance<P: Point> {
+    /// The distance between the shapes, or the opposite of their penetration depth.
+    pub dist: P::Real,
+    /// The point of the first shape closest to (or deepest into) the second shape, expressed in
+    /// world space.
+    pub point1: P,
+    /// The point of the second shape closest to (or deepest into) the first shape, expressed in
+    /// world space.
+    pub point2: P,
+    /// The separating direction, pointing from the first shape toward the second shape.
+    ///
+    /// The shapes stop penetrating if the second one is translated by `-dist` along this
+    /// direction.
+    pub normal: Unit<P::Vector>,
+}
+
+impl<P: Point> SignedDistance<P> {
+    /// Creates a new signed distance result.
+    #[inline]
+    pub fn new(dist: P::Real, point1: P, point2: P, normal: Unit<P::Vector>) -> SignedDistance<P> {
+        SignedDistance {
+            dist: dist,
+            point1: point1,
+            point2: point2,
+            normal: normal,
+        }
+    }
+
+    /// Swaps the roles of the two shapes.
+    #[inline]
+    pub fn flip(&mut self) {
+        mem::swap(&mut self.point1, &mut self.point2);
+        self.normal = -self.normal;
+    }
+}
@@ -0,0 +1,127 @@
+use num::Bounded;
+use approx::ApproxEq;
+
+use alga::linear::Translation;
+use na::{self, Unit};
+use shape::{self, AnnotatedPoint, SupportMap};
+use query::algorithms::gjk::{self, GJKResult};
+use query::algorithms::minkowski_sampling;
+use query::algorithms::epa3;
+use query::algorithms::epa2;
+use query::algorithms::{EPA2, EPA3, Simplex, JohnsonSimplex, VoronoiSimplex2, VoronoiSimplex3};
+use query::SignedDistance;
+use math::{Isometry, Point};
+
+/// Signed distance between support-mapped shapes (`Cuboid`, `ConvexHull`, etc.)
+pub fn support_map_against_support_map<P, M, G1: ?Sized, G2: ?Sized>(
+    m1: &M,
+    g1: &G1,
+    m2: &M,
+    g2: &G2,
+) -> SignedDistance<P>
+where
+    P: Point,
+    M: Isometry<P>,
+    G1: SupportMap<P, M>,
+    G2: SupportMap<P, M>,
+{
+    if na::dimension::<P::Vector>() == 2 {
+        support_map_against_support_map_with_params(
+            m1,
+            g1,
+            m2,
+            g2,
+            &mut VoronoiSimplex2::new(),
+            None,
+        )
+    } else if na::dimension::<P::Vector>() == 3 {
+        support_map_against_support_map_with_params(
+            m1,
+            g1,
+            m2,
+            g2,
+            &mut VoronoiSimplex3::new(),
+            None,
+        )
+    } else {
+        support_map_against_support_map_with_params(
+            m1,
+            g1,
+            m2,
+            g2,
+            &mut JohnsonSimplex::new_w_tls(),
+            None,
+        )
+    }
+}
+
+/// Signed distance between support-mapped shapes (`Cuboid`, `ConvexHull`, etc.)
+///
+/// The distance is computed by the GJK algorithm if the shapes are disjoint, and by the EPA
+/// otherwise. This allows a more fine grained control other the underlying GJK algorigtm.
+pub fn support_map_against_support_map_with_params<P, M, S, G1: ?Sized, G2: ?Sized>(
+    m1: &M,
+    g1: &G1,
+    m2: &M,
+    g2: &G2,
+    simplex: &mut S,
+    init_dir: Option<P::Vector>,
+) -> SignedDistance<P>
+where
+    P: Point,
+    M: Isometry<P>,
+    S: Simplex<AnnotatedPoint<P>>,
+    G1: SupportMap<P, M>,
+    G2: SupportMap<P, M>,
+{
+    let mut dir = match init_dir {
+        // FIXME: or m2.translation - m1.translation ?
+        None => m1.translation().to_vector() - m2.translation().to_vector(),
+        Some(dir) => dir,
+    };
+
+    if dir == na::zero() {
+        dir[0] = na::one();
+    }
+
+    simplex.reset(shape::cso_support_point(m1, g1, m2, g2, dir));
+
+    let _eps = P::Real::default_epsilon();
+
+    match gjk::closest_points_with_max_dist(m1, g1, m2, g2, P::Real::max_value(), simplex) {
+        GJKResult::Projection((p1, p2)) => {
+            if let Some((normal, dist)) = Unit::try_new_and_get(p2 - p1, _eps) {
+                return SignedDistance::new(dist, p1, p2, normal);
+            }
+        }
+        GJKResult::Intersection => {} // fallback
+        GJKResult::NoIntersection(_) => unreachable!(),
+        GJKResult::Proximity(_) => unreachable!(),
+    }
+
+    // The shapes are penetrating: use the EPA to compute the penetration depth.
+    let (p1, p2) = if na::dimension::<P::Vector>() == 2 {
+        let mut epa = EPA2::new();
+        epa2::closest_points(&mut epa, m1, g1, m2, g2, simplex)
+    } else if na::dimension::<P::Vector>() == 3 {
+        let mut epa = EPA3::new();
+        epa3::closest_points(&mut epa, m1, g1, m2, g2, simplex)
+    } else {
+        match minkowski_sampling::closest_points(m1, g1, m2, g2, simplex) {
+            Some((p1, p2, _)) => (p1, p2),
+            None => {
+                let p1 = g1.support_point(m1, &-dir);
+                (p1, p1)
+            }
+        }
+    };
+
+    match Unit::try_new_and_get(p1 - p2, _eps) {
+        Some((normal, depth)) => SignedDistance::new(-depth, p1, p2, normal),
+        None => {
+            // The shapes are just touching.
+            let normal = Unit::new_normalize(-dir);
+            SignedDistance::new(na::zero(), p1, p2, normal)
+        }
+    }
+}
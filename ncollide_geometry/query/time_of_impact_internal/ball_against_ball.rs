@@ -1,6 +1,11 @@
+use approx::ApproxEq;
+
+use alga::linear::FiniteDimVectorSpace;
+use na::Unit;
 use math::Point;
 use shape::Ball;
-use query::{ray_internal, Ray};
+use query::{ray_internal, Ray, TOI};
+use query::time_of_impact_internal::toi;
 
 /// Time Of Impact of two balls under translational movement.
 #[inline]
@@ -11,7 +16,7 @@ pub fn ball_against_ball<P>(
     center2: &P,
     vel2: &P::Vector,
     b2: &Ball<P::Real>,
-) -> Option<P::Real>
+) -> Option<TOI<P>>
 where
     P: Point,
 {
@@ -19,5 +24,22 @@ where
     let radius = b1.radius() + b2.radius();
     let center = *center1 + (-center2.coordinates());
 
-    ray_internal::ball_toi_with_ray(&center, radius, &Ray::new(P::origin(), -vel), true).1
+    let ray = Ray::new(P::origin(), -vel);
+
+    ray_internal::ball_toi_with_ray(&center, radius, &ray, true).1.map(|t| {
+        let c1 = *center1 + *vel1 * t;
+        let c2 = *center2 + *vel2 * t;
+        let normal = match Unit::try_new(c2 - c1, P::Real::default_epsilon()) {
+            Some(normal) => normal,
+            None => Unit::new_unchecked(P::Vector::canonical_basis_element(0)),
+        };
+
+        TOI::new(
+            t,
+            c1 + *normal * b1.radius(),
+            c2 + (-*normal * b2.radius()),
+            normal,
+            toi::exact_status(&t),
+        )
+    })
 }
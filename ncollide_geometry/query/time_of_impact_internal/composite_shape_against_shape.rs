@@ -2,9 +2,9 @@ use alga::general::Id;
 use na;
 use math::{Isometry, Point};
 use bounding_volume::AABB;
-use partitioning::BVTCostFn;
+use partitioning::{self, BVTCostFn};
 use shape::{CompositeShape, Shape};
-use query::{time_of_impact_internal, Ray, RayCast};
+use query::{time_of_impact_internal, QueryError, Ray, RayCast, TOI};
 
 /// Time Of Impact of a composite shape with any other shape, under translational movement.
 pub fn composite_shape_against_shape<P, M, G1: ?Sized>(
@@ -14,15 +14,19 @@ pub fn composite_shape_against_shape<P, M, G1: ?Sized>(
     m2: &M,
     vel2: &P::Vector,
     g2: &Shape<P, M>,
-) -> Option<P::Real>
+) -> Option<TOI<P>>
 where
     P: Point,
     M: Isometry<P>,
     G1: CompositeShape<P, M>,
 {
-    let mut cost_fn = CompositeShapeAgainstAnyTOICostFn::new(m1, vel1, g1, m2, vel2, g2);
-
-    g1.bvt().best_first_search(&mut cost_fn).map(|(_, res)| res)
+    match try_composite_shape_against_shape(m1, vel1, g1, m2, vel2, g2) {
+        Ok(res) => res,
+        Err(QueryError::UnsupportedShapePair) => panic!(
+            "No algorithm known to compute the time of impact between the given pair of shapes."
+        ),
+        Err(err) => panic!("Time of impact computation failed: {}", err),
+    }
 }
 
 /// Time Of Impact of any shape with a composite shape, under translational movement.
@@ -33,13 +37,119 @@ pub fn shape_against_composite_shape<P, M, G2: ?Sized>(
     m2: &M,
     vel2: &P::Vector,
     g2: &G2,
-) -> Option<P::Real>
+) -> Option<TOI<P>>
+where
+    P: Point,
+    M: Isometry<P>,
+    G2: CompositeShape<P, M>,
+{
+    let mut res = composite_shape_against_shape(m2, vel2, g2, m1, vel1, g1);
+
+    for toi in res.iter_mut() {
+        toi.flip()
+    }
+
+    res
+}
+
+/// Time Of Impact of a composite shape with any other shape, under translational movement.
+///
+/// Returns the first error reported by the time of impact computation between a part of `g1` and
+/// `g2`.
+pub fn try_composite_shape_against_shape<P, M, G1: ?Sized>(
+    m1: &M,
+    vel1: &P::Vector,
+    g1: &G1,
+    m2: &M,
+    vel2: &P::Vector,
+    g2: &Shape<P, M>,
+) -> Result<Option<TOI<P>>, QueryError>
+where
+    P: Point,
+    M: Isometry<P>,
+    G1: CompositeShape<P, M>,
+{
+    let mut cost_fn = CompositeShapeAgainstAnyTOICostFn::new(m1, vel1, g1, m2, vel2, g2, true);
+    let res = partitioning::bvh_best_first_search(g1.bvh(), &mut cost_fn);
+
+    match cost_fn.error {
+        Some(err) => Err(err),
+        None => Ok(res.and_then(|(_, (_, res))| res)),
+    }
+}
+
+/// Time Of Impact of any shape with a composite shape, under translational movement.
+///
+/// Returns the first error reported by the time of impact computation between `g1` and a part of
+/// `g2`.
+pub fn try_shape_against_composite_shape<P, M, G2: ?Sized>(
+    m1: &M,
+    vel1: &P::Vector,
+    g1: &Shape<P, M>,
+    m2: &M,
+    vel2: &P::Vector,
+    g2: &G2,
+) -> Result<Option<TOI<P>>, QueryError>
+where
+    P: Point,
+    M: Isometry<P>,
+    G2: CompositeShape<P, M>,
+{
+    let mut res = try_composite_shape_against_shape(m2, vel2, g2, m1, vel1, g1)?;
+
+    for toi in res.iter_mut() {
+        toi.flip()
+    }
+
+    Ok(res)
+}
+
+/// Time Of Impact of a composite shape with any other shape, under translational movement,
+/// without the contact points and normal at this time.
+///
+/// Returns the first error reported by the time of impact computation between a part of `g1` and
+/// `g2`.
+pub fn try_composite_shape_against_shape_toi<P, M, G1: ?Sized>(
+    m1: &M,
+    vel1: &P::Vector,
+    g1: &G1,
+    m2: &M,
+    vel2: &P::Vector,
+    g2: &Shape<P, M>,
+) -> Result<Option<P::Real>, QueryError>
+where
+    P: Point,
+    M: Isometry<P>,
+    G1: CompositeShape<P, M>,
+{
+    let mut cost_fn = CompositeShapeAgainstAnyTOICostFn::new(m1, vel1, g1, m2, vel2, g2, false);
+    let res = partitioning::bvh_best_first_search(g1.bvh(), &mut cost_fn);
+
+    match cost_fn.error {
+        Some(err) => Err(err),
+        None => Ok(res.map(|(_, (toi, _))| toi)),
+    }
+}
+
+/// Time Of Impact of any shape with a composite shape, under translational movement, without the
+/// contact points and normal at this time.
+///
+/// Returns the first error reported by the time of impact computation between `g1` and a part of
+/// `g2`.
+pub fn try_shape_against_composite_shape_toi<P, M, G2: ?Sized>(
+    m1: &M,
+    vel1: &P::Vector,
+    g1: &Shape<P, M>,
+    m2: &M,
+    vel2: &P::Vector,
+    g2: &G2,
+) -> Result<Option<P::Real>, QueryError>
 where
     P: Point,
     M: Isometry<P>,
     G2: CompositeShape<P, M>,
 {
-    composite_shape_against_shape(m2, vel2, g2, m1, vel1, g1)
+    try_composite_shape_against_shape_toi(m2, vel2, g2, m1, vel1, g1)
 }
 
 struct CompositeShapeAgainstAnyTOICostFn<'a, P: 'a + Point, M: 'a, G1: ?Sized + 'a> {
@@ -53,6 +163,10 @@ struct CompositeShapeAgainstAnyTOICostFn<'a, P: 'a + Point, M: 'a, G1: ?Sized +
     m2: &'a M,
     vel2: &'a P::Vector,
     g2: &'a Shape<P, M>,
+
+    // Whether the contact points and normal are computed along with the time of impact.
+    witnesses: bool,
+    error: Option<QueryError>,
 }
 
 impl<'a, P, M, G1: ?Sized> CompositeShapeAgainstAnyTOICostFn<'a, P, M, G1>
@@ -68,6 +182,7 @@ where
         m2: &'a M,
         vel2: &'a P::Vector,
         g2: &'a Shape<P, M>,
+        witnesses: bool,
     ) -> CompositeShapeAgainstAnyTOICostFn<'a, P, M, G1> {
         let ls_m2 = na::inverse(m1) * m2.clone();
         let ls_aabb2 = g2.aabb(&ls_m2);
@@ -82,6 +197,8 @@ where
             m2: m2,
             vel2: vel2,
             g2: g2,
+            witnesses: witnesses,
+            error: None,
         }
     }
 }
@@ -93,10 +210,15 @@ where
     M: Isometry<P>,
     G1: CompositeShape<P, M>,
 {
-    type UserData = P::Real;
+    type UserData = (P::Real, Option<TOI<P>>);
 
     #[inline]
     fn compute_bv_cost(&mut self, bv: &AABB<P>) -> Option<P::Real> {
+        // Stop the traversal as soon as an error occurred.
+        if self.error.is_some() {
+            return None;
+        }
+
         // Compute the minkowski sum of the two AABBs.
         let msum = AABB::new(
             *bv.mins() + self.msum_shift + (-self.msum_margin),
@@ -108,20 +230,44 @@ where
     }
 
     #[inline]
-    fn compute_b_cost(&mut self, b: &usize) -> Option<(P::Real, P::Real)> {
+    fn compute_b_cost(&mut self, b: &usize) -> Option<(P::Real, (P::Real, Option<TOI<P>>))> {
+        if self.error.is_some() {
+            return None;
+        }
+
         let mut res = None;
+        let mut error = None;
 
         self.g1.map_transformed_part_at(*b, self.m1, &mut |m1, g1| {
-            res = time_of_impact_internal::time_of_impact(
-                m1,
-                self.vel1,
-                g1,
-                self.m2,
-                self.vel2,
-                self.g2,
-            ).map(|toi| (toi, toi))
+            if self.witnesses {
+                match time_of_impact_internal::try_time_of_impact_and_witnesses(
+                    m1,
+                    self.vel1,
+                    g1,
+                    self.m2,
+                    self.vel2,
+                    self.g2,
+                ) {
+                    Ok(toi) => res = toi.map(|toi| (toi.toi, (toi.toi, Some(toi)))),
+                    Err(err) => error = Some(err),
+                }
+            } else {
+                match time_of_impact_internal::try_time_of_impact(
+                    m1,
+                    self.vel1,
+                    g1,
+                    self.m2,
+                    self.vel2,
+                    self.g2,
+                ) {
+                    Ok(toi) => res = toi.map(|toi| (toi, (toi, None))),
+                    Err(err) => error = Some(err),
+                }
+            }
         });
 
+        self.error = error;
+
         res
     }
 }
@@ -1,14 +1,27 @@
 //! Implementation details of the `time_of_impact` function.
 
+pub use self::toi::{TOIStatus, TOI};
 pub use self::ball_against_ball::ball_against_ball;
-pub use self::support_map_against_support_map::support_map_against_support_map;
+pub use self::support_map_against_support_map::{support_map_against_support_map,
+                                                support_map_against_support_map_toi,
+                                                try_support_map_against_support_map,
+                                                try_support_map_against_support_map_toi};
 pub use self::plane_against_support_map::{plane_against_support_map, support_map_against_plane};
-pub use self::shape_against_shape::shape_against_shape as time_of_impact;
+pub use self::torus_against_support_map::{support_map_against_torus, torus_against_support_map};
+pub use self::shape_against_shape::shape_against_shape as time_of_impact_and_witnesses;
+pub use self::shape_against_shape::try_shape_against_shape as try_time_of_impact_and_witnesses;
+pub use self::shape_against_shape::{time_of_impact, try_time_of_impact};
 pub use self::composite_shape_against_shape::{composite_shape_against_shape,
-                                              shape_against_composite_shape};
+                                              shape_against_composite_shape,
+                                              try_composite_shape_against_shape,
+                                              try_composite_shape_against_shape_toi,
+                                              try_shape_against_composite_shape,
+                                              try_shape_against_composite_shape_toi};
 
+pub(crate) mod toi;
 mod ball_against_ball;
 mod support_map_against_support_map;
 mod plane_against_support_map;
+mod torus_against_support_map;
 mod shape_against_shape;
 mod composite_shape_against_shape;
@@ -1,6 +1,8 @@
+use alga::linear::Translation;
 use shape::SupportMap;
 use shape::Plane;
-use query::{Ray, RayCast};
+use query::{Ray, RayCast, TOI};
+use query::time_of_impact_internal::toi;
 use math::{Isometry, Point};
 
 /// Time Of Impact of a plane with a support-mapped shape under translational movement.
@@ -11,7 +13,7 @@ pub fn plane_against_support_map<P, M, G: ?Sized>(
     mother: &M,
     vel_other: &P::Vector,
     other: &G,
-) -> Option<P::Real>
+) -> Option<TOI<P>>
 where
     P: Point,
     M: Isometry<P>,
@@ -21,7 +23,17 @@ where
     let plane_normal = mplane.rotate_vector(plane.normal());
     let closest_point = other.support_point(mother, &-plane_normal);
 
-    plane.toi_with_ray(mplane, &Ray::new(closest_point, vel), true)
+    plane
+        .toi_with_ray(mplane, &Ray::new(closest_point, vel), true)
+        .map(|t| {
+            let shift_plane = M::Translation::from_vector(*vel_plane * t).unwrap();
+            let shift_other = M::Translation::from_vector(*vel_other * t).unwrap();
+            let mplane = mplane.append_translation(&shift_plane);
+            let mother = mother.append_translation(&shift_other);
+            let (w1, w2, normal) = toi::plane_witnesses(&mplane, plane, &mother, other);
+
+            TOI::new(t, w1, w2, normal, toi::exact_status(&t))
+        })
 }
 
 /// Time Of Impact of a plane with a support-mapped shape under translational movement.
@@ -32,11 +44,17 @@ pub fn support_map_against_plane<P, M, G: ?Sized>(
     mplane: &M,
     vel_plane: &P::Vector,
     plane: &Plane<P::Vector>,
-) -> Option<P::Real>
+) -> Option<TOI<P>>
 where
     P: Point,
     M: Isometry<P>,
     G: SupportMap<P, M>,
 {
-    plane_against_support_map(mplane, vel_plane, plane, mother, vel_other, other)
+    let mut res = plane_against_support_map(mplane, vel_plane, plane, mother, vel_other, other);
+
+    for toi in res.iter_mut() {
+        toi.flip()
+    }
+
+    res
 }
@@ -1,12 +1,15 @@
 use alga::linear::Translation;
 use math::{Isometry, Point};
-use shape::{Ball, Plane, Shape};
-use query::time_of_impact_internal;
+use shape::{Ball, Plane, Shape, Torus};
+use query::{time_of_impact_internal, QueryError, TOI};
+use query::{query_error, query_registry};
 
 /// Computes the smallest time of impact of two shapes under translational movement.
 ///
-/// Returns `0.0` if the objects are touching or penetrating.
-pub fn shape_against_shape<P, M>(
+/// Returns `0.0` if the objects are touching or penetrating. Unlike
+/// `time_of_impact_and_witnesses`, this does not compute the contact points at the time of impact
+/// which is much cheaper for support-mapped shapes.
+pub fn time_of_impact<P, M>(
     m1: &M,
     vel1: &P::Vector,
     g1: &Shape<P, M>,
@@ -18,25 +21,178 @@ where
     P: Point,
     M: Isometry<P>,
 {
-    if let (Some(b1), Some(b2)) = (
+    match dispatch_toi(m1, vel1, g1, m2, vel2, g2, false) {
+        Ok(res) => res,
+        Err(QueryError::UnsupportedShapePair) => panic!(
+            "No algorithm known to compute the time of impact between the given pair of shapes."
+        ),
+        Err(err) => panic!("Time of impact computation failed: {}", err),
+    }
+}
+
+/// Computes the smallest time of impact of two shapes under translational movement.
+///
+/// Returns `Ok(Some(0.0))` if the objects are touching or penetrating, and an error if the time
+/// of impact could not be computed.
+pub fn try_time_of_impact<P, M>(
+    m1: &M,
+    vel1: &P::Vector,
+    g1: &Shape<P, M>,
+    m2: &M,
+    vel2: &P::Vector,
+    g2: &Shape<P, M>,
+) -> Result<Option<P::Real>, QueryError>
+where
+    P: Point,
+    M: Isometry<P>,
+{
+    query_error::check_isometries::<P, M>(m1, m2)?;
+    query_error::check_vector::<P>(vel1)?;
+    query_error::check_vector::<P>(vel2)?;
+
+    dispatch_toi(m1, vel1, g1, m2, vel2, g2, true)
+}
+
+/// Computes the smallest time of impact of two shapes under translational movement, with the
+/// contact points and normal at this time.
+///
+/// The status of the result is `TOIStatus::Penetrating` if the objects are touching or
+/// penetrating at the time `0`.
+pub fn shape_against_shape<P, M>(
+    m1: &M,
+    vel1: &P::Vector,
+    g1: &Shape<P, M>,
+    m2: &M,
+    vel2: &P::Vector,
+    g2: &Shape<P, M>,
+) -> Option<TOI<P>>
+where
+    P: Point,
+    M: Isometry<P>,
+{
+    match dispatch(m1, vel1, g1, m2, vel2, g2, false) {
+        Ok(res) => res,
+        Err(QueryError::UnsupportedShapePair) => {
+            panic!(
+                "No algorithm known to compute a contact point between the given pair of shapes."
+            )
+        }
+        Err(err) => panic!("Time of impact computation failed: {}", err),
+    }
+}
+
+/// Computes the smallest time of impact of two shapes under translational movement, with the
+/// contact points and normal at this time.
+///
+/// Returns an error if the time of impact could not be computed.
+pub fn try_shape_against_shape<P, M>(
+    m1: &M,
+    vel1: &P::Vector,
+    g1: &Shape<P, M>,
+    m2: &M,
+    vel2: &P::Vector,
+    g2: &Shape<P, M>,
+) -> Result<Option<TOI<P>>, QueryError>
+where
+    P: Point,
+    M: Isometry<P>,
+{
+    query_error::check_isometries::<P, M>(m1, m2)?;
+    query_error::check_vector::<P>(vel1)?;
+    query_error::check_vector::<P>(vel2)?;
+
+    dispatch(m1, vel1, g1, m2, vel2, g2, true)
+}
+
+// If `strict` is `false`, the algorithms that never report errors are used whenever possible.
+fn dispatch<P, M>(
+    m1: &M,
+    vel1: &P::Vector,
+    g1: &Shape<P, M>,
+    m2: &M,
+    vel2: &P::Vector,
+    g2: &Shape<P, M>,
+    strict: bool,
+) -> Result<Option<TOI<P>>, QueryError>
+where
+    P: Point,
+    M: Isometry<P>,
+{
+    if let Some(res) = query_registry::registered_time_of_impact(m1, vel1, g1, m2, vel2, g2) {
+        Ok(res)
+    } else if let (Some(b1), Some(b2)) = (
         g1.as_shape::<Ball<P::Real>>(),
         g2.as_shape::<Ball<P::Real>>(),
     ) {
         let p1 = P::from_coordinates(m1.translation().to_vector());
         let p2 = P::from_coordinates(m2.translation().to_vector());
 
-        time_of_impact_internal::ball_against_ball(&p1, vel1, b1, &p2, vel2, b2)
+        Ok(time_of_impact_internal::ball_against_ball(&p1, vel1, b1, &p2, vel2, b2))
     } else if let (Some(p1), Some(s2)) = (g1.as_shape::<Plane<P::Vector>>(), g2.as_support_map()) {
-        time_of_impact_internal::plane_against_support_map(m1, vel1, p1, m2, vel2, s2)
+        Ok(time_of_impact_internal::plane_against_support_map(m1, vel1, p1, m2, vel2, s2))
     } else if let (Some(s1), Some(p2)) = (g1.as_support_map(), g2.as_shape::<Plane<P::Vector>>()) {
-        time_of_impact_internal::support_map_against_plane(m1, vel1, s1, m2, vel2, p2)
+        Ok(time_of_impact_internal::support_map_against_plane(m1, vel1, s1, m2, vel2, p2))
+    } else if let (Some(t1), Some(s2)) = (g1.as_shape::<Torus<P::Real>>(), g2.as_support_map()) {
+        Ok(time_of_impact_internal::torus_against_support_map(m1, vel1, t1, m2, vel2, s2))
+    } else if let (Some(s1), Some(t2)) = (g1.as_support_map(), g2.as_shape::<Torus<P::Real>>()) {
+        Ok(time_of_impact_internal::support_map_against_torus(m1, vel1, s1, m2, vel2, t2))
+    } else if let (Some(s1), Some(s2)) = (g1.as_support_map(), g2.as_support_map()) {
+        if strict {
+            time_of_impact_internal::try_support_map_against_support_map(m1, vel1, s1, m2, vel2, s2)
+        } else {
+            Ok(time_of_impact_internal::support_map_against_support_map(
+                m1, vel1, s1, m2, vel2, s2,
+            ))
+        }
+    } else if let Some(c1) = g1.as_composite_shape() {
+        time_of_impact_internal::try_composite_shape_against_shape(m1, vel1, c1, m2, vel2, g2)
+    } else if let Some(c2) = g2.as_composite_shape() {
+        time_of_impact_internal::try_shape_against_composite_shape(m1, vel1, g1, m2, vel2, c2)
+    } else {
+        Err(QueryError::UnsupportedShapePair)
+    }
+}
+
+// Same as `dispatch` but only computes the time of impact. The witnesses are skipped for the
+// support-mapped and composite shapes, and are cheap to compute for the other pairs.
+fn dispatch_toi<P, M>(
+    m1: &M,
+    vel1: &P::Vector,
+    g1: &Shape<P, M>,
+    m2: &M,
+    vel2: &P::Vector,
+    g2: &Shape<P, M>,
+    strict: bool,
+) -> Result<Option<P::Real>, QueryError>
+where
+    P: Point,
+    M: Isometry<P>,
+{
+    let is_plane_or_torus =
+        |g: &Shape<P, M>| g.is_shape::<Plane<P::Vector>>() || g.is_shape::<Torus<P::Real>>();
+
+    if let Some(res) = query_registry::registered_time_of_impact(m1, vel1, g1, m2, vel2, g2) {
+        Ok(res.map(|res| res.toi))
+    } else if (g1.is_shape::<Ball<P::Real>>() && g2.is_shape::<Ball<P::Real>>())
+        || (is_plane_or_torus(g1) && g2.is_support_map())
+        || (g1.is_support_map() && is_plane_or_torus(g2))
+    {
+        dispatch(m1, vel1, g1, m2, vel2, g2, strict).map(|res| res.map(|res| res.toi))
     } else if let (Some(s1), Some(s2)) = (g1.as_support_map(), g2.as_support_map()) {
-        time_of_impact_internal::support_map_against_support_map(m1, vel1, s1, m2, vel2, s2)
+        if strict {
+            time_of_impact_internal::try_support_map_against_support_map_toi(
+                m1, vel1, s1, m2, vel2, s2,
+            )
+        } else {
+            Ok(time_of_impact_internal::support_map_against_support_map_toi(
+                m1, vel1, s1, m2, vel2, s2,
+            ))
+        }
     } else if let Some(c1) = g1.as_composite_shape() {
-        time_of_impact_internal::composite_shape_against_shape(m1, vel1, c1, m2, vel2, g2)
+        time_of_impact_internal::try_composite_shape_against_shape_toi(m1, vel1, c1, m2, vel2, g2)
     } else if let Some(c2) = g2.as_composite_shape() {
-        time_of_impact_internal::shape_against_composite_shape(m1, vel1, g1, m2, vel2, c2)
+        time_of_impact_internal::try_shape_against_composite_shape_toi(m1, vel1, g1, m2, vel2, c2)
     } else {
-        panic!("No algorithm known to compute a contact point between the given pair of shapes.")
+        Err(QueryError::UnsupportedShapePair)
     }
 }
@@ -1,6 +1,11 @@
 use alga::general::Id;
+use alga::linear::Translation;
+use na;
 use shape::{MinkowskiSum, Reflection, SupportMap};
-use query::{Ray, RayCast};
+use query::algorithms::gjk;
+use query::algorithms::{JohnsonSimplex, VoronoiSimplex2, VoronoiSimplex3};
+use query::{QueryError, Ray, RayCast, TOI};
+use query::time_of_impact_internal::toi;
 use math::{Isometry, Point};
 
 /// Time of impacts between two support-mapped shapes under translational movement.
@@ -11,6 +16,65 @@ pub fn support_map_against_support_map<P, M, G1: ?Sized, G2: ?Sized>(
     m2: &M,
     vel2: &P::Vector,
     g2: &G2,
+) -> Option<TOI<P>>
+where
+    P: Point,
+    M: Isometry<P>,
+    G1: SupportMap<P, M>,
+    G2: SupportMap<P, M>,
+{
+    support_map_against_support_map_toi(m1, vel1, g1, m2, vel2, g2).map(|t| {
+        let vel = *vel1 - *vel2;
+        let m1 = m1.append_translation(&M::Translation::from_vector(*vel1 * t).unwrap());
+        let m2 = m2.append_translation(&M::Translation::from_vector(*vel2 * t).unwrap());
+        let (w1, w2, normal) = toi::support_map_witnesses(&m1, g1, &m2, g2, &vel);
+
+        TOI::new(t, w1, w2, normal, toi::exact_status(&t))
+    })
+}
+
+/// Time of impacts between two support-mapped shapes under translational movement.
+///
+/// Returns an error if the GJK or the EPA fails.
+pub fn try_support_map_against_support_map<P, M, G1: ?Sized, G2: ?Sized>(
+    m1: &M,
+    vel1: &P::Vector,
+    g1: &G1,
+    m2: &M,
+    vel2: &P::Vector,
+    g2: &G2,
+) -> Result<Option<TOI<P>>, QueryError>
+where
+    P: Point,
+    M: Isometry<P>,
+    G1: SupportMap<P, M>,
+    G2: SupportMap<P, M>,
+{
+    match try_support_map_against_support_map_toi(m1, vel1, g1, m2, vel2, g2)? {
+        Some(t) => {
+            let vel = *vel1 - *vel2;
+            let m1 = m1.append_translation(&M::Translation::from_vector(*vel1 * t).unwrap());
+            let m2 = m2.append_translation(&M::Translation::from_vector(*vel2 * t).unwrap());
+            let (w1, w2, normal) = toi::try_support_map_witnesses(&m1, g1, &m2, g2, &vel)?;
+
+            Ok(Some(TOI::new(t, w1, w2, normal, toi::exact_status(&t))))
+        }
+        None => Ok(None),
+    }
+}
+
+/// Time of impact between two support-mapped shapes under translational movement, without the
+/// contact points and normal at this time.
+///
+/// This only casts a ray on the Minkowski difference of the shapes, so it is much cheaper than
+/// `support_map_against_support_map`.
+pub fn support_map_against_support_map_toi<P, M, G1: ?Sized, G2: ?Sized>(
+    m1: &M,
+    vel1: &P::Vector,
+    g1: &G1,
+    m2: &M,
+    vel2: &P::Vector,
+    g2: &G2,
 ) -> Option<P::Real>
 where
     P: Point,
@@ -24,3 +88,37 @@ where
 
     cso.toi_with_ray(&Id::new(), &Ray::new(P::origin(), -vel), true)
 }
+
+/// Time of impact between two support-mapped shapes under translational movement, without the
+/// contact points and normal at this time.
+///
+/// Returns an error if the GJK fails.
+pub fn try_support_map_against_support_map_toi<P, M, G1: ?Sized, G2: ?Sized>(
+    m1: &M,
+    vel1: &P::Vector,
+    g1: &G1,
+    m2: &M,
+    vel2: &P::Vector,
+    g2: &G2,
+) -> Result<Option<P::Real>, QueryError>
+where
+    P: Point,
+    M: Isometry<P>,
+    G1: SupportMap<P, M>,
+    G2: SupportMap<P, M>,
+{
+    let vel = *vel1 - *vel2;
+    let rg2 = Reflection::new(g2);
+    let cso = MinkowskiSum::new(m1, g1, m2, &rg2);
+    let ray = Ray::new(P::origin(), -vel);
+
+    let inter = if na::dimension::<P::Vector>() == 2 {
+        gjk::try_cast_ray(&Id::new(), &cso, &mut VoronoiSimplex2::<P>::new(), &ray)?
+    } else if na::dimension::<P::Vector>() == 3 {
+        gjk::try_cast_ray(&Id::new(), &cso, &mut VoronoiSimplex3::<P>::new(), &ray)?
+    } else {
+        gjk::try_cast_ray(&Id::new(), &cso, &mut JohnsonSimplex::<P>::new_w_tls(), &ray)?
+    };
+
+    Ok(inter.map(|(t, _)| t))
+}
@@ -0,0 +1,215 @@
+use std::mem;
+use num::{Bounded, Zero};
+use approx::ApproxEq;
+
+use alga::general::Real;
+use alga::linear::{FiniteDimVectorSpace, Translation};
+use na::{self, Unit};
+use math::{Isometry, Point};
+use query::algorithms::gjk::{self, GJKResult};
+use query::algorithms::{Simplex, JohnsonSimplex, VoronoiSimplex2, VoronoiSimplex3};
+use query::{closest_points_internal, contacts_internal, ClosestPoints, QueryError};
+use shape::{self, Plane, SupportMap};
+
+/// The status of a time of impact computation.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum TOIStatus {
+    /// The time of impact has been computed successfully.
+    Converged,
+    /// The algorithm stopped before reaching the required precision.
+    ///
+    /// The time of impact is then a lower bound of the actual time of impact.
+    Failed,
+    /// The shapes are already touching or penetrating at the time `0`.
+    Penetrating,
+}
+
+/// The result of a time of impact computation.
+#[derive(Debug, PartialEq, Clone)]
+pub struct TOI<P: Point> {
+    /// The time of impact.
+    pub toi: P::Real,
+    /// The point of the first shape touching the second shape at the time of impact, expressed
+    /// in world space.
+    pub witness1: P,
+    /// The point of the second shape touching the first shape at the time of impact, expressed
+    /// in world space.
+    pub witness2: P,
+    /// The impact normal, pointing from the first shape toward the second shape.
+    pub normal: Unit<P::Vector>,
+    /// The status of the time of impact computation.
+    pub status: TOIStatus,
+}
+
+impl<P: Point> TOI<P> {
+    /// Creates a new time of impact result.
+    #[inline]
+    pub fn new(
+        toi: P::Real,
+        witness1: P,
+        witness2: P,
+        normal: Unit<P::Vector>,
+        status: TOIStatus,
+    ) -> TOI<P> {
+        TOI {
+            toi: toi,
+            witness1: witness1,
+            witness2: witness2,
+            normal: normal,
+            status: status,
+        }
+    }
+
+    /// Swaps the roles of the two shapes, i.e., swaps the witness points and reverts the normal.
+    #[inline]
+    pub fn flip(&mut self) {
+        mem::swap(&mut self.witness1, &mut self.witness2);
+        self.normal = -self.normal;
+    }
+}
+
+/// The status of a time of impact computed exactly, e.g., by ray casting.
+pub(crate) fn exact_status<N: Zero>(toi: &N) -> TOIStatus {
+    if toi.is_zero() {
+        TOIStatus::Penetrating
+    } else {
+        TOIStatus::Converged
+    }
+}
+
+/// The witness points and the normal of two support-mapped shapes touching each other.
+///
+/// The direction `dir` is used as normal if the shapes do not allow to compute it.
+pub(crate) fn support_map_witnesses<P, M, G1: ?Sized, G2: ?Sized>(
+    m1: &M,
+    g1: &G1,
+    m2: &M,
+    g2: &G2,
+    dir: &P::Vector,
+) -> (P, P, Unit<P::Vector>)
+where
+    P: Point,
+    M: Isometry<P>,
+    G1: SupportMap<P, M>,
+    G2: SupportMap<P, M>,
+{
+    let eps = P::Real::default_epsilon().sqrt();
+    let closest = closest_points_internal::support_map_against_support_map(
+        m1,
+        g1,
+        m2,
+        g2,
+        P::Real::max_value(),
+    );
+
+    if let ClosestPoints::WithinMargin(p1, p2) = closest {
+        if let Some(normal) = Unit::try_new(p2 - p1, eps) {
+            return (p1, p2, normal);
+        }
+    }
+
+    // The shapes are exactly touching or slightly penetrating.
+    match contacts_internal::support_map_against_support_map(m1, g1, m2, g2, eps) {
+        Some(c) => (c.world1, c.world2, c.normal),
+        None => {
+            let normal = match Unit::try_new(*dir, eps) {
+                Some(normal) => normal,
+                None => Unit::new_unchecked(P::Vector::canonical_basis_element(0)),
+            };
+
+            (
+                g1.support_point(m1, &normal),
+                g2.support_point(m2, &-*normal),
+                normal,
+            )
+        }
+    }
+}
+
+/// The witness points and the normal of two support-mapped shapes touching each other.
+///
+/// Returns an error if the GJK or the EPA fails.
+pub(crate) fn try_support_map_witnesses<P, M, G1: ?Sized, G2: ?Sized>(
+    m1: &M,
+    g1: &G1,
+    m2: &M,
+    g2: &G2,
+    dir: &P::Vector,
+) -> Result<(P, P, Unit<P::Vector>), QueryError>
+where
+    P: Point,
+    M: Isometry<P>,
+    G1: SupportMap<P, M>,
+    G2: SupportMap<P, M>,
+{
+    let eps = P::Real::default_epsilon().sqrt();
+    let mut init_dir = m1.translation().to_vector() - m2.translation().to_vector();
+
+    if init_dir.is_zero() {
+        init_dir[0] = na::one();
+    }
+
+    let support_point = shape::cso_support_point(m1, g1, m2, g2, init_dir);
+    let max_dist = P::Real::max_value();
+
+    let closest = if na::dimension::<P::Vector>() == 2 {
+        let simplex = &mut VoronoiSimplex2::new();
+        simplex.reset(support_point);
+        gjk::try_closest_points_with_max_dist(m1, g1, m2, g2, max_dist, simplex)?
+    } else if na::dimension::<P::Vector>() == 3 {
+        let simplex = &mut VoronoiSimplex3::new();
+        simplex.reset(support_point);
+        gjk::try_closest_points_with_max_dist(m1, g1, m2, g2, max_dist, simplex)?
+    } else {
+        let simplex = &mut JohnsonSimplex::new_w_tls();
+        simplex.reset(support_point);
+        gjk::try_closest_points_with_max_dist(m1, g1, m2, g2, max_dist, simplex)?
+    };
+
+    if let GJKResult::Projection((p1, p2)) = closest {
+        if let Some(normal) = Unit::try_new(p2 - p1, eps) {
+            return Ok((p1, p2, normal));
+        }
+    }
+
+    // The shapes are exactly touching or slightly penetrating.
+    match contacts_internal::try_support_map_against_support_map(m1, g1, m2, g2, eps)? {
+        Some(c) => Ok((c.world1, c.world2, c.normal)),
+        None => {
+            let normal = match Unit::try_new(*dir, eps) {
+                Some(normal) => normal,
+                None => Unit::new_unchecked(P::Vector::canonical_basis_element(0)),
+            };
+
+            Ok((
+                g1.support_point(m1, &normal),
+                g2.support_point(m2, &-*normal),
+                normal,
+            ))
+        }
+    }
+}
+
+/// The witness points and the normal of a plane touching a support-mapped shape.
+pub(crate) fn plane_witnesses<P, M, G: ?Sized>(
+    mplane: &M,
+    plane: &Plane<P::Vector>,
+    mother: &M,
+    other: &G,
+) -> (P, P, Unit<P::Vector>)
+where
+    P: Point,
+    M: Isometry<P>,
+    G: SupportMap<P, M>,
+{
+    let plane_normal = mplane.rotate_vector(plane.normal());
+    let plane_center = P::from_coordinates(mplane.translation().to_vector());
+    let deepest = other.support_point(mother, &-plane_normal);
+    let distance = na::dot(&plane_normal, &(plane_center - deepest));
+
+    (
+        deepest + plane_normal * distance,
+        deepest,
+        Unit::new_unchecked(plane_normal),
+    )
+}
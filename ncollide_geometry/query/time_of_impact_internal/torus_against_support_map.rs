@@ -0,0 +1,53 @@
+use math::{Isometry, Point};
+use query::{time_of_impact_internal, TOI};
+use query::contacts_internal::torus_against_support_map::minimize_on_core;
+use shape::{Ball, SupportMap, Torus};
+
+/// Time of impact of a torus with a support-mapped shape under translational movement.
+///
+/// The torus is seen as the union of the balls of radius `minor_radius` centered on its core
+/// circle. The first of those balls to hit the other shape is found by sampling the core circle
+/// and refining the best sample with a golden-section search.
+pub fn torus_against_support_map<P, M, G: ?Sized>(
+    m1: &M,
+    vel1: &P::Vector,
+    t1: &Torus<P::Real>,
+    m2: &M,
+    vel2: &P::Vector,
+    g2: &G,
+) -> Option<TOI<P>>
+where
+    P: Point,
+    M: Isometry<P>,
+    G: SupportMap<P, M>,
+{
+    let ball = Ball::new(t1.minor_radius());
+
+    minimize_on_core(m1, t1, |m| {
+        time_of_impact_internal::support_map_against_support_map(m, vel1, &ball, m2, vel2, g2)
+            .map(|toi| (toi.toi, toi))
+    })
+}
+
+/// Time of impact of a support-mapped shape with a torus under translational movement.
+pub fn support_map_against_torus<P, M, G: ?Sized>(
+    m1: &M,
+    vel1: &P::Vector,
+    g1: &G,
+    m2: &M,
+    vel2: &P::Vector,
+    t2: &Torus<P::Real>,
+) -> Option<TOI<P>>
+where
+    P: Point,
+    M: Isometry<P>,
+    G: SupportMap<P, M>,
+{
+    let mut res = torus_against_support_map(m2, vel2, t2, m1, vel1, g1);
+
+    for toi in res.iter_mut() {
+        toi.flip()
+    }
+
+    res
+}
@@ -1,7 +1,7 @@
 use alga::general::Real;
 use na::Unit;
 
-use shape::SupportMap;
+use shape::{FeatureId, SupportMap};
 use math::{Isometry, Point};
 
 /// A Ball shape.
@@ -39,4 +39,10 @@ impl<P: Point, M: Isometry<P>> SupportMap<P, M> for Ball<P::Real> {
     fn support_point_toward(&self, m: &M, dir: &Unit<P::Vector>) -> P {
         m.translate_point(&P::origin()) + **dir * self.radius()
     }
+
+    /// A ball touches other shapes at a single point so its whole surface is its vertex 0.
+    #[inline]
+    fn support_feature_id_toward(&self, _: &M, _: &Unit<P::Vector>) -> FeatureId {
+        FeatureId::Vertex(0)
+    }
 }
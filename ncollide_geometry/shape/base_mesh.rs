@@ -3,16 +3,44 @@
 use std::sync::Arc;
 use std::marker::PhantomData;
 
-use alga::general::Id;
-use na::Point2;
+use approx::ApproxEq;
+use num::Zero;
+
+use alga::general::{Id, Real};
+use na::{self, Point2, Unit};
 use partitioning::BVT;
 use bounding_volume::{self, HasBoundingVolume, AABB};
 use math::Point;
 
 /// Trait implemented by elements usable on the Mesh.
-pub trait BaseMeshElement<I, P> {
+pub trait BaseMeshElement<I, P: Point> {
     /// Creates a new mesh element from a set of vertices and indices.
     fn new_with_vertices_and_indices(&[P], &I) -> Self;
+
+    /// The normal of the front face of this element, if it has one.
+    ///
+    /// This is used by one-sided meshes to ignore what lies behind their elements. Returns `None`
+    /// by default, i.e., the element is always considered two-sided.
+    #[inline]
+    fn front_normal(&self) -> Option<Unit<P::Vector>> {
+        None
+    }
+}
+
+/// The convexity of the junction between two adjacent elements of a mesh.
+///
+/// For a `TriMesh`, the junction is an edge shared by two triangles. For a `Polyline`, the
+/// junction is a vertex shared by two segments.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum EdgeConvexity {
+    /// The junction is not shared by exactly two elements.
+    Boundary,
+    /// The two elements are coplanar (or collinear).
+    Flat,
+    /// The two elements form a ridge when seen from the front of the first one.
+    Convex,
+    /// The two elements form a valley when seen from the front of the first one.
+    Concave,
 }
 
 /// A mesh generic wrt. the contained mesh elements characterized by vertices.
@@ -23,6 +51,7 @@ pub struct BaseMesh<P: Point, I, E> {
     indices: Arc<Vec<I>>,
     uvs: Option<Arc<Vec<Point2<P::Real>>>>,
     normals: Option<Arc<Vec<P::Vector>>>,
+    oriented: bool,
     elt: PhantomData<E>,
 }
 
@@ -38,6 +67,7 @@ where
             indices: self.indices.clone(),
             uvs: self.uvs.clone(),
             normals: self.normals.clone(),
+            oriented: self.oriented,
             elt: PhantomData,
         }
     }
@@ -85,9 +115,31 @@ where
             indices: indices,
             uvs: uvs,
             normals: normals,
+            oriented: false,
             elt: PhantomData,
         }
     }
+
+    /// Modifies the vertices of this mesh in-place and refits its acceleration structure.
+    ///
+    /// The topology of the mesh is left unchanged: only the bounding volumes of its elements and
+    /// of the nodes of its `BVT` are recomputed. If the vertex buffer is shared with other meshes,
+    /// it is copied first. The normals of this mesh, if any, are not updated.
+    pub fn update_vertices<F: FnOnce(&mut [P])>(&mut self, f: F) {
+        f(&mut Arc::make_mut(&mut self.vertices)[..]);
+        self.refit()
+    }
+
+    /// Recomputes the bounding volumes of the elements of this mesh and refits its `BVT`.
+    pub fn refit(&mut self) {
+        for i in 0..self.bvs.len() {
+            let element = self.element_at(i);
+            self.bvs[i] = bounding_volume::aabb(&element, &Id::new());
+        }
+
+        let bvs = &self.bvs;
+        self.bvt.refit(&mut |i: &usize| bvs[*i].clone());
+    }
 }
 
 impl<P, I, E> BaseMesh<P, I, E>
@@ -135,6 +187,22 @@ where
     pub fn bvt(&self) -> &BVT<usize, AABB<P>> {
         &self.bvt
     }
+
+    /// Whether the elements of this mesh are one-sided.
+    #[inline]
+    pub fn is_oriented(&self) -> bool {
+        self.oriented
+    }
+
+    /// Sets whether the elements of this mesh are one-sided.
+    ///
+    /// If `oriented` is `true`, ray casts ignore the back faces of the elements of this mesh and
+    /// contacts with their back faces are discarded. Elements without a front face, e.g.,
+    /// segments, remain two-sided. Meshes are two-sided by default.
+    #[inline]
+    pub fn set_oriented(&mut self, oriented: bool) {
+        self.oriented = oriented
+    }
 }
 
 impl<P, I, E> BaseMesh<P, I, E>
@@ -149,4 +217,99 @@ where
 
         BaseMeshElement::new_with_vertices_and_indices(vs, &self.indices[i])
     }
+
+    /// The normal of the front face of the i-th mesh element if this mesh is one-sided.
+    ///
+    /// Returns `None` if this mesh is two-sided or if the element has no front face.
+    #[inline]
+    pub fn front_normal_at(&self, i: usize) -> Option<Unit<P::Vector>> {
+        if self.oriented {
+            self.element_at(i).front_normal()
+        } else {
+            None
+        }
+    }
+}
+
+/// The unit direction orthogonal to the edge `ab` pointing away from the point `c`.
+///
+/// If `ab` is degenerate, i.e., `a == b`, this is the direction from `c` to `a`.
+pub(crate) fn edge_outward_direction<P: Point>(a: &P, b: &P, c: &P) -> Option<P::Vector> {
+    let ab = *b - *a;
+    let ca = *a - *c;
+    let sqnab = na::norm_squared(&ab);
+    let dir = if sqnab.is_zero() {
+        ca
+    } else {
+        ca - ab * (na::dot(&ca, &ab) / sqnab)
+    };
+
+    Unit::try_new(dir, P::Real::default_epsilon()).map(|dir| dir.unwrap())
+}
+
+/// The convexity of a junction seen from the side pointed to by `normal`, given the outward
+/// directions `o1` and `o2` of the two elements sharing it.
+pub(crate) fn junction_convexity<P: Point>(
+    normal: &P::Vector,
+    o1: &P::Vector,
+    o2: &P::Vector,
+) -> EdgeConvexity {
+    let eps = P::Real::default_epsilon().sqrt();
+
+    if na::dot(o1, o2) <= eps - na::one::<P::Real>() {
+        EdgeConvexity::Flat
+    } else if na::dot(normal, o2) > na::zero() {
+        EdgeConvexity::Convex
+    } else {
+        EdgeConvexity::Concave
+    }
+}
+
+/// Corrects a contact normal at a junction so that it does not catch on internal edges.
+///
+/// Here, `n1` is the unit normal of the element hit by the contact, pointing toward the same
+/// side as `normal`, and `o1`, `o2` are the outward directions of the two elements sharing the
+/// junction. Returns `false` if `normal` points toward the Voronoi region of the adjacent element,
+/// i.e., the contact should be discarded since the adjacent element will handle it.
+pub(crate) fn adjust_junction_normal<P: Point>(
+    n1: &Unit<P::Vector>,
+    o1: &P::Vector,
+    o2: &P::Vector,
+    convexity: EdgeConvexity,
+    normal: &mut Unit<P::Vector>,
+) -> bool {
+    let eps = P::Real::default_epsilon().sqrt();
+
+    match convexity {
+        EdgeConvexity::Boundary => true,
+        EdgeConvexity::Flat => {
+            *normal = *n1;
+            true
+        }
+        EdgeConvexity::Convex | EdgeConvexity::Concave => {
+            // The stored convexity is relative to the front face, so check the actual side.
+            if na::dot(&**n1, o2) <= na::zero() || na::dot(&**normal, o1) < -eps {
+                *normal = *n1;
+                true
+            } else {
+                na::dot(&**normal, o2) >= -eps
+            }
+        }
+    }
+}
+
+/// Whether `p` lies on the segment `ab` up to a tolerance relative to the length of `ab`.
+pub(crate) fn is_point_on_segment<P: Point>(p: &P, a: &P, b: &P) -> bool {
+    let ab = *b - *a;
+    let ap = *p - *a;
+    let sqnab = na::norm_squared(&ab);
+    let eps = P::Real::default_epsilon().sqrt();
+
+    let t = if sqnab.is_zero() {
+        na::zero()
+    } else {
+        na::clamp(na::dot(&ap, &ab) / sqnab, na::zero(), na::one())
+    };
+
+    na::norm_squared(&(ap - ab * t)) <= eps * eps * sqnab
 }
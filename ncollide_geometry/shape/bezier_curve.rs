@@ -0,0 +1,399 @@
+//! Bezier curve defined by its control points.
+
+use std::collections::BinaryHeap;
+use std::cmp::Ordering;
+use std::mem;
+
+use alga::general::{Id, Real};
+use na;
+
+use bounding_volume::{self, AABB};
+use partitioning::{BVTCostFn, BVTVisitor, BVH};
+use shape::{CompositeShape, Segment, Shape};
+use math::{Isometry, Point};
+
+/// Maximum number of subdivisions of a Bezier curve.
+const MAX_DEPTH: usize = 24;
+
+/// A (non-rational) Bezier curve.
+///
+/// The curve is seen as a composite shape: it is adaptively subdivided until each piece lies
+/// within `tolerance` of the segment joining its extremities. Those segments are the parts of the
+/// composite shape. The subdivision is implicit: the pieces are recomputed when the curve is
+/// traversed so no tessellation is stored.
+#[derive(PartialEq, Debug, Clone)]
+pub struct BezierCurve<P: Point> {
+    control_points: Vec<P>,
+    tolerance: P::Real,
+}
+
+impl<P: Point> BezierCurve<P> {
+    /// Creates a new Bezier curve.
+    ///
+    /// # Arguments:
+    /// * `control_points` - the control points of the curve. At least two are required.
+    /// * `tolerance` - the maximum distance between the curve and its piecewise-linear
+    /// approximation used by the geometric queries. Must be positive.
+    pub fn new(control_points: Vec<P>, tolerance: P::Real) -> BezierCurve<P> {
+        assert!(
+            control_points.len() >= 2,
+            "A Bezier curve must have at least two control points."
+        );
+        assert!(
+            tolerance > na::zero(),
+            "The subdivision tolerance must be positive."
+        );
+
+        BezierCurve {
+            control_points: control_points,
+            tolerance: tolerance,
+        }
+    }
+
+    /// The control points of this curve.
+    #[inline]
+    pub fn control_points(&self) -> &[P] {
+        &self.control_points[..]
+    }
+
+    /// The maximum distance between this curve and its piecewise-linear approximation.
+    #[inline]
+    pub fn tolerance(&self) -> P::Real {
+        self.tolerance
+    }
+
+    /// The degree of this curve.
+    #[inline]
+    pub fn degree(&self) -> usize {
+        self.control_points.len() - 1
+    }
+
+    /// Evaluates this curve at the parameter `t`.
+    #[inline]
+    pub fn point_at(&self, t: P::Real) -> P {
+        let mut cache = self.control_points.clone();
+
+        de_casteljau(&mut cache[..], t)
+    }
+
+    /// Evaluates the derivative of this curve at the parameter `t`.
+    pub fn derivative_at(&self, t: P::Real) -> P::Vector {
+        let degree: P::Real = na::convert(self.degree() as f64);
+        let mut cache: Vec<P> = self.control_points
+            .windows(2)
+            .map(|w| P::from_coordinates((w[1] - w[0]) * degree))
+            .collect();
+
+        de_casteljau(&mut cache[..], t).coordinates()
+    }
+
+    /// Splits this curve at the parameter `t` into two Bezier curves.
+    pub fn split(&self, t: P::Real) -> (BezierCurve<P>, BezierCurve<P>) {
+        let (left, right) = split_control_points(&self.control_points[..], t);
+
+        (
+            BezierCurve::new(left, self.tolerance),
+            BezierCurve::new(right, self.tolerance),
+        )
+    }
+
+    /// The parameter range of the curve piece approximated by the part `id`.
+    pub fn part_range(&self, id: usize) -> (P::Real, P::Real) {
+        let (depth, i) = part_depth_and_index(id);
+        let width: P::Real = na::convert(1.0 / (1usize << depth) as f64);
+        let t0 = width * na::convert(i as f64);
+
+        (t0, t0 + width)
+    }
+
+    /// The segment approximating the curve piece corresponding to the part `id`.
+    #[inline]
+    pub fn segment_at(&self, id: usize) -> Segment<P> {
+        let (t0, t1) = self.part_range(id);
+
+        Segment::new(self.point_at(t0), self.point_at(t1))
+    }
+
+    fn root_piece(&self) -> CurvePiece<P> {
+        CurvePiece {
+            control_points: self.control_points.clone(),
+            depth: 0,
+            index: 0,
+        }
+    }
+
+    /// Whether `piece` is not subdivided any further.
+    fn is_leaf(&self, piece: &CurvePiece<P>) -> bool {
+        piece.depth >= MAX_DEPTH || is_flat(&piece.control_points[..], self.tolerance)
+    }
+}
+
+impl<P: Point, M: Isometry<P>> CompositeShape<P, M> for BezierCurve<P> {
+    #[inline]
+    fn map_part_at(&self, i: usize, f: &mut FnMut(&M, &Shape<P, M>)) {
+        let one: M = na::one();
+
+        self.map_transformed_part_at(i, &one, f)
+    }
+
+    #[inline]
+    fn map_transformed_part_at(&self, i: usize, m: &M, f: &mut FnMut(&M, &Shape<P, M>)) {
+        let element = self.segment_at(i);
+
+        f(m, &element)
+    }
+
+    #[inline]
+    fn aabb_at(&self, i: usize) -> AABB<P> {
+        let (t0, t1) = self.part_range(i);
+
+        piece_aabb(&sub_control_points(&self.control_points[..], t0, t1)[..])
+    }
+
+    #[inline]
+    fn bvh(&self) -> &BVH<P::Real, usize, AABB<P>> {
+        self
+    }
+}
+
+/// The bounding volume hierarchy of a Bezier curve is its adaptive subdivision. The bounding
+/// volume of each piece is the AABB of its control points.
+impl<P: Point> BVH<P::Real, usize, AABB<P>> for BezierCurve<P> {
+    fn visit(&self, visitor: &mut BVTVisitor<usize, AABB<P>>) {
+        let mut stack = vec![self.root_piece()];
+
+        while let Some(piece) = stack.pop() {
+            let aabb = piece_aabb(&piece.control_points[..]);
+
+            if self.is_leaf(&piece) {
+                visitor.visit_leaf(&piece.part_id(), &aabb)
+            } else if visitor.visit_internal(&aabb) {
+                let (left, right) = piece.split();
+                stack.push(right);
+                stack.push(left);
+            }
+        }
+    }
+
+    fn best_first_search(
+        &self,
+        algorithm: &mut BVTCostFn<P::Real, usize, AABB<P>, UserData = ()>,
+    ) -> Option<usize> {
+        let mut queue: BinaryHeap<CurvePieceWithCost<P>> = BinaryHeap::new();
+        let mut best_cost = P::Real::max_value();
+        let mut result = None;
+        let root = self.root_piece();
+
+        match algorithm.compute_bv_cost(&piece_aabb(&root.control_points[..])) {
+            Some(cost) => queue.push(CurvePieceWithCost::new(root, -cost)),
+            None => return None,
+        }
+
+        while let Some(node) = queue.pop() {
+            if -node.cost >= best_cost {
+                break; // solution found.
+            }
+
+            if self.is_leaf(&node.piece) {
+                let part = node.piece.part_id();
+
+                if let Some((cost, _)) = algorithm.compute_b_cost(&part) {
+                    if cost < best_cost {
+                        best_cost = cost;
+                        result = Some(part);
+                    }
+                }
+            } else {
+                let (left, right) = node.piece.split();
+
+                for child in vec![left, right].into_iter() {
+                    let aabb = piece_aabb(&child.control_points[..]);
+
+                    if let Some(cost) = algorithm.compute_bv_cost(&aabb) {
+                        if cost < best_cost {
+                            queue.push(CurvePieceWithCost::new(child, -cost))
+                        }
+                    }
+                }
+            }
+        }
+
+        result
+    }
+}
+
+/// Evaluates the Bezier curve with the control points `points` at the parameter `t`.
+///
+/// The content of `points` is overwritten by the intermediate results of the De Casteljau
+/// algorithm.
+#[doc(hidden)]
+pub fn de_casteljau<P: Point>(points: &mut [P], t: P::Real) -> P {
+    let _1: P::Real = na::one();
+    let t_1 = _1 - t;
+
+    for i in 1..points.len() {
+        for j in 0..points.len() - i {
+            points[j] = points[j] * t_1 + points[j + 1].coordinates() * t;
+        }
+    }
+
+    points[0]
+}
+
+/// Splits the Bezier curve with the control points `points` at the parameter `t`.
+#[doc(hidden)]
+pub fn split_control_points<P: Point>(points: &[P], t: P::Real) -> (Vec<P>, Vec<P>) {
+    let _1: P::Real = na::one();
+    let t_1 = _1 - t;
+    let mut cache = points.to_vec();
+    let mut left = Vec::with_capacity(points.len());
+    let mut right = Vec::with_capacity(points.len());
+
+    left.push(cache[0]);
+    right.push(cache[points.len() - 1]);
+
+    for i in 1..points.len() {
+        for j in 0..points.len() - i {
+            cache[j] = cache[j] * t_1 + cache[j + 1].coordinates() * t;
+        }
+
+        left.push(cache[0]);
+        right.push(cache[points.len() - i - 1]);
+    }
+
+    right.reverse();
+
+    (left, right)
+}
+
+/// The control points of the piece of the Bezier curve with the control points `points` spanning
+/// the parameter range `[t0, t1]`.
+#[doc(hidden)]
+pub fn sub_control_points<P: Point>(points: &[P], t0: P::Real, t1: P::Real) -> Vec<P> {
+    let _1: P::Real = na::one();
+    let (_, right) = split_control_points(points, t0);
+
+    if t0 >= _1 {
+        return right;
+    }
+
+    let (left, _) = split_control_points(&right[..], (t1 - t0) / (_1 - t0));
+
+    left
+}
+
+/// The AABB of a set of control points.
+#[doc(hidden)]
+#[inline]
+pub fn piece_aabb<P: Point>(points: &[P]) -> AABB<P> {
+    let (mins, maxs) = bounding_volume::point_cloud_aabb(&Id::new(), points);
+
+    AABB::new(mins, maxs)
+}
+
+/// Tests whether all the control points of a curve lie within `tolerance` of the segment joining
+/// its extremities.
+fn is_flat<P: Point>(points: &[P], tolerance: P::Real) -> bool {
+    let a = points[0];
+    let ab = points[points.len() - 1] - a;
+    let sqnab = na::norm_squared(&ab);
+    let sq_tolerance = tolerance * tolerance;
+
+    points[1..points.len() - 1].iter().all(|pt| {
+        let ap = *pt - a;
+        let t = if sqnab > na::zero() {
+            na::clamp(na::dot(&ap, &ab) / sqnab, na::zero(), na::one())
+        } else {
+            na::zero()
+        };
+
+        na::norm_squared(&(ap - ab * t)) <= sq_tolerance
+    })
+}
+
+/// Computes the depth and the index of the piece corresponding to the part `id`.
+///
+/// The pieces are numbered in breadth-first order, i.e., the `i`-th piece at depth `d` has the
+/// identifier `2^d - 1 + i`.
+#[inline]
+fn part_depth_and_index(id: usize) -> (usize, usize) {
+    let n = id + 1;
+    let depth = mem::size_of::<usize>() * 8 - 1 - n.leading_zeros() as usize;
+
+    (depth, n - (1 << depth))
+}
+
+struct CurvePiece<P> {
+    control_points: Vec<P>,
+    depth: usize,
+    index: usize,
+}
+
+impl<P: Point> CurvePiece<P> {
+    #[inline]
+    fn part_id(&self) -> usize {
+        (1 << self.depth) - 1 + self.index
+    }
+
+    #[inline]
+    fn split(&self) -> (CurvePiece<P>, CurvePiece<P>) {
+        let (left, right) = split_control_points(&self.control_points[..], na::convert(0.5));
+
+        (
+            CurvePiece {
+                control_points: left,
+                depth: self.depth + 1,
+                index: self.index * 2,
+            },
+            CurvePiece {
+                control_points: right,
+                depth: self.depth + 1,
+                index: self.index * 2 + 1,
+            },
+        )
+    }
+}
+
+struct CurvePieceWithCost<P: Point> {
+    piece: CurvePiece<P>,
+    cost: P::Real,
+}
+
+impl<P: Point> CurvePieceWithCost<P> {
+    #[inline]
+    fn new(piece: CurvePiece<P>, cost: P::Real) -> CurvePieceWithCost<P> {
+        CurvePieceWithCost {
+            piece: piece,
+            cost: cost,
+        }
+    }
+}
+
+impl<P: Point> PartialEq for CurvePieceWithCost<P> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.cost.eq(&other.cost)
+    }
+}
+
+impl<P: Point> Eq for CurvePieceWithCost<P> {}
+
+impl<P: Point> PartialOrd for CurvePieceWithCost<P> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.cost.partial_cmp(&other.cost)
+    }
+}
+
+impl<P: Point> Ord for CurvePieceWithCost<P> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        if self.cost < other.cost {
+            Ordering::Less
+        } else if self.cost > other.cost {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    }
+}
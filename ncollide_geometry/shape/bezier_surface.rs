@@ -0,0 +1,511 @@
+//! Bezier surface defined by a grid of control points.
+
+use std::collections::BinaryHeap;
+use std::cmp::Ordering;
+
+use alga::general::Real;
+use na;
+
+use bounding_volume::AABB;
+use partitioning::{BVTCostFn, BVTVisitor, BVH};
+use shape::{CompositeShape, Shape, Triangle};
+use shape::bezier_curve::{self, piece_aabb};
+use math::{Isometry, Point};
+
+/// Maximum number of subdivisions of a Bezier surface along each parametric direction.
+const MAX_DEPTH: usize = 12;
+
+/// A (non-rational) tensor-product Bezier surface.
+///
+/// The control points are given row by row: the `i`-th control point of the `j`-th row is
+/// `control_points[j * nupoints + i]`. The parameter `u` goes along the rows and `v` along the
+/// columns.
+///
+/// The surface is seen as a composite shape: it is adaptively subdivided into quadrilateral
+/// pieces until each of them lies within `tolerance` of the two triangles joining its corners.
+/// Those triangles are the parts of the composite shape. The subdivision is implicit: the pieces
+/// are recomputed when the surface is traversed so no tessellation is stored.
+#[derive(PartialEq, Debug, Clone)]
+pub struct BezierSurface<P: Point> {
+    control_points: Vec<P>,
+    nupoints: usize,
+    nvpoints: usize,
+    tolerance: P::Real,
+}
+
+impl<P: Point> BezierSurface<P> {
+    /// Creates a new Bezier surface.
+    ///
+    /// # Arguments:
+    /// * `control_points` - the `nupoints * nvpoints` control points of the surface, row by row.
+    /// * `nupoints` - the number of control points on each row. At least two are required.
+    /// * `nvpoints` - the number of rows. At least two are required.
+    /// * `tolerance` - the maximum distance between the surface and its piecewise-linear
+    /// approximation used by the geometric queries. Must be positive.
+    pub fn new(
+        control_points: Vec<P>,
+        nupoints: usize,
+        nvpoints: usize,
+        tolerance: P::Real,
+    ) -> BezierSurface<P> {
+        assert!(
+            nupoints >= 2 && nvpoints >= 2,
+            "A Bezier surface must have at least two control points along each direction."
+        );
+        assert!(
+            control_points.len() == nupoints * nvpoints,
+            "The number of control points must be equal to `nupoints * nvpoints`."
+        );
+        assert!(
+            tolerance > na::zero(),
+            "The subdivision tolerance must be positive."
+        );
+
+        BezierSurface {
+            control_points: control_points,
+            nupoints: nupoints,
+            nvpoints: nvpoints,
+            tolerance: tolerance,
+        }
+    }
+
+    /// The control points of this surface, row by row.
+    #[inline]
+    pub fn control_points(&self) -> &[P] {
+        &self.control_points[..]
+    }
+
+    /// The number of control points on each row.
+    #[inline]
+    pub fn nupoints(&self) -> usize {
+        self.nupoints
+    }
+
+    /// The number of rows of control points.
+    #[inline]
+    pub fn nvpoints(&self) -> usize {
+        self.nvpoints
+    }
+
+    /// The maximum distance between this surface and its piecewise-linear approximation.
+    #[inline]
+    pub fn tolerance(&self) -> P::Real {
+        self.tolerance
+    }
+
+    /// Evaluates this surface at the parameters `(u, v)`.
+    #[inline]
+    pub fn point_at(&self, u: P::Real, v: P::Real) -> P {
+        surface_at(
+            &self.control_points[..],
+            self.nupoints,
+            self.nvpoints,
+            u,
+            v,
+        )
+    }
+
+    /// Evaluates the partial derivatives of this surface wrt. `u` and `v` at the parameters
+    /// `(u, v)`.
+    pub fn derivatives_at(&self, u: P::Real, v: P::Real) -> (P::Vector, P::Vector) {
+        let nu = self.nupoints;
+        let nv = self.nvpoints;
+        let udegree: P::Real = na::convert((nu - 1) as f64);
+        let vdegree: P::Real = na::convert((nv - 1) as f64);
+        let mut udiffs = Vec::with_capacity((nu - 1) * nv);
+        let mut vdiffs = Vec::with_capacity(nu * (nv - 1));
+
+        for j in 0..nv {
+            for i in 0..nu - 1 {
+                let diff = self.control_points[j * nu + i + 1] - self.control_points[j * nu + i];
+                udiffs.push(P::from_coordinates(diff * udegree));
+            }
+        }
+
+        for j in 0..nv - 1 {
+            for i in 0..nu {
+                let diff =
+                    self.control_points[(j + 1) * nu + i] - self.control_points[j * nu + i];
+                vdiffs.push(P::from_coordinates(diff * vdegree));
+            }
+        }
+
+        (
+            surface_at(&udiffs[..], nu - 1, nv, u, v).coordinates(),
+            surface_at(&vdiffs[..], nu, nv - 1, u, v).coordinates(),
+        )
+    }
+
+    /// The parameter ranges `((u0, u1), (v0, v1))` of the surface piece containing the part `id`.
+    pub fn part_range(&self, id: usize) -> ((P::Real, P::Real), (P::Real, P::Real)) {
+        let (depth, iu, iv) = quad_depth_and_indices(id / 2);
+        let width: P::Real = na::convert(1.0 / (1usize << depth) as f64);
+        let u0 = width * na::convert(iu as f64);
+        let v0 = width * na::convert(iv as f64);
+
+        ((u0, u0 + width), (v0, v0 + width))
+    }
+
+    /// The triangle approximating half of the surface piece corresponding to the part `id`.
+    pub fn triangle_at(&self, id: usize) -> Triangle<P> {
+        let ((u0, u1), (v0, v1)) = self.part_range(id);
+        let p00 = self.point_at(u0, v0);
+        let p11 = self.point_at(u1, v1);
+
+        if id % 2 == 0 {
+            Triangle::new(p00, self.point_at(u1, v0), p11)
+        } else {
+            Triangle::new(p00, p11, self.point_at(u0, v1))
+        }
+    }
+
+    fn root_piece(&self) -> SurfacePiece<P> {
+        SurfacePiece {
+            control_points: self.control_points.clone(),
+            depth: 0,
+            iu: 0,
+            iv: 0,
+        }
+    }
+
+    /// Whether `piece` is not subdivided any further.
+    fn is_leaf(&self, piece: &SurfacePiece<P>) -> bool {
+        piece.depth >= MAX_DEPTH
+            || is_flat(
+                &piece.control_points[..],
+                self.nupoints,
+                self.nvpoints,
+                self.tolerance,
+            )
+    }
+
+    /// Splits `piece` into four pieces at its parametric center.
+    fn split(&self, piece: &SurfacePiece<P>) -> [SurfacePiece<P>; 4] {
+        let _0_5: P::Real = na::convert(0.5);
+        let nu = self.nupoints;
+        let nv = self.nvpoints;
+        let (left, right) = split_u(&piece.control_points[..], nu, nv, _0_5);
+        let (left_bottom, left_top) = split_v(&left[..], nu, nv, _0_5);
+        let (right_bottom, right_top) = split_v(&right[..], nu, nv, _0_5);
+        let depth = piece.depth + 1;
+        let (iu, iv) = (piece.iu * 2, piece.iv * 2);
+
+        [
+            SurfacePiece::new(left_bottom, depth, iu, iv),
+            SurfacePiece::new(right_bottom, depth, iu + 1, iv),
+            SurfacePiece::new(left_top, depth, iu, iv + 1),
+            SurfacePiece::new(right_top, depth, iu + 1, iv + 1),
+        ]
+    }
+}
+
+impl<P: Point, M: Isometry<P>> CompositeShape<P, M> for BezierSurface<P> {
+    #[inline]
+    fn map_part_at(&self, i: usize, f: &mut FnMut(&M, &Shape<P, M>)) {
+        let one: M = na::one();
+
+        self.map_transformed_part_at(i, &one, f)
+    }
+
+    #[inline]
+    fn map_transformed_part_at(&self, i: usize, m: &M, f: &mut FnMut(&M, &Shape<P, M>)) {
+        let element = self.triangle_at(i);
+
+        f(m, &element)
+    }
+
+    fn aabb_at(&self, i: usize) -> AABB<P> {
+        let ((u0, u1), (v0, v1)) = self.part_range(i);
+        let nu = self.nupoints;
+        let nv = self.nvpoints;
+        let mut rows = Vec::with_capacity(nu * nv);
+
+        for j in 0..nv {
+            let row = &self.control_points[j * nu..(j + 1) * nu];
+            rows.extend(bezier_curve::sub_control_points(row, u0, u1));
+        }
+
+        let mut sub = rows.clone();
+
+        for i in 0..nu {
+            let column: Vec<P> = (0..nv).map(|j| rows[j * nu + i]).collect();
+
+            for (j, pt) in bezier_curve::sub_control_points(&column[..], v0, v1)
+                .into_iter()
+                .enumerate()
+            {
+                sub[j * nu + i] = pt;
+            }
+        }
+
+        piece_aabb(&sub[..])
+    }
+
+    #[inline]
+    fn bvh(&self) -> &BVH<P::Real, usize, AABB<P>> {
+        self
+    }
+}
+
+/// The bounding volume hierarchy of a Bezier surface is its adaptive subdivision. The bounding
+/// volume of each piece is the AABB of its control points.
+impl<P: Point> BVH<P::Real, usize, AABB<P>> for BezierSurface<P> {
+    fn visit(&self, visitor: &mut BVTVisitor<usize, AABB<P>>) {
+        let mut stack = vec![self.root_piece()];
+
+        while let Some(piece) = stack.pop() {
+            let aabb = piece_aabb(&piece.control_points[..]);
+
+            if self.is_leaf(&piece) {
+                let quad = piece.quad_id();
+                visitor.visit_leaf(&(quad * 2), &aabb);
+                visitor.visit_leaf(&(quad * 2 + 1), &aabb);
+            } else if visitor.visit_internal(&aabb) {
+                let children = self.split(&piece);
+                stack.extend(children.iter().rev().map(|c| c.clone()));
+            }
+        }
+    }
+
+    fn best_first_search(
+        &self,
+        algorithm: &mut BVTCostFn<P::Real, usize, AABB<P>, UserData = ()>,
+    ) -> Option<usize> {
+        let mut queue: BinaryHeap<SurfacePieceWithCost<P>> = BinaryHeap::new();
+        let mut best_cost = P::Real::max_value();
+        let mut result = None;
+        let root = self.root_piece();
+
+        match algorithm.compute_bv_cost(&piece_aabb(&root.control_points[..])) {
+            Some(cost) => queue.push(SurfacePieceWithCost::new(root, -cost)),
+            None => return None,
+        }
+
+        while let Some(node) = queue.pop() {
+            if -node.cost >= best_cost {
+                break; // solution found.
+            }
+
+            if self.is_leaf(&node.piece) {
+                let quad = node.piece.quad_id();
+
+                for part in [quad * 2, quad * 2 + 1].iter() {
+                    if let Some((cost, _)) = algorithm.compute_b_cost(part) {
+                        if cost < best_cost {
+                            best_cost = cost;
+                            result = Some(*part);
+                        }
+                    }
+                }
+            } else {
+                for child in self.split(&node.piece).iter() {
+                    let aabb = piece_aabb(&child.control_points[..]);
+
+                    if let Some(cost) = algorithm.compute_bv_cost(&aabb) {
+                        if cost < best_cost {
+                            queue.push(SurfacePieceWithCost::new(child.clone(), -cost))
+                        }
+                    }
+                }
+            }
+        }
+
+        result
+    }
+}
+
+/// Evaluates the Bezier surface with the control points `points` at the parameters `(u, v)`.
+fn surface_at<P: Point>(
+    points: &[P],
+    nupoints: usize,
+    nvpoints: usize,
+    u: P::Real,
+    v: P::Real,
+) -> P {
+    let mut ucache = Vec::with_capacity(nupoints);
+    let mut vcache = Vec::with_capacity(nvpoints);
+
+    for j in 0..nvpoints {
+        ucache.clear();
+        ucache.extend_from_slice(&points[j * nupoints..(j + 1) * nupoints]);
+        vcache.push(bezier_curve::de_casteljau(&mut ucache[..], u));
+    }
+
+    bezier_curve::de_casteljau(&mut vcache[..], v)
+}
+
+/// Splits each row of a grid of control points at the parameter `u`.
+fn split_u<P: Point>(
+    points: &[P],
+    nupoints: usize,
+    nvpoints: usize,
+    u: P::Real,
+) -> (Vec<P>, Vec<P>) {
+    let mut left = Vec::with_capacity(points.len());
+    let mut right = Vec::with_capacity(points.len());
+
+    for j in 0..nvpoints {
+        let row = &points[j * nupoints..(j + 1) * nupoints];
+        let (l, r) = bezier_curve::split_control_points(row, u);
+        left.extend(l);
+        right.extend(r);
+    }
+
+    (left, right)
+}
+
+/// Splits each column of a grid of control points at the parameter `v`.
+fn split_v<P: Point>(
+    points: &[P],
+    nupoints: usize,
+    nvpoints: usize,
+    v: P::Real,
+) -> (Vec<P>, Vec<P>) {
+    let mut bottom = points.to_vec();
+    let mut top = points.to_vec();
+
+    for i in 0..nupoints {
+        let column: Vec<P> = (0..nvpoints).map(|j| points[j * nupoints + i]).collect();
+        let (b, t) = bezier_curve::split_control_points(&column[..], v);
+
+        for j in 0..nvpoints {
+            bottom[j * nupoints + i] = b[j];
+            top[j * nupoints + i] = t[j];
+        }
+    }
+
+    (bottom, top)
+}
+
+/// Tests whether all the control points of a surface piece lie within `tolerance` of the bilinear
+/// interpolation of its corners, and whether this bilinear patch lies within `tolerance` of the
+/// two triangles joining its corners.
+fn is_flat<P: Point>(points: &[P], nupoints: usize, nvpoints: usize, tolerance: P::Real) -> bool {
+    let _1: P::Real = na::one();
+    let _0_25: P::Real = na::convert(0.25);
+    let sq_tolerance = tolerance * tolerance;
+    let p00 = points[0].coordinates();
+    let p10 = points[nupoints - 1].coordinates();
+    let p01 = points[(nvpoints - 1) * nupoints].coordinates();
+    let p11 = points[nvpoints * nupoints - 1].coordinates();
+
+    // Deviation between the bilinear patch and the triangles, measured at the patch center.
+    let twist = (p00 - p10 - p01 + p11) * _0_25;
+
+    if na::norm_squared(&twist) > sq_tolerance {
+        return false;
+    }
+
+    for j in 0..nvpoints {
+        let t: P::Real = na::convert(j as f64 / (nvpoints - 1) as f64);
+
+        for i in 0..nupoints {
+            let s: P::Real = na::convert(i as f64 / (nupoints - 1) as f64);
+            let bilinear = (p00 * (_1 - s) + p10 * s) * (_1 - t) + (p01 * (_1 - s) + p11 * s) * t;
+            let dev = points[j * nupoints + i].coordinates() - bilinear;
+
+            if na::norm_squared(&dev) > sq_tolerance {
+                return false;
+            }
+        }
+    }
+
+    true
+}
+
+/// The identifier of the first quad at depth `depth`, i.e., `(4^depth - 1) / 3`.
+#[inline]
+fn depth_offset(depth: usize) -> usize {
+    ((1 << (2 * depth)) - 1) / 3
+}
+
+/// Computes the depth and the indices along `u` and `v` of the quad with the identifier `id`.
+///
+/// The quads are numbered in breadth-first order, i.e., the quad `(iu, iv)` at depth `d` has the
+/// identifier `(4^d - 1) / 3 + iv * 2^d + iu`.
+#[inline]
+fn quad_depth_and_indices(id: usize) -> (usize, usize, usize) {
+    let mut depth = 0;
+
+    while depth_offset(depth + 1) <= id {
+        depth += 1;
+    }
+
+    let i = id - depth_offset(depth);
+
+    (depth, i % (1 << depth), i / (1 << depth))
+}
+
+struct SurfacePiece<P> {
+    control_points: Vec<P>,
+    depth: usize,
+    iu: usize,
+    iv: usize,
+}
+
+impl<P: Point> SurfacePiece<P> {
+    #[inline]
+    fn new(control_points: Vec<P>, depth: usize, iu: usize, iv: usize) -> SurfacePiece<P> {
+        SurfacePiece {
+            control_points: control_points,
+            depth: depth,
+            iu: iu,
+            iv: iv,
+        }
+    }
+
+    #[inline]
+    fn quad_id(&self) -> usize {
+        depth_offset(self.depth) + self.iv * (1 << self.depth) + self.iu
+    }
+}
+
+impl<P: Point> Clone for SurfacePiece<P> {
+    fn clone(&self) -> SurfacePiece<P> {
+        SurfacePiece::new(self.control_points.clone(), self.depth, self.iu, self.iv)
+    }
+}
+
+struct SurfacePieceWithCost<P: Point> {
+    piece: SurfacePiece<P>,
+    cost: P::Real,
+}
+
+impl<P: Point> SurfacePieceWithCost<P> {
+    #[inline]
+    fn new(piece: SurfacePiece<P>, cost: P::Real) -> SurfacePieceWithCost<P> {
+        SurfacePieceWithCost {
+            piece: piece,
+            cost: cost,
+        }
+    }
+}
+
+impl<P: Point> PartialEq for SurfacePieceWithCost<P> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.cost.eq(&other.cost)
+    }
+}
+
+impl<P: Point> Eq for SurfacePieceWithCost<P> {}
+
+impl<P: Point> PartialOrd for SurfacePieceWithCost<P> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.cost.partial_cmp(&other.cost)
+    }
+}
+
+impl<P: Point> Ord for SurfacePieceWithCost<P> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        if self.cost < other.cost {
+            Ordering::Less
+        } else if self.cost > other.cost {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    }
+}
@@ -5,7 +5,8 @@ use num::Signed;
 use alga::general::Real;
 use na::{self, Unit};
 
-use shape::SupportMap;
+use shape::{FeatureId, SupportMap};
+use shape::support_map::SUPPORT_FEATURE_ANGLE;
 use math::{Isometry, Point};
 
 /// SupportMap description of a capsule shape with its principal axis aligned with the `y` axis.
@@ -63,4 +64,40 @@ impl<P: Point, M: Isometry<P>> SupportMap<P, M> for Capsule<P::Real> {
 
         m.transform_point(&(P::from_coordinates(res + local_dir * self.radius())))
     }
+
+    /// The features are indexed as those of the segment joining the centers of the rounded ends.
+    fn support_feature_id_toward(&self, m: &M, dir: &Unit<P::Vector>) -> FeatureId {
+        let local_dir = m.inverse_rotate_vector(dir);
+        let sang: P::Real = na::convert(SUPPORT_FEATURE_ANGLE.sin());
+
+        if local_dir[1].abs() <= sang {
+            FeatureId::Edge(0)
+        } else if local_dir[1].is_negative() {
+            FeatureId::Vertex(0)
+        } else {
+            FeatureId::Vertex(1)
+        }
+    }
+
+    fn support_area_toward(&self, m: &M, dir: &Unit<P::Vector>, angle: P::Real, out: &mut Vec<P>) {
+        let local_dir = m.inverse_rotate_vector(dir);
+
+        // Side, when `dir` is almost orthogonal to the axis.
+        if local_dir[1].abs() <= angle.sin() {
+            let mut horizontal = local_dir;
+            horizontal[1] = na::zero();
+
+            if let Some(horizontal) = Unit::try_new(horizontal, P::Real::default_epsilon()) {
+                let mut pt = *horizontal * self.radius();
+
+                pt[1] = -self.half_height();
+                out.push(m.transform_point(&P::from_coordinates(pt)));
+                pt[1] = self.half_height();
+                out.push(m.transform_point(&P::from_coordinates(pt)));
+                return;
+            }
+        }
+
+        out.push(self.support_point_toward(m, dir))
+    }
 }
@@ -1,5 +1,7 @@
+use na::Unit;
+
 use math::Point;
-use partitioning::BVT;
+use partitioning::BVH;
 use bounding_volume::AABB;
 use shape::Shape;
 
@@ -14,9 +16,29 @@ pub trait CompositeShape<P: Point, M> {
     /// shape.
     fn map_transformed_part_at(&self, usize, m: &M, &mut FnMut(&M, &Shape<P, M>));
 
-    // FIXME: the following two methods really are not generic enough.
     /// Gets the AABB of the shape identified by the index `i`.
     fn aabb_at(&self, i: usize) -> AABB<P>;
     /// Gets the acceleration structure of the concave shape.
-    fn bvt(&self) -> &BVT<usize, AABB<P>>;
+    fn bvh(&self) -> &BVH<P::Real, usize, AABB<P>>;
+
+    /// The normal of the front face of the shape identified by the index `i` if it is one-sided.
+    ///
+    /// The normal is expressed in the local frame of this composite shape. Contacts with the
+    /// back of a one-sided part are ignored. Returns `None` by default, i.e., all the parts are
+    /// two-sided.
+    #[inline]
+    fn part_front_normal(&self, _: usize) -> Option<Unit<P::Vector>> {
+        None
+    }
+
+    /// Corrects the normal of a contact with the shape identified by the index `i`.
+    ///
+    /// The contact `point` lies on the shape `i` and the contact `normal` points outward it, both
+    /// expressed in the local frame of this composite shape. This allows contacts with edges shared
+    /// by adjacent parts, e.g., the triangles of a mesh, to be smoothed out. Returns `false` if the
+    /// contact should be discarded. Does nothing by default.
+    #[inline]
+    fn adjust_part_contact_normal(&self, _: usize, _: &P, _: &mut Unit<P::Vector>) -> bool {
+        true
+    }
 }
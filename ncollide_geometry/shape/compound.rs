@@ -7,7 +7,7 @@ use std::ops::Mul;
 use na;
 
 use bounding_volume::{BoundingVolume, AABB};
-use partitioning::BVT;
+use partitioning::{BVH, BVT};
 use shape::{CompositeShape, Shape, ShapeHandle};
 use math::{Isometry, Point};
 
@@ -107,7 +107,7 @@ where
     }
 
     #[inline]
-    fn bvt(&self) -> &BVT<usize, AABB<P>> {
+    fn bvh(&self) -> &BVH<P::Real, usize, AABB<P>> {
         self.bvt()
     }
 }
@@ -4,8 +4,10 @@ use num::{Signed, Zero};
 
 use alga::general::Real;
 use alga::linear::NormedSpace;
-use na;
-use shape::SupportMap;
+use na::{self, Unit};
+use shape::{FeatureId, SupportMap};
+use shape::support_map::SUPPORT_FEATURE_ANGLE;
+use shape::cylinder;
 use math::{Isometry, Point};
 
 /// SupportMap description of a cylinder shape with its principal axis aligned with the `y` axis.
@@ -72,4 +74,80 @@ impl<P: Point, M: Isometry<P>> SupportMap<P, M> for Cone<P::Real> {
 
         m.transform_point(&P::from_coordinates(vres))
     }
+
+    /// The features are indexed as described by `FeatureId`.
+    fn support_feature_id_toward(&self, m: &M, dir: &Unit<P::Vector>) -> FeatureId {
+        let local_dir = m.inverse_rotate_vector(dir);
+        let cang: P::Real = na::convert(SUPPORT_FEATURE_ANGLE.cos());
+
+        // Base.
+        if -local_dir[1] >= cang {
+            return FeatureId::Face(0);
+        }
+
+        let mut horizontal = local_dir;
+        horizontal[1] = na::zero();
+
+        if horizontal.normalize_mut().is_zero() {
+            return FeatureId::Vertex(0);
+        }
+
+        // Side.
+        let mut normal = horizontal * (self.half_height() + self.half_height());
+        normal[1] = self.radius();
+
+        if na::dot(&normal, &local_dir) >= cang * na::norm(&normal) {
+            return FeatureId::Face(1);
+        }
+
+        // Apex or base rim, as in `self.support_point`.
+        let mut rim = horizontal * self.radius();
+        rim[1] = -self.half_height();
+
+        if na::dot(&local_dir, &rim) < local_dir[1] * self.half_height() {
+            FeatureId::Vertex(0)
+        } else if na::dimension::<P::Vector>() == 2 && local_dir[0].is_positive() {
+            FeatureId::Vertex(2)
+        } else {
+            FeatureId::Vertex(1)
+        }
+    }
+
+    fn support_area_toward(&self, m: &M, dir: &Unit<P::Vector>, angle: P::Real, out: &mut Vec<P>) {
+        if na::dimension::<P::Vector>() > 3 {
+            out.push(self.support_point_toward(m, dir));
+            return;
+        }
+
+        let local_dir = m.inverse_rotate_vector(dir);
+        let cang = angle.cos();
+
+        // Base.
+        if -local_dir[1] >= cang {
+            cylinder::push_disk(m, self.radius(), -self.half_height(), out);
+            return;
+        }
+
+        // Side, between the apex and the base.
+        let mut horizontal = local_dir;
+        horizontal[1] = na::zero();
+
+        if !horizontal.normalize_mut().is_zero() {
+            let mut normal = horizontal * (self.half_height() + self.half_height());
+            normal[1] = self.radius();
+
+            if na::dot(&normal, &local_dir) >= cang * na::norm(&normal) {
+                let mut base = horizontal * self.radius();
+                let mut apex = na::zero::<P::Vector>();
+
+                base[1] = -self.half_height();
+                apex[1] = self.half_height();
+                out.push(m.transform_point(&P::from_coordinates(base)));
+                out.push(m.transform_point(&P::from_coordinates(apex)));
+                return;
+            }
+        }
+
+        out.push(self.support_point_toward(m, dir))
+    }
 }
@@ -1,6 +1,13 @@
+use std::cmp::Ordering;
+
+use alga::general::Real;
+use alga::linear::FiniteDimInnerSpace;
+use na::{self, Unit};
+
 use utils;
 
-use shape::SupportMap;
+use shape::{FeatureId, SupportMap};
+use shape::support_map::SUPPORT_FEATURE_ANGLE;
 use math::{Isometry, Point};
 
 #[derive(PartialEq, Debug, Clone)]
@@ -35,4 +42,105 @@ impl<P: Point, M: Isometry<P>> SupportMap<P, M> for ConvexHull<P> {
 
         m.transform_point(&best_pt)
     }
+
+    /// Only the vertices are identified: the feature is unknown if the support point is not
+    /// the only point close to the support plane orthogonal to `dir`.
+    fn support_feature_id_toward(&self, m: &M, dir: &Unit<P::Vector>) -> FeatureId {
+        let local_dir = m.inverse_rotate_vector(dir);
+        let best = utils::point_cloud_support_point_id(&local_dir, self.points());
+        let support = self.points()[best];
+        let sang: P::Real = na::convert(SUPPORT_FEATURE_ANGLE.sin());
+
+        for (i, pt) in self.points().iter().enumerate() {
+            let dpt = *pt - support;
+
+            if i != best && *pt != support && -na::dot(&dpt, &local_dir) <= sang * na::norm(&dpt) {
+                return FeatureId::Unknown;
+            }
+        }
+
+        FeatureId::Vertex(best)
+    }
+
+    fn support_area_toward(&self, m: &M, dir: &Unit<P::Vector>, angle: P::Real, out: &mut Vec<P>) {
+        let local_dir = m.inverse_rotate_vector(dir);
+        let support = utils::point_cloud_support_point(&local_dir, self.points());
+        let dim = na::dimension::<P::Vector>();
+
+        if dim > 3 {
+            out.push(m.transform_point(&support));
+            return;
+        }
+
+        let mut basis = Vec::with_capacity(2);
+        P::Vector::orthonormal_subspace_basis(&[local_dir], |b| {
+            basis.push(*b);
+            true
+        });
+
+        // The points `pt` such that `pt - support` deviates from the plane orthogonal to `dir` by
+        // at most `angle`, expressed in the basis of this plane.
+        let sang = angle.sin();
+        let mut area = Vec::new();
+
+        for (i, pt) in self.points().iter().enumerate() {
+            let dpt = *pt - support;
+
+            if -na::dot(&dpt, &local_dir) <= sang * na::norm(&dpt) {
+                let x = na::dot(&dpt, &basis[0]);
+                let y = if dim == 3 {
+                    na::dot(&dpt, &basis[1])
+                } else {
+                    na::zero()
+                };
+
+                area.push((x, y, i));
+            }
+        }
+
+        for i in convex_hull2_indices(&mut area) {
+            out.push(m.transform_point(&self.points()[i]))
+        }
+    }
+}
+
+// The indices of the vertices of the convex hull of 2D points in counterclockwise order, using
+// the monotone chain algorithm. Collinear points yield the two extremities of their segment.
+fn convex_hull2_indices<N: Real>(pts: &mut Vec<(N, N, usize)>) -> Vec<usize> {
+    pts.sort_by(|a, b| (a.0, a.1).partial_cmp(&(b.0, b.1)).unwrap_or(Ordering::Equal));
+    pts.dedup_by(|a, b| a.0 == b.0 && a.1 == b.1);
+
+    if pts.len() <= 2 {
+        return pts.iter().map(|pt| pt.2).collect();
+    }
+
+    let ccw = |a: &(N, N, usize), b: &(N, N, usize), c: &(N, N, usize)| {
+        (b.0 - a.0) * (c.1 - a.1) - (b.1 - a.1) * (c.0 - a.0) > na::zero()
+    };
+    let mut hull: Vec<(N, N, usize)> = Vec::new();
+
+    // Lower hull.
+    for pt in pts.iter() {
+        while hull.len() >= 2 && !ccw(&hull[hull.len() - 2], &hull[hull.len() - 1], pt) {
+            let _ = hull.pop();
+        }
+
+        hull.push(*pt);
+    }
+
+    // Upper hull.
+    let lower_len = hull.len() + 1;
+
+    for pt in pts.iter().rev().skip(1) {
+        while hull.len() >= lower_len && !ccw(&hull[hull.len() - 2], &hull[hull.len() - 1], pt) {
+            let _ = hull.pop();
+        }
+
+        hull.push(*pt);
+    }
+
+    // The first point is repeated at the end.
+    let _ = hull.pop();
+
+    hull.iter().map(|pt| pt.2).collect()
 }
@@ -0,0 +1,254 @@
+//! Support mapping based convex polygon with topology information.
+
+use approx::ApproxEq;
+
+use alga::general::Real;
+use na::{self, Point2, Unit, Vector2};
+
+use shape::{ConvexPolytope, FeatureId, SupportAreaFeatures, SupportMap};
+use shape::support_map::SUPPORT_FEATURE_ANGLE;
+use math::{Isometry, Point};
+
+/// A 2d convex polygon with its vertices and edges.
+///
+/// The vertices are given in counterclockwise order. The `i`-th edge joins the `i`-th and the
+/// `(i + 1)`-th vertices. Unlike `ConvexHull`, the support point of a convex polygon is found by
+/// hill-climbing along its boundary, and its edge features are available for contact manifold
+/// generation.
+#[derive(PartialEq, Debug, Clone)]
+pub struct ConvexPolygon<N: Real> {
+    points: Vec<Point2<N>>,
+    normals: Vec<Unit<Vector2<N>>>,
+}
+
+impl<N: Real> ConvexPolygon<N> {
+    /// Creates a convex polygon from the ordered vertices of its boundary, e.g., as output by
+    /// `ncollide_transformation::convex_hull2`.
+    ///
+    /// The vertices may be given in clockwise or counterclockwise order. Collinear vertices are
+    /// removed. Returns `None` if less than three non-collinear vertices remain. The convexity of
+    /// the input is not checked.
+    pub fn try_new(mut points: Vec<Point2<N>>) -> Option<ConvexPolygon<N>> {
+        let eps = N::default_epsilon().sqrt();
+
+        // Orient the boundary counterclockwise.
+        let mut area = na::zero::<N>();
+
+        for i in 0..points.len() {
+            let a = points[i];
+            let b = points[(i + 1) % points.len()];
+            area = area + a.x * b.y - b.x * a.y;
+        }
+
+        if area < na::zero() {
+            points.reverse();
+        }
+
+        // Remove the collinear vertices.
+        let mut i = 0;
+
+        while i < points.len() && points.len() >= 3 {
+            let prev = points[(i + points.len() - 1) % points.len()];
+            let next = points[(i + 1) % points.len()];
+            let e1 = points[i] - prev;
+            let e2 = next - points[i];
+            let cross = e1.x * e2.y - e1.y * e2.x;
+
+            if cross.abs() <= eps * na::norm(&e1) * na::norm(&e2) {
+                let _ = points.remove(i);
+            } else {
+                i += 1;
+            }
+        }
+
+        if points.len() < 3 {
+            return None;
+        }
+
+        let mut normals = Vec::with_capacity(points.len());
+
+        for i in 0..points.len() {
+            let dir = points[(i + 1) % points.len()] - points[i];
+            let normal = match Unit::try_new(Vector2::new(dir.y, -dir.x), N::default_epsilon()) {
+                Some(normal) => normal,
+                None => return None,
+            };
+
+            normals.push(normal);
+        }
+
+        Some(ConvexPolygon {
+            points: points,
+            normals: normals,
+        })
+    }
+
+    /// The vertices of this convex polygon, in counterclockwise order.
+    #[inline]
+    pub fn points(&self) -> &[Point2<N>] {
+        &self.points[..]
+    }
+
+    /// The outward unit normals of the edges of this convex polygon.
+    #[inline]
+    pub fn normals(&self) -> &[Unit<Vector2<N>>] {
+        &self.normals[..]
+    }
+
+    /// The indices of the two vertices of the `i`-th edge.
+    #[inline]
+    pub fn edge_vertices(&self, i: usize) -> Point2<usize> {
+        Point2::new(i, (i + 1) % self.points.len())
+    }
+
+    /// The index of the vertex furthest along the local-space direction `dir`.
+    ///
+    /// This performs a hill-climbing along the boundary of the polygon.
+    pub fn support_point_id(&self, dir: &Vector2<N>) -> usize {
+        let n = self.points.len();
+        let mut best = 0;
+        let mut best_dot = na::dot(&self.points[0].coordinates(), dir);
+
+        loop {
+            let curr = best;
+
+            for other in [(curr + 1) % n, (curr + n - 1) % n].iter() {
+                let dot = na::dot(&self.points[*other].coordinates(), dir);
+
+                if dot > best_dot {
+                    best = *other;
+                    best_dot = dot;
+                }
+            }
+
+            if best == curr {
+                return best;
+            }
+        }
+    }
+
+    /// The index of the edge with the normal the most aligned with the local-space direction
+    /// `dir`.
+    pub fn support_edge_id(&self, dir: &Vector2<N>) -> usize {
+        // The best edge is adjacent to the support point.
+        let n = self.points.len();
+        let best = self.support_point_id(dir);
+        let prev = (best + n - 1) % n;
+
+        if na::dot(self.normals[best].as_ref(), dir) >= na::dot(self.normals[prev].as_ref(), dir) {
+            best
+        } else {
+            prev
+        }
+    }
+}
+
+impl<N: Real, M: Isometry<Point2<N>>> SupportMap<Point2<N>, M> for ConvexPolygon<N> {
+    #[inline]
+    fn support_point(&self, m: &M, dir: &Vector2<N>) -> Point2<N> {
+        let local_dir = m.inverse_rotate_vector(dir);
+        let best = self.support_point_id(&local_dir);
+
+        m.transform_point(&self.points[best])
+    }
+
+    #[inline]
+    fn support_feature_id_toward(&self, m: &M, dir: &Unit<Vector2<N>>) -> FeatureId {
+        let local_dir = m.inverse_rotate_vector(dir);
+        let angle: N = na::convert(SUPPORT_FEATURE_ANGLE);
+        let edge = self.support_edge_id(&local_dir);
+
+        if na::dot(self.normals[edge].as_ref(), &local_dir) >= angle.cos() {
+            FeatureId::Face(edge)
+        } else {
+            FeatureId::Vertex(self.support_point_id(&local_dir))
+        }
+    }
+
+    fn support_area_toward(
+        &self,
+        m: &M,
+        dir: &Unit<Vector2<N>>,
+        angle: N,
+        out: &mut Vec<Point2<N>>,
+    ) {
+        let mut features = SupportAreaFeatures::new();
+        self.support_area_features_toward(m, dir, angle, out, &mut features)
+    }
+
+    fn support_area_features_toward(
+        &self,
+        m: &M,
+        dir: &Unit<Vector2<N>>,
+        angle: N,
+        out: &mut Vec<Point2<N>>,
+        features: &mut SupportAreaFeatures,
+    ) {
+        let local_dir = m.inverse_rotate_vector(dir);
+        let edge = self.support_edge_id(&local_dir);
+
+        if na::dot(self.normals[edge].as_ref(), &local_dir) >= angle.cos() {
+            let vtx = self.edge_vertices(edge);
+
+            out.push(m.transform_point(&self.points[vtx.x]));
+            out.push(m.transform_point(&self.points[vtx.y]));
+            features.vertices.push(FeatureId::Vertex(vtx.x));
+            features.vertices.push(FeatureId::Vertex(vtx.y));
+            features.area = FeatureId::Face(edge);
+        } else {
+            let best = self.support_point_id(&local_dir);
+
+            out.push(m.transform_point(&self.points[best]));
+            features.vertices.push(FeatureId::Vertex(best));
+            features.area = FeatureId::Vertex(best);
+        }
+    }
+}
+
+impl<N: Real, M: Isometry<Point2<N>>> ConvexPolytope<Point2<N>, M> for ConvexPolygon<N> {
+    #[inline]
+    fn nfaces(&self) -> usize {
+        self.points.len()
+    }
+
+    #[inline]
+    fn face_normal(&self, m: &M, i: usize) -> Unit<Vector2<N>> {
+        Unit::new_unchecked(m.rotate_vector(self.normals[i].as_ref()))
+    }
+
+    #[inline]
+    fn face(&self, m: &M, i: usize, out: &mut Vec<Point2<N>>) {
+        let vtx = self.edge_vertices(i);
+
+        out.push(m.transform_point(&self.points[vtx.x]));
+        out.push(m.transform_point(&self.points[vtx.y]));
+    }
+
+    #[inline]
+    fn face_vertex_ids(&self, i: usize, out: &mut Vec<FeatureId>) {
+        let vtx = self.edge_vertices(i);
+
+        out.push(FeatureId::Vertex(vtx.x));
+        out.push(FeatureId::Vertex(vtx.y));
+    }
+
+    #[inline]
+    fn face_edge_ids(&self, _: usize, _: &mut Vec<FeatureId>) {}
+
+    #[inline]
+    fn nedge_dirs(&self) -> usize {
+        0
+    }
+
+    #[inline]
+    fn edge_dir(&self, m: &M, i: usize) -> Unit<Vector2<N>> {
+        let normal = self.face_normal(m, i);
+
+        Unit::new_unchecked(Vector2::new(-normal.y, normal.x))
+    }
+
+    #[inline]
+    fn support_face_toward(&self, m: &M, dir: &Unit<Vector2<N>>) -> usize {
+        self.support_edge_id(&m.inverse_rotate_vector(dir))
+    }
+}
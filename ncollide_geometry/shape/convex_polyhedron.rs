@@ -0,0 +1,566 @@
+//! Support mapping based convex polyhedron with topology information.
+
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+use approx::ApproxEq;
+
+use alga::general::Real;
+use na::{self, Point2, Point3, Unit, Vector3};
+
+use utils;
+use shape::{ConvexPolytope, FeatureId, SupportAreaFeatures, SupportMap};
+use shape::support_map::SUPPORT_FEATURE_ANGLE;
+use math::{Isometry, Point};
+
+#[derive(PartialEq, Debug, Copy, Clone)]
+struct Vertex {
+    first_adj_edge: usize,
+    num_adj_edges: usize,
+}
+
+#[derive(PartialEq, Debug, Copy, Clone)]
+struct Edge<N: Real> {
+    vertices: Point2<usize>,
+    faces: Point2<usize>,
+    dir: Unit<Vector3<N>>,
+}
+
+#[derive(PartialEq, Debug, Copy, Clone)]
+struct Face<N: Real> {
+    first_vertex_or_edge: usize,
+    num_vertices_or_edges: usize,
+    normal: Unit<Vector3<N>>,
+}
+
+/// A 3d convex polyhedron with its vertices, edges, and faces.
+///
+/// Unlike `ConvexHull`, the support point of a convex polyhedron is found by hill-climbing over
+/// the vertex adjacency graph, and its face and edge features are available for contact
+/// manifold generation.
+#[derive(PartialEq, Debug, Clone)]
+pub struct ConvexPolyhedron<N: Real> {
+    points: Vec<Point3<N>>,
+    vertices: Vec<Vertex>,
+    edges: Vec<Edge<N>>,
+    faces: Vec<Face<N>>,
+    // The vertices of a face are given in counterclockwise order when seen from the outside.
+    // The k-th edge of a face joins its k-th and (k + 1)-th vertices.
+    vertices_adj_to_face: Vec<usize>,
+    edges_adj_to_face: Vec<usize>,
+    edges_adj_to_vertex: Vec<usize>,
+}
+
+impl<N: Real> ConvexPolyhedron<N> {
+    /// Creates a convex polyhedron from the triangulation of its boundary, e.g., as output by
+    /// `ncollide_transformation::convex_hull3`.
+    ///
+    /// Adjacent coplanar triangles are merged into a single polygonal face. Returns `None` if the
+    /// triangles do not form a closed manifold, or if one of them is degenerate. The convexity of
+    /// the input is not checked.
+    pub fn try_new(
+        points: Vec<Point3<N>>,
+        indices: &[Point3<usize>],
+    ) -> Option<ConvexPolyhedron<N>> {
+        let eps = N::default_epsilon().sqrt();
+        let _1: N = na::one();
+
+        if indices.len() < 4 {
+            return None;
+        }
+
+        /*
+         * Compute the outward normal of each triangle.
+         */
+        let mut center = Point3::origin();
+
+        for idx in indices.iter() {
+            for k in 0..3 {
+                center = center + points[idx[k]].coordinates();
+            }
+        }
+
+        center = center / na::convert::<f64, N>((indices.len() * 3) as f64);
+
+        let mut triangles = Vec::with_capacity(indices.len());
+        let mut normals = Vec::with_capacity(indices.len());
+
+        for idx in indices.iter() {
+            let mut idx = *idx;
+            let a = points[idx.x];
+            let normal = utils::cross3(&(points[idx.y] - a), &(points[idx.z] - a));
+            let mut normal = match Unit::try_new(normal, N::default_epsilon()) {
+                Some(n) => n,
+                None => return None,
+            };
+
+            if na::dot(normal.as_ref(), &(a - center)) < na::zero() {
+                idx = Point3::new(idx.x, idx.z, idx.y);
+                normal = -normal;
+            }
+
+            triangles.push(idx);
+            normals.push(normal);
+        }
+
+        /*
+         * Find the triangles adjacent to each edge.
+         */
+        let mut edge_tris: HashMap<(usize, usize), Vec<usize>> = HashMap::new();
+
+        for (i, idx) in triangles.iter().enumerate() {
+            for k in 0..3 {
+                edge_tris
+                    .entry(sorted_pair(idx[k], idx[(k + 1) % 3]))
+                    .or_insert_with(Vec::new)
+                    .push(i);
+            }
+        }
+
+        if edge_tris.values().any(|tris| tris.len() != 2) {
+            return None;
+        }
+
+        /*
+         * Merge adjacent coplanar triangles.
+         */
+        let mut groups: Vec<usize> = (0..triangles.len()).collect();
+
+        for tris in edge_tris.values() {
+            if na::dot(normals[tris[0]].as_ref(), normals[tris[1]].as_ref()) > _1 - eps {
+                let g1 = find_root(&mut groups, tris[0]);
+                let g2 = find_root(&mut groups, tris[1]);
+                groups[g1] = g2;
+            }
+        }
+
+        let mut face_ids = HashMap::new();
+        let mut tri_faces = Vec::with_capacity(triangles.len());
+        let mut face_normals = Vec::new();
+
+        for i in 0..triangles.len() {
+            let root = find_root(&mut groups, i);
+            let new_id = face_ids.len();
+            let face_id = *face_ids.entry(root).or_insert(new_id);
+
+            if face_id == new_id {
+                face_normals.push(normals[root]);
+            }
+
+            tri_faces.push(face_id);
+        }
+
+        /*
+         * Collect the boundary half-edges of each face and the edges of the polyhedron.
+         */
+        let mut half_edges: Vec<HashMap<usize, usize>> = vec![HashMap::new(); face_normals.len()];
+        let mut edge_ids: HashMap<(usize, usize), usize> = HashMap::new();
+        let mut edge_faces: Vec<(Point2<usize>, Point2<usize>)> = Vec::new();
+
+        for (i, idx) in triangles.iter().enumerate() {
+            for k in 0..3 {
+                let (a, b) = (idx[k], idx[(k + 1) % 3]);
+                let tris = &edge_tris[&sorted_pair(a, b)];
+                let other = if tris[0] == i { tris[1] } else { tris[0] };
+                let face = tri_faces[i];
+                let other_face = tri_faces[other];
+
+                if face == other_face {
+                    continue;
+                }
+
+                if half_edges[face].insert(a, b).is_some() {
+                    // Non-manifold face boundary.
+                    return None;
+                }
+
+                match edge_ids.entry(sorted_pair(a, b)) {
+                    Entry::Occupied(_) => {}
+                    Entry::Vacant(e) => {
+                        let _ = e.insert(edge_faces.len());
+                        edge_faces.push((Point2::new(a, b), Point2::new(face, other_face)));
+                    }
+                }
+            }
+        }
+
+        /*
+         * Keep only the points that are vertices of the polyhedron.
+         */
+        let mut vertex_ids: Vec<Option<usize>> = vec![None; points.len()];
+        let mut new_points = Vec::new();
+
+        for &(vtx, _) in edge_faces.iter() {
+            for k in 0..2 {
+                if vertex_ids[vtx[k]].is_none() {
+                    vertex_ids[vtx[k]] = Some(new_points.len());
+                    new_points.push(points[vtx[k]]);
+                }
+            }
+        }
+
+        let mut edges = Vec::with_capacity(edge_faces.len());
+
+        for &(vtx, faces) in edge_faces.iter() {
+            let vertices = Point2::new(vertex_ids[vtx.x].unwrap(), vertex_ids[vtx.y].unwrap());
+            let dir = match Unit::try_new(points[vtx.y] - points[vtx.x], N::default_epsilon()) {
+                Some(dir) => dir,
+                None => return None,
+            };
+
+            edges.push(Edge {
+                vertices: vertices,
+                faces: faces,
+                dir: dir,
+            });
+        }
+
+        /*
+         * Order the boundary of each face.
+         */
+        let mut faces = Vec::with_capacity(face_normals.len());
+        let mut vertices_adj_to_face = Vec::new();
+        let mut edges_adj_to_face = Vec::new();
+
+        for (face_half_edges, normal) in half_edges.iter().zip(face_normals.into_iter()) {
+            let first = match face_half_edges.keys().next() {
+                Some(first) => *first,
+                None => return None,
+            };
+
+            let first_vertex_or_edge = vertices_adj_to_face.len();
+            let mut curr = first;
+
+            loop {
+                let next = match face_half_edges.get(&curr) {
+                    Some(next) => *next,
+                    None => return None,
+                };
+
+                vertices_adj_to_face.push(vertex_ids[curr].unwrap());
+                edges_adj_to_face.push(edge_ids[&sorted_pair(curr, next)]);
+
+                curr = next;
+
+                if curr == first {
+                    break;
+                }
+
+                if vertices_adj_to_face.len() - first_vertex_or_edge > face_half_edges.len() {
+                    return None;
+                }
+            }
+
+            let num_vertices_or_edges = vertices_adj_to_face.len() - first_vertex_or_edge;
+
+            if num_vertices_or_edges != face_half_edges.len() {
+                // The face boundary is made of several loops.
+                return None;
+            }
+
+            faces.push(Face {
+                first_vertex_or_edge: first_vertex_or_edge,
+                num_vertices_or_edges: num_vertices_or_edges,
+                normal: normal,
+            });
+        }
+
+        /*
+         * Vertex-edge adjacency.
+         */
+        let mut adj_edges: Vec<Vec<usize>> = vec![Vec::new(); new_points.len()];
+
+        for (i, edge) in edges.iter().enumerate() {
+            adj_edges[edge.vertices.x].push(i);
+            adj_edges[edge.vertices.y].push(i);
+        }
+
+        let mut vertices = Vec::with_capacity(new_points.len());
+        let mut edges_adj_to_vertex = Vec::with_capacity(edges.len() * 2);
+
+        for adj in adj_edges.iter() {
+            vertices.push(Vertex {
+                first_adj_edge: edges_adj_to_vertex.len(),
+                num_adj_edges: adj.len(),
+            });
+            edges_adj_to_vertex.extend_from_slice(&adj[..]);
+        }
+
+        Some(ConvexPolyhedron {
+            points: new_points,
+            vertices: vertices,
+            edges: edges,
+            faces: faces,
+            vertices_adj_to_face: vertices_adj_to_face,
+            edges_adj_to_face: edges_adj_to_face,
+            edges_adj_to_vertex: edges_adj_to_vertex,
+        })
+    }
+
+    /// The vertices of this convex polyhedron.
+    #[inline]
+    pub fn points(&self) -> &[Point3<N>] {
+        &self.points[..]
+    }
+
+    /// The number of faces of this convex polyhedron.
+    #[inline]
+    pub fn num_faces(&self) -> usize {
+        self.faces.len()
+    }
+
+    /// The number of edges of this convex polyhedron.
+    #[inline]
+    pub fn num_edges(&self) -> usize {
+        self.edges.len()
+    }
+
+    /// The outward unit normal of the `i`-th face.
+    #[inline]
+    pub fn face_normal(&self, i: usize) -> &Unit<Vector3<N>> {
+        &self.faces[i].normal
+    }
+
+    /// The indices of the vertices of the `i`-th face, in counterclockwise order when seen from
+    /// the outside of the polyhedron.
+    #[inline]
+    pub fn face_vertices(&self, i: usize) -> &[usize] {
+        let face = &self.faces[i];
+        let end = face.first_vertex_or_edge + face.num_vertices_or_edges;
+
+        &self.vertices_adj_to_face[face.first_vertex_or_edge..end]
+    }
+
+    /// The indices of the edges of the `i`-th face.
+    ///
+    /// The k-th edge joins the k-th and the (k + 1)-th vertices returned by `face_vertices`.
+    #[inline]
+    pub fn face_edges(&self, i: usize) -> &[usize] {
+        let face = &self.faces[i];
+        let end = face.first_vertex_or_edge + face.num_vertices_or_edges;
+
+        &self.edges_adj_to_face[face.first_vertex_or_edge..end]
+    }
+
+    /// The indices of the two vertices of the `i`-th edge.
+    #[inline]
+    pub fn edge_vertices(&self, i: usize) -> Point2<usize> {
+        self.edges[i].vertices
+    }
+
+    /// The indices of the two faces adjacent to the `i`-th edge.
+    #[inline]
+    pub fn edge_faces(&self, i: usize) -> Point2<usize> {
+        self.edges[i].faces
+    }
+
+    /// The unit direction of the `i`-th edge, from its first vertex to its second vertex.
+    #[inline]
+    pub fn edge_dir(&self, i: usize) -> &Unit<Vector3<N>> {
+        &self.edges[i].dir
+    }
+
+    /// The indices of the edges adjacent to the `i`-th vertex.
+    #[inline]
+    pub fn vertex_edges(&self, i: usize) -> &[usize] {
+        let vertex = &self.vertices[i];
+        let end = vertex.first_adj_edge + vertex.num_adj_edges;
+
+        &self.edges_adj_to_vertex[vertex.first_adj_edge..end]
+    }
+
+    /// The index of the vertex furthest along the local-space direction `dir`.
+    ///
+    /// This performs a hill-climbing over the vertex adjacency graph.
+    pub fn support_point_id(&self, dir: &Vector3<N>) -> usize {
+        let mut best = 0;
+        let mut best_dot = na::dot(&self.points[0].coordinates(), dir);
+
+        loop {
+            let curr = best;
+
+            for edge in self.vertex_edges(curr) {
+                let vtx = self.edges[*edge].vertices;
+                let other = if vtx.x == curr { vtx.y } else { vtx.x };
+                let dot = na::dot(&self.points[other].coordinates(), dir);
+
+                if dot > best_dot {
+                    best = other;
+                    best_dot = dot;
+                }
+            }
+
+            if best == curr {
+                return best;
+            }
+        }
+    }
+
+    /// The index of the face with the normal the most aligned with the local-space direction
+    /// `dir`.
+    pub fn support_face_id(&self, dir: &Vector3<N>) -> usize {
+        let mut best = 0;
+        let mut best_dot = na::dot(self.faces[0].normal.as_ref(), dir);
+
+        for (i, face) in self.faces.iter().enumerate().skip(1) {
+            let dot = na::dot(face.normal.as_ref(), dir);
+
+            if dot > best_dot {
+                best = i;
+                best_dot = dot;
+            }
+        }
+
+        best
+    }
+}
+
+impl<N: Real, M: Isometry<Point3<N>>> SupportMap<Point3<N>, M> for ConvexPolyhedron<N> {
+    #[inline]
+    fn support_point(&self, m: &M, dir: &Vector3<N>) -> Point3<N> {
+        let local_dir = m.inverse_rotate_vector(dir);
+        let best = self.support_point_id(&local_dir);
+
+        m.transform_point(&self.points[best])
+    }
+
+    #[inline]
+    fn support_feature_id_toward(&self, m: &M, dir: &Unit<Vector3<N>>) -> FeatureId {
+        let local_dir = m.inverse_rotate_vector(dir);
+        let angle: N = na::convert(SUPPORT_FEATURE_ANGLE);
+        let face = self.support_face_id(&local_dir);
+
+        if na::dot(self.faces[face].normal.as_ref(), &local_dir) >= angle.cos() {
+            return FeatureId::Face(face);
+        }
+
+        let best = self.support_point_id(&local_dir);
+
+        for edge in self.vertex_edges(best) {
+            if na::dot(self.edges[*edge].dir.as_ref(), &local_dir).abs() <= angle.sin() {
+                return FeatureId::Edge(*edge);
+            }
+        }
+
+        FeatureId::Vertex(best)
+    }
+
+    fn support_area_toward(
+        &self,
+        m: &M,
+        dir: &Unit<Vector3<N>>,
+        angle: N,
+        out: &mut Vec<Point3<N>>,
+    ) {
+        let mut features = SupportAreaFeatures::new();
+        self.support_area_features_toward(m, dir, angle, out, &mut features)
+    }
+
+    fn support_area_features_toward(
+        &self,
+        m: &M,
+        dir: &Unit<Vector3<N>>,
+        angle: N,
+        out: &mut Vec<Point3<N>>,
+        features: &mut SupportAreaFeatures,
+    ) {
+        let local_dir = m.inverse_rotate_vector(dir);
+
+        // Face feature.
+        let face = self.support_face_id(&local_dir);
+
+        if na::dot(self.faces[face].normal.as_ref(), &local_dir) >= angle.cos() {
+            for vtx in self.face_vertices(face) {
+                out.push(m.transform_point(&self.points[*vtx]));
+            }
+
+            ConvexPolytope::<Point3<N>, M>::face_vertex_ids(self, face, &mut features.vertices);
+            ConvexPolytope::<Point3<N>, M>::face_edge_ids(self, face, &mut features.edges);
+            features.area = FeatureId::Face(face);
+            return;
+        }
+
+        // Edge feature.
+        let best = self.support_point_id(&local_dir);
+        let sang = angle.sin();
+
+        for id in self.vertex_edges(best) {
+            let edge = &self.edges[*id];
+
+            if na::dot(edge.dir.as_ref(), &local_dir).abs() <= sang {
+                out.push(m.transform_point(&self.points[edge.vertices.x]));
+                out.push(m.transform_point(&self.points[edge.vertices.y]));
+                features.vertices.push(FeatureId::Vertex(edge.vertices.x));
+                features.vertices.push(FeatureId::Vertex(edge.vertices.y));
+                features.area = FeatureId::Edge(*id);
+                return;
+            }
+        }
+
+        // Vertex feature.
+        out.push(m.transform_point(&self.points[best]));
+        features.vertices.push(FeatureId::Vertex(best));
+        features.area = FeatureId::Vertex(best);
+    }
+}
+
+impl<N: Real, M: Isometry<Point3<N>>> ConvexPolytope<Point3<N>, M> for ConvexPolyhedron<N> {
+    #[inline]
+    fn nfaces(&self) -> usize {
+        self.faces.len()
+    }
+
+    #[inline]
+    fn face_normal(&self, m: &M, i: usize) -> Unit<Vector3<N>> {
+        Unit::new_unchecked(m.rotate_vector(self.faces[i].normal.as_ref()))
+    }
+
+    #[inline]
+    fn face(&self, m: &M, i: usize, out: &mut Vec<Point3<N>>) {
+        for vtx in self.face_vertices(i) {
+            out.push(m.transform_point(&self.points[*vtx]));
+        }
+    }
+
+    #[inline]
+    fn face_vertex_ids(&self, i: usize, out: &mut Vec<FeatureId>) {
+        out.extend(self.face_vertices(i).iter().map(|vtx| FeatureId::Vertex(*vtx)));
+    }
+
+    #[inline]
+    fn face_edge_ids(&self, i: usize, out: &mut Vec<FeatureId>) {
+        out.extend(self.face_edges(i).iter().map(|edge| FeatureId::Edge(*edge)));
+    }
+
+    #[inline]
+    fn nedge_dirs(&self) -> usize {
+        self.edges.len()
+    }
+
+    #[inline]
+    fn edge_dir(&self, m: &M, i: usize) -> Unit<Vector3<N>> {
+        Unit::new_unchecked(m.rotate_vector(self.edges[i].dir.as_ref()))
+    }
+
+    #[inline]
+    fn support_face_toward(&self, m: &M, dir: &Unit<Vector3<N>>) -> usize {
+        self.support_face_id(&m.inverse_rotate_vector(dir))
+    }
+}
+
+#[inline]
+fn sorted_pair(a: usize, b: usize) -> (usize, usize) {
+    if a < b {
+        (a, b)
+    } else {
+        (b, a)
+    }
+}
+
+fn find_root(groups: &mut [usize], mut i: usize) -> usize {
+    while groups[i] != i {
+        groups[i] = groups[groups[i]];
+        i = groups[i];
+    }
+
+    i
+}
@@ -0,0 +1,59 @@
+//! Traits for convex shapes with planar faces.
+
+use na::{self, Unit};
+use shape::{FeatureId, SupportMap};
+use math::Point;
+
+/// Trait of convex shapes with planar faces, e.g., boxes, convex polygons and convex polyhedra.
+///
+/// In 2D, the faces of a polytope are its edges and it has no other edge. This is used for contact
+/// manifold generation based on the separating axis theorem.
+pub trait ConvexPolytope<P: Point, M>: SupportMap<P, M> {
+    /// The number of faces of this polytope.
+    fn nfaces(&self) -> usize;
+
+    /// The outward unit normal of the `i`-th face of this polytope transformed by `m`.
+    fn face_normal(&self, m: &M, i: usize) -> Unit<P::Vector>;
+
+    /// Pushes to `out` the vertices of the `i`-th face of this polytope transformed by `m`.
+    ///
+    /// In 3D, the vertices are given in counterclockwise order when seen from the outside.
+    fn face(&self, m: &M, i: usize, out: &mut Vec<P>);
+
+    /// Pushes to `out` the identifiers of the vertices of the `i`-th face of this polytope.
+    ///
+    /// The vertices are given in the same order as by `self.face`.
+    fn face_vertex_ids(&self, i: usize, out: &mut Vec<FeatureId>);
+
+    /// Pushes to `out` the identifiers of the edges of the `i`-th face of this polytope.
+    ///
+    /// The `k`-th edge joins the `k`-th and `(k + 1)`-th vertices of the face. Nothing is pushed in
+    /// 2D since a face is itself an edge.
+    fn face_edge_ids(&self, i: usize, out: &mut Vec<FeatureId>);
+
+    /// The number of edge directions of this polytope.
+    ///
+    /// Parallel edges may be represented by a single direction. This is zero in 2D since edges are
+    /// faces.
+    fn nedge_dirs(&self) -> usize;
+
+    /// The `i`-th unit edge direction of this polytope transformed by `m`.
+    fn edge_dir(&self, m: &M, i: usize) -> Unit<P::Vector>;
+
+    /// The index of the face with the normal the most aligned with `dir`.
+    fn support_face_toward(&self, m: &M, dir: &Unit<P::Vector>) -> usize {
+        let mut best = 0;
+        let mut best_dot = na::dot(self.face_normal(m, 0).as_ref(), dir.as_ref());
+
+        for i in 1..self.nfaces() {
+            let dot = na::dot(self.face_normal(m, i).as_ref(), dir.as_ref());
+
+            if dot > best_dot {
+                best = i;
+                best_dot = dot;
+            }
+        }
+
+        best
+    }
+}
@@ -1,9 +1,12 @@
 //! Support mapping based Cuboid shape.
 
+use std::mem;
 use num::Zero;
 
+use alga::linear::FiniteDimVectorSpace;
 use na::{self, Real, Unit};
-use shape::SupportMap;
+use shape::{ConvexPolytope, FeatureId, SupportAreaFeatures, SupportMap};
+use shape::support_map::SUPPORT_FEATURE_ANGLE;
 use math::{Isometry, Point, Vector};
 
 /// Shape of a box.
@@ -51,46 +54,263 @@ impl<P: Point, M: Isometry<P>> SupportMap<P, M> for Cuboid<P::Vector> {
         m.transform_point(&P::from_coordinates(res))
     }
 
-    #[inline]
+    /// The features are indexed as described by `FeatureId`.
+    fn support_feature_id_toward(&self, m: &M, dir: &Unit<P::Vector>) -> FeatureId {
+        let local_dir = m.inverse_rotate_vector(dir);
+        let dim = na::dimension::<P::Vector>();
+        let sang: P::Real = na::convert(SUPPORT_FEATURE_ANGLE.sin());
+        let mut best = 0;
+        let mut flat = 0;
+        let mut nflats = 0;
+
+        for i in 0..dim {
+            // The support feature is parallel to the axes almost orthogonal to `dir`.
+            if local_dir[i].abs() <= sang {
+                flat = i;
+                nflats += 1;
+            }
+
+            if local_dir[i].abs() > local_dir[best].abs() {
+                best = i;
+            }
+        }
+
+        let bit = |i: usize| if local_dir[i] > P::Real::zero() { 1 } else { 0 };
+
+        if nflats == 0 {
+            FeatureId::Vertex((0..dim).fold(0, |id, i| id | (bit(i) << i)))
+        } else if nflats == dim - 1 {
+            FeatureId::Face(best + (1 - bit(best)) * dim)
+        } else if dim == 3 && nflats == 1 {
+            FeatureId::Edge(4 * flat + bit((flat + 1) % 3) + 2 * bit((flat + 2) % 3))
+        } else {
+            FeatureId::Unknown
+        }
+    }
+
     fn support_area_toward(&self, m: &M, dir: &Unit<P::Vector>, angle: P::Real, out: &mut Vec<P>) {
+        let mut features = SupportAreaFeatures::new();
+        self.support_area_features_toward(m, dir, angle, out, &mut features)
+    }
+
+    /// The features are indexed as described by `FeatureId`.
+    fn support_area_features_toward(
+        &self,
+        m: &M,
+        dir: &Unit<P::Vector>,
+        angle: P::Real,
+        out: &mut Vec<P>,
+        features: &mut SupportAreaFeatures,
+    ) {
+        let dim = na::dimension::<P::Vector>();
+
+        if dim > 3 {
+            features.area = FeatureId::Unknown;
+            out.push(self.support_point_toward(m, dir));
+            return;
+        }
+
         let local_dir = m.inverse_rotate_vector(dir);
-        let cang = angle.cos();
+        let mut best = 0;
+        let mut worst = 0;
+
+        for i in 1..dim {
+            if local_dir[i].abs() > local_dir[best].abs() {
+                best = i;
+            }
+
+            if local_dir[i].abs() < local_dir[worst].abs() {
+                worst = i;
+            }
+        }
+
+        // Face feature.
+        if local_dir[best].abs() >= angle.cos() {
+            let face = if local_dir[best] < P::Real::zero() {
+                best + dim
+            } else {
+                best
+            };
+
+            ConvexPolytope::<P, M>::face(self, m, face, out);
+            ConvexPolytope::<P, M>::face_vertex_ids(self, face, &mut features.vertices);
+            ConvexPolytope::<P, M>::face_edge_ids(self, face, &mut features.edges);
+            features.area = FeatureId::Face(face);
+            return;
+        }
+
+        let mut support_point = *self.half_extents();
+        let mut vertex = 0;
+
+        for i in 0..dim {
+            if local_dir[i] < P::Real::zero() {
+                support_point[i] = -support_point[i];
+            } else {
+                vertex |= 1 << i;
+            }
+        }
+
+        out.push(m.transform_point(&P::from_coordinates(support_point)));
+        features.vertices.push(FeatureId::Vertex(vertex));
+
+        // Edge feature, in 3D only.
+        if dim == 3 && local_dir[worst].abs() <= angle.sin() {
+            let b1 = (vertex >> ((worst + 1) % 3)) & 1;
+            let b2 = (vertex >> ((worst + 2) % 3)) & 1;
+
+            support_point[worst] = -support_point[worst];
+            out.push(m.transform_point(&P::from_coordinates(support_point)));
+            features.vertices.push(FeatureId::Vertex(vertex ^ (1 << worst)));
+            features.area = FeatureId::Edge(4 * worst + b1 + 2 * b2);
+        } else {
+            features.area = FeatureId::Vertex(vertex);
+        }
+    }
+}
+
+/// The faces are indexed as described by `FeatureId`. Only the faces of 2D and 3D cuboids can be
+/// enumerated.
+impl<P: Point, M: Isometry<P>> ConvexPolytope<P, M> for Cuboid<P::Vector> {
+    #[inline]
+    fn nfaces(&self) -> usize {
+        2 * na::dimension::<P::Vector>()
+    }
+
+    #[inline]
+    fn face_normal(&self, m: &M, i: usize) -> Unit<P::Vector> {
         let dim = na::dimension::<P::Vector>();
-        let mut half_extents = *self.half_extents();
-        let mut support_point = half_extents;
+        let mut normal = na::zero::<P::Vector>();
+
+        if i < dim {
+            normal[i] = na::one();
+        } else {
+            normal[i - dim] = -na::one::<P::Real>();
+        }
+
+        Unit::new_unchecked(m.rotate_vector(&normal))
+    }
+
+    fn face(&self, m: &M, i: usize, out: &mut Vec<P>) {
+        let dim = na::dimension::<P::Vector>();
+        let axis = i % dim;
+        let mut vertex = *self.half_extents();
+
+        if i >= dim {
+            vertex[axis] = -vertex[axis];
+        }
 
         match dim {
             2 => {
-                for i1 in 0 .. 2 {
-                    let sign = local_dir[i1].signum();
-                    if sign * local_dir[i1] >= cang {
-                        let i2 = (i1 + 1) % 2;
-
-                        half_extents[i1] *= sign;
-                        half_extents[i2] *= -sign;
-                        let p1 = P::from_coordinates(half_extents);
-
-                        half_extents[i2] = -half_extents[i2];
-                        let p2 = P::from_coordinates(half_extents);
-
-                        out.push(m.transform_point(&p1));
-                        out.push(m.transform_point(&p2));
-                        return;
-                    }
-                    else {
-                        support_point[i1] *= sign;
-                    }
-                }
+                let other = (axis + 1) % 2;
 
-                // We are not on a face, return the support vertex.
-                out.push(m.transform_point(&P::from_coordinates(support_point)));
-            },
+                out.push(m.transform_point(&P::from_coordinates(vertex)));
+                vertex[other] = -vertex[other];
+                out.push(m.transform_point(&P::from_coordinates(vertex)));
+            }
             3 => {
-                unimplemented!()
-            },
-            _ => out.push(self.support_point_toward(m, dir))
+                // Swapping the two other axes flips the orientation of the face.
+                let (mut i1, mut i2) = ((axis + 1) % 3, (axis + 2) % 3);
+
+                if i >= dim {
+                    mem::swap(&mut i1, &mut i2);
+                }
+
+                for &(s1, s2) in [(1.0, -1.0), (1.0, 1.0), (-1.0, 1.0), (-1.0, -1.0)].iter() {
+                    let mut pt = vertex;
+                    pt[i1] *= na::convert::<f64, P::Real>(s1);
+                    pt[i2] *= na::convert::<f64, P::Real>(s2);
+                    out.push(m.transform_point(&P::from_coordinates(pt)));
+                }
+            }
+            _ => unreachable!("Only the faces of 2D and 3D cuboids can be enumerated."),
+        }
+    }
+
+    fn face_vertex_ids(&self, i: usize, out: &mut Vec<FeatureId>) {
+        let mut vertices = Vec::with_capacity(4);
+        face_vertex_bits(na::dimension::<P::Vector>(), i, &mut vertices);
+
+        out.extend(vertices.iter().map(|vtx| FeatureId::Vertex(*vtx)));
+    }
+
+    fn face_edge_ids(&self, i: usize, out: &mut Vec<FeatureId>) {
+        if na::dimension::<P::Vector>() != 3 {
+            return;
+        }
+
+        let mut vertices = Vec::with_capacity(4);
+        face_vertex_bits(3, i, &mut vertices);
+
+        for k in 0..4 {
+            let a = vertices[k];
+            let b = vertices[(k + 1) % 4];
+            // The edge is parallel to the only axis along which its vertices differ.
+            let axis = (a ^ b).trailing_zeros() as usize;
+            let b1 = (a >> ((axis + 1) % 3)) & 1;
+            let b2 = (a >> ((axis + 2) % 3)) & 1;
+
+            out.push(FeatureId::Edge(4 * axis + b1 + 2 * b2));
+        }
+    }
+
+    #[inline]
+    fn nedge_dirs(&self) -> usize {
+        if na::dimension::<P::Vector>() == 3 {
+            3
+        } else {
+            0
         }
-         
     }
 
+    #[inline]
+    fn edge_dir(&self, m: &M, i: usize) -> Unit<P::Vector> {
+        Unit::new_unchecked(m.rotate_vector(&P::Vector::canonical_basis_element(i)))
+    }
+
+    #[inline]
+    fn support_face_toward(&self, m: &M, dir: &Unit<P::Vector>) -> usize {
+        let local_dir = m.inverse_rotate_vector(dir);
+        let dim = na::dimension::<P::Vector>();
+        let mut best = 0;
+
+        for i in 1..dim {
+            if local_dir[i].abs() > local_dir[best].abs() {
+                best = i;
+            }
+        }
+
+        if local_dir[best] < P::Real::zero() {
+            best + dim
+        } else {
+            best
+        }
+    }
+}
+
+// Pushes to `out` the identifiers of the vertices of the `i`-th face of a cuboid, in the same order
+// as `ConvexPolytope::face`.
+fn face_vertex_bits(dim: usize, i: usize, out: &mut Vec<usize>) {
+    let axis = i % dim;
+    let vertex = if i < dim { 1 << axis } else { 0 };
+
+    match dim {
+        2 => {
+            let other = (axis + 1) % 2;
+
+            out.push(vertex | (1 << other));
+            out.push(vertex);
+        }
+        3 => {
+            let (mut i1, mut i2) = ((axis + 1) % 3, (axis + 2) % 3);
+
+            if i >= dim {
+                mem::swap(&mut i1, &mut i2);
+            }
+
+            for &(b1, b2) in [(1, 0), (1, 1), (0, 1), (0, 0)].iter() {
+                out.push(vertex | (b1 << i1) | (b2 << i2));
+            }
+        }
+        _ => unreachable!("Only the faces of 2D and 3D cuboids can be enumerated."),
+    }
 }
@@ -6,8 +6,9 @@ use num::{Signed, Zero};
 
 use alga::general::Real;
 use alga::linear::NormedSpace;
-use na;
-use shape::SupportMap;
+use na::{self, Unit};
+use shape::{FeatureId, SupportMap};
+use shape::support_map::SUPPORT_FEATURE_ANGLE;
 use math::{Isometry, Point};
 
 /// SupportMap description of a cylinder shape with its principal axis aligned with the `y` axis.
@@ -68,4 +69,95 @@ impl<P: Point, M: Isometry<P>> SupportMap<P, M> for Cylinder<P::Real> {
 
         m.transform_point(&P::from_coordinates(vres))
     }
+
+    /// The features are indexed as described by `FeatureId`.
+    fn support_feature_id_toward(&self, m: &M, dir: &Unit<P::Vector>) -> FeatureId {
+        let local_dir = m.inverse_rotate_vector(dir);
+        let angle: P::Real = na::convert(SUPPORT_FEATURE_ANGLE);
+        let cap = if local_dir[1].is_negative() { 0 } else { 1 };
+
+        // Cap.
+        if local_dir[1].abs() >= angle.cos() {
+            return FeatureId::Face(cap);
+        }
+
+        // Side.
+        if local_dir[1].abs() <= angle.sin() {
+            return FeatureId::Face(2);
+        }
+
+        // Rim.
+        if na::dimension::<P::Vector>() == 2 && local_dir[0].is_positive() {
+            FeatureId::Vertex(cap + 2)
+        } else {
+            FeatureId::Vertex(cap)
+        }
+    }
+
+    fn support_area_toward(&self, m: &M, dir: &Unit<P::Vector>, angle: P::Real, out: &mut Vec<P>) {
+        if na::dimension::<P::Vector>() > 3 {
+            out.push(self.support_point_toward(m, dir));
+            return;
+        }
+
+        let local_dir = m.inverse_rotate_vector(dir);
+
+        // Cap.
+        if local_dir[1].abs() >= angle.cos() {
+            let y = if local_dir[1].is_negative() {
+                -self.half_height()
+            } else {
+                self.half_height()
+            };
+
+            push_disk(m, self.radius(), y, out);
+            return;
+        }
+
+        // Side.
+        let mut horizontal = local_dir;
+        horizontal[1] = na::zero();
+
+        if local_dir[1].abs() <= angle.sin() && !horizontal.normalize_mut().is_zero() {
+            let mut pt = horizontal * self.radius();
+
+            pt[1] = -self.half_height();
+            out.push(m.transform_point(&P::from_coordinates(pt)));
+            pt[1] = self.half_height();
+            out.push(m.transform_point(&P::from_coordinates(pt)));
+        } else {
+            out.push(self.support_point_toward(m, dir))
+        }
+    }
+}
+
+// The number of vertices of the polygons approximating disks.
+const NSUBDIV: usize = 8;
+
+// Pushes the vertices of the disk of the given radius, orthogonal to the `y` axis at the height
+// `y`. In 3D, the disk is approximated by a polygon.
+pub(crate) fn push_disk<P, M>(m: &M, radius: P::Real, y: P::Real, out: &mut Vec<P>)
+where
+    P: Point,
+    M: Isometry<P>,
+{
+    let mut pt = na::zero::<P::Vector>();
+    pt[1] = y;
+
+    if na::dimension::<P::Vector>() == 2 {
+        pt[0] = radius;
+        out.push(m.transform_point(&P::from_coordinates(pt)));
+        pt[0] = -radius;
+        out.push(m.transform_point(&P::from_coordinates(pt)));
+    } else {
+        let dtheta = P::Real::two_pi() / na::convert(NSUBDIV as f64);
+
+        for i in 0..NSUBDIV {
+            let theta = dtheta * na::convert(i as f64);
+
+            pt[0] = radius * theta.cos();
+            pt[2] = radius * theta.sin();
+            out.push(m.transform_point(&P::from_coordinates(pt)));
+        }
+    }
 }
@@ -0,0 +1,200 @@
+//!
+//! Shape composed from the union of primitives that can be modified after construction.
+//!
+
+use std::ops::Mul;
+use std::slice::Iter;
+
+use na;
+
+use utils::data::hash_map::{Entry, HashMap};
+use utils::data::hash::UintTWHash;
+use bounding_volume::{BoundingVolume, AABB};
+use partitioning::{DBVTLeaf, DBVTLeafId, BVH, DBVT};
+use shape::{CompositeShape, Shape, ShapeHandle};
+use math::{Isometry, Point};
+
+/// A compound shape on which parts can be added, removed, or moved after construction.
+///
+/// Unlike `Compound`, the parts are stored on a `DBVT` so that modifying the compound does not
+/// require to rebuild its whole acceleration structure. Each part is identified by an integer
+/// returned by `DynamicCompound::add`. This identifier remains valid until the part is removed
+/// and is never reused afterward for another part.
+pub struct DynamicCompound<P: Point, M> {
+    parts: HashMap<usize, DynamicCompoundPart<P, M>, UintTWHash>,
+    dbvt: DBVT<P, usize, AABB<P>>,
+    next_id: usize,
+}
+
+struct DynamicCompoundPart<P: Point, M> {
+    delta: M,
+    shape: ShapeHandle<P, M>,
+    aabb: AABB<P>,
+    leaf: DBVTLeafId,
+}
+
+impl<P: Point, M: Isometry<P>> DynamicCompound<P, M> {
+    /// Builds a new empty dynamic compound shape.
+    pub fn new() -> DynamicCompound<P, M> {
+        DynamicCompound {
+            parts: HashMap::new(UintTWHash::new()),
+            dbvt: DBVT::new(),
+            next_id: 0,
+        }
+    }
+
+    /// Adds a new part to this compound and returns its identifier.
+    ///
+    /// The part is positioned by `delta` relative to the local frame of this compound.
+    pub fn add(&mut self, delta: M, shape: ShapeHandle<P, M>) -> usize {
+        let id = self.next_id;
+        self.next_id += 1;
+
+        // loosen for better persistancy
+        let aabb = shape.as_ref().aabb(&delta).loosened(na::convert(0.04f64));
+        let leaf = self.dbvt.insert(DBVTLeaf::new(aabb.clone(), id));
+        let part = DynamicCompoundPart {
+            delta: delta,
+            shape: shape,
+            aabb: aabb,
+            leaf: leaf,
+        };
+        let _ = self.parts.insert(id, part);
+
+        id
+    }
+
+    /// Removes the part identified by `id` from this compound.
+    ///
+    /// Returns the position and shape of the removed part, or `None` if it does not exist.
+    pub fn remove(&mut self, id: usize) -> Option<(M, ShapeHandle<P, M>)> {
+        match self.parts.get_and_remove(&id) {
+            Some(entry) => {
+                let part = entry.value;
+                let _ = self.dbvt.remove(part.leaf);
+
+                Some((part.delta, part.shape))
+            }
+            None => None,
+        }
+    }
+
+    /// Sets the position of the part identified by `id` relative to the local frame of this
+    /// compound.
+    ///
+    /// Panics if the part does not exist.
+    pub fn set_part_position(&mut self, id: usize, delta: M) {
+        let part = self.parts
+            .find_mut(&id)
+            .expect("Set part position: part not found.");
+        let _ = self.dbvt.remove(part.leaf);
+
+        part.aabb = part.shape
+            .as_ref()
+            .aabb(&delta)
+            .loosened(na::convert(0.04f64));
+        part.leaf = self.dbvt.insert(DBVTLeaf::new(part.aabb.clone(), id));
+        part.delta = delta;
+    }
+}
+
+impl<P: Point, M> DynamicCompound<P, M> {
+    /// The number of parts of this compound shape.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.parts.len()
+    }
+
+    /// Whether this compound shape has no parts.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.parts.is_empty()
+    }
+
+    /// Whether the part identified by `id` exists on this compound shape.
+    #[inline]
+    pub fn contains_part(&self, id: usize) -> bool {
+        self.parts.contains_key(&id)
+    }
+
+    /// An iterator through the identifier, position, and shape of each part of this compound.
+    #[inline]
+    pub fn parts(&self) -> DynamicCompoundParts<P, M> {
+        DynamicCompoundParts {
+            iter: self.parts.elements().iter(),
+        }
+    }
+
+    /// The position and shape of the part identified by `id`.
+    #[inline]
+    pub fn part(&self, id: usize) -> Option<(&M, &ShapeHandle<P, M>)> {
+        self.parts.find(&id).map(|part| (&part.delta, &part.shape))
+    }
+
+    /// The optimization structure used by this compound shape.
+    #[inline]
+    pub fn dbvt(&self) -> &DBVT<P, usize, AABB<P>> {
+        &self.dbvt
+    }
+
+    /// The AABB of the part identified by `id`.
+    ///
+    /// Panics if the part does not exist.
+    #[inline]
+    pub fn aabb_at(&self, id: usize) -> &AABB<P> {
+        &self.get(id).aabb
+    }
+
+    #[inline]
+    fn get(&self, id: usize) -> &DynamicCompoundPart<P, M> {
+        self.parts
+            .find(&id)
+            .expect("Dynamic compound: part not found.")
+    }
+}
+
+/// An iterator through the parts of a `DynamicCompound`.
+pub struct DynamicCompoundParts<'a, P: 'a + Point, M: 'a> {
+    iter: Iter<'a, Entry<usize, DynamicCompoundPart<P, M>>>,
+}
+
+impl<'a, P: Point, M> Iterator for DynamicCompoundParts<'a, P, M> {
+    type Item = (usize, &'a M, &'a ShapeHandle<P, M>);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next()
+            .map(|entry| (entry.key, &entry.value.delta, &entry.value.shape))
+    }
+}
+
+impl<P, M> CompositeShape<P, M> for DynamicCompound<P, M>
+where
+    P: Point,
+    M: Clone + Mul<M, Output = M>,
+{
+    #[inline(always)]
+    fn map_part_at(&self, i: usize, f: &mut FnMut(&M, &Shape<P, M>)) {
+        let part = self.get(i);
+
+        f(&part.delta, part.shape.as_ref())
+    }
+
+    #[inline(always)]
+    fn map_transformed_part_at(&self, i: usize, m: &M, f: &mut FnMut(&M, &Shape<P, M>)) {
+        let part = self.get(i);
+
+        f(&(m.clone() * part.delta.clone()), part.shape.as_ref())
+    }
+
+    #[inline]
+    fn aabb_at(&self, i: usize) -> AABB<P> {
+        self.get(i).aabb.clone()
+    }
+
+    #[inline]
+    fn bvh(&self) -> &BVH<P::Real, usize, AABB<P>> {
+        &self.dbvt
+    }
+}
@@ -0,0 +1,85 @@
+//! Support mapping based Ellipsoid shape.
+
+use num::Zero;
+
+use na::{self, Unit};
+use shape::{FeatureId, SupportMap};
+use math::{Isometry, Point, Vector};
+
+/// An ellipsoid centered at the origin with its principal axes aligned with the coordinate axes.
+#[derive(PartialEq, Debug, Clone)]
+pub struct Ellipsoid<V> {
+    radii: V,
+}
+
+impl<V: Vector> Ellipsoid<V> {
+    /// Creates a new ellipsoid from its radii along each axis. Each radius must be strictly
+    /// positive.
+    #[inline]
+    pub fn new(radii: V) -> Ellipsoid<V> {
+        for i in 0..na::dimension::<V>() {
+            assert!(
+                radii[i] > V::Real::zero(),
+                "The radii of an ellipsoid must be strictly positive."
+            );
+        }
+
+        Ellipsoid { radii: radii }
+    }
+
+    /// The outward normal of this ellipsoid at the point `pt` of its local frame.
+    ///
+    /// The point is assumed to lie on the ellipsoid surface.
+    #[inline]
+    pub fn normal_at(&self, pt: &V) -> V {
+        let mut res = *pt;
+
+        for i in 0..na::dimension::<V>() {
+            res[i] = res[i] / (self.radii[i] * self.radii[i]);
+        }
+
+        na::normalize(&res)
+    }
+}
+
+impl<V> Ellipsoid<V> {
+    /// The radii of this ellipsoid along each axis.
+    #[inline]
+    pub fn radii(&self) -> &V {
+        &self.radii
+    }
+}
+
+impl<P: Point, M: Isometry<P>> SupportMap<P, M> for Ellipsoid<P::Vector> {
+    #[inline]
+    fn support_point(&self, m: &M, dir: &P::Vector) -> P {
+        // The support point is `R² d / |R d|` where `R` is the diagonal matrix of the radii.
+        let local_dir = m.inverse_rotate_vector(dir);
+        let mut scaled_dir = local_dir;
+
+        for i in 0..na::dimension::<P::Vector>() {
+            scaled_dir[i] = scaled_dir[i] * self.radii[i];
+        }
+
+        let norm = na::norm(&scaled_dir);
+
+        if norm.is_zero() {
+            return m.translate_point(&P::origin());
+        }
+
+        let mut res = scaled_dir;
+
+        for i in 0..na::dimension::<P::Vector>() {
+            res[i] = res[i] * self.radii[i] / norm;
+        }
+
+        m.transform_point(&P::from_coordinates(res))
+    }
+
+    /// An ellipsoid touches other shapes at a single point so its whole surface is its vertex 0.
+    #[inline]
+    fn support_feature_id_toward(&self, _: &M, _: &Unit<P::Vector>) -> FeatureId {
+        FeatureId::Vertex(0)
+    }
+}
+
@@ -0,0 +1,44 @@
+//! Identifiers of the geometric features of a shape.
+
+/// An identifier of a feature of a shape, i.e., one of its vertices, edges, or faces.
+///
+/// The meaning of the contained index depends on the shape:
+///
+/// * The faces of a `Cuboid` are indexed by their normal: the face with the normal `+e_i` has the
+///   index `i` while the face with the normal `-e_i` has the index `i + d` where `d` is the
+///   dimension. The `k`-th bit of the index of a vertex is set if its `k`-th coordinate is
+///   positive. The edges of a 3D cuboid parallel to `e_i` have the indices `4 * i + b1 + 2 * b2`
+///   where `b1` (resp. `b2`) is 1 if their `(i + 1) % 3`-th (resp. `(i + 2) % 3`-th) coordinate is
+///   positive, and 0 otherwise.
+/// * The faces of a `ConvexPolyhedron`, or the edges of a `ConvexPolygon`, are indexed as their
+///   normals, i.e., as by their `ConvexPolytope` implementation. Their vertices are indexed as
+///   their points, and the edges of a `ConvexPolyhedron` as by `ConvexPolyhedron::face_edges`.
+/// * The front and back faces of a 3D `Triangle` have the indices 0 and 1. The vertices `a`, `b`,
+///   and `c` of a `Triangle` have the indices 0, 1, and 2, and its `k`-th edge, i.e., its `k`-th
+///   face in 2D, joins its `k`-th and `(k + 1) % 3`-th vertices.
+/// * The vertices `a` and `b` of a `Segment` have the indices 0 and 1, and the segment itself is
+///   its edge 0.
+/// * A `Ball` or an `Ellipsoid` touches other shapes at a single point, so its whole surface is
+///   its vertex 0 and its contacts are identified by the feature of the other shape.
+/// * The vertices of a `ConvexHull` are indexed as its points. Its edges and faces are unknown.
+/// * The rounded ends of a `Capsule` at negative and positive `y` are its vertices 0 and 1, and
+///   its side is its edge 0, as for the segment joining their centers.
+/// * The bottom and top caps of a `Cylinder` are its faces 0 and 1, and its side is its face 2.
+///   The points of its bottom and top rims are its vertices 0 and 1 in 3D. In 2D, the corners
+///   at positive `x` are its vertices 2 and 3 instead.
+/// * The base of a `Cone` is its face 0, and its side is its face 1. Its apex is its vertex 0 and
+///   the points of the rim of its base are its vertex 1 in 3D. In 2D, the corner at positive `x`
+///   is its vertex 2 instead.
+/// * The faces of a `TriMesh`, or of a 2D `Polyline`, are its triangles, or segments, indexed as on
+///   the mesh. The segments of a 3D `Polyline` are its edges.
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum FeatureId {
+    /// Shape-dependent identifier of a vertex.
+    Vertex(usize),
+    /// Shape-dependent identifier of an edge.
+    Edge(usize),
+    /// Shape-dependent identifier of a face.
+    Face(usize),
+    /// The feature is unknown.
+    Unknown,
+}
@@ -0,0 +1,512 @@
+//! 2d height line and 3d height grid.
+
+use std::collections::BinaryHeap;
+use std::cmp::Ordering;
+
+use alga::general::Real;
+use na::{self, DMatrix};
+
+use bounding_volume::AABB;
+use partitioning::{BVTCostFn, BVTVisitor, BVH};
+use shape::{CompositeShape, Segment, Shape, Triangle};
+use math::{Isometry, Point};
+
+/// A 2d height line or a 3d height grid.
+///
+/// The heights are sampled on a regular grid centered at the origin. The grid spans `scale[0]`
+/// along the `x` axis and, in 3d, `scale[2]` along the `z` axis. Each height is multiplied by
+/// `scale[1]`.
+///
+/// In 3d, the rows of the height matrix are laid out along the `z` axis and its columns along
+/// the `x` axis. Each grid cell is split into two triangles. In 2d, the height matrix must have
+/// exactly one row and each cell is a segment.
+#[derive(Clone)]
+pub struct HeightField<P: Point> {
+    heights: DMatrix<P::Real>,
+    scale: P::Vector,
+    aabb: AABB<P>,
+}
+
+impl<P: Point> HeightField<P> {
+    /// Creates a new height field with the given heights and scale factors.
+    pub fn new(heights: DMatrix<P::Real>, scale: P::Vector) -> HeightField<P> {
+        if Self::is_3d() {
+            assert!(
+                heights.nrows() > 1 && heights.ncols() > 1,
+                "A 3d height field must have at least two rows and two columns."
+            );
+        } else {
+            assert!(
+                heights.nrows() == 1 && heights.ncols() > 1,
+                "A 2d height field must have exactly one row and at least two columns."
+            );
+        }
+
+        let mut min = heights[(0, 0)];
+        let mut max = heights[(0, 0)];
+
+        for h in heights.iter() {
+            if *h < min {
+                min = *h;
+            } else if *h > max {
+                max = *h;
+            }
+        }
+
+        let _0_5: P::Real = na::convert(0.5f64);
+        let half_scale = scale * _0_5;
+        let mut mins = P::from_coordinates(-half_scale);
+        let mut maxs = P::from_coordinates(half_scale);
+
+        if scale[1] >= na::zero() {
+            mins[1] = min * scale[1];
+            maxs[1] = max * scale[1];
+        } else {
+            mins[1] = max * scale[1];
+            maxs[1] = min * scale[1];
+        }
+
+        HeightField {
+            heights: heights,
+            scale: scale,
+            aabb: AABB::new(mins, maxs),
+        }
+    }
+
+    #[inline]
+    fn is_3d() -> bool {
+        na::dimension::<P::Vector>() > 2
+    }
+
+    /// The height samples of this height field.
+    #[inline]
+    pub fn heights(&self) -> &DMatrix<P::Real> {
+        &self.heights
+    }
+
+    /// The scale factors applied to this height field.
+    #[inline]
+    pub fn scale(&self) -> &P::Vector {
+        &self.scale
+    }
+
+    /// The local-space AABB of this height field.
+    #[inline]
+    pub fn local_aabb(&self) -> &AABB<P> {
+        &self.aabb
+    }
+
+    /// The number of cells along the `x` axis.
+    #[inline]
+    pub fn ncells_x(&self) -> usize {
+        self.heights.ncols() - 1
+    }
+
+    /// The number of cells along the `z` axis.
+    ///
+    /// This is always 1 for a 2d height field.
+    #[inline]
+    pub fn ncells_z(&self) -> usize {
+        if Self::is_3d() {
+            self.heights.nrows() - 1
+        } else {
+            1
+        }
+    }
+
+    /// The number of triangles (in 3d) or segments (in 2d) of this height field.
+    #[inline]
+    pub fn num_parts(&self) -> usize {
+        if Self::is_3d() {
+            self.ncells_x() * self.ncells_z() * 2
+        } else {
+            self.ncells_x()
+        }
+    }
+
+    /// The width of a single cell along the `x` axis.
+    #[inline]
+    pub fn cell_width(&self) -> P::Real {
+        self.scale[0] / na::convert(self.ncells_x() as f64)
+    }
+
+    /// The depth of a single cell along the `z` axis.
+    ///
+    /// This is zero for a 2d height field.
+    #[inline]
+    pub fn cell_depth(&self) -> P::Real {
+        if Self::is_3d() {
+            self.scale[2] / na::convert(self.ncells_z() as f64)
+        } else {
+            na::zero()
+        }
+    }
+
+    /// The local-space position of the height sample at the row `i` and column `j`.
+    pub fn point_at(&self, i: usize, j: usize) -> P {
+        let _0_5: P::Real = na::convert(0.5f64);
+        let mut res = P::origin();
+
+        res[0] = -self.scale[0] * _0_5 + self.cell_width() * na::convert(j as f64);
+        res[1] = self.heights[(i, j)] * self.scale[1];
+
+        if Self::is_3d() {
+            res[2] = -self.scale[2] * _0_5 + self.cell_depth() * na::convert(i as f64);
+        }
+
+        res
+    }
+
+    /// The row and column of the cell containing the projection of `pt` on the `xz` plane.
+    ///
+    /// Returns `None` if this projection is outside of the height field footprint.
+    pub fn cell_at_point(&self, pt: &P) -> Option<(usize, usize)> {
+        let _0_5: P::Real = na::convert(0.5f64);
+
+        let j = match Self::cell_coordinate(pt[0], self.scale[0] * _0_5, self.ncells_x()) {
+            Some(j) => j,
+            None => return None,
+        };
+
+        if Self::is_3d() {
+            Self::cell_coordinate(pt[2], self.scale[2] * _0_5, self.ncells_z()).map(|i| (i, j))
+        } else {
+            Some((0, j))
+        }
+    }
+
+    fn cell_coordinate(x: P::Real, half_extent: P::Real, ncells: usize) -> Option<usize> {
+        if x < -half_extent || x > half_extent {
+            return None;
+        }
+
+        let rel: P::Real = (x + half_extent) / (half_extent + half_extent);
+        let id: f64 = na::convert_unchecked((rel * na::convert(ncells as f64)).floor());
+
+        Some(na::clamp(id as usize, 0, ncells - 1))
+    }
+
+    /// The height of the surface of this height field above the projection of `pt` on the `xz`
+    /// plane.
+    ///
+    /// Returns `None` if this projection is outside of the height field footprint.
+    pub fn height_at_point(&self, pt: &P) -> Option<P::Real> {
+        let (i, j) = match self.cell_at_point(pt) {
+            Some(cell) => cell,
+            None => return None,
+        };
+
+        let p00 = self.point_at(i, j);
+        let p01 = self.point_at(i, j + 1);
+        let u = (pt[0] - p00[0]) / self.cell_width();
+
+        if !Self::is_3d() {
+            return Some(p00[1] + (p01[1] - p00[1]) * u);
+        }
+
+        let p10 = self.point_at(i + 1, j);
+        let p11 = self.point_at(i + 1, j + 1);
+        let v = (pt[2] - p00[2]) / self.cell_depth();
+
+        if v >= u {
+            // First triangle of the cell.
+            Some(p00[1] + (p10[1] - p00[1]) * v + (p11[1] - p10[1]) * u)
+        } else {
+            // Second triangle of the cell.
+            Some(p00[1] + (p01[1] - p00[1]) * u + (p11[1] - p01[1]) * v)
+        }
+    }
+
+    /// The identifiers of the parts contained by the cell at the row `i` and column `j`.
+    ///
+    /// In 2d, the second identifier is always `None`.
+    #[inline]
+    pub fn parts_at_cell(&self, i: usize, j: usize) -> (usize, Option<usize>) {
+        if Self::is_3d() {
+            let cell = i * self.ncells_x() + j;
+            (cell * 2, Some(cell * 2 + 1))
+        } else {
+            (j, None)
+        }
+    }
+
+    /// The row and column of the cell containing the part with identifier `id`.
+    #[inline]
+    pub fn cell_of_part(&self, id: usize) -> (usize, usize) {
+        if Self::is_3d() {
+            let cell = id / 2;
+            (cell / self.ncells_x(), cell % self.ncells_x())
+        } else {
+            (0, id)
+        }
+    }
+
+    /// The `id`-th triangle of this 3d height field.
+    ///
+    /// The cell at the row `i` and column `j` contains the triangles `2 * (i * ncells_x + j)` and
+    /// `2 * (i * ncells_x + j) + 1`.
+    pub fn triangle_at(&self, id: usize) -> Triangle<P> {
+        assert!(Self::is_3d(), "Only 3d height fields are made of triangles.");
+
+        let (i, j) = self.cell_of_part(id);
+        let p00 = self.point_at(i, j);
+        let p11 = self.point_at(i + 1, j + 1);
+
+        if id % 2 == 0 {
+            Triangle::new(p00, self.point_at(i + 1, j), p11)
+        } else {
+            Triangle::new(p00, p11, self.point_at(i, j + 1))
+        }
+    }
+
+    /// The `id`-th segment of this 2d height field.
+    pub fn segment_at(&self, id: usize) -> Segment<P> {
+        assert!(!Self::is_3d(), "Only 2d height fields are made of segments.");
+
+        Segment::new(self.point_at(0, id), self.point_at(0, id + 1))
+    }
+
+    /// The local-space AABB of the cell at the row `i` and column `j`.
+    pub fn cell_aabb(&self, i: usize, j: usize) -> AABB<P> {
+        let mut mins = self.point_at(i, j);
+        let mut maxs = mins;
+        let mut corners = vec![self.point_at(i, j + 1)];
+
+        if Self::is_3d() {
+            corners.push(self.point_at(i + 1, j));
+            corners.push(self.point_at(i + 1, j + 1));
+        }
+
+        for pt in corners.iter() {
+            mins = na::inf(&mins, pt);
+            maxs = na::sup(&maxs, pt);
+        }
+
+        AABB::new(mins, maxs)
+    }
+
+    /// The local-space AABB of a range of cells.
+    ///
+    /// Its extents along the `y` axis are those of the whole height field unless the range
+    /// contains a single cell.
+    fn range_aabb(&self, range: &CellRange) -> AABB<P> {
+        if range.is_single_cell() {
+            return self.cell_aabb(range.i0, range.j0);
+        }
+
+        let mut mins = *self.aabb.mins();
+        let mut maxs = *self.aabb.maxs();
+
+        mins[0] = mins[0] + self.cell_width() * na::convert(range.j0 as f64);
+        maxs[0] = mins[0] + self.cell_width() * na::convert((range.j1 - range.j0) as f64);
+
+        if Self::is_3d() {
+            mins[2] = mins[2] + self.cell_depth() * na::convert(range.i0 as f64);
+            maxs[2] = mins[2] + self.cell_depth() * na::convert((range.i1 - range.i0) as f64);
+        }
+
+        AABB::new(mins, maxs)
+    }
+
+    fn visit_range(&self, range: &CellRange, visitor: &mut BVTVisitor<usize, AABB<P>>) {
+        if range.is_single_cell() {
+            let (part1, part2) = self.parts_at_cell(range.i0, range.j0);
+
+            visitor.visit_leaf(&part1, &self.part_aabb(part1));
+
+            if let Some(part2) = part2 {
+                visitor.visit_leaf(&part2, &self.part_aabb(part2));
+            }
+        } else if visitor.visit_internal(&self.range_aabb(range)) {
+            let (left, right) = range.split();
+
+            self.visit_range(&left, visitor);
+            self.visit_range(&right, visitor);
+        }
+    }
+
+    fn part_aabb(&self, id: usize) -> AABB<P> {
+        if Self::is_3d() {
+            let tri = self.triangle_at(id);
+            let mins = na::inf(&na::inf(tri.a(), tri.b()), tri.c());
+            let maxs = na::sup(&na::sup(tri.a(), tri.b()), tri.c());
+
+            AABB::new(mins, maxs)
+        } else {
+            let seg = self.segment_at(id);
+
+            AABB::new(na::inf(seg.a(), seg.b()), na::sup(seg.a(), seg.b()))
+        }
+    }
+
+    #[inline]
+    fn root_range(&self) -> CellRange {
+        CellRange {
+            i0: 0,
+            i1: self.ncells_z(),
+            j0: 0,
+            j1: self.ncells_x(),
+        }
+    }
+}
+
+impl<P: Point, M: Isometry<P>> CompositeShape<P, M> for HeightField<P> {
+    #[inline(always)]
+    fn map_part_at(&self, i: usize, f: &mut FnMut(&M, &Shape<P, M>)) {
+        let one: M = na::one();
+
+        self.map_transformed_part_at(i, &one, f)
+    }
+
+    #[inline(always)]
+    fn map_transformed_part_at(&self, i: usize, m: &M, f: &mut FnMut(&M, &Shape<P, M>)) {
+        if Self::is_3d() {
+            f(m, &self.triangle_at(i))
+        } else {
+            f(m, &self.segment_at(i))
+        }
+    }
+
+    #[inline]
+    fn aabb_at(&self, i: usize) -> AABB<P> {
+        self.part_aabb(i)
+    }
+
+    #[inline]
+    fn bvh(&self) -> &BVH<P::Real, usize, AABB<P>> {
+        self
+    }
+}
+
+/// The height field is its own implicit bounding volume hierarchy: each internal node is a
+/// rectangular range of cells, recursively split in half along its largest dimension.
+impl<P: Point> BVH<P::Real, usize, AABB<P>> for HeightField<P> {
+    fn visit(&self, visitor: &mut BVTVisitor<usize, AABB<P>>) {
+        self.visit_range(&self.root_range(), visitor)
+    }
+
+    fn best_first_search(
+        &self,
+        algorithm: &mut BVTCostFn<P::Real, usize, AABB<P>, UserData = ()>,
+    ) -> Option<usize> {
+        let mut queue: BinaryHeap<CellRangeWithCost<P::Real>> = BinaryHeap::new();
+        let mut best_cost = P::Real::max_value();
+        let mut result = None;
+        let root = self.root_range();
+
+        match algorithm.compute_bv_cost(&self.range_aabb(&root)) {
+            Some(cost) => queue.push(CellRangeWithCost::new(root, -cost)),
+            None => return None,
+        }
+
+        while let Some(node) = queue.pop() {
+            if -node.cost >= best_cost {
+                break; // solution found.
+            }
+
+            if node.range.is_single_cell() {
+                let (part1, part2) = self.parts_at_cell(node.range.i0, node.range.j0);
+
+                for part in Some(part1).iter().chain(part2.iter()) {
+                    if let Some((cost, _)) = algorithm.compute_b_cost(part) {
+                        if cost < best_cost {
+                            best_cost = cost;
+                            result = Some(*part);
+                        }
+                    }
+                }
+            } else {
+                let (left, right) = node.range.split();
+
+                for child in [left, right].iter() {
+                    if let Some(cost) = algorithm.compute_bv_cost(&self.range_aabb(child)) {
+                        if cost < best_cost {
+                            queue.push(CellRangeWithCost::new(*child, -cost))
+                        }
+                    }
+                }
+            }
+        }
+
+        result
+    }
+}
+
+/// A rectangular range of cells: rows `i0..i1` and columns `j0..j1`.
+#[derive(Copy, Clone)]
+struct CellRange {
+    i0: usize,
+    i1: usize,
+    j0: usize,
+    j1: usize,
+}
+
+impl CellRange {
+    #[inline]
+    fn is_single_cell(&self) -> bool {
+        self.i1 - self.i0 == 1 && self.j1 - self.j0 == 1
+    }
+
+    /// Splits this range in half along its largest dimension.
+    #[inline]
+    fn split(&self) -> (CellRange, CellRange) {
+        let mut left = *self;
+        let mut right = *self;
+
+        if self.i1 - self.i0 > self.j1 - self.j0 {
+            let mid = (self.i0 + self.i1) / 2;
+            left.i1 = mid;
+            right.i0 = mid;
+        } else {
+            let mid = (self.j0 + self.j1) / 2;
+            left.j1 = mid;
+            right.j0 = mid;
+        }
+
+        (left, right)
+    }
+}
+
+struct CellRangeWithCost<N> {
+    range: CellRange,
+    cost: N,
+}
+
+impl<N> CellRangeWithCost<N> {
+    #[inline]
+    fn new(range: CellRange, cost: N) -> CellRangeWithCost<N> {
+        CellRangeWithCost {
+            range: range,
+            cost: cost,
+        }
+    }
+}
+
+impl<N: Real> PartialEq for CellRangeWithCost<N> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.cost.eq(&other.cost)
+    }
+}
+
+impl<N: Real> Eq for CellRangeWithCost<N> {}
+
+impl<N: Real> PartialOrd for CellRangeWithCost<N> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.cost.partial_cmp(&other.cost)
+    }
+}
+
+impl<N: Real> Ord for CellRangeWithCost<N> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        if self.cost < other.cost {
+            Ordering::Less
+        } else if self.cost > other.cost {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    }
+}
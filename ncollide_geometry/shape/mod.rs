@@ -3,25 +3,39 @@
 pub use self::ball::Ball;
 pub use self::plane::Plane;
 pub use self::cuboid::Cuboid;
+pub use self::ellipsoid::Ellipsoid;
 pub use self::capsule::Capsule;
 pub use self::cone::Cone;
 pub use self::cylinder::Cylinder;
 pub use self::convex::ConvexHull;
+pub use self::convex_polyhedron::ConvexPolyhedron;
+pub use self::convex_polygon::ConvexPolygon;
 pub use self::minkowski_sum::{cso_support_point, AnnotatedCSO, AnnotatedMinkowskiSum,
                               AnnotatedPoint, MinkowskiSum, CSO};
 pub use self::reflection::Reflection;
 pub use self::compound::Compound;
-pub use self::base_mesh::{BaseMesh, BaseMeshElement};
+pub use self::dynamic_compound::{DynamicCompound, DynamicCompoundParts};
+pub use self::base_mesh::{BaseMesh, BaseMeshElement, EdgeConvexity};
 pub use self::trimesh::TriMesh;
 pub use self::polyline::Polyline;
+pub use self::heightfield::HeightField;
+pub use self::voxels::Voxels;
+pub use self::feature_id::FeatureId;
+pub use self::bezier_curve::BezierCurve;
+pub use self::bezier_surface::BezierSurface;
 pub use self::segment::Segment;
 pub use self::triangle::Triangle;
 pub use self::tetrahedron::Tetrahedron;
 pub use self::torus::Torus;
+pub use self::scaled::Scaled;
+pub(crate) use self::scaled::ScaledPart;
+pub use self::round_shape::RoundShape;
 #[doc(inline)]
 pub use self::composite_shape::CompositeShape;
 #[doc(inline)]
-pub use self::support_map::SupportMap;
+pub use self::support_map::{SupportAreaFeatures, SupportMap};
+#[doc(inline)]
+pub use self::convex_polytope::ConvexPolytope;
 #[doc(inline)]
 pub use self::shape::{Shape, ShapeHandle};
 
@@ -32,9 +46,12 @@ pub mod composite_shape;
 #[doc(hidden)]
 pub mod support_map;
 #[doc(hidden)]
+pub mod convex_polytope;
+#[doc(hidden)]
 pub mod shape;
 mod plane;
 mod cuboid;
+mod ellipsoid;
 mod minkowski_sum;
 mod segment;
 mod triangle;
@@ -42,14 +59,24 @@ mod tetrahedron;
 mod base_mesh;
 mod trimesh;
 mod polyline;
+mod heightfield;
+mod voxels;
+mod feature_id;
+mod bezier_curve;
+mod bezier_surface;
 mod ball;
 mod capsule;
 mod cone;
 mod cylinder;
 mod reflection;
 mod torus;
+mod scaled;
+mod round_shape;
 mod compound;
+mod dynamic_compound;
 mod convex;
+mod convex_polyhedron;
+mod convex_polygon;
 mod shape_impl;
 
 /*
@@ -63,6 +90,8 @@ pub type Ball2<N> = Ball<N>;
 pub type Plane2<N> = Plane<Vector2<N>>;
 #[doc = "A 2D cuboid."]
 pub type Cuboid2<N> = Cuboid<Vector2<N>>;
+#[doc = "A 2D ellipse."]
+pub type Ellipsoid2<N> = Ellipsoid<Vector2<N>>;
 #[doc = "A 2D capsule."]
 pub type Capsule2<N> = Capsule<N>;
 #[doc = "A 2D cone."]
@@ -77,16 +106,28 @@ pub type Segment2<N> = Segment<Point2<N>>;
 pub type Triangle2<N> = Triangle<Point2<N>>;
 #[doc = "A 2D polyline."]
 pub type Polyline2<N> = Polyline<Point2<N>>;
+#[doc = "A 2D height line."]
+pub type HeightField2<N> = HeightField<Point2<N>>;
+#[doc = "A 2D voxel grid."]
+pub type Voxels2<N> = Voxels<Point2<N>>;
+#[doc = "A 2D Bezier curve."]
+pub type BezierCurve2<N> = BezierCurve<Point2<N>>;
 #[doc = "A 2D compound shape."]
 pub type Compound2<N> = Compound<Point2<N>, Isometry2<N>>;
+#[doc = "A 2D dynamic compound shape."]
+pub type DynamicCompound2<N> = DynamicCompound<Point2<N>, Isometry2<N>>;
 #[doc = "A 2D abstract composite shape."]
 pub type CompositeShape2<N> = CompositeShape<Point2<N>, Isometry2<N>>;
 #[doc = "A 2D abstract support mapping."]
 pub type SupportMap2<N> = SupportMap<Point2<N>, Isometry2<N>>;
+#[doc = "A 2D abstract convex polygon."]
+pub type ConvexPolytope2<N> = ConvexPolytope<Point2<N>, Isometry2<N>>;
 #[doc = "A 2D dynamic shape."]
 pub type Shape2<N> = Shape<Point2<N>, Isometry2<N>>;
 #[doc = "A 2D shared dynamic shape handle."]
 pub type ShapeHandle2<N> = ShapeHandle<Point2<N>, Isometry2<N>>;
+#[doc = "A 2D shape handle scaled non-uniformly."]
+pub type Scaled2<N> = Scaled<ShapeHandle2<N>, Vector2<N>>;
 
 #[doc = "A 3D ball."]
 pub type Ball3<N> = Ball<N>;
@@ -94,6 +135,8 @@ pub type Ball3<N> = Ball<N>;
 pub type Plane3<N> = Plane<Vector3<N>>;
 #[doc = "A 3D cuboid."]
 pub type Cuboid3<N> = Cuboid<Vector3<N>>;
+#[doc = "A 3D ellipsoid."]
+pub type Ellipsoid3<N> = Ellipsoid<Vector3<N>>;
 #[doc = "A 3D capsule."]
 pub type Capsule3<N> = Capsule<N>;
 #[doc = "A 3D cone."]
@@ -112,13 +155,27 @@ pub type Tetrahedron3<N> = Tetrahedron<Point3<N>>;
 pub type Polyline3<N> = Polyline<Point3<N>>;
 #[doc = "A 3D triangle mesh."]
 pub type TriMesh3<N> = TriMesh<Point3<N>>;
+#[doc = "A 3D height grid."]
+pub type HeightField3<N> = HeightField<Point3<N>>;
+#[doc = "A 3D voxel grid."]
+pub type Voxels3<N> = Voxels<Point3<N>>;
+#[doc = "A 3D Bezier curve."]
+pub type BezierCurve3<N> = BezierCurve<Point3<N>>;
+#[doc = "A 3D Bezier surface."]
+pub type BezierSurface3<N> = BezierSurface<Point3<N>>;
 #[doc = "A 3D compound shape."]
 pub type Compound3<N> = Compound<Point3<N>, Isometry3<N>>;
+#[doc = "A 3D dynamic compound shape."]
+pub type DynamicCompound3<N> = DynamicCompound<Point3<N>, Isometry3<N>>;
 #[doc = "A 3D abstract composite shape."]
 pub type CompositeShape3<N> = CompositeShape<Point3<N>, Isometry3<N>>;
 #[doc = "A 3D abstract support mapping."]
 pub type SupportMap3<N> = SupportMap<Point3<N>, Isometry3<N>>;
+#[doc = "A 3D abstract convex polyhedron."]
+pub type ConvexPolytope3<N> = ConvexPolytope<Point3<N>, Isometry3<N>>;
 #[doc = "A 3D dynamic shape."]
 pub type Shape3<N> = Shape<Point3<N>, Isometry3<N>>;
 #[doc = "A 3D shared dynamic shape handle."]
 pub type ShapeHandle3<N> = ShapeHandle<Point3<N>, Isometry3<N>>;
+#[doc = "A 3D shape handle scaled non-uniformly."]
+pub type Scaled3<N> = Scaled<ShapeHandle3<N>, Vector3<N>>;
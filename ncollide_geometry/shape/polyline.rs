@@ -2,22 +2,31 @@
 
 use std::mem;
 use std::sync::Arc;
+use std::collections::HashMap;
 
-use na::{self, Point2};
-use partitioning::BVT;
+use approx::ApproxEq;
+
+use alga::general::Real;
+use na::{self, Point2, Unit};
+use partitioning::{BVH, BVT};
 use bounding_volume::AABB;
-use shape::{BaseMesh, CompositeShape, Segment, Shape};
+use shape::{BaseMesh, CompositeShape, EdgeConvexity, Segment, Shape};
+use shape::base_mesh;
 use math::{Isometry, Point};
 
 /// Shape commonly known as a 2d line strip or a 3d segment mesh.
 pub struct Polyline<P: Point> {
     mesh: BaseMesh<P, Point2<usize>, Segment<P>>,
+    adjacency: Vec<[Option<usize>; 2]>,
+    convexity: Vec<[EdgeConvexity; 2]>,
 }
 
 impl<P: Point> Clone for Polyline<P> {
     fn clone(&self) -> Polyline<P> {
         Polyline {
             mesh: self.mesh.clone(),
+            adjacency: self.adjacency.clone(),
+            convexity: self.convexity.clone(),
         }
     }
 }
@@ -30,13 +39,29 @@ impl<P: Point> Polyline<P> {
         uvs: Option<Arc<Vec<Point2<P::Real>>>>,
         normals: Option<Arc<Vec<P::Vector>>>,
     ) -> Polyline<P> {
-        Polyline {
+        let adjacency = compute_adjacency(&indices[..]);
+        let mut res = Polyline {
             mesh: BaseMesh::new(vertices, indices, uvs, normals),
-        }
+            adjacency: adjacency,
+            convexity: Vec::new(),
+        };
+
+        res.update_convexity();
+        res
     }
 }
 
 impl<P: Point> Polyline<P> {
+    /// Modifies the vertices of this mesh in-place and refits its acceleration structure.
+    ///
+    /// The topology of this mesh is left unchanged and its normals are not updated. The
+    /// convexity of its vertices is recomputed.
+    #[inline]
+    pub fn update_vertices<F: FnOnce(&mut [P])>(&mut self, f: F) {
+        self.mesh.update_vertices(f);
+        self.update_convexity()
+    }
+
     /// The base representation of this mesh.
     #[inline]
     pub fn base_mesh(&self) -> &BaseMesh<P, Point2<usize>, Segment<P>> {
@@ -78,6 +103,24 @@ impl<P: Point> Polyline<P> {
     pub fn bvt(&self) -> &BVT<usize, AABB<P>> {
         self.mesh.bvt()
     }
+
+    /// For each segment, the index of the segment sharing each of its vertices.
+    ///
+    /// A vertex is adjacent to no segment if it lies on the boundary of this mesh or if it is
+    /// shared by more than two segments.
+    #[inline]
+    pub fn adjacency(&self) -> &[[Option<usize>; 2]] {
+        &self.adjacency[..]
+    }
+
+    /// For each segment, the convexity of each of its vertices relative to its front side.
+    ///
+    /// The front side of a segment `ab` is on the left of the direction from `a` to `b`. Only
+    /// two-dimensional meshes have non-boundary vertices.
+    #[inline]
+    pub fn vertex_convexity(&self) -> &[[EdgeConvexity; 2]] {
+        &self.convexity[..]
+    }
 }
 
 impl<P: Point> Polyline<P> {
@@ -86,6 +129,89 @@ impl<P: Point> Polyline<P> {
     pub fn segment_at(&self, i: usize) -> Segment<P> {
         self.mesh.element_at(i)
     }
+
+    // The outward directions of the i-th segment and of its neighbor at its k-th vertex.
+    fn junction(&self, i: usize, k: usize) -> Option<(P::Vector, P::Vector)> {
+        let j = match self.adjacency[i][k] {
+            Some(j) => j,
+            None => return None,
+        };
+
+        let vs = &self.vertices()[..];
+        let idx = &self.indices()[i];
+        let jdx = &self.indices()[j];
+        let (ia, ic) = (idx[k], idx[1 - k]);
+        let jc = if jdx[0] == ia { jdx[1] } else { jdx[0] };
+
+        let o1 = base_mesh::edge_outward_direction(&vs[ia], &vs[ia], &vs[ic]);
+        let o2 = base_mesh::edge_outward_direction(&vs[ia], &vs[ia], &vs[jc]);
+
+        match (o1, o2) {
+            (Some(o1), Some(o2)) => Some((o1, o2)),
+            _ => None,
+        }
+    }
+
+    fn update_convexity(&mut self) {
+        let mut convexity = Vec::with_capacity(self.adjacency.len());
+
+        for i in 0..self.adjacency.len() {
+            let mut vertices = [EdgeConvexity::Boundary; 2];
+
+            if na::dimension::<P::Vector>() == 2 {
+                let n = front_normal(&self.segment_at(i));
+
+                for k in 0..2 {
+                    if let Some((o1, o2)) = self.junction(i, k) {
+                        vertices[k] = base_mesh::junction_convexity::<P>(&n, &o1, &o2);
+                    }
+                }
+            }
+
+            convexity.push(vertices);
+        }
+
+        self.convexity = convexity;
+    }
+}
+
+// The normal of a 2D segment pointing toward its left.
+fn front_normal<P: Point>(segment: &Segment<P>) -> P::Vector {
+    let ab = *segment.b() - *segment.a();
+    let mut n = na::zero::<P::Vector>();
+    n[0] = -ab[1];
+    n[1] = ab[0];
+
+    n
+}
+
+// For each segment, the segments sharing each of its vertices.
+fn compute_adjacency(indices: &[Point2<usize>]) -> Vec<[Option<usize>; 2]> {
+    let mut vertices: HashMap<usize, Vec<usize>> = HashMap::new();
+
+    for (i, idx) in indices.iter().enumerate() {
+        for k in 0..2 {
+            vertices.entry(idx[k]).or_insert_with(Vec::new).push(i);
+        }
+    }
+
+    indices
+        .iter()
+        .enumerate()
+        .map(|(i, idx)| {
+            let mut adj = [None; 2];
+
+            for k in 0..2 {
+                let segs = &vertices[&idx[k]];
+
+                if segs.len() == 2 && segs[0] != segs[1] {
+                    adj[k] = Some(if segs[0] == i { segs[1] } else { segs[0] });
+                }
+            }
+
+            adj
+        })
+        .collect()
 }
 
 impl<P: Point, M: Isometry<P>> CompositeShape<P, M> for Polyline<P> {
@@ -109,7 +235,47 @@ impl<P: Point, M: Isometry<P>> CompositeShape<P, M> for Polyline<P> {
     }
 
     #[inline]
-    fn bvt(&self) -> &BVT<usize, AABB<P>> {
+    fn bvh(&self) -> &BVH<P::Real, usize, AABB<P>> {
         self.bvt()
     }
+
+    fn adjust_part_contact_normal(
+        &self,
+        i: usize,
+        point: &P,
+        normal: &mut Unit<P::Vector>,
+    ) -> bool {
+        if na::dimension::<P::Vector>() != 2 {
+            return true;
+        }
+
+        let segment = self.segment_at(i);
+        let mut n1 = match Unit::try_new(front_normal(&segment), P::Real::default_epsilon()) {
+            Some(n1) => n1,
+            None => return true,
+        };
+
+        if na::dot(&*n1, &**normal) < na::zero() {
+            n1 = -n1;
+        }
+
+        let pts = [segment.a(), segment.b()];
+        let eps = P::Real::default_epsilon().sqrt();
+        let sqeps = eps * eps * na::norm_squared(&(*segment.b() - *segment.a()));
+
+        for k in 0..2 {
+            let convexity = self.convexity[i][k];
+            let on_vertex = na::norm_squared(&(*point - *pts[k])) <= sqeps;
+
+            if convexity != EdgeConvexity::Boundary && on_vertex {
+                if let Some((o1, o2)) = self.junction(i, k) {
+                    if !base_mesh::adjust_junction_normal::<P>(&n1, &o1, &o2, convexity, normal) {
+                        return false;
+                    }
+                }
+            }
+        }
+
+        true
+    }
 }
@@ -0,0 +1,103 @@
+//! Shape dilated by a ball.
+
+use num::Signed;
+
+use alga::general::Real;
+use na::Unit;
+
+use shape::{FeatureId, SupportAreaFeatures, SupportMap};
+use math::{Isometry, Point};
+
+/// A convex shape with rounded borders.
+///
+/// This is the Minkowski sum of the support-mapped core shape `S` and a ball of radius
+/// `border_radius`. Unlike `MinkowskiSum`, a round shape owns its core shape and can be stored into
+/// a `ShapeHandle`. Most queries are performed on the core shape before taking the border radius
+/// into account, which is faster and more accurate than working on the rounded shape directly.
+#[derive(PartialEq, Debug, Clone)]
+pub struct RoundShape<S, N> {
+    shape: S,
+    border_radius: N,
+}
+
+impl<S, N: Real> RoundShape<S, N> {
+    /// Creates a new shape by rounding the borders of `shape`.
+    ///
+    /// # Arguments:
+    /// * `shape` - the core shape, i.e., the shape without its rounded borders.
+    /// * `border_radius` - the radius of the rounded borders. Must be positive.
+    pub fn new(shape: S, border_radius: N) -> RoundShape<S, N> {
+        assert!(
+            border_radius.is_positive(),
+            "The border radius must be positive."
+        );
+
+        RoundShape {
+            shape: shape,
+            border_radius: border_radius,
+        }
+    }
+
+    /// The core shape, i.e., the shape without its rounded borders.
+    #[inline]
+    pub fn shape(&self) -> &S {
+        &self.shape
+    }
+
+    /// The radius of the rounded borders.
+    #[inline]
+    pub fn border_radius(&self) -> N {
+        self.border_radius
+    }
+}
+
+impl<P, M, S> SupportMap<P, M> for RoundShape<S, P::Real>
+where
+    P: Point,
+    M: Isometry<P>,
+    S: SupportMap<P, M>,
+{
+    #[inline]
+    fn support_point(&self, m: &M, dir: &P::Vector) -> P {
+        self.support_point_toward(m, &Unit::new_normalize(*dir))
+    }
+
+    #[inline]
+    fn support_point_toward(&self, m: &M, dir: &Unit<P::Vector>) -> P {
+        self.shape.support_point_toward(m, dir) + **dir * self.border_radius
+    }
+
+    #[inline]
+    fn support_feature_id_toward(&self, m: &M, dir: &Unit<P::Vector>) -> FeatureId {
+        self.shape.support_feature_id_toward(m, dir)
+    }
+
+    fn support_area_toward(&self, m: &M, dir: &Unit<P::Vector>, angle: P::Real, out: &mut Vec<P>) {
+        let start = out.len();
+
+        self.shape.support_area_toward(m, dir, angle, out);
+
+        for pt in out[start..].iter_mut() {
+            *pt = *pt + **dir * self.border_radius
+        }
+    }
+
+    /// The features are those of the core shape.
+    fn support_area_features_toward(
+        &self,
+        m: &M,
+        dir: &Unit<P::Vector>,
+        angle: P::Real,
+        out: &mut Vec<P>,
+        features: &mut SupportAreaFeatures,
+    ) {
+        let start = out.len();
+
+        self.shape
+            .support_area_features_toward(m, dir, angle, out, features);
+
+        for pt in out[start..].iter_mut() {
+            *pt = *pt + **dir * self.border_radius
+        }
+    }
+}
@@ -0,0 +1,493 @@
+//! Shape scaled non-uniformly along each axis of its local frame.
+
+use std::ops::Deref;
+
+use approx::ApproxEq;
+use alga::general::Real;
+use alga::linear::Translation;
+use na;
+
+use bounding_volume::AABB;
+use partitioning::{BVTCostFn, BVTVisitor, BVH};
+use shape::{CompositeShape, Compound, Cuboid, DynamicCompound, Segment, Shape, ShapeHandle,
+            SupportMap, Triangle};
+use math::{Isometry, Point, Vector};
+
+/// A shape scaled along each axis of its local frame.
+///
+/// The wrapped shape is usually a `ShapeHandle`, so that a single shape can be shared by several
+/// objects with different sizes without duplicating its geometry. The scaled shape supports the
+/// same queries as the wrapped shape.
+///
+/// Parts of a scaled composite shape are scaled exactly if they are triangles or segments, or
+/// cuboids aligned with the axes of the scaling. Other parts are passed to the closures of
+/// `map_part_at` and `map_transformed_part_at` as linearly transformed shapes. Those parts are
+/// shared with the wrapped shape if it is a `Compound` or a `DynamicCompound`. Composite shapes
+/// with other parts are not supported.
+#[derive(PartialEq, Debug, Clone)]
+pub struct Scaled<S, V> {
+    shape: S,
+    scale: V,
+}
+
+impl<S, V: Vector> Scaled<S, V> {
+    /// Creates a new scaled shape. Each scale factor must be strictly positive.
+    #[inline]
+    pub fn new(shape: S, scale: V) -> Scaled<S, V> {
+        for i in 0..na::dimension::<V>() {
+            assert!(
+                scale[i] > na::zero(),
+                "The scale factors must be strictly positive."
+            );
+        }
+
+        Scaled {
+            shape: shape,
+            scale: scale,
+        }
+    }
+
+    /// The scaled shape.
+    #[inline]
+    pub fn shape(&self) -> &S {
+        &self.shape
+    }
+
+    /// The scale factors along each local axis.
+    #[inline]
+    pub fn scale(&self) -> &V {
+        &self.scale
+    }
+
+    /// Multiplies each component of `v` by the corresponding scale factor.
+    #[inline]
+    pub fn scale_vector(&self, v: &V) -> V {
+        let mut res = *v;
+
+        for i in 0..na::dimension::<V>() {
+            res[i] = res[i] * self.scale[i];
+        }
+
+        res
+    }
+
+    /// Divides each component of `v` by the corresponding scale factor.
+    #[inline]
+    pub fn unscale_vector(&self, v: &V) -> V {
+        let mut res = *v;
+
+        for i in 0..na::dimension::<V>() {
+            res[i] = res[i] / self.scale[i];
+        }
+
+        res
+    }
+
+    /// Maps a point from the frame of the wrapped shape to the frame of the scaled shape.
+    #[inline]
+    pub fn scale_point<P: Point<Vector = V>>(&self, pt: &P) -> P {
+        P::from_coordinates(self.scale_vector(&pt.coordinates()))
+    }
+
+    /// Maps a point from the frame of the scaled shape to the frame of the wrapped shape.
+    #[inline]
+    pub fn unscale_point<P: Point<Vector = V>>(&self, pt: &P) -> P {
+        P::from_coordinates(self.unscale_vector(&pt.coordinates()))
+    }
+
+    /// The scale factors expressed along the local axes of a part with the orientation `part_m`.
+    ///
+    /// Returns `None` if the part cannot be scaled exactly by a scaling along its own axes, i.e.,
+    /// if the scale is not uniform and the rotation of `part_m` does not map each coordinate axis
+    /// to a coordinate axis.
+    pub fn part_scale<P, M>(&self, part_m: &M) -> Option<V>
+    where
+        P: Point<Vector = V>,
+        M: Isometry<P>,
+    {
+        let dim = na::dimension::<V>();
+
+        if (1..dim).all(|k| self.scale[k] == self.scale[0]) {
+            return Some(self.scale);
+        }
+
+        let eps = P::Real::default_epsilon().sqrt();
+        let mut res = self.scale;
+
+        for j in 0..dim {
+            let mut axis = na::zero::<V>();
+            axis[j] = na::one();
+            let axis = part_m.rotate_vector(&axis);
+
+            match (0..dim).find(|&k| (axis[k].abs() - na::one()).abs() <= eps) {
+                Some(k) => res[j] = self.scale[k],
+                None => return None,
+            }
+        }
+
+        Some(res)
+    }
+
+    /// Maps an AABB from the frame of the wrapped shape to the frame of the scaled shape.
+    #[inline]
+    pub fn scale_aabb<P: Point<Vector = V>>(&self, aabb: &AABB<P>) -> AABB<P> {
+        AABB::new(self.scale_point(aabb.mins()), self.scale_point(aabb.maxs()))
+    }
+}
+
+impl<P, M, S> SupportMap<P, M> for Scaled<S, P::Vector>
+where
+    P: Point,
+    M: Isometry<P>,
+    S: Deref<Target = Shape<P, M>>,
+{
+    #[inline]
+    fn support_point(&self, m: &M, dir: &P::Vector) -> P {
+        let one: M = na::one();
+        let local_dir = self.scale_vector(&m.inverse_rotate_vector(dir));
+        let local_pt = self.shape
+            .as_support_map()
+            .expect("The scaled shape is not a support map.")
+            .support_point(&one, &local_dir);
+
+        m.transform_point(&self.scale_point(&local_pt))
+    }
+}
+
+impl<P, M, S> CompositeShape<P, M> for Scaled<S, P::Vector>
+where
+    P: Point,
+    M: Isometry<P>,
+    S: Deref<Target = Shape<P, M>>,
+{
+    #[inline]
+    fn map_part_at(&self, i: usize, f: &mut FnMut(&M, &Shape<P, M>)) {
+        let one: M = na::one();
+
+        self.map_transformed_part_at(i, &one, f)
+    }
+
+    #[inline]
+    fn map_transformed_part_at(&self, i: usize, m: &M, f: &mut FnMut(&M, &Shape<P, M>)) {
+        let one: M = na::one();
+
+        map_scaled_part_at(self, &*self.shape, &one, i, m, f)
+    }
+
+    #[inline]
+    fn aabb_at(&self, i: usize) -> AABB<P> {
+        let composite = self.shape
+            .as_composite_shape()
+            .expect("The scaled shape is not a composite shape.");
+
+        self.scale_aabb(&composite.aabb_at(i))
+    }
+
+    #[inline]
+    fn bvh(&self) -> &BVH<P::Real, usize, AABB<P>> {
+        self
+    }
+}
+
+/// The acceleration structure of a scaled composite shape is the one of the wrapped shape with
+/// its bounding volumes scaled on-the-fly.
+impl<P, M, S> BVH<P::Real, usize, AABB<P>> for Scaled<S, P::Vector>
+where
+    P: Point,
+    M: Isometry<P>,
+    S: Deref<Target = Shape<P, M>>,
+{
+    fn visit(&self, visitor: &mut BVTVisitor<usize, AABB<P>>) {
+        let one: M = na::one();
+
+        visit_scaled(&*self.shape, &self.scale, &one, visitor)
+    }
+
+    fn best_first_search(
+        &self,
+        algorithm: &mut BVTCostFn<P::Real, usize, AABB<P>, UserData = ()>,
+    ) -> Option<usize> {
+        let one: M = na::one();
+
+        best_first_search_scaled(&*self.shape, &self.scale, &one, algorithm)
+    }
+}
+
+/// A part of a scaled composite shape, i.e., the shape `shape` transformed by `delta` and then
+/// scaled along the axes of the composite shape.
+///
+/// Unlike a `Scaled` shape, the scaling is not necessarily aligned with the local axes of `shape`.
+/// This is a support map (resp. a composite shape) if `shape` is.
+pub(crate) struct ScaledPart<P: Point, M> {
+    scaled: Scaled<ShapeHandle<P, M>, P::Vector>,
+    delta: M,
+}
+
+impl<P: Point, M: Isometry<P>> ScaledPart<P, M> {
+    /// Creates a new scaled part.
+    #[inline]
+    pub fn new(shape: ShapeHandle<P, M>, delta: M, scale: P::Vector) -> ScaledPart<P, M> {
+        ScaledPart {
+            scaled: Scaled::new(shape, scale),
+            delta: delta,
+        }
+    }
+
+    /// The shape of this part, with its scaling.
+    #[inline]
+    pub fn scaled(&self) -> &Scaled<ShapeHandle<P, M>, P::Vector> {
+        &self.scaled
+    }
+
+    /// The transformation applied to the shape of this part before the scaling.
+    #[inline]
+    pub fn delta(&self) -> &M {
+        &self.delta
+    }
+
+    /// The wrapped shape.
+    #[inline]
+    pub fn shape(&self) -> &Shape<P, M> {
+        &**self.scaled.shape()
+    }
+
+    /// Maps an AABB from the frame of the wrapped shape to the frame of this part.
+    #[inline]
+    pub fn transform_aabb(&self, aabb: &AABB<P>) -> AABB<P> {
+        transform_aabb(self.scaled.scale(), &self.delta, aabb)
+    }
+}
+
+impl<P: Point, M: Isometry<P>> SupportMap<P, M> for ScaledPart<P, M> {
+    #[inline]
+    fn support_point(&self, m: &M, dir: &P::Vector) -> P {
+        // The support function of the image of a shape by a linear map `A` is `A * s(A^t * dir)`.
+        let local_dir = self.scaled.scale_vector(&m.inverse_rotate_vector(dir));
+        let local_pt = self.shape()
+            .as_support_map()
+            .expect("The scaled part is not a support map.")
+            .support_point(&self.delta, &local_dir);
+
+        m.transform_point(&self.scaled.scale_point(&local_pt))
+    }
+}
+
+impl<P: Point, M: Isometry<P>> CompositeShape<P, M> for ScaledPart<P, M> {
+    #[inline]
+    fn map_part_at(&self, i: usize, f: &mut FnMut(&M, &Shape<P, M>)) {
+        let one: M = na::one();
+
+        self.map_transformed_part_at(i, &one, f)
+    }
+
+    #[inline]
+    fn map_transformed_part_at(&self, i: usize, m: &M, f: &mut FnMut(&M, &Shape<P, M>)) {
+        map_scaled_part_at(&self.scaled, self.shape(), &self.delta, i, m, f)
+    }
+
+    #[inline]
+    fn aabb_at(&self, i: usize) -> AABB<P> {
+        let composite = self.shape()
+            .as_composite_shape()
+            .expect("The scaled part is not a composite shape.");
+
+        self.transform_aabb(&composite.aabb_at(i))
+    }
+
+    #[inline]
+    fn bvh(&self) -> &BVH<P::Real, usize, AABB<P>> {
+        self
+    }
+}
+
+impl<P: Point, M: Isometry<P>> BVH<P::Real, usize, AABB<P>> for ScaledPart<P, M> {
+    fn visit(&self, visitor: &mut BVTVisitor<usize, AABB<P>>) {
+        visit_scaled(self.shape(), self.scaled.scale(), &self.delta, visitor)
+    }
+
+    fn best_first_search(
+        &self,
+        algorithm: &mut BVTCostFn<P::Real, usize, AABB<P>, UserData = ()>,
+    ) -> Option<usize> {
+        best_first_search_scaled(self.shape(), self.scaled.scale(), &self.delta, algorithm)
+    }
+}
+
+// Calls `f` with the `i`-th part of the composite shape `shape`, transformed by `delta` and then
+// scaled by `scaled`.
+fn map_scaled_part_at<P, M, S>(
+    scaled: &Scaled<S, P::Vector>,
+    shape: &Shape<P, M>,
+    delta: &M,
+    i: usize,
+    m: &M,
+    f: &mut FnMut(&M, &Shape<P, M>),
+) where
+    P: Point,
+    M: Isometry<P>,
+{
+    let composite = shape
+        .as_composite_shape()
+        .expect("The scaled shape is not a composite shape.");
+
+    // The parts of compound shapes are shape handles which can be wrapped.
+    let handle = if let Some(compound) = shape.as_shape::<Compound<P, M>>() {
+        Some(&compound.shapes()[i].1)
+    } else if let Some(compound) = shape.as_shape::<DynamicCompound<P, M>>() {
+        compound.part(i).map(|part| part.1)
+    } else {
+        None
+    };
+
+    composite.map_part_at(i, &mut |part_m, part| {
+        let part_m = delta.clone() * part_m.clone();
+
+        // Polygonal parts are scaled exactly by scaling their vertices.
+        if let Some(t) = part.as_shape::<Triangle<P>>() {
+            let a = scaled.scale_point(&part_m.transform_point(t.a()));
+            let b = scaled.scale_point(&part_m.transform_point(t.b()));
+            let c = scaled.scale_point(&part_m.transform_point(t.c()));
+
+            return f(m, &Triangle::new(a, b, c));
+        }
+
+        if let Some(s) = part.as_shape::<Segment<P>>() {
+            let a = scaled.scale_point(&part_m.transform_point(s.a()));
+            let b = scaled.scale_point(&part_m.transform_point(s.b()));
+
+            return f(m, &Segment::new(a, b));
+        }
+
+        let cuboid = part.as_shape::<Cuboid<P::Vector>>();
+
+        // Cuboids aligned with the axes of the scaling remain cuboids.
+        if let (Some(cuboid), Some(part_scale)) = (cuboid, scaled.part_scale(&part_m)) {
+            let mut half_extents = *cuboid.half_extents();
+
+            for k in 0..na::dimension::<P::Vector>() {
+                half_extents[k] = half_extents[k] * part_scale[k];
+            }
+
+            let part_t = part_m.translation().to_vector();
+            let shift = scaled.scale_vector(&part_t) - part_t;
+            let shift = M::Translation::from_vector(shift).unwrap();
+            let scaled_m = part_m.append_translation(&shift);
+
+            return f(&(m.clone() * scaled_m), &Cuboid::new(half_extents));
+        }
+
+        let part = match (handle, cuboid) {
+            (Some(handle), _) => handle.clone(),
+            (None, Some(cuboid)) => ShapeHandle::new(cuboid.clone()),
+            (None, None) => panic!(
+                "Only the triangles, segments, and cuboids of this composite shape can be scaled."
+            ),
+        };
+
+        f(m, &ScaledPart::new(part, part_m, *scaled.scale()))
+    })
+}
+
+fn visit_scaled<P, M>(
+    shape: &Shape<P, M>,
+    scale: &P::Vector,
+    delta: &M,
+    visitor: &mut BVTVisitor<usize, AABB<P>>,
+) where
+    P: Point,
+    M: Isometry<P>,
+{
+    let composite = shape
+        .as_composite_shape()
+        .expect("The scaled shape is not a composite shape.");
+    let mut scaled_visitor = ScaledVisitor {
+        scale: scale,
+        delta: delta,
+        visitor: visitor,
+    };
+
+    composite.bvh().visit(&mut scaled_visitor)
+}
+
+fn best_first_search_scaled<P, M>(
+    shape: &Shape<P, M>,
+    scale: &P::Vector,
+    delta: &M,
+    algorithm: &mut BVTCostFn<P::Real, usize, AABB<P>, UserData = ()>,
+) -> Option<usize>
+where
+    P: Point,
+    M: Isometry<P>,
+{
+    let composite = shape
+        .as_composite_shape()
+        .expect("The scaled shape is not a composite shape.");
+    let mut scaled_algorithm = ScaledCostFn {
+        scale: scale,
+        delta: delta,
+        algorithm: algorithm,
+    };
+
+    composite.bvh().best_first_search(&mut scaled_algorithm)
+}
+
+// The AABB of the image of `aabb` by `delta` followed by the scaling `scale`.
+#[inline]
+fn transform_aabb<P: Point, M: Isometry<P>>(
+    scale: &P::Vector,
+    delta: &M,
+    aabb: &AABB<P>,
+) -> AABB<P> {
+    let center = delta.transform_point(&aabb.center());
+    let half_extents = delta.absolute_rotate_vector(&aabb.half_extents());
+    let mut mins = center + (-half_extents);
+    let mut maxs = center + half_extents;
+
+    for i in 0..na::dimension::<P::Vector>() {
+        mins[i] = mins[i] * scale[i];
+        maxs[i] = maxs[i] * scale[i];
+    }
+
+    AABB::new(mins, maxs)
+}
+
+struct ScaledVisitor<'a, P: 'a + Point, M: 'a> {
+    scale: &'a P::Vector,
+    delta: &'a M,
+    visitor: &'a mut BVTVisitor<usize, AABB<P>>,
+}
+
+impl<'a, P: Point, M: Isometry<P>> BVTVisitor<usize, AABB<P>> for ScaledVisitor<'a, P, M> {
+    #[inline]
+    fn visit_internal(&mut self, bv: &AABB<P>) -> bool {
+        self.visitor
+            .visit_internal(&transform_aabb(self.scale, self.delta, bv))
+    }
+
+    #[inline]
+    fn visit_leaf(&mut self, b: &usize, bv: &AABB<P>) {
+        self.visitor
+            .visit_leaf(b, &transform_aabb(self.scale, self.delta, bv))
+    }
+}
+
+struct ScaledCostFn<'a, P: 'a + Point, M: 'a> {
+    scale: &'a P::Vector,
+    delta: &'a M,
+    algorithm: &'a mut BVTCostFn<P::Real, usize, AABB<P>, UserData = ()>,
+}
+
+impl<'a, P: Point, M: Isometry<P>> BVTCostFn<P::Real, usize, AABB<P>> for ScaledCostFn<'a, P, M> {
+    type UserData = ();
+
+    #[inline]
+    fn compute_bv_cost(&mut self, bv: &AABB<P>) -> Option<P::Real> {
+        self.algorithm
+            .compute_bv_cost(&transform_aabb(self.scale, self.delta, bv))
+    }
+
+    #[inline]
+    fn compute_b_cost(&mut self, b: &usize) -> Option<(P::Real, ())> {
+        self.algorithm.compute_b_cost(b)
+    }
+}
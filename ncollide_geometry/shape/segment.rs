@@ -1,8 +1,11 @@
 //! Definition of the segment shape.
 
 use std::mem;
-use na::{self, Point2};
-use shape::{BaseMeshElement, SupportMap};
+use approx::ApproxEq;
+use alga::general::Real;
+use na::{self, Point2, Unit};
+use shape::{BaseMeshElement, FeatureId, SupportAreaFeatures, SupportMap};
+use shape::support_map::SUPPORT_FEATURE_ANGLE;
 use math::{Isometry, Point};
 
 /// A segment shape.
@@ -68,4 +71,67 @@ impl<P: Point, M: Isometry<P>> SupportMap<P, M> for Segment<P> {
             m.transform_point(self.b())
         }
     }
+
+    #[inline]
+    fn support_feature_id_toward(&self, m: &M, dir: &Unit<P::Vector>) -> FeatureId {
+        let local_dir = m.inverse_rotate_vector(dir);
+        let sang: P::Real = na::convert(SUPPORT_FEATURE_ANGLE.sin());
+
+        if let Some(edge) = Unit::try_new(*self.b() - *self.a(), P::Real::default_epsilon()) {
+            if na::dot(edge.as_ref(), &local_dir).abs() <= sang {
+                return FeatureId::Edge(0);
+            }
+        }
+
+        if na::dot(&self.a().coordinates(), &local_dir)
+            > na::dot(&self.b().coordinates(), &local_dir)
+        {
+            FeatureId::Vertex(0)
+        } else {
+            FeatureId::Vertex(1)
+        }
+    }
+
+    fn support_area_toward(&self, m: &M, dir: &Unit<P::Vector>, angle: P::Real, out: &mut Vec<P>) {
+        let mut features = SupportAreaFeatures::new();
+        self.support_area_features_toward(m, dir, angle, out, &mut features)
+    }
+
+    /// The features are indexed as described by `FeatureId`.
+    fn support_area_features_toward(
+        &self,
+        m: &M,
+        dir: &Unit<P::Vector>,
+        angle: P::Real,
+        out: &mut Vec<P>,
+        features: &mut SupportAreaFeatures,
+    ) {
+        let a = m.transform_point(self.a());
+        let b = m.transform_point(self.b());
+
+        if let Some(edge) = Unit::try_new(b - a, P::Real::default_epsilon()) {
+            if na::dot(edge.as_ref(), dir.as_ref()).abs() <= angle.sin() {
+                out.push(a);
+                out.push(b);
+                features.vertices.push(FeatureId::Vertex(0));
+                features.vertices.push(FeatureId::Vertex(1));
+                features.area = FeatureId::Edge(0);
+                return;
+            }
+        }
+
+        let local_dir = m.inverse_rotate_vector(dir);
+        let vertex = if na::dot(&self.a().coordinates(), &local_dir)
+            > na::dot(&self.b().coordinates(), &local_dir)
+        {
+            out.push(a);
+            0
+        } else {
+            out.push(b);
+            1
+        };
+
+        features.vertices.push(FeatureId::Vertex(vertex));
+        features.area = FeatureId::Vertex(vertex);
+    }
 }
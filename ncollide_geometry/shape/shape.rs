@@ -6,7 +6,7 @@ use std::sync::Arc;
 use na;
 
 // Repr.
-use shape::{CompositeShape, SupportMap};
+use shape::{CompositeShape, ConvexPolytope, SupportMap};
 // Queries.
 use bounding_volume::{BoundingSphere, AABB};
 use query::{PointQuery, RayCast};
@@ -46,23 +46,47 @@ pub trait Shape<P: Point, M>: Send + Sync + Any + GetTypeId {
         None
     }
 
+    /// The faces of `self` if it is a convex polytope.
+    #[inline]
+    fn as_convex_polytope(&self) -> Option<&ConvexPolytope<P, M>> {
+        None
+    }
+
     /// The composite shape representation of `self` if applicable.
     #[inline]
     fn as_composite_shape(&self) -> Option<&CompositeShape<P, M>> {
         None
     }
 
+    /// The core support mapping and the border radius of `self` if it is a `RoundShape`.
+    #[inline]
+    fn as_round_shape(&self) -> Option<(&SupportMap<P, M>, P::Real)> {
+        None
+    }
+
     /// Whether `self` uses a supportmapping-based representation.
     #[inline]
     fn is_support_map(&self) -> bool {
         self.as_support_map().is_some()
     }
 
+    /// Whether `self` is a convex polytope with enumerable faces.
+    #[inline]
+    fn is_convex_polytope(&self) -> bool {
+        self.as_convex_polytope().is_some()
+    }
+
     /// Whether `self` uses a composite shape-based representation.
     #[inline]
     fn is_composite_shape(&self) -> bool {
         self.as_composite_shape().is_some()
     }
+
+    /// Whether `self` is a support mapping with rounded borders.
+    #[inline]
+    fn is_round_shape(&self) -> bool {
+        self.as_round_shape().is_some()
+    }
 }
 
 // Define our own because it is unstable.
@@ -78,6 +102,12 @@ struct TraitObject {
 
 /// Trait for casting shapes to its exact represetation.
 impl<P: Point, M: 'static> Shape<P, M> {
+    /// The `TypeId` of the concrete type of this shape.
+    #[inline]
+    pub fn shape_type_id(&self) -> TypeId {
+        self.type_id()
+    }
+
     /// Tests if this shape has a specific type `T`.
     #[inline]
     pub fn is_shape<T: Shape<P, M>>(&self) -> bool {
@@ -96,6 +126,30 @@ impl<P: Point, M: 'static> Shape<P, M> {
             None
         }
     }
+
+    /// Performs the cast to a mutable reference.
+    #[inline]
+    pub fn as_shape_mut<T: Shape<P, M>>(&mut self) -> Option<&mut T> {
+        if self.is_shape::<T>() {
+            unsafe {
+                let to: TraitObject = mem::transmute(self);
+                mem::transmute(to.data)
+            }
+        } else {
+            None
+        }
+    }
+
+    /// The support mapping of `self` split into its core shape and its border radius.
+    ///
+    /// Support mappings that are not a `RoundShape` have a zero border radius.
+    #[inline]
+    pub fn as_support_map_with_border(&self) -> Option<(&SupportMap<P, M>, P::Real)> {
+        match self.as_round_shape() {
+            Some(res) => Some(res),
+            None => self.as_support_map().map(|s| (s, na::zero())),
+        }
+    }
 }
 
 /// A shared immutable handle to an abstract shape.
@@ -112,6 +166,12 @@ impl<P: Point, M> ShapeHandle<P, M> {
             handle: Arc::new(shape),
         }
     }
+
+    /// A mutable reference to the shape of this handle if it is not shared with any other handle.
+    #[inline]
+    pub fn get_mut(&mut self) -> Option<&mut Shape<P, M>> {
+        Arc::get_mut(&mut self.handle)
+    }
 }
 
 impl<P: Point, M> AsRef<Shape<P, M>> for ShapeHandle<P, M> {
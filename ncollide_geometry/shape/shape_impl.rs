@@ -1,7 +1,13 @@
-use bounding_volume::{self, BoundingSphere, AABB};
+use std::ops::Deref;
+
+use alga::general::Real;
+use na::{self, Point2, Point3};
+use bounding_volume::{self, BoundingSphere, BoundingVolume, AABB};
 use query::{PointQuery, RayCast};
-use shape::{Ball, CompositeShape, Compound, Cone, ConvexHull, Cuboid, Cylinder, Plane, Polyline,
-            Segment, Shape, SupportMap, TriMesh, Triangle};
+use shape::{Ball, BezierCurve, BezierSurface, Capsule, CompositeShape, Compound, Cone,
+            ConvexHull, ConvexPolygon, ConvexPolyhedron, ConvexPolytope, Cuboid, Cylinder,
+            DynamicCompound, Ellipsoid, HeightField, Plane, Polyline, RoundShape, Scaled,
+            ScaledPart, Segment, Shape, SupportMap, Tetrahedron, Torus, TriMesh, Triangle, Voxels};
 use math::{Isometry, Point};
 
 macro_rules! impl_as_support_map(
@@ -61,6 +67,11 @@ impl<P: Point, M: Isometry<P>> Shape<P, M> for Triangle<P> {
     impl_as_support_map!();
 }
 
+impl<P: Point, M: Isometry<P>> Shape<P, M> for Tetrahedron<P> {
+    impl_shape_common!();
+    impl_as_support_map!();
+}
+
 impl<P: Point, M: Isometry<P>> Shape<P, M> for Segment<P> {
     impl_shape_common!();
     impl_as_support_map!();
@@ -71,9 +82,27 @@ impl<P: Point, M: Isometry<P>> Shape<P, M> for Ball<P::Real> {
     impl_as_support_map!();
 }
 
+impl<P: Point, M: Isometry<P>> Shape<P, M> for Capsule<P::Real> {
+    impl_shape_common!();
+    impl_as_support_map!();
+}
+
 impl<P: Point, M: Isometry<P>> Shape<P, M> for Cuboid<P::Vector> {
     impl_shape_common!();
     impl_as_support_map!();
+
+    #[inline]
+    fn as_convex_polytope(&self) -> Option<&ConvexPolytope<P, M>> {
+        match na::dimension::<P::Vector>() {
+            2 | 3 => Some(self),
+            _ => None,
+        }
+    }
+}
+
+impl<P: Point, M: Isometry<P>> Shape<P, M> for Ellipsoid<P::Vector> {
+    impl_shape_common!();
+    impl_as_support_map!();
 }
 
 impl<P: Point, M: Isometry<P>> Shape<P, M> for Cylinder<P::Real> {
@@ -86,16 +115,100 @@ impl<P: Point, M: Isometry<P>> Shape<P, M> for Cone<P::Real> {
     impl_as_support_map!();
 }
 
+impl<P: Point, M: Isometry<P>> Shape<P, M> for Torus<P::Real> {
+    impl_shape_common!();
+}
+
 impl<P: Point, M: Isometry<P>> Shape<P, M> for ConvexHull<P> {
     impl_shape_common!();
     impl_as_support_map!();
 }
 
+// NOTE: the following shapes have a fixed dimension so the `impl_*` macros cannot be used.
+impl<N: Real, M: Isometry<Point3<N>>> Shape<Point3<N>, M> for ConvexPolyhedron<N> {
+    #[inline]
+    fn aabb(&self, m: &M) -> AABB<Point3<N>> {
+        bounding_volume::aabb(self, m)
+    }
+
+    #[inline]
+    fn bounding_sphere(&self, m: &M) -> BoundingSphere<Point3<N>> {
+        bounding_volume::bounding_sphere(self, m)
+    }
+
+    #[inline]
+    fn as_ray_cast(&self) -> Option<&RayCast<Point3<N>, M>> {
+        Some(self)
+    }
+
+    #[inline]
+    fn as_point_query(&self) -> Option<&PointQuery<Point3<N>, M>> {
+        Some(self)
+    }
+
+    #[inline]
+    fn as_support_map(&self) -> Option<&SupportMap<Point3<N>, M>> {
+        Some(self)
+    }
+
+    #[inline]
+    fn as_convex_polytope(&self) -> Option<&ConvexPolytope<Point3<N>, M>> {
+        Some(self)
+    }
+
+    #[inline]
+    fn is_support_map(&self) -> bool {
+        true
+    }
+}
+
+impl<N: Real, M: Isometry<Point2<N>>> Shape<Point2<N>, M> for ConvexPolygon<N> {
+    #[inline]
+    fn aabb(&self, m: &M) -> AABB<Point2<N>> {
+        bounding_volume::aabb(self, m)
+    }
+
+    #[inline]
+    fn bounding_sphere(&self, m: &M) -> BoundingSphere<Point2<N>> {
+        bounding_volume::bounding_sphere(self, m)
+    }
+
+    #[inline]
+    fn as_ray_cast(&self) -> Option<&RayCast<Point2<N>, M>> {
+        Some(self)
+    }
+
+    #[inline]
+    fn as_point_query(&self) -> Option<&PointQuery<Point2<N>, M>> {
+        Some(self)
+    }
+
+    #[inline]
+    fn as_support_map(&self) -> Option<&SupportMap<Point2<N>, M>> {
+        Some(self)
+    }
+
+    #[inline]
+    fn as_convex_polytope(&self) -> Option<&ConvexPolytope<Point2<N>, M>> {
+        Some(self)
+    }
+
+    #[inline]
+    fn is_support_map(&self) -> bool {
+        true
+    }
+}
+
 impl<P: Point, M: 'static + Send + Sync + Isometry<P>> Shape<P, M> for Compound<P, M> {
     impl_shape_common!();
     impl_as_composite_shape!();
 }
 
+impl<P: Point, M: 'static + Send + Sync + Isometry<P>> Shape<P, M> for DynamicCompound<P, M> {
+    impl_shape_common!();
+    impl_as_composite_shape!();
+}
+
 impl<P: Point, M: Isometry<P>> Shape<P, M> for TriMesh<P> {
     impl_shape_common!();
     impl_as_composite_shape!();
@@ -106,6 +219,110 @@ impl<P: Point, M: Isometry<P>> Shape<P, M> for Polyline<P> {
     impl_as_composite_shape!();
 }
 
+impl<P: Point, M: Isometry<P>> Shape<P, M> for HeightField<P> {
+    impl_shape_common!();
+    impl_as_composite_shape!();
+}
+
+impl<P: Point, M: Isometry<P>> Shape<P, M> for Voxels<P> {
+    impl_shape_common!();
+    impl_as_composite_shape!();
+}
+
+impl<P: Point, M: Isometry<P>> Shape<P, M> for BezierCurve<P> {
+    impl_shape_common!();
+    impl_as_composite_shape!();
+}
+
+impl<P: Point, M: Isometry<P>> Shape<P, M> for BezierSurface<P> {
+    impl_shape_common!();
+    impl_as_composite_shape!();
+}
+
 impl<P: Point, M: Isometry<P>> Shape<P, M> for Plane<P::Vector> {
     impl_shape_common!();
 }
+
+impl<P, M, S> Shape<P, M> for RoundShape<S, P::Real>
+where
+    P: Point,
+    M: Isometry<P>,
+    S: Shape<P, M> + SupportMap<P, M>,
+{
+    #[inline]
+    fn aabb(&self, m: &M) -> AABB<P> {
+        self.shape().aabb(m).loosened(self.border_radius())
+    }
+
+    #[inline]
+    fn bounding_sphere(&self, m: &M) -> BoundingSphere<P> {
+        self.shape().bounding_sphere(m).loosened(self.border_radius())
+    }
+
+    #[inline]
+    fn as_ray_cast(&self) -> Option<&RayCast<P, M>> {
+        Some(self)
+    }
+
+    #[inline]
+    fn as_point_query(&self) -> Option<&PointQuery<P, M>> {
+        Some(self)
+    }
+
+    #[inline]
+    fn as_round_shape(&self) -> Option<(&SupportMap<P, M>, P::Real)> {
+        Some((self.shape(), self.border_radius()))
+    }
+
+    impl_as_support_map!();
+}
+
+// NOTE: the capabilities of a scaled shape are those of the shape it wraps.
+impl<P, M, S> Shape<P, M> for Scaled<S, P::Vector>
+where
+    P: Point,
+    M: Isometry<P>,
+    S: Send + Sync + 'static + Deref<Target = Shape<P, M>>,
+{
+    impl_shape_common!();
+
+    #[inline]
+    fn as_support_map(&self) -> Option<&SupportMap<P, M>> {
+        if self.shape().is_support_map() {
+            Some(self)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn as_composite_shape(&self) -> Option<&CompositeShape<P, M>> {
+        if self.shape().is_composite_shape() {
+            Some(self)
+        } else {
+            None
+        }
+    }
+}
+
+impl<P: Point, M: Isometry<P>> Shape<P, M> for ScaledPart<P, M> {
+    impl_shape_common!();
+
+    #[inline]
+    fn as_support_map(&self) -> Option<&SupportMap<P, M>> {
+        if self.shape().is_support_map() {
+            Some(self)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn as_composite_shape(&self) -> Option<&CompositeShape<P, M>> {
+        if self.shape().is_composite_shape() {
+            Some(self)
+        } else {
+            None
+        }
+    }
+}
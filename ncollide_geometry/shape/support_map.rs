@@ -1,8 +1,14 @@
 //! Traits for support mapping based shapes.
 
 use na::Unit;
+use shape::FeatureId;
 use math::Point;
 
+// The maximum angle, in radians, between a direction and the normal of a face, or the plane
+// orthogonal to an edge, for this face or edge to be the support feature toward this direction
+// returned by `SupportMap::support_feature_id_toward`.
+pub(crate) const SUPPORT_FEATURE_ANGLE: f64 = 0.01;
+
 /// Traits of convex shapes representable by a support mapping function.
 ///
 /// # Parameters:
@@ -21,8 +27,79 @@ pub trait SupportMap<P: Point, M> {
         self.support_point(transform, dir.as_ref())
     }
 
+    /// The identifier of the feature of this shape which supports it toward `dir`.
+    ///
+    /// This is a face if its normal deviates from `dir` by a small angle, or an edge if it deviates
+    /// from the plane orthogonal to `dir` by a small angle. Otherwise, this is the support vertex.
+    /// This is used to identify contacts between frames. Returns `FeatureId::Unknown` by default.
+    fn support_feature_id_toward(&self, _: &M, _: &Unit<P::Vector>) -> FeatureId {
+        FeatureId::Unknown
+    }
+
+    /// Pushes to `out` the vertices of the feature of this shape which supports it toward `dir`.
+    ///
+    /// This is a face if its normal deviates from `dir` by at most `angle`, or an edge if it
+    /// deviates from the plane orthogonal to `dir` by at most `angle`. Otherwise, this is the
+    /// support point toward `dir`. The vertices of a face are given in order along its boundary,
+    /// and curved faces are approximated by polygons. Returns only the support point by default.
     // XXX: output into a dedicated structure instead of Vec.
-    fn support_area_toward(&self, transform: &M, dir: &Unit<P::Vector>, _angle: P::Real, out: &mut Vec<P>) {
+    fn support_area_toward(
+        &self,
+        transform: &M,
+        dir: &Unit<P::Vector>,
+        _angle: P::Real,
+        out: &mut Vec<P>,
+    ) {
         out.push(self.support_point_toward(transform, dir))
     }
+
+    /// Same as `self.support_area_toward` but also outputs the identifiers of the features of the
+    /// support area to `features`.
+    ///
+    /// Those identifiers are the same as the ones returned by `self.support_feature_id_toward`.
+    /// The vertices and edges of `features` are pushed in the same order as the points of `out`.
+    /// The features are unknown by default.
+    fn support_area_features_toward(
+        &self,
+        transform: &M,
+        dir: &Unit<P::Vector>,
+        angle: P::Real,
+        out: &mut Vec<P>,
+        features: &mut SupportAreaFeatures,
+    ) {
+        features.area = FeatureId::Unknown;
+        self.support_area_toward(transform, dir, angle, out)
+    }
+}
+
+/// The identifiers of the features of a support area.
+///
+/// See `SupportMap::support_area_features_toward`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SupportAreaFeatures {
+    /// The feature of the shape the support area lies on.
+    pub area: FeatureId,
+    /// The vertices of the support area.
+    pub vertices: Vec<FeatureId>,
+    /// The edges of the support area if it is a 3D face. The `k`-th edge joins the `k`-th and
+    /// `(k + 1)`-th vertices.
+    pub edges: Vec<FeatureId>,
+}
+
+impl SupportAreaFeatures {
+    /// Creates an empty set of support area features.
+    pub fn new() -> SupportAreaFeatures {
+        SupportAreaFeatures {
+            area: FeatureId::Unknown,
+            vertices: Vec::new(),
+            edges: Vec::new(),
+        }
+    }
+
+    /// Resets the area feature to `FeatureId::Unknown` and removes all the vertices and edges.
+    pub fn clear(&mut self) {
+        self.area = FeatureId::Unknown;
+        self.vertices.clear();
+        self.edges.clear();
+    }
 }
@@ -2,8 +2,8 @@
 
 use std::mem;
 use na;
-use shape::{Segment, Triangle};
-use math::Point;
+use shape::{Segment, SupportMap, Triangle};
+use math::{Isometry, Point};
 
 /// A tetrahedron with 4 vertices.
 #[derive(Copy, Clone, Debug)]
@@ -87,3 +87,47 @@ impl<P: Point> Tetrahedron<P> {
         }
     }
 }
+
+impl<P: Point, M: Isometry<P>> SupportMap<P, M> for Tetrahedron<P> {
+    #[inline]
+    fn support_point(&self, m: &M, dir: &P::Vector) -> P {
+        let local_dir = m.inverse_rotate_vector(dir);
+
+        let d1 = na::dot(&self.a().coordinates(), &local_dir);
+        let d2 = na::dot(&self.b().coordinates(), &local_dir);
+        let d3 = na::dot(&self.c().coordinates(), &local_dir);
+        let d4 = na::dot(&self.d().coordinates(), &local_dir);
+
+        let res = if d1 > d2 {
+            if d1 > d3 {
+                if d1 > d4 {
+                    self.a()
+                } else {
+                    self.d()
+                }
+            } else {
+                if d3 > d4 {
+                    self.c()
+                } else {
+                    self.d()
+                }
+            }
+        } else {
+            if d2 > d3 {
+                if d2 > d4 {
+                    self.b()
+                } else {
+                    self.d()
+                }
+            } else {
+                if d3 > d4 {
+                    self.c()
+                } else {
+                    self.d()
+                }
+            }
+        };
+
+        m.transform_point(res)
+    }
+}
@@ -1,6 +1,9 @@
 use alga::general::Real;
 
-/// A torus.
+/// A torus with its principal axis aligned with the `y` axis.
+///
+/// The torus is the set of points located at a distance smaller than `minor_radius` from the
+/// circle of radius `major_radius` centered at the origin and contained in the `xz` plane.
 #[derive(PartialEq, Debug, Clone)]
 pub struct Torus<N> {
     major_radius: N,
@@ -1,8 +1,11 @@
 //! Definition of the triangle shape.
 
 use std::mem;
+use approx::ApproxEq;
+use alga::general::Real;
 use na::{self, Point3, Unit};
-use shape::{BaseMeshElement, SupportMap};
+use shape::{BaseMeshElement, FeatureId, SupportAreaFeatures, SupportMap};
+use shape::support_map::SUPPORT_FEATURE_ANGLE;
 use math::{Isometry, Point};
 use utils;
 
@@ -75,6 +78,15 @@ impl<P: Point> BaseMeshElement<Point3<usize>, P> for Triangle<P> {
     fn new_with_vertices_and_indices(vs: &[P], is: &Point3<usize>) -> Triangle<P> {
         Triangle::new(vs[is.x], vs[is.y], vs[is.z])
     }
+
+    #[inline]
+    fn front_normal(&self) -> Option<Unit<P::Vector>> {
+        if na::dimension::<P::Vector>() == 3 {
+            Unit::try_new(self.scaled_normal(), P::Real::default_epsilon())
+        } else {
+            None
+        }
+    }
 }
 
 impl<P: Point, M: Isometry<P>> SupportMap<P, M> for Triangle<P> {
@@ -102,4 +114,128 @@ impl<P: Point, M: Isometry<P>> SupportMap<P, M> for Triangle<P> {
 
         m.transform_point(res)
     }
+
+    #[inline]
+    fn support_feature_id_toward(&self, m: &M, dir: &Unit<P::Vector>) -> FeatureId {
+        let local_dir = m.inverse_rotate_vector(dir);
+        let angle: P::Real = na::convert(SUPPORT_FEATURE_ANGLE);
+        let pts = [*self.a(), *self.b(), *self.c()];
+
+        if let Some(normal) = self.front_normal() {
+            let dot = na::dot(normal.as_ref(), &local_dir);
+
+            if dot >= angle.cos() {
+                return FeatureId::Face(0);
+            } else if -dot >= angle.cos() {
+                return FeatureId::Face(1);
+            }
+        }
+
+        let mut best = 0;
+
+        for i in 1..3 {
+            if na::dot(&pts[i].coordinates(), &local_dir)
+                > na::dot(&pts[best].coordinates(), &local_dir)
+            {
+                best = i;
+            }
+        }
+
+        // The `k`-th edge joins the `k`-th and `(k + 1)`-th vertices.
+        for &(i, k) in [((best + 1) % 3, best), ((best + 2) % 3, (best + 2) % 3)].iter() {
+            if let Some(edge) = Unit::try_new(pts[i] - pts[best], P::Real::default_epsilon()) {
+                if na::dot(edge.as_ref(), &local_dir).abs() <= angle.sin() {
+                    return if na::dimension::<P::Vector>() == 2 {
+                        FeatureId::Face(k)
+                    } else {
+                        FeatureId::Edge(k)
+                    };
+                }
+            }
+        }
+
+        FeatureId::Vertex(best)
+    }
+
+    fn support_area_toward(&self, m: &M, dir: &Unit<P::Vector>, angle: P::Real, out: &mut Vec<P>) {
+        let mut features = SupportAreaFeatures::new();
+        self.support_area_features_toward(m, dir, angle, out, &mut features)
+    }
+
+    /// The features are indexed as described by `FeatureId`.
+    fn support_area_features_toward(
+        &self,
+        m: &M,
+        dir: &Unit<P::Vector>,
+        angle: P::Real,
+        out: &mut Vec<P>,
+        features: &mut SupportAreaFeatures,
+    ) {
+        let local_dir = m.inverse_rotate_vector(dir);
+        let pts = [*self.a(), *self.b(), *self.c()];
+
+        // Face feature, in 3D only.
+        if let Some(normal) = self.front_normal() {
+            let dot = na::dot(normal.as_ref(), &local_dir);
+
+            if dot.abs() >= angle.cos() {
+                for (k, pt) in pts.iter().enumerate() {
+                    out.push(m.transform_point(pt));
+                    features.vertices.push(FeatureId::Vertex(k));
+                    features.edges.push(FeatureId::Edge(k));
+                }
+
+                features.area = if dot >= na::zero() {
+                    FeatureId::Face(0)
+                } else {
+                    FeatureId::Face(1)
+                };
+                return;
+            }
+        }
+
+        let mut best = 0;
+
+        for i in 1..3 {
+            if na::dot(&pts[i].coordinates(), &local_dir)
+                > na::dot(&pts[best].coordinates(), &local_dir)
+            {
+                best = i;
+            }
+        }
+
+        out.push(m.transform_point(&pts[best]));
+        features.vertices.push(FeatureId::Vertex(best));
+        features.area = FeatureId::Vertex(best);
+
+        // Edge feature: the edge adjacent to the support vertex the most orthogonal to `dir`.
+        let mut best_edge = None;
+        let mut best_cos = angle.sin();
+
+        for i in [(best + 1) % 3, (best + 2) % 3].iter() {
+            let edge = pts[*i] - pts[best];
+
+            if let Some(edge) = Unit::try_new(edge, P::Real::default_epsilon()) {
+                let cos = na::dot(edge.as_ref(), &local_dir).abs();
+
+                if cos <= best_cos {
+                    best_edge = Some(*i);
+                    best_cos = cos;
+                }
+            }
+        }
+
+        if let Some(i) = best_edge {
+            // The `k`-th edge joins the `k`-th and `(k + 1)`-th vertices.
+            let k = if i == (best + 1) % 3 { best } else { i };
+
+            out.push(m.transform_point(&pts[i]));
+            features.vertices.push(FeatureId::Vertex(i));
+            features.area = if na::dimension::<P::Vector>() == 2 {
+                FeatureId::Face(k)
+            } else {
+                FeatureId::Edge(k)
+            };
+        }
+    }
 }
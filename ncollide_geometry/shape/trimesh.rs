@@ -1,22 +1,30 @@
 //! 2d line strip, 3d triangle mesh, and nd subsimplex mesh.
 
 use std::sync::Arc;
+use std::collections::HashMap;
 
-use na::{self, Point2, Point3};
-use partitioning::BVT;
+use approx::ApproxEq;
+
+use na::{self, Point2, Point3, Unit};
+use partitioning::{BVH, BVT};
 use bounding_volume::AABB;
-use shape::{BaseMesh, CompositeShape, Shape, Triangle};
+use shape::{BaseMesh, CompositeShape, EdgeConvexity, Shape, Triangle};
+use shape::base_mesh;
 use math::{Isometry, Point};
 
 /// Shape commonly known as a 2d line strip or a 3d triangle mesh.
 pub struct TriMesh<P: Point> {
     mesh: BaseMesh<P, Point3<usize>, Triangle<P>>,
+    adjacency: Vec<[Option<usize>; 3]>,
+    convexity: Vec<[EdgeConvexity; 3]>,
 }
 
 impl<P: Point> Clone for TriMesh<P> {
     fn clone(&self) -> TriMesh<P> {
         TriMesh {
             mesh: self.mesh.clone(),
+            adjacency: self.adjacency.clone(),
+            convexity: self.convexity.clone(),
         }
     }
 }
@@ -29,9 +37,25 @@ impl<P: Point> TriMesh<P> {
         uvs: Option<Arc<Vec<Point2<P::Real>>>>,
         normals: Option<Arc<Vec<P::Vector>>>,
     ) -> TriMesh<P> {
-        TriMesh {
+        let adjacency = compute_adjacency(&indices[..]);
+        let mut res = TriMesh {
             mesh: BaseMesh::new(vertices, indices, uvs, normals),
-        }
+            adjacency: adjacency,
+            convexity: Vec::new(),
+        };
+
+        res.update_convexity();
+        res
+    }
+
+    /// Modifies the vertices of this mesh in-place and refits its acceleration structure.
+    ///
+    /// The topology of this mesh is left unchanged and its normals are not updated. The
+    /// convexity of its edges is recomputed.
+    #[inline]
+    pub fn update_vertices<F: FnOnce(&mut [P])>(&mut self, f: F) {
+        self.mesh.update_vertices(f);
+        self.update_convexity()
     }
 
     /// The base representation of this mesh.
@@ -75,6 +99,41 @@ impl<P: Point> TriMesh<P> {
     pub fn bvt(&self) -> &BVT<usize, AABB<P>> {
         self.mesh.bvt()
     }
+
+    /// For each triangle, the index of the triangle sharing each of its edges.
+    ///
+    /// The `k`-th edge of a triangle joins its `k`-th and `(k + 1) % 3`-th vertices. An edge is
+    /// adjacent to no triangle if it lies on the boundary of this mesh or if it is shared by more
+    /// than two triangles.
+    #[inline]
+    pub fn adjacency(&self) -> &[[Option<usize>; 3]] {
+        &self.adjacency[..]
+    }
+
+    /// For each triangle, the convexity of each of its edges relative to its front face.
+    ///
+    /// The edges are ordered as in `self.adjacency()`. Edges of a two-dimensional mesh are all
+    /// boundary edges.
+    #[inline]
+    pub fn edge_convexity(&self) -> &[[EdgeConvexity; 3]] {
+        &self.convexity[..]
+    }
+
+    /// Whether the triangles of this mesh are one-sided.
+    #[inline]
+    pub fn is_oriented(&self) -> bool {
+        self.mesh.is_oriented()
+    }
+
+    /// Sets whether the triangles of this mesh are one-sided.
+    ///
+    /// The front face of a triangle is the one pointed to by its normal, i.e., its vertices
+    /// appear counterclockwise when seen from the front. If `oriented` is `true`, ray casts ignore
+    /// the back faces and contacts whose normal opposes the triangle normal are discarded.
+    #[inline]
+    pub fn set_oriented(&mut self, oriented: bool) {
+        self.mesh.set_oriented(oriented)
+    }
 }
 
 impl<P: Point> TriMesh<P> {
@@ -83,6 +142,95 @@ impl<P: Point> TriMesh<P> {
     pub fn triangle_at(&self, i: usize) -> Triangle<P> {
         self.mesh.element_at(i)
     }
+
+    // The outward directions of the i-th triangle and of its neighbor along its k-th edge.
+    fn junction(&self, i: usize, k: usize) -> Option<(P::Vector, P::Vector)> {
+        let j = match self.adjacency[i][k] {
+            Some(j) => j,
+            None => return None,
+        };
+
+        let vs = &self.vertices()[..];
+        let idx = &self.indices()[i];
+        let (ia, ib) = (idx[k], idx[(k + 1) % 3]);
+        let ic = idx[(k + 2) % 3];
+        let jdx = &self.indices()[j];
+        let jc = (0..3)
+            .map(|l| jdx[l])
+            .find(|v| *v != ia && *v != ib)
+            .unwrap_or(ic);
+
+        let o1 = base_mesh::edge_outward_direction(&vs[ia], &vs[ib], &vs[ic]);
+        let o2 = base_mesh::edge_outward_direction(&vs[ia], &vs[ib], &vs[jc]);
+
+        match (o1, o2) {
+            (Some(o1), Some(o2)) => Some((o1, o2)),
+            _ => None,
+        }
+    }
+
+    fn update_convexity(&mut self) {
+        let mut convexity = Vec::with_capacity(self.adjacency.len());
+
+        for i in 0..self.adjacency.len() {
+            let mut edges = [EdgeConvexity::Boundary; 3];
+
+            if na::dimension::<P::Vector>() == 3 {
+                let n = self.triangle_at(i).scaled_normal();
+
+                for k in 0..3 {
+                    if let Some((o1, o2)) = self.junction(i, k) {
+                        edges[k] = base_mesh::junction_convexity::<P>(&n, &o1, &o2);
+                    }
+                }
+            }
+
+            convexity.push(edges);
+        }
+
+        self.convexity = convexity;
+    }
+}
+
+// For each triangle, the triangles sharing each of its edges.
+fn compute_adjacency(indices: &[Point3<usize>]) -> Vec<[Option<usize>; 3]> {
+    let mut edges: HashMap<(usize, usize), Vec<usize>> = HashMap::new();
+
+    for (i, idx) in indices.iter().enumerate() {
+        for k in 0..3 {
+            edges
+                .entry(edge_key(idx[k], idx[(k + 1) % 3]))
+                .or_insert_with(Vec::new)
+                .push(i);
+        }
+    }
+
+    indices
+        .iter()
+        .enumerate()
+        .map(|(i, idx)| {
+            let mut adj = [None; 3];
+
+            for k in 0..3 {
+                let tris = &edges[&edge_key(idx[k], idx[(k + 1) % 3])];
+
+                if tris.len() == 2 && tris[0] != tris[1] {
+                    adj[k] = Some(if tris[0] == i { tris[1] } else { tris[0] });
+                }
+            }
+
+            adj
+        })
+        .collect()
+}
+
+#[inline]
+fn edge_key(a: usize, b: usize) -> (usize, usize) {
+    if a < b {
+        (a, b)
+    } else {
+        (b, a)
+    }
 }
 
 impl<P: Point, M: Isometry<P>> CompositeShape<P, M> for TriMesh<P> {
@@ -106,7 +254,51 @@ impl<P: Point, M: Isometry<P>> CompositeShape<P, M> for TriMesh<P> {
     }
 
     #[inline]
-    fn bvt(&self) -> &BVT<usize, AABB<P>> {
+    fn bvh(&self) -> &BVH<P::Real, usize, AABB<P>> {
         self.bvt()
     }
+
+    #[inline]
+    fn part_front_normal(&self, i: usize) -> Option<Unit<P::Vector>> {
+        self.mesh.front_normal_at(i)
+    }
+
+    fn adjust_part_contact_normal(
+        &self,
+        i: usize,
+        point: &P,
+        normal: &mut Unit<P::Vector>,
+    ) -> bool {
+        if na::dimension::<P::Vector>() != 3 {
+            return true;
+        }
+
+        let tri = self.triangle_at(i);
+        let mut n1 = match Unit::try_new(tri.scaled_normal(), P::Real::default_epsilon()) {
+            Some(n1) => n1,
+            None => return true,
+        };
+
+        if na::dot(&*n1, &**normal) < na::zero() {
+            n1 = -n1;
+        }
+
+        let pts = [tri.a(), tri.b(), tri.c()];
+
+        for k in 0..3 {
+            let convexity = self.convexity[i][k];
+
+            if convexity != EdgeConvexity::Boundary
+                && base_mesh::is_point_on_segment(point, pts[k], pts[(k + 1) % 3])
+            {
+                if let Some((o1, o2)) = self.junction(i, k) {
+                    if !base_mesh::adjust_junction_normal::<P>(&n1, &o1, &o2, convexity, normal) {
+                        return false;
+                    }
+                }
+            }
+        }
+
+        true
+    }
 }
@@ -0,0 +1,557 @@
+//! 2d and 3d voxel grids.
+
+use std::collections::BinaryHeap;
+use std::cmp::Ordering;
+
+use alga::general::Real;
+use alga::linear::Translation;
+use na;
+
+use bounding_volume::AABB;
+use partitioning::{BVTCostFn, BVTVisitor, BVH};
+use shape::{CompositeShape, Cuboid, Shape};
+use math::{Isometry, Point};
+
+/// A 2d or 3d grid of voxels with a fixed size.
+///
+/// The grid lies on the positive side of each axis of its local frame: the voxel with integer
+/// coordinates `(i, j, k)` spans `[i * s, (i + 1) * s] x [j * s, (j + 1) * s] x [k * s, (k + 1) *
+/// s]` where `s` is the voxel size. In 2d, only the first two coordinates are used.
+///
+/// Each voxel is identified by its linear index `i + j * ni + k * ni * nj` where `ni` and `nj`
+/// are the number of voxels along the `x` and `y` axes. As a composite shape, only the boundary
+/// voxels (see `Voxels::is_boundary`) are exposed, as cuboids.
+#[derive(Clone)]
+pub struct Voxels<P: Point> {
+    dimensions: [usize; 3],
+    voxel_size: P::Real,
+    occupied: Vec<bool>,
+    boundary: Vec<bool>,
+    // Number of boundary voxels of each node of the implicit BVH, in heap order.
+    counts: Vec<u32>,
+    // Number of occupied voxels of each slice orthogonal to each axis.
+    slices: [Vec<u32>; 3],
+}
+
+impl<P: Point> Voxels<P> {
+    /// Creates a new grid without any occupied voxel.
+    ///
+    /// The number of voxels along each axis is given by `dimensions`, which must have as many
+    /// elements as there are space dimensions.
+    pub fn new(dimensions: &[usize], voxel_size: P::Real) -> Voxels<P> {
+        let dim = na::dimension::<P::Vector>();
+        assert!(
+            dim <= 3 && dimensions.len() == dim,
+            "Voxel grids must have one dimension per axis."
+        );
+        assert!(
+            voxel_size > na::zero(),
+            "The size of a voxel must be strictly positive."
+        );
+
+        let mut dims = [1; 3];
+
+        for i in 0..dim {
+            assert!(dimensions[i] > 0, "A voxel grid cannot be empty.");
+            dims[i] = dimensions[i];
+        }
+
+        let len = dims[0] * dims[1] * dims[2];
+        let root = VoxelRange {
+            mins: [0; 3],
+            maxs: dims,
+        };
+
+        Voxels {
+            dimensions: dims,
+            voxel_size: voxel_size,
+            occupied: vec![false; len],
+            boundary: vec![false; len],
+            counts: vec![0; root.heap_size(0)],
+            slices: [vec![0; dims[0]], vec![0; dims[1]], vec![0; dims[2]]],
+        }
+    }
+
+    /// The number of voxels along each axis.
+    #[inline]
+    pub fn dimensions(&self) -> &[usize] {
+        &self.dimensions[..na::dimension::<P::Vector>()]
+    }
+
+    /// The size of each voxel.
+    #[inline]
+    pub fn voxel_size(&self) -> P::Real {
+        self.voxel_size
+    }
+
+    /// The total number of voxels, occupied or not, of this grid.
+    #[inline]
+    pub fn num_voxels(&self) -> usize {
+        self.occupied.len()
+    }
+
+    /// The linear index of the voxel with the integer coordinates `coords`.
+    #[inline]
+    pub fn voxel_id(&self, coords: &[usize]) -> usize {
+        let mut id = 0;
+
+        for i in (0..na::dimension::<P::Vector>()).rev() {
+            assert!(coords[i] < self.dimensions[i], "Voxel coordinates out of bounds.");
+            id = id * self.dimensions[i] + coords[i];
+        }
+
+        id
+    }
+
+    /// The integer coordinates of the voxel with the linear index `id`.
+    ///
+    /// Unused coordinates are set to zero in 2d.
+    #[inline]
+    pub fn voxel_coords(&self, id: usize) -> [usize; 3] {
+        [
+            id % self.dimensions[0],
+            (id / self.dimensions[0]) % self.dimensions[1],
+            id / (self.dimensions[0] * self.dimensions[1]),
+        ]
+    }
+
+    /// Whether the voxel with the linear index `id` is occupied.
+    #[inline]
+    pub fn is_occupied(&self, id: usize) -> bool {
+        self.occupied[id]
+    }
+
+    /// Whether the voxel with the linear index `id` is occupied and either lies on the border of
+    /// the grid or has at least one free neighbor.
+    #[inline]
+    pub fn is_boundary(&self, id: usize) -> bool {
+        self.boundary[id]
+    }
+
+    /// Sets whether the voxel with the linear index `id` is occupied.
+    ///
+    /// Only the voxel and its direct neighbors are updated, as well as the bounding volumes
+    /// containing them.
+    pub fn set_occupied(&mut self, id: usize, occupied: bool) {
+        if self.occupied[id] == occupied {
+            return;
+        }
+
+        self.occupied[id] = occupied;
+
+        let coords = self.voxel_coords(id);
+
+        for i in 0..3 {
+            if occupied {
+                self.slices[i][coords[i]] += 1;
+            } else {
+                self.slices[i][coords[i]] -= 1;
+            }
+        }
+
+        self.update_boundary(id);
+
+        for i in 0..na::dimension::<P::Vector>() {
+            if let Some(neighbor) = self.neighbor(&coords, i, -1) {
+                self.update_boundary(neighbor);
+            }
+
+            if let Some(neighbor) = self.neighbor(&coords, i, 1) {
+                self.update_boundary(neighbor);
+            }
+        }
+    }
+
+    /// The integer coordinates of the voxel containing `pt`, expressed in the local frame of
+    /// this grid.
+    ///
+    /// Returns `None` if the point lies outside of the grid.
+    pub fn voxel_at_point(&self, pt: &P) -> Option<[usize; 3]> {
+        let mut res = [0; 3];
+
+        for i in 0..na::dimension::<P::Vector>() {
+            // NOTE: this also rejects NaN coordinates.
+            if !(pt[i] >= na::zero()) {
+                return None;
+            }
+
+            let coord: f64 = na::convert_unchecked((pt[i] / self.voxel_size).floor());
+            let coord = coord as usize;
+
+            if coord < self.dimensions[i] {
+                res[i] = coord;
+            } else if pt[i] == self.voxel_size * na::convert(self.dimensions[i] as f64) {
+                // Points on the upper border belong to the last voxel.
+                res[i] = self.dimensions[i] - 1;
+            } else {
+                return None;
+            }
+        }
+
+        Some(res)
+    }
+
+    /// The local-space AABB of the voxel with the linear index `id`.
+    pub fn voxel_aabb(&self, id: usize) -> AABB<P> {
+        let coords = self.voxel_coords(id);
+        let mut mins = P::origin();
+
+        for i in 0..na::dimension::<P::Vector>() {
+            mins[i] = self.voxel_size * na::convert(coords[i] as f64);
+        }
+
+        let mut maxs = mins;
+
+        for i in 0..na::dimension::<P::Vector>() {
+            maxs[i] = maxs[i] + self.voxel_size;
+        }
+
+        AABB::new(mins, maxs)
+    }
+
+    /// The local-space center of the voxel with the linear index `id`.
+    #[inline]
+    pub fn voxel_center(&self, id: usize) -> P {
+        self.voxel_aabb(id).center()
+    }
+
+    /// The cuboid with the same extents as a single voxel, centered at the origin.
+    #[inline]
+    pub fn voxel_cuboid(&self) -> Cuboid<P::Vector> {
+        let half_size = self.voxel_size * na::convert(0.5f64);
+        let mut half_extents = na::zero::<P::Vector>();
+
+        for i in 0..na::dimension::<P::Vector>() {
+            half_extents[i] = half_size;
+        }
+
+        Cuboid::new(half_extents)
+    }
+
+    /// The local-space AABB of the whole grid, occupied or not.
+    pub fn grid_aabb(&self) -> AABB<P> {
+        let mut maxs = P::origin();
+
+        for i in 0..na::dimension::<P::Vector>() {
+            maxs[i] = self.voxel_size * na::convert(self.dimensions[i] as f64);
+        }
+
+        AABB::new(P::origin(), maxs)
+    }
+
+    /// The smallest local-space AABB containing all the occupied voxels.
+    ///
+    /// Returns `None` if no voxel is occupied.
+    pub fn local_aabb(&self) -> Option<AABB<P>> {
+        let mut mins = P::origin();
+        let mut maxs = P::origin();
+
+        for i in 0..na::dimension::<P::Vector>() {
+            let slices = &self.slices[i];
+            let first = match slices.iter().position(|n| *n != 0) {
+                Some(first) => first,
+                None => return None,
+            };
+            let last = slices.iter().rposition(|n| *n != 0).unwrap();
+
+            mins[i] = self.voxel_size * na::convert(first as f64);
+            maxs[i] = self.voxel_size * na::convert((last + 1) as f64);
+        }
+
+        Some(AABB::new(mins, maxs))
+    }
+
+    fn neighbor(&self, coords: &[usize; 3], axis: usize, step: isize) -> Option<usize> {
+        let mut res = *coords;
+
+        if step < 0 {
+            if res[axis] == 0 {
+                return None;
+            }
+
+            res[axis] -= 1;
+        } else {
+            if res[axis] + 1 == self.dimensions[axis] {
+                return None;
+            }
+
+            res[axis] += 1;
+        }
+
+        Some(self.voxel_id(&res))
+    }
+
+    fn update_boundary(&mut self, id: usize) {
+        let is_boundary = self.occupied[id] && {
+            let coords = self.voxel_coords(id);
+
+            (0..na::dimension::<P::Vector>()).any(|i| {
+                [-1, 1].iter().any(|step| match self.neighbor(&coords, i, *step) {
+                    Some(neighbor) => !self.occupied[neighbor],
+                    None => true,
+                })
+            })
+        };
+
+        if is_boundary == self.boundary[id] {
+            return;
+        }
+
+        self.boundary[id] = is_boundary;
+
+        // Update the number of boundary voxels of each BVH node containing this voxel.
+        let coords = self.voxel_coords(id);
+        let mut range = self.root_range();
+        let mut node = 0;
+
+        loop {
+            if is_boundary {
+                self.counts[node] += 1;
+            } else {
+                self.counts[node] -= 1;
+            }
+
+            if range.is_single_voxel() {
+                break;
+            }
+
+            let (left, right) = range.split();
+
+            if left.contains(&coords) {
+                range = left;
+                node = node * 2 + 1;
+            } else {
+                range = right;
+                node = node * 2 + 2;
+            }
+        }
+    }
+
+    fn range_aabb(&self, range: &VoxelRange) -> AABB<P> {
+        let mut mins = P::origin();
+        let mut maxs = P::origin();
+
+        for i in 0..na::dimension::<P::Vector>() {
+            mins[i] = self.voxel_size * na::convert(range.mins[i] as f64);
+            maxs[i] = self.voxel_size * na::convert(range.maxs[i] as f64);
+        }
+
+        AABB::new(mins, maxs)
+    }
+
+    fn visit_range(
+        &self,
+        range: &VoxelRange,
+        node: usize,
+        visitor: &mut BVTVisitor<usize, AABB<P>>,
+    ) {
+        if self.counts[node] == 0 {
+            return;
+        }
+
+        if range.is_single_voxel() {
+            let id = self.voxel_id(&range.mins);
+
+            visitor.visit_leaf(&id, &self.voxel_aabb(id));
+        } else if visitor.visit_internal(&self.range_aabb(range)) {
+            let (left, right) = range.split();
+
+            self.visit_range(&left, node * 2 + 1, visitor);
+            self.visit_range(&right, node * 2 + 2, visitor);
+        }
+    }
+
+    #[inline]
+    fn root_range(&self) -> VoxelRange {
+        VoxelRange {
+            mins: [0; 3],
+            maxs: self.dimensions,
+        }
+    }
+}
+
+impl<P: Point, M: Isometry<P>> CompositeShape<P, M> for Voxels<P> {
+    #[inline(always)]
+    fn map_part_at(&self, i: usize, f: &mut FnMut(&M, &Shape<P, M>)) {
+        let one: M = na::one();
+
+        self.map_transformed_part_at(i, &one, f)
+    }
+
+    #[inline(always)]
+    fn map_transformed_part_at(&self, i: usize, m: &M, f: &mut FnMut(&M, &Shape<P, M>)) {
+        let center = self.voxel_center(i).coordinates();
+        let part_m = m.prepend_translation(&M::Translation::from_vector(center).unwrap());
+
+        f(&part_m, &self.voxel_cuboid())
+    }
+
+    #[inline]
+    fn aabb_at(&self, i: usize) -> AABB<P> {
+        self.voxel_aabb(i)
+    }
+
+    #[inline]
+    fn bvh(&self) -> &BVH<P::Real, usize, AABB<P>> {
+        self
+    }
+}
+
+/// The voxel grid is its own implicit bounding volume hierarchy: each internal node is a box of
+/// voxels, recursively split in half along its largest dimension. Nodes without any boundary
+/// voxel are skipped.
+impl<P: Point> BVH<P::Real, usize, AABB<P>> for Voxels<P> {
+    fn visit(&self, visitor: &mut BVTVisitor<usize, AABB<P>>) {
+        self.visit_range(&self.root_range(), 0, visitor)
+    }
+
+    fn best_first_search(
+        &self,
+        algorithm: &mut BVTCostFn<P::Real, usize, AABB<P>, UserData = ()>,
+    ) -> Option<usize> {
+        let mut queue: BinaryHeap<VoxelRangeWithCost<P::Real>> = BinaryHeap::new();
+        let mut best_cost = P::Real::max_value();
+        let mut result = None;
+        let root = self.root_range();
+
+        if self.counts[0] == 0 {
+            return None;
+        }
+
+        match algorithm.compute_bv_cost(&self.range_aabb(&root)) {
+            Some(cost) => queue.push(VoxelRangeWithCost::new(root, 0, -cost)),
+            None => return None,
+        }
+
+        while let Some(node) = queue.pop() {
+            if -node.cost >= best_cost {
+                break; // solution found.
+            }
+
+            if node.range.is_single_voxel() {
+                let id = self.voxel_id(&node.range.mins);
+
+                if let Some((cost, _)) = algorithm.compute_b_cost(&id) {
+                    if cost < best_cost {
+                        best_cost = cost;
+                        result = Some(id);
+                    }
+                }
+            } else {
+                let (left, right) = node.range.split();
+                let children = [(left, node.node * 2 + 1), (right, node.node * 2 + 2)];
+
+                for &(ref child, child_node) in children.iter() {
+                    if self.counts[child_node] == 0 {
+                        continue;
+                    }
+
+                    if let Some(cost) = algorithm.compute_bv_cost(&self.range_aabb(child)) {
+                        if cost < best_cost {
+                            queue.push(VoxelRangeWithCost::new(*child, child_node, -cost))
+                        }
+                    }
+                }
+            }
+        }
+
+        result
+    }
+}
+
+/// A box of voxels: integer coordinates in `mins[i]..maxs[i]` along each axis `i`.
+#[derive(Copy, Clone)]
+struct VoxelRange {
+    mins: [usize; 3],
+    maxs: [usize; 3],
+}
+
+impl VoxelRange {
+    #[inline]
+    fn is_single_voxel(&self) -> bool {
+        (0..3).all(|i| self.maxs[i] - self.mins[i] == 1)
+    }
+
+    #[inline]
+    fn contains(&self, coords: &[usize; 3]) -> bool {
+        (0..3).all(|i| coords[i] >= self.mins[i] && coords[i] < self.maxs[i])
+    }
+
+    /// Splits this range in half along its largest dimension.
+    #[inline]
+    fn split(&self) -> (VoxelRange, VoxelRange) {
+        let mut left = *self;
+        let mut right = *self;
+        let mut axis = 0;
+
+        for i in 1..3 {
+            if self.maxs[i] - self.mins[i] > self.maxs[axis] - self.mins[axis] {
+                axis = i;
+            }
+        }
+
+        let mid = (self.mins[axis] + self.maxs[axis]) / 2;
+        left.maxs[axis] = mid;
+        right.mins[axis] = mid;
+
+        (left, right)
+    }
+
+    /// The size of the heap containing the nodes of the tree obtained by splitting this range
+    /// recursively, if this range is stored at the index `node` of the heap.
+    fn heap_size(&self, node: usize) -> usize {
+        if self.is_single_voxel() {
+            node + 1
+        } else {
+            let (left, right) = self.split();
+
+            na::max(left.heap_size(node * 2 + 1), right.heap_size(node * 2 + 2))
+        }
+    }
+}
+
+struct VoxelRangeWithCost<N> {
+    range: VoxelRange,
+    node: usize,
+    cost: N,
+}
+
+impl<N> VoxelRangeWithCost<N> {
+    #[inline]
+    fn new(range: VoxelRange, node: usize, cost: N) -> VoxelRangeWithCost<N> {
+        VoxelRangeWithCost {
+            range: range,
+            node: node,
+            cost: cost,
+        }
+    }
+}
+
+impl<N: Real> PartialEq for VoxelRangeWithCost<N> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.cost.eq(&other.cost)
+    }
+}
+
+impl<N: Real> Eq for VoxelRangeWithCost<N> {}
+
+impl<N: Real> PartialOrd for VoxelRangeWithCost<N> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.cost.partial_cmp(&other.cost)
+    }
+}
+
+impl<N: Real> Ord for VoxelRangeWithCost<N> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        if self.cost < other.cost {
+            Ordering::Less
+        } else if self.cost > other.cost {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    }
+}
@@ -0,0 +1,206 @@
+use std::marker::PhantomData;
+
+use math::{Isometry, Point};
+use geometry::shape::{Ball, Capsule, Shape};
+use geometry::query::{Contact, ContactPrediction};
+use geometry::query::contacts_internal;
+use narrow_phase::{ContactDispatcher, ContactGenerator};
+
+/// Collision detector between two capsules.
+///
+/// The contact point is computed from the closest points between the capsules' segments, without
+/// relying on GJK.
+pub struct CapsuleCapsuleContactGenerator<P: Point, M> {
+    contact: Option<Contact<P>>,
+    mat_type: PhantomData<M>, // FIXME: can we avoid this?
+}
+
+impl<P: Point, M> Clone for CapsuleCapsuleContactGenerator<P, M> {
+    fn clone(&self) -> CapsuleCapsuleContactGenerator<P, M> {
+        CapsuleCapsuleContactGenerator {
+            contact: self.contact.clone(),
+            mat_type: PhantomData,
+        }
+    }
+}
+
+impl<P: Point, M> CapsuleCapsuleContactGenerator<P, M> {
+    /// Creates a new persistent collision detector between two capsules.
+    #[inline]
+    pub fn new() -> CapsuleCapsuleContactGenerator<P, M> {
+        CapsuleCapsuleContactGenerator {
+            contact: None,
+            mat_type: PhantomData,
+        }
+    }
+}
+
+impl<P: Point, M: Isometry<P>> ContactGenerator<P, M> for CapsuleCapsuleContactGenerator<P, M> {
+    fn update(
+        &mut self,
+        _: &ContactDispatcher<P, M>,
+        ma: &M,
+        a: &Shape<P, M>,
+        mb: &M,
+        b: &Shape<P, M>,
+        prediction: &ContactPrediction<P::Real>,
+    ) -> bool {
+        if let (Some(a), Some(b)) = (
+            a.as_shape::<Capsule<P::Real>>(),
+            b.as_shape::<Capsule<P::Real>>(),
+        ) {
+            self.contact =
+                contacts_internal::capsule_against_capsule(ma, a, mb, b, prediction.linear);
+
+            true
+        } else {
+            false
+        }
+    }
+
+    #[inline]
+    fn num_contacts(&self) -> usize {
+        match self.contact {
+            None => 0,
+            Some(_) => 1,
+        }
+    }
+
+    #[inline]
+    fn contacts(&self, out_contacts: &mut Vec<Contact<P>>) {
+        match self.contact {
+            Some(ref c) => out_contacts.push(c.clone()),
+            None => (),
+        }
+    }
+}
+
+/// Collision detector between a capsule and a ball.
+pub struct CapsuleBallContactGenerator<P: Point, M> {
+    contact: Option<Contact<P>>,
+    mat_type: PhantomData<M>, // FIXME: can we avoid this?
+}
+
+impl<P: Point, M> Clone for CapsuleBallContactGenerator<P, M> {
+    fn clone(&self) -> CapsuleBallContactGenerator<P, M> {
+        CapsuleBallContactGenerator {
+            contact: self.contact.clone(),
+            mat_type: PhantomData,
+        }
+    }
+}
+
+impl<P: Point, M> CapsuleBallContactGenerator<P, M> {
+    /// Creates a new persistent collision detector between a capsule and a ball.
+    #[inline]
+    pub fn new() -> CapsuleBallContactGenerator<P, M> {
+        CapsuleBallContactGenerator {
+            contact: None,
+            mat_type: PhantomData,
+        }
+    }
+}
+
+impl<P: Point, M: Isometry<P>> ContactGenerator<P, M> for CapsuleBallContactGenerator<P, M> {
+    fn update(
+        &mut self,
+        _: &ContactDispatcher<P, M>,
+        ma: &M,
+        a: &Shape<P, M>,
+        mb: &M,
+        b: &Shape<P, M>,
+        prediction: &ContactPrediction<P::Real>,
+    ) -> bool {
+        if let (Some(a), Some(b)) = (
+            a.as_shape::<Capsule<P::Real>>(),
+            b.as_shape::<Ball<P::Real>>(),
+        ) {
+            self.contact = contacts_internal::capsule_against_ball(ma, a, mb, b, prediction.linear);
+
+            true
+        } else {
+            false
+        }
+    }
+
+    #[inline]
+    fn num_contacts(&self) -> usize {
+        match self.contact {
+            None => 0,
+            Some(_) => 1,
+        }
+    }
+
+    #[inline]
+    fn contacts(&self, out_contacts: &mut Vec<Contact<P>>) {
+        match self.contact {
+            Some(ref c) => out_contacts.push(c.clone()),
+            None => (),
+        }
+    }
+}
+
+/// Collision detector between a ball and a capsule.
+pub struct BallCapsuleContactGenerator<P: Point, M> {
+    contact: Option<Contact<P>>,
+    mat_type: PhantomData<M>, // FIXME: can we avoid this?
+}
+
+impl<P: Point, M> Clone for BallCapsuleContactGenerator<P, M> {
+    fn clone(&self) -> BallCapsuleContactGenerator<P, M> {
+        BallCapsuleContactGenerator {
+            contact: self.contact.clone(),
+            mat_type: PhantomData,
+        }
+    }
+}
+
+impl<P: Point, M> BallCapsuleContactGenerator<P, M> {
+    /// Creates a new persistent collision detector between a ball and a capsule.
+    #[inline]
+    pub fn new() -> BallCapsuleContactGenerator<P, M> {
+        BallCapsuleContactGenerator {
+            contact: None,
+            mat_type: PhantomData,
+        }
+    }
+}
+
+impl<P: Point, M: Isometry<P>> ContactGenerator<P, M> for BallCapsuleContactGenerator<P, M> {
+    fn update(
+        &mut self,
+        _: &ContactDispatcher<P, M>,
+        ma: &M,
+        a: &Shape<P, M>,
+        mb: &M,
+        b: &Shape<P, M>,
+        prediction: &ContactPrediction<P::Real>,
+    ) -> bool {
+        if let (Some(a), Some(b)) = (
+            a.as_shape::<Ball<P::Real>>(),
+            b.as_shape::<Capsule<P::Real>>(),
+        ) {
+            self.contact = contacts_internal::ball_against_capsule(ma, a, mb, b, prediction.linear);
+
+            true
+        } else {
+            false
+        }
+    }
+
+    #[inline]
+    fn num_contacts(&self) -> usize {
+        match self.contact {
+            None => 0,
+            Some(_) => 1,
+        }
+    }
+
+    #[inline]
+    fn contacts(&self, out_contacts: &mut Vec<Contact<P>>) {
+        match self.contact {
+            Some(ref c) => out_contacts.push(c.clone()),
+            None => (),
+        }
+    }
+}
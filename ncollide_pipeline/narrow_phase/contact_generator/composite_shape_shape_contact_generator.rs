@@ -1,4 +1,4 @@
-use na;
+use na::{self, Unit};
 use math::{Isometry, Point};
 use utils::data::hash_map::HashMap;
 use utils::data::hash::UintTWHash;
@@ -9,10 +9,18 @@ use geometry::query::{Contact, ContactPrediction};
 use narrow_phase::{ContactAlgorithm, ContactDispatcher, ContactGenerator};
 
 /// Collision detector between a concave shape and another shape.
+///
+/// Contacts with the back face of one-sided parts of the concave shape (see
+/// `CompositeShape::part_front_normal`) are discarded, and the normals of the other contacts are
+/// corrected by `CompositeShape::adjust_part_contact_normal`. Each contact records the index of
+/// the part of the concave shape it involves.
 pub struct CompositeShapeShapeContactGenerator<P: Point, M> {
-    sub_detectors: HashMap<usize, ContactAlgorithm<P, M>, UintTWHash>,
+    // Each sub-detector is stored with its post-processed contacts.
+    sub_detectors: HashMap<usize, (ContactAlgorithm<P, M>, Vec<Contact<P>>), UintTWHash>,
     to_delete: Vec<usize>,
     interferences: Vec<usize>,
+    // Whether the sub-detectors must be recreated because the composite shape has been deformed.
+    deformed: bool,
 }
 
 impl<P: Point, M> CompositeShapeShapeContactGenerator<P, M> {
@@ -22,6 +30,7 @@ impl<P: Point, M> CompositeShapeShapeContactGenerator<P, M> {
             sub_detectors: HashMap::new_with_capacity(5, UintTWHash::new()),
             to_delete: Vec::new(),
             interferences: Vec::new(),
+            deformed: false,
         }
     }
 }
@@ -37,6 +46,12 @@ impl<P: Point, M: Isometry<P>> CompositeShapeShapeContactGenerator<P, M> {
         prediction: &ContactPrediction<P::Real>,
         swap: bool,
     ) {
+        // The parts of a deformed shape may have been replaced or moved.
+        if self.deformed {
+            self.sub_detectors.clear();
+            self.deformed = false;
+        }
+
         // Find new collisions
         let ls_m2 = na::inverse(m1) * m2.clone();
         let ls_aabb2 = bounding_volume::aabb(g2, &ls_m2).loosened(prediction.linear);
@@ -44,7 +59,7 @@ impl<P: Point, M: Isometry<P>> CompositeShapeShapeContactGenerator<P, M> {
         {
             let mut visitor =
                 BoundingVolumeInterferencesCollector::new(&ls_aabb2, &mut self.interferences);
-            g1.bvt().visit(&mut visitor);
+            g1.bvh().visit(&mut visitor);
         }
 
         for i in self.interferences.iter() {
@@ -59,33 +74,36 @@ impl<P: Point, M: Isometry<P>> CompositeShapeShapeContactGenerator<P, M> {
                     }
                 });
 
-                new_detector
+                new_detector.map(|detector| (detector, Vec::new()))
             });
         }
 
-        self.interferences.clear();
+        // Parts that are not reported by the BVH are either too far, or have been removed from
+        // the composite shape.
+        self.interferences.sort();
 
         // Update all collisions
         for detector in self.sub_detectors.elements_mut().iter_mut() {
             let key = detector.key;
-            if ls_aabb2.intersects(&g1.aabb_at(key)) {
+            if self.interferences.binary_search(&key).is_ok() {
+                let (ref mut algorithm, ref mut contacts) = detector.value;
                 g1.map_transformed_part_at(key, m1, &mut |m1, g1| {
                     if swap {
                         assert!(
-                            detector
-                                .value
-                                .update(dispatcher, m2, g2, m1, g1, prediction),
+                            algorithm.update(dispatcher, m2, g2, m1, g1, prediction),
                             "Internal error: the shape was no longer valid."
                         );
                     } else {
                         assert!(
-                            detector
-                                .value
-                                .update(dispatcher, m1, g1, m2, g2, prediction),
+                            algorithm.update(dispatcher, m1, g1, m2, g2, prediction),
                             "Internal error: the shape was no longer valid."
                         );
                     }
                 });
+
+                contacts.clear();
+                algorithm.contacts(contacts);
+                postprocess_contacts(g1, key, m1, swap, contacts);
             } else {
                 // FIXME: ask the detector if it wants to be removed or not
                 self.to_delete.push(key);
@@ -98,7 +116,67 @@ impl<P: Point, M: Isometry<P>> CompositeShapeShapeContactGenerator<P, M> {
         }
 
         self.to_delete.clear();
+        self.interferences.clear();
+    }
+}
+
+// Discards or corrects the contacts with the i-th part of the composite shape `g1`.
+fn postprocess_contacts<P: Point, M: Isometry<P>>(
+    g1: &CompositeShape<P, M>,
+    i: usize,
+    m1: &M,
+    swap: bool,
+    contacts: &mut Vec<Contact<P>>,
+) {
+    let front = g1.part_front_normal(i);
+    let mut k = 0;
+
+    while k < contacts.len() {
+        if postprocess_contact(g1, i, m1, &front, swap, &mut contacts[k]) {
+            k += 1;
+        } else {
+            let _ = contacts.swap_remove(k);
+        }
+    }
+}
+
+// Returns `false` if the contact should be discarded.
+fn postprocess_contact<P: Point, M: Isometry<P>>(
+    g1: &CompositeShape<P, M>,
+    i: usize,
+    m1: &M,
+    front: &Option<Unit<P::Vector>>,
+    swap: bool,
+    contact: &mut Contact<P>,
+) -> bool {
+    // Make the contact go from the part toward the other shape.
+    if swap {
+        contact.flip();
     }
+
+    // The feature identifier is the one of the innermost shape, while the part index is the one
+    // of this composite shape.
+    contact.part1 = Some(i);
+
+    let ls_point = m1.inverse_transform_point(&contact.world1);
+    let ls_normal = Unit::new_unchecked(m1.inverse_rotate_vector(&*contact.normal));
+    let mut new_ls_normal = ls_normal;
+
+    let keep = match *front {
+        Some(ref n) if na::dot(&**n, &*ls_normal) < na::zero() => false,
+        _ => g1.adjust_part_contact_normal(i, &ls_point, &mut new_ls_normal),
+    };
+
+    if keep && new_ls_normal != ls_normal {
+        contact.normal = Unit::new_unchecked(m1.rotate_vector(&*new_ls_normal));
+        contact.depth = na::dot(&(contact.world1 - contact.world2), &*contact.normal);
+    }
+
+    if swap {
+        contact.flip();
+    }
+
+    keep
 }
 
 /// Collision detector between a shape and a concave shape.
@@ -139,7 +217,7 @@ impl<P: Point, M: Isometry<P>> ContactGenerator<P, M>
         let mut res = 0;
 
         for detector in self.sub_detectors.elements().iter() {
-            res = res + detector.value.num_contacts()
+            res = res + detector.value.1.len()
         }
 
         res
@@ -147,9 +225,13 @@ impl<P: Point, M: Isometry<P>> ContactGenerator<P, M>
 
     fn contacts(&self, out: &mut Vec<Contact<P>>) {
         for detector in self.sub_detectors.elements().iter() {
-            detector.value.contacts(out);
+            out.extend(detector.value.1.iter().cloned());
         }
     }
+
+    fn handle_shape_deformation(&mut self) {
+        self.deformed = true
+    }
 }
 
 impl<P: Point, M: Isometry<P>> ContactGenerator<P, M>
@@ -180,4 +262,8 @@ impl<P: Point, M: Isometry<P>> ContactGenerator<P, M>
     fn contacts(&self, out: &mut Vec<Contact<P>>) {
         self.sub_detector.contacts(out)
     }
+
+    fn handle_shape_deformation(&mut self) {
+        self.sub_detector.handle_shape_deformation()
+    }
 }
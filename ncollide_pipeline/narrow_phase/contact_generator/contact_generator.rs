@@ -24,6 +24,12 @@ pub trait ContactGenerator<P: Point, M>: Any + Send + Sync {
 
     /// Collects the contacts generated during the last update.
     fn contacts(&self, &mut Vec<Contact<P>>);
+
+    /// Notifies this algorithm that one of its shapes has been modified in-place.
+    ///
+    /// The data cached about the parts of this shape are discarded during the next update while
+    /// the current contacts are kept until then. Does nothing by default.
+    fn handle_shape_deformation(&mut self) {}
 }
 
 pub type ContactAlgorithm<P, M> = Box<ContactGenerator<P, M>>;
@@ -1,15 +1,25 @@
 use std::marker::PhantomData;
 use na;
 use math::{Isometry, Point};
-use geometry::shape::{Ball, Plane, Shape};
+use geometry::shape::{Ball, Capsule, Plane, Shape, Torus};
+use geometry::query::{self, QueryRegistry};
 use geometry::query::algorithms::{JohnsonSimplex, VoronoiSimplex2, VoronoiSimplex3};
-use narrow_phase::{BallBallContactGenerator, CompositeShapeShapeContactGenerator,
-                   ContactAlgorithm, ContactDispatcher, OneShotContactManifoldGenerator,
-                   PlaneSupportMapContactGenerator, ShapeCompositeShapeContactGenerator,
-                   SupportMapPlaneContactGenerator, SupportMapSupportMapContactGenerator};
+use narrow_phase::{BallBallContactGenerator, BallCapsuleContactGenerator,
+                   CapsuleBallContactGenerator, CapsuleCapsuleContactGenerator,
+                   CompositeShapeShapeContactGenerator, ContactAlgorithm, ContactDispatcher,
+                   OneShotContactManifoldGenerator, PlaneSupportMapContactGenerator,
+                   PolytopePolytopeContactGenerator, RegisteredContactGenerator,
+                   RoundShapeContactGenerator, ShapeCompositeShapeContactGenerator,
+                   SupportMapPlaneContactGenerator, SupportMapSupportMapContactGenerator,
+                   SupportMapTorusContactGenerator, TorusSupportMapContactGenerator};
 
 /// Collision dispatcher for shapes defined by `ncollide_entities`.
+///
+/// The contact functions registered on the global query registry (see
+/// `query::modify_query_registry`) take precedence over the built-in contact generators.
+/// Pairs of convex polytopes, e.g., cuboids, use the `PolytopePolytopeContactGenerator`.
 pub struct DefaultContactDispatcher<P: Point, M> {
+    use_mpr: bool,
     _point_type: PhantomData<P>,
     _matrix_type: PhantomData<M>,
 }
@@ -18,10 +28,28 @@ impl<P: Point, M> DefaultContactDispatcher<P, M> {
     /// Creates a new basic collision dispatcher.
     pub fn new() -> DefaultContactDispatcher<P, M> {
         DefaultContactDispatcher {
+            use_mpr: false,
             _point_type: PhantomData,
             _matrix_type: PhantomData,
         }
     }
+
+    /// Sets whether the support-map contact generators created by this dispatcher use the MPR
+    /// algorithm instead of GJK and EPA.
+    ///
+    /// See `SupportMapSupportMapContactGenerator::set_use_mpr`. This only affects the contact
+    /// generators created afterward.
+    #[inline]
+    pub fn set_use_mpr(&mut self, use_mpr: bool) {
+        self.use_mpr = use_mpr
+    }
+
+    /// Whether the support-map contact generators created by this dispatcher use the MPR
+    /// algorithm.
+    #[inline]
+    pub fn uses_mpr(&self) -> bool {
+        self.use_mpr
+    }
 }
 
 impl<P: Point, M: Isometry<P>> ContactDispatcher<P, M> for DefaultContactDispatcher<P, M> {
@@ -32,9 +60,22 @@ impl<P: Point, M: Isometry<P>> ContactDispatcher<P, M> for DefaultContactDispatc
     ) -> Option<ContactAlgorithm<P, M>> {
         let a_is_ball = a.is_shape::<Ball<P::Real>>();
         let b_is_ball = b.is_shape::<Ball<P::Real>>();
+        let a_is_capsule = a.is_shape::<Capsule<P::Real>>();
+        let b_is_capsule = b.is_shape::<Capsule<P::Real>>();
+        let registered = query::read_query_registry(|r: &QueryRegistry<P, M>| r.has_contact(a, b));
 
-        if a_is_ball && b_is_ball {
+        if registered == Some(true) {
+            Some(Box::new(RegisteredContactGenerator::<P, M>::new()))
+        } else if a_is_ball && b_is_ball {
             Some(Box::new(BallBallContactGenerator::<P, M>::new()))
+        } else if a_is_capsule && b_is_capsule {
+            let wo_manifold = CapsuleCapsuleContactGenerator::<P, M>::new();
+            let manifold = OneShotContactManifoldGenerator::new(wo_manifold);
+            Some(Box::new(manifold))
+        } else if a_is_capsule && b_is_ball {
+            Some(Box::new(CapsuleBallContactGenerator::<P, M>::new()))
+        } else if a_is_ball && b_is_capsule {
+            Some(Box::new(BallCapsuleContactGenerator::<P, M>::new()))
         } else if a.is_shape::<Plane<P::Vector>>() && b.is_support_map() {
             let wo_manifold = PlaneSupportMapContactGenerator::<P, M>::new();
 
@@ -53,11 +94,28 @@ impl<P: Point, M: Isometry<P>> ContactDispatcher<P, M> for DefaultContactDispatc
             } else {
                 Some(Box::new(wo_manifold))
             }
+        } else if a.is_shape::<Torus<P::Real>>() && b.is_support_map() {
+            let wo_manifold = TorusSupportMapContactGenerator::<P, M>::new();
+            let manifold = OneShotContactManifoldGenerator::new(wo_manifold);
+            Some(Box::new(manifold))
+        } else if b.is_shape::<Torus<P::Real>>() && a.is_support_map() {
+            let wo_manifold = SupportMapTorusContactGenerator::<P, M>::new();
+            let manifold = OneShotContactManifoldGenerator::new(wo_manifold);
+            Some(Box::new(manifold))
+        } else if a.is_convex_polytope() && b.is_convex_polytope() {
+            Some(Box::new(PolytopePolytopeContactGenerator::<P, M>::new()))
+        } else if (a.is_round_shape() && b.is_support_map())
+            || (a.is_support_map() && b.is_round_shape())
+        {
+            let wo_manifold = RoundShapeContactGenerator::<P, M>::new();
+            let manifold = OneShotContactManifoldGenerator::new(wo_manifold);
+            Some(Box::new(manifold))
         } else if a.is_support_map() && b.is_support_map() {
             match na::dimension::<P::Vector>() {
                 2 => {
                     let simplex = VoronoiSimplex2::new();
-                    let wo_manifold = SupportMapSupportMapContactGenerator::new(simplex);
+                    let mut wo_manifold = SupportMapSupportMapContactGenerator::new(simplex);
+                    wo_manifold.set_use_mpr(self.use_mpr);
 
                     if !a_is_ball && !b_is_ball {
                         let manifold = OneShotContactManifoldGenerator::new(wo_manifold);
@@ -68,7 +126,8 @@ impl<P: Point, M: Isometry<P>> ContactDispatcher<P, M> for DefaultContactDispatc
                 }
                 3 => {
                     let simplex = VoronoiSimplex3::new();
-                    let wo_manifold = SupportMapSupportMapContactGenerator::new(simplex);
+                    let mut wo_manifold = SupportMapSupportMapContactGenerator::new(simplex);
+                    wo_manifold.set_use_mpr(self.use_mpr);
 
                     if !a_is_ball && !b_is_ball {
                         let manifold = OneShotContactManifoldGenerator::new(wo_manifold);
@@ -79,7 +138,8 @@ impl<P: Point, M: Isometry<P>> ContactDispatcher<P, M> for DefaultContactDispatc
                 }
                 _ => {
                     let simplex = JohnsonSimplex::new_w_tls();
-                    let wo_manifold = SupportMapSupportMapContactGenerator::new(simplex);
+                    let mut wo_manifold = SupportMapSupportMapContactGenerator::new(simplex);
+                    wo_manifold.set_use_mpr(self.use_mpr);
 
                     if false {
                         // !a_is_ball && !b_is_ball {
@@ -29,7 +29,8 @@ impl<P: Point> ContactWLocals<P> {
 /// One contact is added per update until the maximum number of contact is reached. When the
 /// maximum number of contact is reached, each time a new contact is created, the new manifold is
 /// computed by maximizing the variance along each canonical axis (of the space in which leaves the
-/// contacts).
+/// contacts). A new contact involving the same features as an existing one (see
+/// `Contact::has_same_features`) replaces it instead.
 #[derive(Clone)]
 pub struct IncrementalContactManifoldGenerator<P: Point, M, CD> {
     contacts: Vec<ContactWLocals<P>>, // FIXME: replace by a vec slice to avoid allocations ?
@@ -112,16 +113,8 @@ where
         } else {
             self.sub_detector.contacts(&mut self.collector);
 
-            // remove duplicates
-            let _max_num_contact = (na::dimension::<P::Vector>() - 1) * 2;
-
             for c in self.collector.iter() {
-                if self.contacts.len() == _max_num_contact {
-                    add_reduce_by_variance(&mut self.contacts[..], c.clone(), m1, m2)
-                } else {
-                    self.contacts
-                        .push(ContactWLocals::new_with_contact(c.clone(), m1, m2))
-                }
+                add_contact(&mut self.contacts, c.clone(), m1, m2)
             }
 
             self.collector.clear();
@@ -130,6 +123,14 @@ where
         }
     }
 
+    /// Adds a contact to the current manifold.
+    ///
+    /// The contact replaces an existing one involving the same features. If the maximum number of
+    /// contacts is reached, the manifold is reduced by maximizing its variance.
+    pub fn add_contact(&mut self, contact: Contact<P>, m1: &M, m2: &M) {
+        add_contact(&mut self.contacts, contact, m1, m2)
+    }
+
     /// Updates the contacts already existing on this manifold.
     pub fn update_contacts(&mut self, m1: &M, m2: &M, prediction: P::Real) {
         // cleanup existing contacts
@@ -198,6 +199,29 @@ where
     }
 }
 
+fn add_contact<P, M>(contacts: &mut Vec<ContactWLocals<P>>, contact: Contact<P>, m1: &M, m2: &M)
+where
+    P: Point,
+    M: Isometry<P>,
+{
+    // remove duplicates
+    let _max_num_contact = (na::dimension::<P::Vector>() - 1) * 2;
+
+    // A contact between the same features replaces the existing one so that its identifier
+    // remains stable from one update to the next.
+    let same = contacts
+        .iter()
+        .position(|old| old.contact.has_same_features(&contact));
+
+    if let Some(k) = same {
+        contacts[k] = ContactWLocals::new_with_contact(contact, m1, m2)
+    } else if contacts.len() == _max_num_contact {
+        add_reduce_by_variance(&mut contacts[..], contact, m1, m2)
+    } else {
+        contacts.push(ContactWLocals::new_with_contact(contact, m1, m2))
+    }
+}
+
 fn add_reduce_by_variance<P, M>(pts: &mut [ContactWLocals<P>], to_add: Contact<P>, m1: &M, m2: &M)
 where
     P: Point,
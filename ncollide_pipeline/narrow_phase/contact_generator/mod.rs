@@ -3,21 +3,34 @@
 pub use self::contact_generator::{ContactAlgorithm, ContactDispatcher, ContactGenerator};
 pub use self::default_contact_dispatcher::DefaultContactDispatcher;
 pub use self::ball_ball_contact_generator::BallBallContactGenerator;
+pub use self::capsule_contact_generator::{BallCapsuleContactGenerator,
+                                          CapsuleBallContactGenerator,
+                                          CapsuleCapsuleContactGenerator};
 pub use self::plane_support_map_contact_generator::{PlaneSupportMapContactGenerator,
                                                     SupportMapPlaneContactGenerator};
 pub use self::support_map_support_map_contact_generator::SupportMapSupportMapContactGenerator;
+pub use self::polytope_polytope_contact_generator::PolytopePolytopeContactGenerator;
+pub use self::torus_support_map_contact_generator::{SupportMapTorusContactGenerator,
+                                                    TorusSupportMapContactGenerator};
+pub use self::round_shape_contact_generator::RoundShapeContactGenerator;
 pub use self::incremental_contact_manifold_generator::IncrementalContactManifoldGenerator;
 pub use self::one_shot_contact_manifold_generator::OneShotContactManifoldGenerator;
 pub use self::composite_shape_shape_contact_generator::{CompositeShapeShapeContactGenerator,
                                                         ShapeCompositeShapeContactGenerator};
+pub use self::registered_contact_generator::RegisteredContactGenerator;
 
 // FIXME: un-hide this and move everything to a folder.
 #[doc(hidden)]
 pub mod contact_generator;
 mod default_contact_dispatcher;
 mod ball_ball_contact_generator;
+mod capsule_contact_generator;
 mod plane_support_map_contact_generator;
 mod support_map_support_map_contact_generator;
+mod polytope_polytope_contact_generator;
+mod torus_support_map_contact_generator;
+mod round_shape_contact_generator;
 mod incremental_contact_manifold_generator;
 mod one_shot_contact_manifold_generator;
 mod composite_shape_shape_contact_generator;
+mod registered_contact_generator;
@@ -1,18 +1,26 @@
-use alga::linear::{FiniteDimInnerSpace, Rotation};
-use na;
+use na::{self, Unit};
 use math::{Isometry, Point};
-use geometry::shape::Shape;
+use geometry::shape::{FeatureId, Plane, Shape, SupportAreaFeatures};
 use geometry::query::{Contact, ContactPrediction};
+use geometry::query::contacts_internal;
 use narrow_phase::{ContactDispatcher, ContactGenerator, IncrementalContactManifoldGenerator};
 
 /// Contact manifold generator producing a full manifold at the first update.
 ///
 /// Whenever a new contact is detected (i.e. when the current manifold is empty) a full manifold is
-/// generated. Then, the manifold is incrementally updated by an
-/// `IncrementalContactManifoldGenerator`.
+/// generated by clipping the support areas of both shapes along the contact normal (see
+/// `SupportMap::support_area_features_toward`). The angular tolerance of those areas is given by
+/// the angular parts of the `ContactPrediction`. Then, the manifold is incrementally updated by an
+/// `IncrementalContactManifoldGenerator`, which matches the new contacts with the existing ones
+/// by their feature identifiers.
 #[derive(Clone)]
 pub struct OneShotContactManifoldGenerator<P: Point, M, CD> {
     sub_detector: IncrementalContactManifoldGenerator<P, M, CD>,
+    area1: Vec<P>,
+    area2: Vec<P>,
+    features1: SupportAreaFeatures,
+    features2: SupportAreaFeatures,
+    manifold: Vec<Contact<P>>,
 }
 
 impl<P, M, CD> OneShotContactManifoldGenerator<P, M, CD>
@@ -24,17 +32,15 @@ where
     pub fn new(cd: CD) -> OneShotContactManifoldGenerator<P, M, CD> {
         OneShotContactManifoldGenerator {
             sub_detector: IncrementalContactManifoldGenerator::new(cd),
+            area1: Vec::new(),
+            area2: Vec::new(),
+            features1: SupportAreaFeatures::new(),
+            features2: SupportAreaFeatures::new(),
+            manifold: Vec::new(),
         }
     }
 }
 
-static ROT_ERROR: &'static str = "The provided transformation does not have enough \
-                                  rotational degree of freedom for to work ith the \
-                                  one-shot contact manifold generator.";
-static TR_ERROR: &'static str = "The provided transformation does not have enough    \
-                                 translational degree of freedom for to work ith the \
-                                 one-shot contact manifold generator.";
-
 impl<P, M, CD> ContactGenerator<P, M> for OneShotContactManifoldGenerator<P, M, CD>
 where
     P: Point,
@@ -56,41 +62,48 @@ where
                 .get_sub_collision(d, m1, g1, m2, g2, prediction)
             {
                 Some(Some(coll)) => {
-                    P::Vector::orthonormal_subspace_basis(&[*coll.normal], |b| {
-                        let perturbation = M::Rotation::scaled_rotation_between(
-                            &coll.normal,
-                            &b,
-                            na::convert(0.01),
-                        ).expect(ROT_ERROR);
-                        let shifted_m1 = m1.append_rotation_wrt_point(&perturbation, &coll.world1)
-                            .expect(TR_ERROR);
+                    self.area1.clear();
+                    self.area2.clear();
+                    self.features1.clear();
+                    self.features2.clear();
+                    self.manifold.clear();
 
-                        let _ = self.sub_detector.add_new_contacts(
-                            d,
-                            &shifted_m1,
-                            g1,
-                            m2,
-                            g2,
-                            prediction,
-                        );
+                    support_area(
+                        m1,
+                        g1,
+                        &coll.normal,
+                        prediction.angular1,
+                        &coll.world1,
+                        &mut self.area1,
+                        &mut self.features1,
+                    );
+                    support_area(
+                        m2,
+                        g2,
+                        &-coll.normal,
+                        prediction.angular2,
+                        &coll.world2,
+                        &mut self.area2,
+                        &mut self.features2,
+                    );
 
-                        // second perturbation (opposite direction)
-                        let shifted_m1 =
-                            m1.append_rotation_wrt_point(&na::inverse(&perturbation), &coll.world1)
-                                .expect(TR_ERROR);
-                        let _ = self.sub_detector.add_new_contacts(
-                            d,
-                            &shifted_m1,
-                            g1,
-                            m2,
-                            g2,
-                            prediction,
-                        );
+                    let clipped = contacts_internal::support_area_against_support_area(
+                        &coll,
+                        &self.area1,
+                        &self.features1,
+                        &self.area2,
+                        &self.features2,
+                        prediction.linear,
+                        &mut self.manifold,
+                    );
 
-                        true
-                    });
+                    if !clipped || self.manifold.is_empty() {
+                        self.manifold.push(coll)
+                    }
 
-                    self.sub_detector.update_contacts(m1, m2, prediction.linear);
+                    for contact in self.manifold.drain(..) {
+                        self.sub_detector.add_contact(contact, m1, m2)
+                    }
 
                     true
                 }
@@ -113,3 +126,30 @@ where
         self.sub_detector.contacts(out_contacts)
     }
 }
+
+// Pushes to `out` the support area of `g` toward `dir`, and its features to `features`. It is left
+// empty for planes, and reduced to the contact point `pt` for shapes without a support mapping.
+fn support_area<P, M>(
+    m: &M,
+    g: &Shape<P, M>,
+    dir: &Unit<P::Vector>,
+    angle: P::Real,
+    pt: &P,
+    out: &mut Vec<P>,
+    features: &mut SupportAreaFeatures,
+) where
+    P: Point,
+    M: Isometry<P>,
+{
+    // Small tolerance so that faces are detected despite numerical errors.
+    let _min_angle: P::Real = na::convert(0.01);
+    let angle = if angle > _min_angle { angle } else { _min_angle };
+
+    if let Some(s) = g.as_support_map() {
+        s.support_area_features_toward(m, dir, angle, out, features)
+    } else if g.is_shape::<Plane<P::Vector>>() {
+        features.area = FeatureId::Face(0)
+    } else {
+        out.push(*pt)
+    }
+}
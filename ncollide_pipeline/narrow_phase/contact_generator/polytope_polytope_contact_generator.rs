@@ -0,0 +1,76 @@
+use std::marker::PhantomData;
+
+use math::{Isometry, Point};
+use geometry::shape::Shape;
+use geometry::query::{Contact, ContactPrediction};
+use geometry::query::contacts_internal::{self, SeparatingAxis};
+use narrow_phase::{ContactDispatcher, ContactGenerator};
+
+/// Collision detector between two convex polytopes, e.g., `Cuboid` or `ConvexPolyhedron`.
+///
+/// It is based on the separating axis theorem. The full contact manifold is generated at once by
+/// clipping the incident face of one polytope against the reference face of the other. The axis
+/// of largest separation is cached so that separated polytopes are usually rejected by testing
+/// this single axis.
+#[derive(Clone)]
+pub struct PolytopePolytopeContactGenerator<P: Point, M> {
+    contacts: Vec<Contact<P>>,
+    axis: SeparatingAxis,
+    mat_type: PhantomData<M>, // FIXME: can we avoid this?
+}
+
+impl<P: Point, M> PolytopePolytopeContactGenerator<P, M> {
+    /// Creates a new persistent collision detector between two convex polytopes.
+    #[inline]
+    pub fn new() -> PolytopePolytopeContactGenerator<P, M> {
+        PolytopePolytopeContactGenerator {
+            contacts: Vec::new(),
+            axis: SeparatingAxis::Unknown,
+            mat_type: PhantomData,
+        }
+    }
+}
+
+impl<P: Point, M: Isometry<P>> ContactGenerator<P, M> for PolytopePolytopeContactGenerator<P, M> {
+    fn update(
+        &mut self,
+        _: &ContactDispatcher<P, M>,
+        ma: &M,
+        a: &Shape<P, M>,
+        mb: &M,
+        b: &Shape<P, M>,
+        prediction: &ContactPrediction<P::Real>,
+    ) -> bool {
+        if let (Some(pa), Some(pb)) = (a.as_convex_polytope(), b.as_convex_polytope()) {
+            self.contacts.clear();
+            contacts_internal::polytope_against_polytope_with_cache(
+                ma,
+                pa,
+                mb,
+                pb,
+                prediction.linear,
+                &mut self.axis,
+                &mut self.contacts,
+            );
+
+            true
+        } else {
+            false
+        }
+    }
+
+    #[inline]
+    fn handle_shape_deformation(&mut self) {
+        self.axis = SeparatingAxis::Unknown;
+    }
+
+    #[inline]
+    fn num_contacts(&self) -> usize {
+        self.contacts.len()
+    }
+
+    #[inline]
+    fn contacts(&self, out_contacts: &mut Vec<Contact<P>>) {
+        out_contacts.extend_from_slice(&self.contacts[..])
+    }
+}
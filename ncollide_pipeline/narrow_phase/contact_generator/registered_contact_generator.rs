@@ -0,0 +1,72 @@
+use std::marker::PhantomData;
+
+use math::{Isometry, Point};
+use geometry::shape::Shape;
+use geometry::query::{self, Contact, ContactPrediction, QueryRegistry};
+use narrow_phase::{ContactDispatcher, ContactGenerator};
+
+/// Collision detector between two shapes using the contact function registered for them on the
+/// global query registry.
+pub struct RegisteredContactGenerator<P: Point, M> {
+    contact: Option<Contact<P>>,
+    mat_type: PhantomData<M>, // FIXME: can we avoid this?
+}
+
+impl<P: Point, M> Clone for RegisteredContactGenerator<P, M> {
+    fn clone(&self) -> RegisteredContactGenerator<P, M> {
+        RegisteredContactGenerator {
+            contact: self.contact.clone(),
+            mat_type: PhantomData,
+        }
+    }
+}
+
+impl<P: Point, M> RegisteredContactGenerator<P, M> {
+    /// Creates a new persistent collision detector using the global query registry.
+    #[inline]
+    pub fn new() -> RegisteredContactGenerator<P, M> {
+        RegisteredContactGenerator {
+            contact: None,
+            mat_type: PhantomData,
+        }
+    }
+}
+
+impl<P: Point, M: Isometry<P>> ContactGenerator<P, M> for RegisteredContactGenerator<P, M> {
+    fn update(
+        &mut self,
+        _: &ContactDispatcher<P, M>,
+        ma: &M,
+        a: &Shape<P, M>,
+        mb: &M,
+        b: &Shape<P, M>,
+        prediction: &ContactPrediction<P::Real>,
+    ) -> bool {
+        let registered = query::read_query_registry(|r: &QueryRegistry<P, M>| r.has_contact(a, b));
+
+        if registered == Some(true) {
+            // The contact query consults the registry first.
+            self.contact = query::contact(ma, a, mb, b, prediction.linear);
+
+            true
+        } else {
+            false
+        }
+    }
+
+    #[inline]
+    fn num_contacts(&self) -> usize {
+        match self.contact {
+            None => 0,
+            Some(_) => 1,
+        }
+    }
+
+    #[inline]
+    fn contacts(&self, out_contacts: &mut Vec<Contact<P>>) {
+        match self.contact {
+            Some(ref c) => out_contacts.push(c.clone()),
+            None => (),
+        }
+    }
+}
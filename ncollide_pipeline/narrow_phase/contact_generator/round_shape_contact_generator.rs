@@ -0,0 +1,83 @@
+use std::marker::PhantomData;
+
+use math::{Isometry, Point};
+use geometry::shape::Shape;
+use geometry::query::{Contact, ContactPrediction};
+use geometry::query::contacts_internal;
+use narrow_phase::{ContactDispatcher, ContactGenerator};
+
+/// Collision detector between two support-mapped shapes, at least one of them having rounded
+/// borders.
+///
+/// The contact point is computed by GJK on the core shapes and then shifted by the border radii.
+pub struct RoundShapeContactGenerator<P: Point, M> {
+    contact: Option<Contact<P>>,
+    mat_type: PhantomData<M>, // FIXME: can we avoid this?
+}
+
+impl<P: Point, M> Clone for RoundShapeContactGenerator<P, M> {
+    fn clone(&self) -> RoundShapeContactGenerator<P, M> {
+        RoundShapeContactGenerator {
+            contact: self.contact.clone(),
+            mat_type: PhantomData,
+        }
+    }
+}
+
+impl<P: Point, M> RoundShapeContactGenerator<P, M> {
+    /// Creates a new persistent collision detector between two rounded shapes.
+    #[inline]
+    pub fn new() -> RoundShapeContactGenerator<P, M> {
+        RoundShapeContactGenerator {
+            contact: None,
+            mat_type: PhantomData,
+        }
+    }
+}
+
+impl<P: Point, M: Isometry<P>> ContactGenerator<P, M> for RoundShapeContactGenerator<P, M> {
+    fn update(
+        &mut self,
+        _: &ContactDispatcher<P, M>,
+        ma: &M,
+        a: &Shape<P, M>,
+        mb: &M,
+        b: &Shape<P, M>,
+        prediction: &ContactPrediction<P::Real>,
+    ) -> bool {
+        if let (Some((a, ra)), Some((b, rb))) = (
+            a.as_support_map_with_border(),
+            b.as_support_map_with_border(),
+        ) {
+            self.contact = contacts_internal::round_shape_against_round_shape(
+                ma,
+                a,
+                ra,
+                mb,
+                b,
+                rb,
+                prediction.linear,
+            );
+
+            true
+        } else {
+            false
+        }
+    }
+
+    #[inline]
+    fn num_contacts(&self) -> usize {
+        match self.contact {
+            None => 0,
+            Some(_) => 1,
+        }
+    }
+
+    #[inline]
+    fn contacts(&self, out_contacts: &mut Vec<Contact<P>>) {
+        match self.contact {
+            Some(ref c) => out_contacts.push(c.clone()),
+            None => (),
+        }
+    }
+}
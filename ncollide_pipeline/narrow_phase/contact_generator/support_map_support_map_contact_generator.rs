@@ -9,8 +9,9 @@ use narrow_phase::{ContactDispatcher, ContactGenerator};
 
 /// Persistent collision detector between two shapes having a support mapping function.
 ///
-/// It is based on the GJK algorithm.  This detector generates only one contact point. For a full
-/// manifold generation, see `IncrementalContactManifoldGenerator`.
+/// It is based on the GJK algorithm, or on the MPR algorithm if enabled with `set_use_mpr`.  This
+/// detector generates only one contact point. For a full manifold generation, see
+/// `IncrementalContactManifoldGenerator`.
 #[derive(Clone)]
 pub struct SupportMapSupportMapContactGenerator<P: Point, M, S> {
     simplex: S,
@@ -18,6 +19,7 @@ pub struct SupportMapSupportMapContactGenerator<P: Point, M, S> {
     contact_manifold: Vec<Contact<P>>,
     manifold1: Vec<P>,
     manifold2: Vec<P>,
+    use_mpr: bool,
     mat_type: PhantomData<M>, // FIXME: can we avoid this?
 }
 
@@ -37,9 +39,24 @@ where
             contact_manifold: Vec::new(),
             manifold1: Vec::new(),
             manifold2: Vec::new(),
+            use_mpr: false,
             mat_type: PhantomData,
         }
     }
+
+    /// Sets whether the MPR algorithm should be used instead of GJK and EPA.
+    ///
+    /// MPR is faster but computes only an approximation of the penetration depth.
+    #[inline]
+    pub fn set_use_mpr(&mut self, use_mpr: bool) {
+        self.use_mpr = use_mpr
+    }
+
+    /// Whether this contact generator uses the MPR algorithm instead of GJK and EPA.
+    #[inline]
+    pub fn uses_mpr(&self) -> bool {
+        self.use_mpr
+    }
 }
 
 impl<P, M, S> ContactGenerator<P, M> for SupportMapSupportMapContactGenerator<P, M, S>
@@ -59,6 +76,18 @@ where
         prediction: &ContactPrediction<P::Real>,
     ) -> bool {
         if let (Some(sma), Some(smb)) = (a.as_support_map(), b.as_support_map()) {
+            if self.use_mpr {
+                self.contact = contacts_internal::support_map_against_support_map_mpr(
+                    ma,
+                    sma,
+                    mb,
+                    smb,
+                    prediction.linear,
+                );
+
+                return true;
+            }
+
             let initial_direction = match self.contact {
                 GJKResult::NoIntersection(ref separator) => Some(separator.clone()),
                 GJKResult::Projection(ref contact) => Some(contact.normal.unwrap()),
@@ -183,6 +183,20 @@ impl<P: Point, M: 'static, T> NarrowPhase<P, M, T> for DefaultNarrowPhase<P, M>
         let _ = self.contact_generators.remove(&key);
     }
 
+    fn handle_shape_deformation(&mut self, handle: CollisionObjectHandle) {
+        for (key, detector) in self.contact_generators.iter_mut() {
+            if key.0 == handle || key.1 == handle {
+                detector.handle_shape_deformation()
+            }
+        }
+
+        for (key, detector) in self.proximity_detectors.iter_mut() {
+            if key.0 == handle || key.1 == handle {
+                detector.handle_shape_deformation()
+            }
+        }
+    }
+
     fn contact_pairs<'a>(
         &'a self,
         objects: &'a CollisionObjectSlab<P, M, T>,
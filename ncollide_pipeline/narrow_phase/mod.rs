@@ -5,23 +5,32 @@ pub use self::narrow_phase::{ContactPairs, Contacts, NarrowPhase, ProximityPairs
 pub use self::default_narrow_phase::DefaultNarrowPhase;
 
 #[doc(inline)]
-pub use self::contact_generator::{BallBallContactGenerator, CompositeShapeShapeContactGenerator,
-                                  ContactAlgorithm, ContactDispatcher, ContactGenerator,
-                                  DefaultContactDispatcher, IncrementalContactManifoldGenerator,
+pub use self::contact_generator::{BallBallContactGenerator, BallCapsuleContactGenerator,
+                                  CapsuleBallContactGenerator, CapsuleCapsuleContactGenerator,
+                                  CompositeShapeShapeContactGenerator, ContactAlgorithm,
+                                  ContactDispatcher, ContactGenerator, DefaultContactDispatcher,
+                                  IncrementalContactManifoldGenerator,
                                   OneShotContactManifoldGenerator,
                                   PlaneSupportMapContactGenerator,
+                                  PolytopePolytopeContactGenerator, RegisteredContactGenerator,
+                                  RoundShapeContactGenerator,
                                   ShapeCompositeShapeContactGenerator,
                                   SupportMapPlaneContactGenerator,
-                                  SupportMapSupportMapContactGenerator};
+                                  SupportMapSupportMapContactGenerator,
+                                  SupportMapTorusContactGenerator,
+                                  TorusSupportMapContactGenerator};
 
 #[doc(inline)]
 pub use self::proximity_detector::{BallBallProximityDetector,
                                    CompositeShapeShapeProximityDetector,
                                    DefaultProximityDispatcher, PlaneSupportMapProximityDetector,
                                    ProximityAlgorithm, ProximityDetector, ProximityDispatcher,
+                                   RegisteredProximityDetector, RoundShapeProximityDetector,
                                    ShapeCompositeShapeProximityDetector,
                                    SupportMapPlaneProximityDetector,
-                                   SupportMapSupportMapProximityDetector};
+                                   SupportMapSupportMapProximityDetector,
+                                   SupportMapTorusProximityDetector,
+                                   TorusSupportMapProximityDetector};
 
 #[doc(hidden)]
 pub mod contact_generator;
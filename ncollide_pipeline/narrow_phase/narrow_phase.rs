@@ -41,6 +41,12 @@ pub trait NarrowPhase<P: Point, M, T>: Any + Send + Sync {
         handle2: CollisionObjectHandle,
     );
 
+    /// Called when the shape of the collision object identified by `handle` is modified in-place.
+    ///
+    /// The contact and proximity algorithms involving this object are kept but must discard the
+    /// data they cached about its shape.
+    fn handle_shape_deformation(&mut self, handle: CollisionObjectHandle);
+
     // FIXME: the fact that the return type is imposed is not as generic as it could be.
     /// Returns all the potential contact pairs found during the broad phase, and validated by the
     /// narrow phase.
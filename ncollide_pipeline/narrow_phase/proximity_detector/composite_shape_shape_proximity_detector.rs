@@ -15,6 +15,8 @@ pub struct CompositeShapeShapeProximityDetector<P: Point, M> {
     to_delete: Vec<usize>,
     interferences: Vec<usize>,
     intersecting_key: usize,
+    // Whether the sub-detectors must be recreated because the composite shape has been deformed.
+    deformed: bool,
 }
 
 impl<P: Point, M> CompositeShapeShapeProximityDetector<P, M> {
@@ -26,6 +28,7 @@ impl<P: Point, M> CompositeShapeShapeProximityDetector<P, M> {
             to_delete: Vec::new(),
             interferences: Vec::new(),
             intersecting_key: usize::max_value(),
+            deformed: false,
         }
     }
 }
@@ -48,28 +51,52 @@ impl<P: Point, M: Isometry<P>> CompositeShapeShapeProximityDetector<P, M> {
         self.to_delete.clear();
         self.interferences.clear();
 
+        // The parts of a deformed shape may have been replaced or moved.
+        if self.deformed {
+            self.sub_detectors.clear();
+            self.intersecting_key = usize::max_value();
+            self.deformed = false;
+        }
+
+        let m12 = na::inverse(m1) * m2.clone();
+        let ls_aabb2 = bounding_volume::aabb(g2, &m12).loosened(margin);
+
+        // Parts that are not reported by the BVH are either too far, or have been removed from
+        // the composite shape.
+        {
+            let mut visitor =
+                BoundingVolumeInterferencesCollector::new(&ls_aabb2, &mut self.interferences);
+            g1.bvh().visit(&mut visitor);
+        }
+
+        self.interferences.sort();
+
         // First, test if the previously intersecting shapes are still intersecting.
         if self.proximity == Proximity::Intersecting {
-            let detector = self.sub_detectors.find_mut(&self.intersecting_key).unwrap();
-            g1.map_transformed_part_at(self.intersecting_key, m1, &mut |m1, g1| {
-                assert!(
-                    detector.update(disp, m1, g1, m2, g2, margin),
-                    "The shape was no longer valid."
-                );
-            });
+            if self.interferences
+                .binary_search(&self.intersecting_key)
+                .is_ok()
+            {
+                let detector = self.sub_detectors.find_mut(&self.intersecting_key).unwrap();
+                g1.map_transformed_part_at(self.intersecting_key, m1, &mut |m1, g1| {
+                    assert!(
+                        detector.update(disp, m1, g1, m2, g2, margin),
+                        "The shape was no longer valid."
+                    );
+                });
 
-            match detector.proximity() {
-                Proximity::Intersecting => return, // Early return.
-                Proximity::WithinMargin => self.proximity = Proximity::WithinMargin,
-                Proximity::Disjoint => {}
+                match detector.proximity() {
+                    Proximity::Intersecting => return, // Early return.
+                    Proximity::WithinMargin => self.proximity = Proximity::WithinMargin,
+                    Proximity::Disjoint => {}
+                }
+            } else {
+                self.intersecting_key = usize::max_value();
             }
         }
 
         self.proximity = Proximity::Disjoint;
 
-        let m12 = na::inverse(m1) * m2.clone();
-        let ls_aabb2 = bounding_volume::aabb(g2, &m12).loosened(margin);
-
         // Update all collisions
         for detector in self.sub_detectors.elements_mut().iter_mut() {
             let key = detector.key;
@@ -79,7 +106,7 @@ impl<P: Point, M: Isometry<P>> CompositeShapeShapeProximityDetector<P, M> {
                 continue;
             }
 
-            if ls_aabb2.intersects(&g1.aabb_at(key)) {
+            if self.interferences.binary_search(&key).is_ok() {
                 g1.map_transformed_part_at(key, m1, &mut |m1, g1| {
                     assert!(
                         detector.value.update(disp, m1, g1, m2, g2, margin),
@@ -103,12 +130,6 @@ impl<P: Point, M: Isometry<P>> CompositeShapeShapeProximityDetector<P, M> {
         }
 
         // Find new proximities.
-        {
-            let mut visitor =
-                BoundingVolumeInterferencesCollector::new(&ls_aabb2, &mut self.interferences);
-            g1.bvt().visit(&mut visitor);
-        }
-
         for key in self.interferences.iter() {
             let detector = self.sub_detectors.find_or_insert_lazy(*key, || {
                 let mut new_detector = None;
@@ -179,6 +200,10 @@ impl<P: Point, M: Isometry<P>> ProximityDetector<P, M>
     fn proximity(&self) -> Proximity {
         self.proximity
     }
+
+    fn handle_shape_deformation(&mut self) {
+        self.deformed = true
+    }
 }
 
 impl<P: Point, M: Isometry<P>> ProximityDetector<P, M>
@@ -204,4 +229,8 @@ impl<P: Point, M: Isometry<P>> ProximityDetector<P, M>
     fn proximity(&self) -> Proximity {
         self.sub_detector.proximity()
     }
+
+    fn handle_shape_deformation(&mut self) {
+        self.sub_detector.handle_shape_deformation()
+    }
 }
@@ -1,17 +1,26 @@
 use std::marker::PhantomData;
 use math::{Isometry, Point};
 use na;
-use geometry::shape::{Ball, Plane, Shape};
+use geometry::shape::{Ball, Plane, Shape, Torus};
+use geometry::query::{self, QueryRegistry};
 use geometry::query::algorithms::{JohnsonSimplex, VoronoiSimplex2, VoronoiSimplex3};
 use narrow_phase::proximity_detector::{BallBallProximityDetector,
                                        CompositeShapeShapeProximityDetector,
                                        PlaneSupportMapProximityDetector, ProximityAlgorithm,
-                                       ProximityDispatcher, ShapeCompositeShapeProximityDetector,
+                                       ProximityDispatcher, RegisteredProximityDetector,
+                                       RoundShapeProximityDetector,
+                                       ShapeCompositeShapeProximityDetector,
                                        SupportMapPlaneProximityDetector,
-                                       SupportMapSupportMapProximityDetector};
+                                       SupportMapSupportMapProximityDetector,
+                                       SupportMapTorusProximityDetector,
+                                       TorusSupportMapProximityDetector};
 
 /// Proximity dispatcher for shapes defined by `ncollide_entities`.
+///
+/// The proximity functions registered on the global query registry (see
+/// `query::modify_query_registry`) take precedence over the built-in proximity detectors.
 pub struct DefaultProximityDispatcher<P: Point, M> {
+    use_mpr: bool,
     _point_type: PhantomData<P>,
     _matrix_type: PhantomData<M>,
 }
@@ -20,10 +29,28 @@ impl<P: Point, M> DefaultProximityDispatcher<P, M> {
     /// Creates a new basic proximity dispatcher.
     pub fn new() -> DefaultProximityDispatcher<P, M> {
         DefaultProximityDispatcher {
+            use_mpr: false,
             _point_type: PhantomData,
             _matrix_type: PhantomData,
         }
     }
+
+    /// Sets whether the support-map proximity detectors created by this dispatcher use the MPR
+    /// algorithm instead of GJK.
+    ///
+    /// See `SupportMapSupportMapProximityDetector::set_use_mpr`. This only affects the proximity
+    /// detectors created afterward.
+    #[inline]
+    pub fn set_use_mpr(&mut self, use_mpr: bool) {
+        self.use_mpr = use_mpr
+    }
+
+    /// Whether the support-map proximity detectors created by this dispatcher use the MPR
+    /// algorithm.
+    #[inline]
+    pub fn uses_mpr(&self) -> bool {
+        self.use_mpr
+    }
 }
 
 impl<P: Point, M: Isometry<P>> ProximityDispatcher<P, M> for DefaultProximityDispatcher<P, M> {
@@ -34,29 +61,41 @@ impl<P: Point, M: Isometry<P>> ProximityDispatcher<P, M> for DefaultProximityDis
     ) -> Option<ProximityAlgorithm<P, M>> {
         let a_is_ball = a.is_shape::<Ball<P::Real>>();
         let b_is_ball = b.is_shape::<Ball<P::Real>>();
+        let registered =
+            query::read_query_registry(|r: &QueryRegistry<P, M>| r.has_proximity(a, b));
 
-        if a_is_ball && b_is_ball {
+        if registered == Some(true) {
+            Some(Box::new(RegisteredProximityDetector::<P, M>::new()))
+        } else if a_is_ball && b_is_ball {
             Some(Box::new(BallBallProximityDetector::<P, M>::new()))
         } else if a.is_shape::<Plane<P::Vector>>() && b.is_support_map() {
             Some(Box::new(PlaneSupportMapProximityDetector::<P, M>::new()))
         } else if b.is_shape::<Plane<P::Vector>>() && a.is_support_map() {
             Some(Box::new(SupportMapPlaneProximityDetector::<P, M>::new()))
+        } else if a.is_shape::<Torus<P::Real>>() && b.is_support_map() {
+            Some(Box::new(TorusSupportMapProximityDetector::<P, M>::new()))
+        } else if b.is_shape::<Torus<P::Real>>() && a.is_support_map() {
+            Some(Box::new(SupportMapTorusProximityDetector::<P, M>::new()))
+        } else if (a.is_round_shape() && b.is_support_map())
+            || (a.is_support_map() && b.is_round_shape())
+        {
+            Some(Box::new(RoundShapeProximityDetector::<P, M>::new()))
         } else if a.is_support_map() && b.is_support_map() {
             if na::dimension::<P::Vector>() == 2 {
                 let simplex = VoronoiSimplex2::new();
-                Some(Box::new(SupportMapSupportMapProximityDetector::new(
-                    simplex,
-                )))
+                let mut detector = SupportMapSupportMapProximityDetector::<P, M, _>::new(simplex);
+                detector.set_use_mpr(self.use_mpr);
+                Some(Box::new(detector))
             } else if na::dimension::<P::Vector>() == 3 {
                 let simplex = VoronoiSimplex3::new();
-                Some(Box::new(SupportMapSupportMapProximityDetector::new(
-                    simplex,
-                )))
+                let mut detector = SupportMapSupportMapProximityDetector::<P, M, _>::new(simplex);
+                detector.set_use_mpr(self.use_mpr);
+                Some(Box::new(detector))
             } else {
                 let simplex = JohnsonSimplex::new_w_tls();
-                Some(Box::new(SupportMapSupportMapProximityDetector::new(
-                    simplex,
-                )))
+                let mut detector = SupportMapSupportMapProximityDetector::<P, M, _>::new(simplex);
+                detector.set_use_mpr(self.use_mpr);
+                Some(Box::new(detector))
             }
         } else if a.is_composite_shape() {
             Some(Box::new(
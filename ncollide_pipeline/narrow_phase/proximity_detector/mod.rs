@@ -5,8 +5,12 @@ pub use self::ball_ball_proximity_detector::BallBallProximityDetector;
 pub use self::plane_support_map_proximity_detector::{PlaneSupportMapProximityDetector,
                                                      SupportMapPlaneProximityDetector};
 pub use self::support_map_support_map_proximity_detector::SupportMapSupportMapProximityDetector;
+pub use self::torus_support_map_proximity_detector::{SupportMapTorusProximityDetector,
+                                                     TorusSupportMapProximityDetector};
+pub use self::round_shape_proximity_detector::RoundShapeProximityDetector;
 pub use self::composite_shape_shape_proximity_detector::{CompositeShapeShapeProximityDetector,
                                                          ShapeCompositeShapeProximityDetector};
+pub use self::registered_proximity_detector::RegisteredProximityDetector;
 pub use self::default_proximity_dispatcher::DefaultProximityDispatcher;
 
 #[doc(hidden)]
@@ -14,5 +18,8 @@ pub mod proximity_detector;
 mod ball_ball_proximity_detector;
 mod plane_support_map_proximity_detector;
 mod support_map_support_map_proximity_detector;
+mod torus_support_map_proximity_detector;
+mod round_shape_proximity_detector;
 mod composite_shape_shape_proximity_detector;
+mod registered_proximity_detector;
 mod default_proximity_dispatcher;
@@ -20,6 +20,12 @@ pub trait ProximityDetector<P: Point, M>: Any + Send + Sync {
 
     /// The number of collision detected during the last update.
     fn proximity(&self) -> Proximity;
+
+    /// Notifies this algorithm that one of its shapes has been modified in-place.
+    ///
+    /// The data cached about the parts of this shape are discarded during the next update while
+    /// the current proximity is kept until then. Does nothing by default.
+    fn handle_shape_deformation(&mut self) {}
 }
 
 pub type ProximityAlgorithm<P, M> = Box<ProximityDetector<P, M>>;
@@ -0,0 +1,57 @@
+use std::marker::PhantomData;
+
+use math::{Isometry, Point};
+use geometry::shape::Shape;
+use geometry::query::{self, Proximity, QueryRegistry};
+use narrow_phase::{ProximityDetector, ProximityDispatcher};
+
+/// Proximity detector between two shapes using the proximity function registered for them on the
+/// global query registry.
+#[derive(Clone)]
+pub struct RegisteredProximityDetector<P: Point, M> {
+    proximity: Proximity,
+    pt_type: PhantomData<P>,  // FIXME: can we avoid this?
+    mat_type: PhantomData<M>, // FIXME: can we avoid this?
+}
+
+impl<P: Point, M> RegisteredProximityDetector<P, M> {
+    /// Creates a new persistent proximity detector using the global query registry.
+    #[inline]
+    pub fn new() -> RegisteredProximityDetector<P, M> {
+        RegisteredProximityDetector {
+            proximity: Proximity::Disjoint,
+            pt_type: PhantomData,
+            mat_type: PhantomData,
+        }
+    }
+}
+
+impl<P: Point, M: Isometry<P>> ProximityDetector<P, M> for RegisteredProximityDetector<P, M> {
+    #[inline]
+    fn update(
+        &mut self,
+        _: &ProximityDispatcher<P, M>,
+        ma: &M,
+        a: &Shape<P, M>,
+        mb: &M,
+        b: &Shape<P, M>,
+        margin: P::Real,
+    ) -> bool {
+        let registered =
+            query::read_query_registry(|r: &QueryRegistry<P, M>| r.has_proximity(a, b));
+
+        if registered == Some(true) {
+            // The proximity query consults the registry first.
+            self.proximity = query::proximity(ma, a, mb, b, margin);
+
+            true
+        } else {
+            false
+        }
+    }
+
+    #[inline]
+    fn proximity(&self) -> Proximity {
+        self.proximity
+    }
+}
@@ -0,0 +1,73 @@
+use std::marker::PhantomData;
+
+use math::{Isometry, Point};
+use geometry::shape::Shape;
+use geometry::query::Proximity;
+use geometry::query::proximity_internal;
+use narrow_phase::{ProximityDetector, ProximityDispatcher};
+
+/// Proximity detector between two support-mapped shapes, at least one of them having rounded
+/// borders.
+pub struct RoundShapeProximityDetector<P: Point, M> {
+    proximity: Proximity,
+    pt_type: PhantomData<P>,  // FIXME: can we avoid this?
+    mat_type: PhantomData<M>, // FIXME: can we avoid this?
+}
+
+impl<P: Point, M> Clone for RoundShapeProximityDetector<P, M> {
+    fn clone(&self) -> RoundShapeProximityDetector<P, M> {
+        RoundShapeProximityDetector {
+            proximity: self.proximity,
+            pt_type: PhantomData,
+            mat_type: PhantomData,
+        }
+    }
+}
+
+impl<P: Point, M> RoundShapeProximityDetector<P, M> {
+    /// Creates a new persistent proximity detector between two rounded shapes.
+    #[inline]
+    pub fn new() -> RoundShapeProximityDetector<P, M> {
+        RoundShapeProximityDetector {
+            proximity: Proximity::Disjoint,
+            pt_type: PhantomData,
+            mat_type: PhantomData,
+        }
+    }
+}
+
+impl<P: Point, M: Isometry<P>> ProximityDetector<P, M> for RoundShapeProximityDetector<P, M> {
+    fn update(
+        &mut self,
+        _: &ProximityDispatcher<P, M>,
+        ma: &M,
+        a: &Shape<P, M>,
+        mb: &M,
+        b: &Shape<P, M>,
+        margin: P::Real,
+    ) -> bool {
+        if let (Some((a, ra)), Some((b, rb))) = (
+            a.as_support_map_with_border(),
+            b.as_support_map_with_border(),
+        ) {
+            self.proximity = proximity_internal::round_shape_against_round_shape::<P, _, _, _>(
+                ma,
+                a,
+                ra,
+                mb,
+                b,
+                rb,
+                margin,
+            );
+
+            true
+        } else {
+            false
+        }
+    }
+
+    #[inline]
+    fn proximity(&self) -> Proximity {
+        self.proximity
+    }
+}
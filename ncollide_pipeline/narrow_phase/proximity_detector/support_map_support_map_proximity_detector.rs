@@ -9,12 +9,13 @@ use narrow_phase::{ProximityDetector, ProximityDispatcher};
 
 /// Persistent proximity detector between two shapes having a support mapping function.
 ///
-/// It is based on the GJK algorithm.
+/// It is based on the GJK algorithm, or on the MPR algorithm if enabled with `set_use_mpr`.
 #[derive(Clone)]
 pub struct SupportMapSupportMapProximityDetector<P: Point, M, S> {
     simplex: S,
     proximity: Proximity,
     sep_axis: P::Vector,
+    use_mpr: bool,
     pt_type: PhantomData<P>,  // FIXME: can we avoid this?
     mat_type: PhantomData<M>, // FIXME: can we avoid this?
 }
@@ -33,10 +34,26 @@ where
             simplex: simplex,
             proximity: Proximity::Disjoint,
             sep_axis: na::zero(),
+            use_mpr: false,
             pt_type: PhantomData,
             mat_type: PhantomData,
         }
     }
+
+    /// Sets whether the MPR algorithm should be used instead of GJK.
+    ///
+    /// MPR is faster but may report `Proximity::WithinMargin` for shapes slightly farther than
+    /// the margin.
+    #[inline]
+    pub fn set_use_mpr(&mut self, use_mpr: bool) {
+        self.use_mpr = use_mpr
+    }
+
+    /// Whether this proximity detector uses the MPR algorithm instead of GJK.
+    #[inline]
+    pub fn uses_mpr(&self) -> bool {
+        self.use_mpr
+    }
 }
 
 impl<P, M, S> ProximityDetector<P, M> for SupportMapSupportMapProximityDetector<P, M, S>
@@ -56,6 +73,18 @@ where
         margin: P::Real,
     ) -> bool {
         if let (Some(sma), Some(smb)) = (a.as_support_map(), b.as_support_map()) {
+            if self.use_mpr {
+                self.proximity = proximity_internal::support_map_against_support_map_mpr(
+                    ma,
+                    sma,
+                    mb,
+                    smb,
+                    margin,
+                );
+
+                return true;
+            }
+
             let initial_direction;
             if self.proximity == Proximity::Disjoint {
                 initial_direction = None
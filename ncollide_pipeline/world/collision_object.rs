@@ -151,6 +151,11 @@ impl<P: Point, M, T> CollisionObject<P, M, T> {
         &self.shape
     }
 
+    #[inline]
+    pub(crate) fn shape_mut(&mut self) -> &mut ShapeHandle<P, M> {
+        &mut self.shape
+    }
+
     /// The collision groups of the collision object.
     #[inline]
     pub fn collision_groups(&self) -> &CollisionGroups {
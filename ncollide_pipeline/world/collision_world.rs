@@ -3,8 +3,9 @@ use std::vec::IntoIter;
 
 use math::{Isometry, Point};
 use geometry::bounding_volume::{self, BoundingVolume, AABB};
-use geometry::shape::ShapeHandle;
-use geometry::query::{PointQuery, Ray, RayCast, RayIntersection};
+use geometry::shape::{Shape, ShapeHandle};
+use geometry::query::{self, Contact, PointQuery, Proximity, QueryError, Ray, RayCast,
+                      RayIntersection};
 use narrow_phase::{ContactPairs, Contacts, DefaultContactDispatcher, DefaultNarrowPhase,
                    DefaultProximityDispatcher, NarrowPhase, ProximityPairs};
 use broad_phase::{BroadPhase, BroadPhasePairFilter, BroadPhasePairFilters, DBVTBroadPhase,
@@ -151,6 +152,80 @@ impl<P: Point, M: Isometry<P>, T> CollisionWorld<P, M, T> {
             .deferred_set_bounding_volume(co.proxy_handle(), aabb);
     }
 
+    /// Modifies in-place the shape of the collision object attached to the specified handle.
+    ///
+    /// This is intended for deformable shapes, e.g., a `TriMesh` which vertices are updated with
+    /// `TriMesh::update_vertices`, or a `DynamicCompound` which parts are added or removed. Unlike
+    /// `set_shape`, the contact and proximity algorithms involving this collision object are kept,
+    /// along with their contacts, so that no event is generated unless those contacts actually
+    /// change during the next update. Only the data they cached about the parts of the shape are
+    /// discarded.
+    ///
+    /// Returns `None` without calling `f` if the shape handle of this collision object is shared
+    /// with other handles. Otherwise, returns the result of `f`.
+    pub fn deform_shape<F, R>(&mut self, handle: CollisionObjectHandle, f: F) -> Option<R>
+    where
+        F: FnOnce(&mut Shape<P, M>) -> R,
+    {
+        let res = {
+            let co = self.objects
+                .get_mut(handle)
+                .expect("Deform shape: collision object not found.");
+
+            match co.shape_mut().get_mut() {
+                Some(shape) => f(shape),
+                None => return None,
+            }
+        };
+
+        let co = &mut self.objects[handle];
+        co.timestamp = self.timestamp;
+        let mut aabb = bounding_volume::aabb(co.shape().as_ref(), co.position());
+        aabb.loosen(co.query_type().query_limit());
+        self.broad_phase
+            .deferred_set_bounding_volume(co.proxy_handle(), aabb);
+        self.narrow_phase.handle_shape_deformation(handle);
+
+        Some(res)
+    }
+
+    /// Replaces the shape of the collision object attached to the specified handle.
+    ///
+    /// All the contact and proximity algorithms involving this collision object are removed
+    /// and the corresponding stop events are generated. Those are recreated during the next
+    /// update of the world.
+    pub fn set_shape(&mut self, handle: CollisionObjectHandle, shape: ShapeHandle<P, M>) {
+        *self.objects
+            .get_mut(handle)
+            .expect("Set shape: collision object not found.")
+            .shape_mut() = shape;
+
+        self.reset_proxy(handle);
+    }
+
+    // Replaces the broad phase proxy of the specified collision object after its shape was
+    // replaced. This removes all the contact and proximity algorithms involving this object.
+    fn reset_proxy(&mut self, handle: CollisionObjectHandle) {
+        let proxy_handle = self.objects[handle].proxy_handle();
+
+        {
+            let nf = &mut self.narrow_phase;
+            let objects = &self.objects;
+            let sig = &mut self.contact_events;
+            let prox = &mut self.proximity_events;
+            self.broad_phase.remove(&[proxy_handle], &mut |b1, b2| {
+                nf.handle_interaction(sig, prox, objects, *b1, *b2, false)
+            });
+        }
+
+        let co = &mut self.objects[handle];
+        co.timestamp = self.timestamp;
+        let mut aabb = bounding_volume::aabb(co.shape().as_ref(), co.position());
+        aabb.loosen(co.query_type().query_limit());
+        let proxy_handle = self.broad_phase.create_proxy(aabb, handle);
+        co.set_proxy_handle(proxy_handle);
+    }
+
     /// Adds a filter that tells if a potential collision pair should be ignored or not.
     ///
     /// The proximity filter returns `false` for a given pair of collision objects if they should
@@ -259,6 +334,10 @@ impl<P: Point, M: Isometry<P>, T> CollisionWorld<P, M, T> {
     }
 
     /// Computes the interferences between every rigid bodies on this world and a ray.
+    ///
+    /// Each interference comes with its ray intersection, which also identifies the part and
+    /// feature of the shape hit by the ray when they are known. This never panics: if the ray cast
+    /// on a shape does not converge, the best estimate of its intersection is used instead.
     #[inline]
     pub fn interferences_with_ray<'a>(
         &'a self,
@@ -316,6 +395,90 @@ impl<P: Point, M: Isometry<P>, T> CollisionWorld<P, M, T> {
         }
     }
 
+    /// Computes the distance between two collision objects.
+    ///
+    /// Returns an error instead of panicking if the distance cannot be computed, or if one of the
+    /// handles does not identify a collision object of this world.
+    pub fn try_distance(
+        &self,
+        handle1: CollisionObjectHandle,
+        handle2: CollisionObjectHandle,
+    ) -> Result<P::Real, QueryError> {
+        let (co1, co2) = self.try_objects(handle1, handle2)?;
+
+        query::try_distance(
+            co1.position(),
+            co1.shape().as_ref(),
+            co2.position(),
+            co2.shape().as_ref(),
+        )
+    }
+
+    /// Computes one contact point between two collision objects.
+    ///
+    /// Returns an error instead of panicking if the contact cannot be computed, or if one of the
+    /// handles does not identify a collision object of this world.
+    pub fn try_contact(
+        &self,
+        handle1: CollisionObjectHandle,
+        handle2: CollisionObjectHandle,
+        prediction: P::Real,
+    ) -> Result<Option<Contact<P>>, QueryError> {
+        let (co1, co2) = self.try_objects(handle1, handle2)?;
+
+        query::try_contact(
+            co1.position(),
+            co1.shape().as_ref(),
+            co2.position(),
+            co2.shape().as_ref(),
+            prediction,
+        )
+    }
+
+    /// Tests the proximity of two collision objects.
+    ///
+    /// Returns an error instead of panicking if the proximity cannot be determined, or if one of the
+    /// handles does not identify a collision object of this world.
+    pub fn try_proximity(
+        &self,
+        handle1: CollisionObjectHandle,
+        handle2: CollisionObjectHandle,
+        margin: P::Real,
+    ) -> Result<Proximity, QueryError> {
+        let (co1, co2) = self.try_objects(handle1, handle2)?;
+
+        query::try_proximity(
+            co1.position(),
+            co1.shape().as_ref(),
+            co2.position(),
+            co2.shape().as_ref(),
+            margin,
+        )
+    }
+
+    /// Computes the time of impact of two collision objects moving with the given velocities.
+    ///
+    /// Returns an error instead of panicking if the time of impact cannot be computed, or if one of
+    /// the handles does not identify a collision object of this world.
+    pub fn try_time_of_impact(
+        &self,
+        handle1: CollisionObjectHandle,
+        vel1: &P::Vector,
+        handle2: CollisionObjectHandle,
+        vel2: &P::Vector,
+    ) -> Result<Option<P::Real>, QueryError> {
+        let (co1, co2) = self.try_objects(handle1, handle2)?;
+
+        query::try_time_of_impact(
+            co1.position(),
+            vel1,
+            co1.shape().as_ref(),
+            co2.position(),
+            vel2,
+            co2.shape().as_ref(),
+        )
+    }
+
     /// The contact events pool.
     pub fn contact_events(&self) -> &ContactEvents {
         &self.contact_events
@@ -326,6 +489,18 @@ impl<P: Point, M: Isometry<P>, T> CollisionWorld<P, M, T> {
         &self.proximity_events
     }
 
+    // The collision objects identified by the given handles.
+    fn try_objects(
+        &self,
+        handle1: CollisionObjectHandle,
+        handle2: CollisionObjectHandle,
+    ) -> Result<(&CollisionObject<P, M, T>, &CollisionObject<P, M, T>), QueryError> {
+        match (self.objects.get(handle1), self.objects.get(handle2)) {
+            (Some(co1), Some(co2)) => Ok((co1, co2)),
+            _ => Err(QueryError::InvalidHandle),
+        }
+    }
+
     // Filters by group and by the user-provided callback.
     #[inline]
     fn filter_collision(
@@ -25,6 +25,7 @@ pub use cuboid::{cuboid, rectangle, unit_cuboid, unit_rectangle};
 pub use cylinder::{cylinder, unit_cylinder};
 pub use quad::{quad, quad_with_vertices, unit_quad};
 pub use sphere::{circle, sphere, unit_circle, unit_hemisphere, unit_sphere};
+pub use torus::torus;
 
 use na::{Point2, Point3};
 
@@ -39,6 +40,7 @@ mod cone;
 mod cuboid;
 mod cylinder;
 mod quad;
+mod torus;
 mod bezier;
 
 /// A 3D triangle mesh.
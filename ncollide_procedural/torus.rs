@@ -0,0 +1,72 @@
+use alga::general::Real;
+use na;
+use na::{Point3, Vector3};
+use super::{IndexBuffer, TriMesh};
+
+/// Generates a torus with its principal axis aligned with the `y` axis.
+///
+/// # Arguments:
+/// * `major_radius` - the radius of the torus core circle.
+/// * `minor_radius` - the radius of the torus tube.
+/// * `nmajor_subdiv` - the number of subdivisions along the core circle.
+/// * `nminor_subdiv` - the number of subdivisions along the tube cross-section.
+pub fn torus<N: Real>(
+    major_radius: N,
+    minor_radius: N,
+    nmajor_subdiv: u32,
+    nminor_subdiv: u32,
+) -> TriMesh<Point3<N>> {
+    assert!(
+        nmajor_subdiv >= 3 && nminor_subdiv >= 3,
+        "A torus needs at least 3 subdivisions along each circle."
+    );
+
+    let dphi = N::two_pi() / na::convert(nmajor_subdiv as f64);
+    let dtheta = N::two_pi() / na::convert(nminor_subdiv as f64);
+
+    let mut coords = Vec::with_capacity((nmajor_subdiv * nminor_subdiv) as usize);
+    let mut normals = Vec::with_capacity((nmajor_subdiv * nminor_subdiv) as usize);
+    let mut indices = Vec::with_capacity((nmajor_subdiv * nminor_subdiv * 2) as usize);
+
+    let mut curr_phi = N::zero();
+
+    for _ in 0..nmajor_subdiv {
+        let radial = Vector3::new(curr_phi.cos(), na::zero(), curr_phi.sin());
+        let center = Point3::from_coordinates(radial * major_radius);
+        let mut curr_theta = N::zero();
+
+        for _ in 0..nminor_subdiv {
+            let normal = radial * curr_theta.cos() + Vector3::y() * curr_theta.sin();
+
+            coords.push(center + normal * minor_radius);
+            normals.push(normal);
+
+            curr_theta = curr_theta + dtheta;
+        }
+
+        curr_phi = curr_phi + dphi;
+    }
+
+    for i in 0..nmajor_subdiv {
+        let next_i = (i + 1) % nmajor_subdiv;
+
+        for j in 0..nminor_subdiv {
+            let next_j = (j + 1) % nminor_subdiv;
+
+            let a = i * nminor_subdiv + j;
+            let b = i * nminor_subdiv + next_j;
+            let c = next_i * nminor_subdiv + j;
+            let d = next_i * nminor_subdiv + next_j;
+
+            indices.push(Point3::new(a, b, c));
+            indices.push(Point3::new(b, d, c));
+        }
+    }
+
+    TriMesh::new(
+        coords,
+        Some(normals),
+        None,
+        Some(IndexBuffer::Unified(indices)),
+    )
+}
@@ -4,6 +4,7 @@ use alga::general::Real;
 use na::{Point2, Vector2};
 use na;
 use procedural::Polyline;
+use geometry::shape::ConvexPolygon;
 use convex_hull_utils::{indexed_support_point_id, support_point_id};
 
 /// Computes the convex hull of a set of 2d points.
@@ -18,6 +19,18 @@ pub fn convex_hull2<N: Real>(points: &[Point2<N>]) -> Polyline<Point2<N>> {
     Polyline::new(pts, None)
 }
 
+/// Computes the convex hull of a set of 2d points as a `ConvexPolygon`.
+///
+/// Returns `None` if the convex hull is degenerate, e.g., if all the points are collinear.
+pub fn convex_polygon<N: Real>(points: &[Point2<N>]) -> Option<ConvexPolygon<N>> {
+    let pts = convex_hull2_idx(points)
+        .into_iter()
+        .map(|id| points[id])
+        .collect();
+
+    ConvexPolygon::try_new(pts)
+}
+
 /// Computes the convex hull of a set of 2d points and returns only the indices of the hull
 /// vertices.
 pub fn convex_hull2_idx<N: Real>(points: &[Point2<N>]) -> Vec<usize> {
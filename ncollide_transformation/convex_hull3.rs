@@ -6,6 +6,7 @@ use na::{Matrix3, Point2, Point3, Vector3};
 use na;
 use utils;
 use procedural::{IndexBuffer, TriMesh};
+use geometry::shape::ConvexPolyhedron;
 use convex_hull_utils::{denormalize, indexed_support_point_id, normalize, support_point_id};
 
 /// Computes the convariance matrix of a set of points.
@@ -22,6 +23,20 @@ fn cov<N: Real>(pts: &[Point3<N>]) -> Matrix3<N> {
     cov
 }
 
+/// Computes the convex hull of a set of 3d points as a `ConvexPolyhedron`.
+///
+/// Returns `None` if the convex hull is degenerate, e.g., if all the points are coplanar.
+pub fn convex_polyhedron<N: Real>(points: &[Point3<N>]) -> Option<ConvexPolyhedron<N>> {
+    let hull = convex_hull3(points);
+    let indices: Vec<Point3<usize>> = hull.indices
+        .unwrap_unified()
+        .into_iter()
+        .map(|idx| Point3::new(idx.x as usize, idx.y as usize, idx.z as usize))
+        .collect();
+
+    ConvexPolyhedron::try_new(hull.coords, &indices[..])
+}
+
 /// Computes the convex hull of a set of 3d points.
 pub fn convex_hull3<N: Real>(points: &[Point3<N>]) -> TriMesh<Point3<N>> {
     assert!(
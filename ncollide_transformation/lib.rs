@@ -22,8 +22,8 @@ extern crate num_traits as num;
 pub use to_trimesh::ToTriMesh;
 pub use to_polyline::ToPolyline;
 pub use hacd::hacd;
-pub use convex_hull3::convex_hull3;
-pub use convex_hull2::{convex_hull2, convex_hull2_idx};
+pub use convex_hull3::{convex_hull3, convex_polyhedron};
+pub use convex_hull2::{convex_hull2, convex_hull2_idx, convex_polygon};
 pub use triangulate::triangulate;
 
 mod to_trimesh;
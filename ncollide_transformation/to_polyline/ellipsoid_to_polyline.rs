@@ -0,0 +1,16 @@
+use alga::general::Real;
+use na::{self, Point2};
+use geometry::shape::Ellipsoid2;
+use procedural::Polyline2;
+use procedural;
+use super::ToPolyline;
+
+impl<N: Real> ToPolyline<Point2<N>, u32> for Ellipsoid2<N> {
+    fn to_polyline(&self, nsubdiv: u32) -> Polyline2<N> {
+        let mut polyline: Polyline2<N> = procedural::circle(&na::convert(2.0f64), nsubdiv);
+
+        polyline.scale_by(self.radii());
+
+        polyline
+    }
+}
@@ -0,0 +1,29 @@
+use alga::general::Real;
+use na::{self, Point2};
+use geometry::shape::RoundShape;
+use procedural::Polyline2;
+use procedural;
+use super::ToPolyline;
+
+/// The polyline of a round shape is the convex hull of the core shape polyline dilated by the
+/// polyline of a ball. Therefore, the core shape is assumed to be convex.
+impl<N: Real, S, A> ToPolyline<Point2<N>, (A, u32)> for RoundShape<S, N>
+where
+    S: ToPolyline<Point2<N>, A>,
+{
+    fn to_polyline(&self, (a, nsubdiv): (A, u32)) -> Polyline2<N> {
+        let core = self.shape().to_polyline(a);
+        let diameter = self.border_radius() * na::convert(2.0f64);
+        let ball: Polyline2<N> = procedural::circle(&diameter, nsubdiv);
+
+        let mut all_points = Vec::with_capacity(core.coords().len() * ball.coords().len());
+
+        for pt in core.coords().iter() {
+            for ball_pt in ball.coords().iter() {
+                all_points.push(*pt + ball_pt.coords);
+            }
+        }
+
+        ::convex_hull2(&all_points[..])
+    }
+}
@@ -3,6 +3,7 @@ pub use self::to_trimesh::ToTriMesh;
 #[doc(hidden)]
 pub mod to_trimesh;
 mod ball_to_trimesh;
+mod ellipsoid_to_trimesh;
 mod capsule_to_trimesh;
 mod cone_to_trimesh;
 mod cuboid_to_trimesh;
@@ -11,3 +12,5 @@ mod mesh_to_trimesh;
 // mod minkowski_sum_to_trimesh;
 mod reflection_to_trimesh;
 mod triangle_to_trimesh;
+mod torus_to_trimesh;
+mod round_shape_to_trimesh;
@@ -0,0 +1,33 @@
+use alga::general::Real;
+use na::{self, Point3};
+use geometry::shape::RoundShape;
+use procedural::TriMesh3;
+use procedural;
+use super::ToTriMesh;
+
+/// The triangle mesh of a round shape is the convex hull of the core shape mesh dilated by the
+/// mesh of a ball. Therefore, the core shape is assumed to be convex.
+impl<N: Real, S, A> ToTriMesh<Point3<N>, (A, (u32, u32))> for RoundShape<S, N>
+where
+    S: ToTriMesh<Point3<N>, A>,
+{
+    fn to_trimesh(&self, (a, (ntheta_subdiv, nphi_subdiv)): (A, (u32, u32))) -> TriMesh3<N> {
+        let core = self.shape().to_trimesh(a);
+        let ball = procedural::sphere(
+            self.border_radius() * na::convert(2.0f64),
+            ntheta_subdiv,
+            nphi_subdiv,
+            false,
+        );
+
+        let mut all_points = Vec::with_capacity(core.coords.len() * ball.coords.len());
+
+        for pt in core.coords.iter() {
+            for ball_pt in ball.coords.iter() {
+                all_points.push(*pt + ball_pt.coords);
+            }
+        }
+
+        ::convex_hull3(&all_points[..])
+    }
+}
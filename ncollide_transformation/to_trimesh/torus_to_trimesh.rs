@@ -0,0 +1,17 @@
+use alga::general::Real;
+use na::Point3;
+use geometry::shape::Torus;
+use procedural::TriMesh3;
+use procedural;
+use super::ToTriMesh;
+
+impl<N: Real> ToTriMesh<Point3<N>, (u32, u32)> for Torus<N> {
+    fn to_trimesh(&self, (nmajor_subdiv, nminor_subdiv): (u32, u32)) -> TriMesh3<N> {
+        procedural::torus(
+            self.major_radius(),
+            self.minor_radius(),
+            nmajor_subdiv,
+            nminor_subdiv,
+        )
+    }
+}
@@ -48,7 +48,13 @@ impl<T> SparseVec<T> {
     #[inline]
     pub fn remove(&mut self, id: usize) -> Option<T> {
         if id < self.data.len() {
-            mem::replace(&mut self.data[id], None)
+            let res = mem::replace(&mut self.data[id], None);
+
+            if res.is_some() {
+                self.free.push(id);
+            }
+
+            res
         } else {
             None
         }
@@ -25,8 +25,8 @@ pub use triangle::{circumcircle, is_point_in_triangle, triangle_area, triangle_c
 pub use tetrahedron::{tetrahedron_center, tetrahedron_signed_volume, tetrahedron_volume};
 pub use cleanup::remove_unused_points;
 pub use derivatives::{binom, dcos, dsin};
-// pub use optimization::{maximize_with_newton, newton, minimize_with_bfgs, bfgs,
-//                        LineSearch, BacktrackingLineSearch};
+pub use optimization::{maximize_with_newton, newton, minimize_with_bfgs, bfgs, LineSearch,
+                       BacktrackingLineSearch};
 pub use hashable_partial_eq::HashablePartialEq;
 #[doc(inline)]
 pub use as_bytes::AsBytes;
@@ -35,7 +35,8 @@ pub use median::median;
 pub use sort::sort3;
 pub use cross3::cross3;
 pub use perp2::perp2;
-pub use point_cloud_support_point::point_cloud_support_point;
+pub use point_cloud_support_point::{point_cloud_support_point, point_cloud_support_point_id};
+pub use polynomial::{polynomial_eval, polynomial_roots};
 pub use repeat::repeat;
 
 pub mod data;
@@ -45,7 +46,7 @@ mod tetrahedron;
 mod triangle;
 mod cleanup;
 mod derivatives;
-// mod optimization;
+mod optimization;
 mod hashable_partial_eq;
 #[doc(hidden)]
 pub mod as_bytes;
@@ -55,4 +56,5 @@ mod sort;
 mod cross3;
 mod perp2;
 mod point_cloud_support_point;
+mod polynomial;
 mod repeat;
@@ -1,39 +1,41 @@
-use std::ops::{Add, Sub, Mul};
-use rand::{self, Rand};
-use na::{Inverse, PartialOrder, SquareMatrix, Outer, Dot};
-use na;
-use math::{Scalar, Vector, FloatError};
+use rand;
+use approx::ApproxEq;
 
+use alga::general::Real;
+use na::{self, DefaultAllocator, DimName, MatrixN, VectorN};
+use na::allocator::Allocator;
 
 // FIXME: implement a proper metaheuristic.
 /// Maximizes a real function using the Newton method.
-pub fn maximize_with_newton<N, V, M, F: Fn(&V) -> N, D: Fn(&V) -> (V, M)>(
-                            niter:       usize,
-                            num_guesses: usize,
-                            domain_min:  &V,
-                            domain_max:  &V,
-                            f:  &mut F,
-                            df: &mut D)
-                            -> (V, N)
-    where N: Scalar,
-          V: Add<V, Output = V> + Sub<V, Output = V> + Mul<V, Output = V> + Clone + Rand + PartialOrder + Copy,
-          M: Inverse + Mul<V, Output = V> + Copy {
-    let mut best_sol     = domain_min.clone();
+pub fn maximize_with_newton<N, D, F, DF>(
+    niter: usize,
+    num_guesses: usize,
+    domain_min: &VectorN<N, D>,
+    domain_max: &VectorN<N, D>,
+    f: &mut F,
+    df: &mut DF,
+) -> (VectorN<N, D>, N)
+where
+    N: Real,
+    D: DimName,
+    DefaultAllocator: Allocator<N, D> + Allocator<N, D, D>,
+    F: FnMut(&VectorN<N, D>) -> N,
+    DF: FnMut(&VectorN<N, D>) -> (VectorN<N, D>, MatrixN<N, D>),
+{
+    let mut best_sol = domain_min.clone();
     let mut best_sol_val = (*f)(domain_min);
-    let domain_width     = *domain_max - *domain_min;
 
-    for _ in 0 .. num_guesses {
-        let guess: V = rand::random();
-        let guess    = *domain_min + domain_width * guess; // FIXME: let the user pass a random generator?
+    for _ in 0..num_guesses {
+        // FIXME: let the user pass a random generator?
+        let guess = random_in_domain(domain_min, domain_max);
         let (arg, _) = newton(niter, guess, df);
 
-        if na::partial_le(&arg, domain_max) && na::partial_ge(&arg, domain_min) {
+        if is_in_domain(&arg, domain_min, domain_max) {
             let val = (*f)(&arg);
 
             if val > best_sol_val {
                 best_sol_val = val;
-                best_sol     = arg;
-
+                best_sol = arg;
             }
         }
     }
@@ -42,61 +44,57 @@ pub fn maximize_with_newton<N, V, M, F: Fn(&V) -> N, D: Fn(&V) -> (V, M)>(
 }
 
 /// Finds the root of a function using the Newton method.
-pub fn newton<V, M, F: Fn(&V) -> (V, M)>(niter: usize, guess: V, f: &mut F) -> (V, bool)
-    where V: Sub<V, Output = V>,
-          M: Inverse + Mul<V, Output = V> + Copy {
+pub fn newton<N, D, F>(niter: usize, guess: VectorN<N, D>, f: &mut F) -> (VectorN<N, D>, bool)
+where
+    N: Real,
+    D: DimName,
+    DefaultAllocator: Allocator<N, D> + Allocator<N, D, D>,
+    F: FnMut(&VectorN<N, D>) -> (VectorN<N, D>, MatrixN<N, D>),
+{
     let mut curr = guess;
 
-    for _ in 0 .. niter {
-        let (value, mut jacobian) = (*f)(&curr);
+    for _ in 0..niter {
+        let (value, jacobian) = (*f)(&curr);
 
-        if !jacobian.inverse_mut() {
-            return (curr, false)
+        match jacobian.try_inverse() {
+            Some(inv_jacobian) => curr = curr - inv_jacobian * value,
+            None => return (curr, false),
         }
-
-        curr = curr - jacobian * value;
     }
 
     (curr, true)
 }
 
 /// Minimizes a function using the bfgs method.
-pub fn minimize_with_bfgs<V, F, D>(niter:       usize,
-                                   num_guesses: usize,
-                                   domain_min:  &V,
-                                   domain_max:  &V,
-                                   f:           &mut F,
-                                   df:          &mut D)
-                                   -> (V, V::Scalar)
-    where V: Vector + Outer + Mul<<V as Outer>::OuterProductType, Output = V>,
-          F: Fn(&V) -> V::Scalar,
-          D: Fn(&V) -> V,
-          V::OuterProductType: SquareMatrix<<V as Vector>::Scalar, V> +
-                               Add<<V as Outer>::OuterProductType, Output = <V as Outer>::OuterProductType> +
-                               Sub<<V as Outer>::OuterProductType, Output = <V as Outer>::OuterProductType> +
-                               Clone + Copy {
-    let mut best_sol     = domain_min.clone();
+pub fn minimize_with_bfgs<N, D, F, DF>(
+    niter: usize,
+    num_guesses: usize,
+    domain_min: &VectorN<N, D>,
+    domain_max: &VectorN<N, D>,
+    f: &mut F,
+    df: &mut DF,
+) -> (VectorN<N, D>, N)
+where
+    N: Real,
+    D: DimName,
+    DefaultAllocator: Allocator<N, D> + Allocator<N, D, D>,
+    F: FnMut(&VectorN<N, D>) -> N,
+    DF: FnMut(&VectorN<N, D>) -> VectorN<N, D>,
+{
+    let mut best_sol = domain_min.clone();
     let mut best_sol_val = (*f)(domain_min);
-    let domain_width     = *domain_max - *domain_min;
-    let ss               = BacktrackingLineSearch::new(na::one::<V::Scalar>(), na::convert(0.5), na::convert(0.5), 1000);
-
-    for _ in 0 .. num_guesses {
-        let mut guess: V = rand::random();
-        let shape        = na::shape(&guess);
-
-        for i in 0usize .. shape {
-            let inbound_guess = domain_min[i] + guess[i] * domain_width[i];
-            guess[i] = inbound_guess;
-        }
+    let ss = BacktrackingLineSearch::new(na::one::<N>(), na::convert(0.5), na::convert(0.5), 1000);
 
-        let arg = bfgs(niter, &ss, guess, na::one(), f, df);
+    for _ in 0..num_guesses {
+        let guess = random_in_domain(domain_min, domain_max);
+        let arg = bfgs(niter, &ss, guess, MatrixN::identity(), f, df);
 
-        if na::partial_le(&arg, domain_max) && na::partial_ge(&arg, domain_min) {
+        if is_in_domain(&arg, domain_min, domain_max) {
             let val = (*f)(&arg);
 
             if val < best_sol_val {
                 best_sol_val = val;
-                best_sol     = arg;
+                best_sol = arg;
             }
         }
     }
@@ -107,15 +105,15 @@ pub fn minimize_with_bfgs<V, F, D>(niter:       usize,
 /// Trait for line search methods.
 pub trait LineSearch<N, V> {
     /// Gets a near-optimal step size for the next descent.
-    fn step_size<F: Fn(&V) -> N>(&self, f: &mut F, df: &V, x: &V, dir: &V) -> N;
+    fn step_size<F: FnMut(&V) -> N>(&self, f: &mut F, df: &V, x: &V, dir: &V) -> N;
 }
 
 /// The backtracking line search method.
 pub struct BacktrackingLineSearch<N> {
     alpha: N,
-    tau:   N,
-    c:     N,
-    niter: usize
+    tau: N,
+    c: N,
+    niter: usize,
 }
 
 impl<N> BacktrackingLineSearch<N> {
@@ -123,23 +121,32 @@ impl<N> BacktrackingLineSearch<N> {
     pub fn new(alpha: N, tau: N, c: N, niter: usize) -> BacktrackingLineSearch<N> {
         BacktrackingLineSearch {
             alpha: alpha,
-            tau:   tau,
-            c:     c,
-            niter: niter
+            tau: tau,
+            c: c,
+            niter: niter,
         }
     }
 }
 
-impl<N, V> LineSearch<N, V> for BacktrackingLineSearch<N>
-    where N: Scalar,
-          V: Dot<N> + Add<V, Output = V> + Mul<N, Output = V> + Copy {
-    fn step_size<F: Fn(&V) -> N>(&self, f: &mut F, df: &V, x: &V, dir: &V) -> N {
-        let     t    = -self.c * na::dot(df, dir);
-        let     fx   = (*f)(x);
-        let mut step = self.alpha.clone();
-
-        for _ in 0 .. self.niter {
-            if fx - (*f)(&(*x + *dir * step)) >= step * t {
+impl<N, D> LineSearch<N, VectorN<N, D>> for BacktrackingLineSearch<N>
+where
+    N: Real,
+    D: DimName,
+    DefaultAllocator: Allocator<N, D>,
+{
+    fn step_size<F: FnMut(&VectorN<N, D>) -> N>(
+        &self,
+        f: &mut F,
+        df: &VectorN<N, D>,
+        x: &VectorN<N, D>,
+        dir: &VectorN<N, D>,
+    ) -> N {
+        let t = -self.c * df.dot(dir);
+        let fx = (*f)(x);
+        let mut step = self.alpha;
+
+        for _ in 0..self.niter {
+            if fx - (*f)(&(x.clone() + dir.clone() * step)) >= step * t {
                 break;
             }
 
@@ -151,66 +158,66 @@ impl<N, V> LineSearch<N, V> for BacktrackingLineSearch<N>
 }
 
 /// Minimizes a function using the quasi-newton BFGS method.
-pub fn bfgs<V, SS, F, D>(niter:   usize,
-                         ss:      &SS,
-                         guess:   V,
-                         hessian: V::OuterProductType,
-                         f:       &mut F,
-                         df:      &mut D)
-                         -> V
-    where V: Vector + Outer + Mul<<V as Outer>::OuterProductType, Output = V>,
-          F: Fn(&V) -> V::Scalar,
-          D: Fn(&V) -> V,
-          V::OuterProductType: SquareMatrix<<V as Vector>::Scalar, V> +
-                               Add<<V as Outer>::OuterProductType, Output = <V as Outer>::OuterProductType> +
-                               Sub<<V as Outer>::OuterProductType, Output = <V as Outer>::OuterProductType> +
-                               Clone + Copy,
-          SS: LineSearch<V::Scalar, V> {
-    let mut x  = guess;
-    let mut hx = hessian;
-    let mut dx = na::zero();
-
-    if !hx.inverse_mut() {
-        hx = na::one();
-    }
-
-    for _ in 0 .. niter {
-        let     new_dx     = (*df)(&x);
-        let mut search_dir = hx * (-new_dx);
-
-        if na::dot(&search_dir, &new_dx) >= na::zero() {
+pub fn bfgs<N, D, SS, F, DF>(
+    niter: usize,
+    ss: &SS,
+    guess: VectorN<N, D>,
+    hessian: MatrixN<N, D>,
+    f: &mut F,
+    df: &mut DF,
+) -> VectorN<N, D>
+where
+    N: Real,
+    D: DimName,
+    DefaultAllocator: Allocator<N, D> + Allocator<N, D, D>,
+    SS: LineSearch<N, VectorN<N, D>>,
+    F: FnMut(&VectorN<N, D>) -> N,
+    DF: FnMut(&VectorN<N, D>) -> VectorN<N, D>,
+{
+    let mut x = guess;
+    let mut hx = match hessian.try_inverse() {
+        Some(inv_hessian) => inv_hessian,
+        None => MatrixN::identity(),
+    };
+    let mut dx = VectorN::<N, D>::zeros();
+    let _eps = N::default_epsilon() * na::convert(100.0);
+
+    for _ in 0..niter {
+        let new_dx = (*df)(&x);
+        let mut search_dir = hx.clone() * (-new_dx.clone());
+
+        if search_dir.dot(&new_dx) >= na::zero() {
             // Not a descent direction.
-            hx         = na::one();
-            search_dir = -new_dx;
+            hx = MatrixN::identity();
+            search_dir = -new_dx.clone();
         }
 
-        let _eps: V::Scalar = FloatError::epsilon();
-        let _eps = _eps * na::convert(100.0);
-        if na::norm_squared(&new_dx) <= _eps {
+        if new_dx.norm_squared() <= _eps {
             break;
         }
 
         let alpha = ss.step_size(f, &new_dx, &x, &search_dir);
-        let step  = search_dir * alpha;
+        let step = search_dir * alpha;
 
         if alpha * alpha <= _eps {
             break;
         }
 
-        x = x + step;
+        x = x + step.clone();
 
-        let d_dx  = new_dx - dx;
-        let denom = na::dot(&step, &d_dx);
+        let d_dx = new_dx.clone() - dx;
+        let denom = step.dot(&d_dx);
 
         if denom * denom <= _eps {
-            hx = na::one()
-        }
-        else {
-            let idenom = na::one::<V::Scalar>() / denom;
-
-            hx = hx +
-                 na::outer(&step, &(step * ((denom + na::dot(&d_dx, &(hx * d_dx))) * idenom * idenom))) -
-                 (hx * na::outer(&d_dx, &(step * idenom)) + na::outer(&step, &(d_dx * idenom)) * hx);
+            hx = MatrixN::identity()
+        } else {
+            let idenom = na::one::<N>() / denom;
+            let hx_d_dx = hx.clone() * d_dx.clone();
+            let scale = (denom + d_dx.dot(&hx_d_dx)) * idenom * idenom;
+
+            hx = hx.clone() + outer(&step, &(step.clone() * scale))
+                - (hx.clone() * outer(&d_dx, &(step.clone() * idenom))
+                    + outer(&step, &(d_dx.clone() * idenom)) * hx);
         }
 
         dx = new_dx;
@@ -219,41 +226,82 @@ pub fn bfgs<V, SS, F, D>(niter:   usize,
     x
 }
 
+/// The outer product `a * b^T`.
+#[inline]
+fn outer<N, D>(a: &VectorN<N, D>, b: &VectorN<N, D>) -> MatrixN<N, D>
+where
+    N: Real,
+    D: DimName,
+    DefaultAllocator: Allocator<N, D> + Allocator<N, D, D>,
+{
+    MatrixN::from_fn(|i, j| a[i] * b[j])
+}
+
+/// Generates a random point inside of the box `[domain_min, domain_max]`.
+#[inline]
+fn random_in_domain<N, D>(domain_min: &VectorN<N, D>, domain_max: &VectorN<N, D>) -> VectorN<N, D>
+where
+    N: Real,
+    D: DimName,
+    DefaultAllocator: Allocator<N, D>,
+{
+    VectorN::from_fn(|i, _| {
+        let r: f64 = rand::random();
+        domain_min[i] + (domain_max[i] - domain_min[i]) * na::convert(r)
+    })
+}
+
+/// Tests whether a point lies inside of the box `[domain_min, domain_max]`.
+#[inline]
+fn is_in_domain<N, D>(
+    pt: &VectorN<N, D>,
+    domain_min: &VectorN<N, D>,
+    domain_max: &VectorN<N, D>,
+) -> bool
+where
+    N: Real,
+    D: DimName,
+    DefaultAllocator: Allocator<N, D>,
+{
+    (0..D::dim()).all(|i| pt[i] >= domain_min[i] && pt[i] <= domain_max[i])
+}
+
 #[cfg(test)]
 mod test {
-    use symbolic::{BivariateFn, U, V, sin, cos};
-    use na::{Vector2, Mat2};
-    use na;
-    use super::{BacktrackingLineSearch, bfgs};
+    use na::{Matrix2, Vector2};
+    use super::{bfgs, BacktrackingLineSearch};
 
     #[test]
     fn test_bfgs_m_cos_u() {
-        let f = -cos(U);
-        let h = na::one::<Mat2<f64>>();
+        let h = Matrix2::<f64>::identity();
         let ss = BacktrackingLineSearch::new(1.0f64, 0.5, 0.5, 1000);
 
-        let o = bfgs(100, &ss, Vector2::new(-0.5, -0.5), h,
-                     &mut |uv| f.d0(uv.x, uv.y),
-                     &mut |uv| Vector2::new(f.du(uv.x, uv.y), f.dv(uv.x, uv.y)));
+        let o = bfgs(
+            100,
+            &ss,
+            Vector2::new(-0.5, -0.5),
+            h,
+            &mut |uv| -uv.x.cos(),
+            &mut |uv| Vector2::new(uv.x.sin(), 0.0),
+        );
 
-        println!("Minimum: f({}) = {}", o, f.d0(o.x, o.y));
-        assert!(f.d0(o.x, o.y) == -1.0);
+        assert!((-o.x.cos() + 1.0).abs() < 1.0e-7);
     }
 
     #[test]
     fn test_bfgs_m_cos_u_m_sin_v() {
-        let f  = -cos(U) - sin(V);
-        let h  = na::one::<Mat2<f64>>();
+        let h = Matrix2::<f64>::identity();
         let ss = BacktrackingLineSearch::new(1.0f64, 0.5, 0.5, 1000);
 
-        let o = bfgs(10000,
-                     &ss,
-                     Vector2::new(-0.5, -0.5),
-                     h,
-                     &mut |uv| f.d0(uv.x, uv.y),
-                     &mut |uv| Vector2::new(f.du(uv.x, uv.y), f.dv(uv.x, uv.y)));
+        let o = bfgs(
+            10000,
+            &ss,
+            Vector2::new(-0.5, -0.5),
+            h,
+            &mut |uv| -uv.x.cos() - uv.y.sin(),
+            &mut |uv| Vector2::new(uv.x.sin(), -uv.y.cos()),
+        );
 
-        println!("Minimum: f({}) = {}", o, f.d0(o.x, o.y));
-        assert!(f.d0(o.x, o.y) == -2.0);
+        assert!((-o.x.cos() - o.y.sin() + 2.0).abs() < 1.0e-7);
     }
 }
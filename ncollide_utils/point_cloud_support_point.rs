@@ -4,17 +4,23 @@ use math::Point;
 /// Computes the support point of a cloud of points.
 #[inline]
 pub fn point_cloud_support_point<P: Point>(dir: &P::Vector, points: &[P]) -> P {
-    let mut best_pt = &points[0];
-    let mut best_dot = na::dot(&best_pt.coordinates(), dir);
+    points[point_cloud_support_point_id(dir, points)]
+}
+
+/// Computes the index of the support point of a cloud of points.
+#[inline]
+pub fn point_cloud_support_point_id<P: Point>(dir: &P::Vector, points: &[P]) -> usize {
+    let mut best_pt = 0;
+    let mut best_dot = na::dot(&points[0].coordinates(), dir);
 
-    for p in points[1..].iter() {
+    for (i, p) in points.iter().enumerate().skip(1) {
         let dot = na::dot(&p.coordinates(), dir);
 
         if dot > best_dot {
             best_dot = dot;
-            best_pt = p;
+            best_pt = i;
         }
     }
 
-    *best_pt
+    best_pt
 }
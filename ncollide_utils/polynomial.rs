@@ -0,0 +1,160 @@
+use num::Zero;
+use approx::ApproxEq;
+
+use alga::general::Real;
+use na;
+
+/// Evaluates a polynomial at `x`.
+///
+/// The coefficients are given in increasing degree order, i.e., `coeffs[i]` is the coefficient
+/// of `x^i`.
+#[inline]
+pub fn polynomial_eval<N: Real>(coeffs: &[N], x: N) -> N {
+    let mut res = na::zero::<N>();
+
+    for c in coeffs.iter().rev() {
+        res = res * x + *c;
+    }
+
+    res
+}
+
+/// Computes the real roots of a polynomial contained in the interval `[min, max]`.
+///
+/// The coefficients are given in increasing degree order, i.e., `coeffs[i]` is the coefficient
+/// of `x^i`. The roots are pushed to `out` in increasing order. The polynomial is split into
+/// monotonic pieces using the roots of its derivative, and each piece is then solved by
+/// bisection. Roots with an even multiplicity, e.g., tangent intersections, are located at the
+/// roots of the derivative and are reported if the polynomial is negligible there with regard to
+/// the rounding errors of its evaluation.
+pub fn polynomial_roots<N: Real>(coeffs: &[N], min: N, max: N, out: &mut Vec<N>) {
+    let mut deg = coeffs.len();
+
+    while deg > 0 && coeffs[deg - 1].is_zero() {
+        deg -= 1;
+    }
+
+    if deg < 2 || min > max {
+        return;
+    }
+
+    let coeffs = &coeffs[..deg];
+
+    if deg == 2 {
+        let root = -coeffs[0] / coeffs[1];
+
+        if root >= min && root <= max {
+            out.push(root)
+        }
+
+        return;
+    }
+
+    let derivative: Vec<N> = coeffs[1..]
+        .iter()
+        .enumerate()
+        .map(|(i, c)| *c * na::convert((i + 1) as f64))
+        .collect();
+
+    let mut breakpoints = vec![min];
+    polynomial_roots(&derivative[..], min, max, &mut breakpoints);
+    breakpoints.push(max);
+
+    let first_root = out.len();
+
+    for bounds in breakpoints.windows(2) {
+        let (a, b) = (bounds[0], bounds[1]);
+        let fa = polynomial_eval(coeffs, a);
+        let fb = polynomial_eval(coeffs, b);
+
+        if is_negligible(coeffs, a, fa) {
+            push_unique(out, first_root, a);
+        } else if (fa < na::zero()) != (fb < na::zero()) && !is_negligible(coeffs, b, fb) {
+            push_unique(out, first_root, bisect(coeffs, a, fa, b));
+        }
+    }
+
+    if is_negligible(coeffs, max, polynomial_eval(coeffs, max)) {
+        push_unique(out, first_root, max);
+    }
+}
+
+// Whether `fx`, the value of the polynomial at `x`, is indistinguishable from zero given the
+// rounding errors of its evaluation.
+#[inline]
+fn is_negligible<N: Real>(coeffs: &[N], x: N, fx: N) -> bool {
+    let mut scale = na::zero::<N>();
+
+    for c in coeffs.iter().rev() {
+        scale = scale * x.abs() + c.abs();
+    }
+
+    let tolerance = N::default_epsilon() * na::convert((coeffs.len() * 4) as f64);
+
+    fx.abs() <= scale * tolerance
+}
+
+#[inline]
+fn push_unique<N: Real>(out: &mut Vec<N>, first: usize, root: N) {
+    if out.len() == first || out[out.len() - 1] != root {
+        out.push(root)
+    }
+}
+
+fn bisect<N: Real>(coeffs: &[N], mut lo: N, mut flo: N, mut hi: N) -> N {
+    let _0_5: N = na::convert(0.5f64);
+
+    for _ in 0..200 {
+        let mid = (lo + hi) * _0_5;
+
+        if mid <= lo || mid >= hi {
+            break;
+        }
+
+        let fmid = polynomial_eval(coeffs, mid);
+
+        if fmid.is_zero() {
+            return mid;
+        }
+
+        if (fmid < na::zero()) == (flo < na::zero()) {
+            lo = mid;
+            flo = fmid;
+        } else {
+            hi = mid;
+        }
+    }
+
+    (lo + hi) * _0_5
+}
+
+#[cfg(test)]
+mod test {
+    #[test]
+    fn test_quartic_roots() {
+        // (x - 1)(x + 2)(x - 3)(x - 0.5)
+        let coeffs = [-3.0f64, 8.5, -4.0, -2.5, 1.0];
+        let mut roots = Vec::new();
+
+        super::polynomial_roots(&coeffs, -10.0, 10.0, &mut roots);
+
+        assert_eq!(roots.len(), 4);
+        assert!(relative_eq!(roots[0], -2.0, epsilon = 1.0e-10));
+        assert!(relative_eq!(roots[1], 0.5, epsilon = 1.0e-10));
+        assert!(relative_eq!(roots[2], 1.0, epsilon = 1.0e-10));
+        assert!(relative_eq!(roots[3], 3.0, epsilon = 1.0e-10));
+    }
+
+    #[test]
+    fn test_quartic_double_roots() {
+        // (x - 1)²(x + 2)²
+        let coeffs = [4.0f64, -4.0, -3.0, 2.0, 1.0];
+        let mut roots = Vec::new();
+
+        super::polynomial_roots(&coeffs, -10.0, 10.0, &mut roots);
+
+        assert_eq!(roots.len(), 2);
+        assert!(relative_eq!(roots[0], -2.0, epsilon = 1.0e-6));
+        assert!(relative_eq!(roots[1], 1.0, epsilon = 1.0e-6));
+    }
+}
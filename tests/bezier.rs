@@ -0,0 +1,69 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{Isometry2, Isometry3, Point2, Point3, Vector2, Vector3};
+use ncollide::bounding_volume;
+use ncollide::shape::{Ball, BezierCurve, BezierSurface};
+use ncollide::query::{self, PointQuery, Ray, RayCast};
+
+#[test]
+fn bezier_curve_queries() {
+    // y = 4t(1 - t), x = 2t.
+    let curve = BezierCurve::new(
+        vec![
+            Point2::new(0.0f64, 0.0),
+            Point2::new(1.0, 2.0),
+            Point2::new(2.0, 0.0),
+        ],
+        1.0e-4,
+    );
+    let m = Isometry2::identity();
+
+    let aabb = bounding_volume::aabb(&curve, &m);
+    assert_eq!(*aabb.mins(), Point2::new(0.0, 0.0));
+    assert_eq!(*aabb.maxs(), Point2::new(2.0, 2.0));
+
+    let ray = Ray::new(Point2::new(1.0, 5.0), -Vector2::y());
+    let toi = curve.toi_with_ray(&m, &ray, true).unwrap();
+    assert!((toi - 4.0).abs() < 1.0e-3);
+
+    let ray = Ray::new(Point2::new(3.0, 5.0), -Vector2::y());
+    assert!(curve.toi_with_ray(&m, &ray, true).is_none());
+
+    // The projection is refined on the exact curve.
+    let proj = curve.project_point(&m, &Point2::new(1.0, 3.0), true);
+    assert!(!proj.is_inside);
+    assert!((proj.point - Point2::new(1.0, 1.0)).norm() < 1.0e-6);
+}
+
+#[test]
+fn bezier_surface_queries() {
+    // A 3x3 grid on the `xz` plane with its center control point raised.
+    let mut control_points = Vec::new();
+
+    for j in 0..3 {
+        for i in 0..3 {
+            let y = if i == 1 && j == 1 { 2.0 } else { 0.0 };
+            control_points.push(Point3::new(i as f64, y, j as f64));
+        }
+    }
+
+    let surface = BezierSurface::new(control_points, 3, 3, 1.0e-3);
+    let m = Isometry3::identity();
+
+    assert!((surface.point_at(0.5, 0.5) - Point3::new(1.0, 0.5, 1.0)).norm() < 1.0e-7);
+
+    let ray = Ray::new(Point3::new(1.0, 5.0, 1.0), -Vector3::y());
+    let toi = surface.toi_with_ray(&m, &ray, true).unwrap();
+    assert!((toi - 4.5).abs() < 1.0e-2);
+
+    let proj = surface.project_point(&m, &Point3::new(1.0, 3.0, 1.0), true);
+    assert!((proj.point - Point3::new(1.0, 0.5, 1.0)).norm() < 1.0e-6);
+
+    // Contact with the adaptive subdivision of the surface.
+    let ball = Ball::new(0.5);
+    let m2 = Isometry3::new(Vector3::new(1.0, 0.9, 1.0), na::zero());
+    let contact = query::contact(&m, &surface, &m2, &ball, 0.0).unwrap();
+    assert!((contact.depth - 0.1).abs() < 1.0e-2);
+    assert!((contact.normal.y - 1.0).abs() < 1.0e-2);
+}
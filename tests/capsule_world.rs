@@ -0,0 +1,43 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{Isometry3, Vector3};
+use ncollide::world::{CollisionGroups, CollisionWorld3, GeometricQueryType};
+use ncollide::shape::{Ball, Capsule, ShapeHandle};
+use ncollide::query;
+
+#[test]
+fn capsule_capsule_contact() {
+    let capsule = Capsule::new(1.0f64, 0.5);
+    let m1 = Isometry3::new(na::zero(), na::zero());
+    let m2 = Isometry3::new(Vector3::new(0.9, 0.5, 0.0), na::zero());
+
+    let contact = query::contact(&m1, &capsule, &m2, &capsule, 0.0).unwrap();
+
+    assert!((contact.depth - 0.1).abs() < 1.0e-7);
+    assert!((contact.normal.x - 1.0).abs() < 1.0e-7);
+}
+
+#[test]
+fn capsule_ball_in_world() {
+    let mut world = CollisionWorld3::new(0.02);
+    let contact_query = GeometricQueryType::Contacts(0.0, 0.0);
+
+    let _ = world.add(
+        Isometry3::new(na::zero(), na::zero()),
+        ShapeHandle::new(Capsule::new(1.0f64, 0.5)),
+        CollisionGroups::new(),
+        contact_query,
+        (),
+    );
+    let _ = world.add(
+        Isometry3::new(Vector3::new(0.0, 1.75, 0.0), na::zero()),
+        ShapeHandle::new(Ball::new(0.5f64)),
+        CollisionGroups::new(),
+        contact_query,
+        (),
+    );
+    world.update();
+
+    assert_eq!(world.contacts().count(), 1);
+}
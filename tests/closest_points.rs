@@ -0,0 +1,85 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{Isometry3, Point3, Vector3};
+use ncollide::shape::{Ball, Compound, Cuboid, Plane, ShapeHandle};
+use ncollide::query::{self, ClosestPoints};
+
+fn assert_points(res: ClosestPoints<Point3<f64>>, p1: Point3<f64>, p2: Point3<f64>) {
+    match res {
+        ClosestPoints::WithinMargin(q1, q2) => {
+            assert!(na::norm(&(q1 - p1)) < 1.0e-6);
+            assert!(na::norm(&(q2 - p2)) < 1.0e-6);
+        }
+        _ => panic!("The shapes should be within margin."),
+    }
+}
+
+#[test]
+fn closest_points_ball_ball() {
+    let ball = Ball::new(1.0f64);
+    let m1 = Isometry3::identity();
+    let m2 = Isometry3::new(Vector3::new(3.0, 0.0, 0.0), na::zero());
+
+    let res = query::closest_points(&m1, &ball, &m2, &ball, 2.0);
+    assert_points(res, Point3::new(1.0, 0.0, 0.0), Point3::new(2.0, 0.0, 0.0));
+
+    assert_eq!(
+        query::closest_points(&m1, &ball, &m2, &ball, 0.5),
+        ClosestPoints::Disjoint
+    );
+
+    let m2 = Isometry3::new(Vector3::new(1.5, 0.0, 0.0), na::zero());
+    assert_eq!(
+        query::closest_points(&m1, &ball, &m2, &ball, 0.5),
+        ClosestPoints::Intersecting
+    );
+}
+
+#[test]
+fn closest_points_plane_cuboid() {
+    let plane = Plane::new(Vector3::y_axis());
+    let cuboid = Cuboid::new(Vector3::new(1.0f64, 1.0, 1.0));
+    let m1 = Isometry3::identity();
+    let m2 = Isometry3::new(Vector3::new(0.0, 1.5, 0.0), na::zero());
+
+    match query::closest_points(&m2, &cuboid, &m1, &plane, 1.0) {
+        ClosestPoints::WithinMargin(p1, p2) => {
+            assert!((p1.y - 0.5).abs() < 1.0e-6);
+            assert!(p2.y.abs() < 1.0e-6);
+        }
+        _ => panic!("The shapes should be within margin."),
+    }
+}
+
+#[test]
+fn closest_points_cuboid_compound() {
+    let cuboid = Cuboid::new(Vector3::new(0.5f64, 0.5, 0.5));
+    let shapes = vec![
+        (
+            Isometry3::new(Vector3::new(-2.0, 0.0, 0.0), na::zero()),
+            ShapeHandle::new(cuboid.clone()),
+        ),
+        (
+            Isometry3::new(Vector3::new(2.0, 0.0, 0.0), na::zero()),
+            ShapeHandle::new(cuboid.clone()),
+        ),
+    ];
+    let compound = Compound::new(shapes);
+    let m1 = Isometry3::identity();
+    let m2 = Isometry3::new(Vector3::new(0.0, 0.0, 0.0), na::zero());
+
+    let res = query::closest_points(&m1, &compound, &m2, &cuboid, 2.0);
+
+    match res {
+        ClosestPoints::WithinMargin(p1, p2) => {
+            assert!((na::norm(&(p2 - p1)) - 1.0).abs() < 1.0e-6);
+        }
+        _ => panic!("The shapes should be within margin."),
+    }
+
+    assert_eq!(
+        query::closest_points(&m1, &compound, &m2, &cuboid, 0.5),
+        ClosestPoints::Disjoint
+    );
+}
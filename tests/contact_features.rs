@@ -0,0 +1,213 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use std::sync::Arc;
+
+use na::{Isometry3, Point3, Unit, Vector3};
+use ncollide::world::{CollisionGroups, CollisionWorld3, GeometricQueryType};
+use ncollide::shape::{Ball, Capsule, Cone, ConvexHull, Cuboid, Cylinder, FeatureId, Plane,
+                      ShapeHandle, SupportMap, TriMesh};
+use ncollide::query::{self, ContactPrediction};
+use ncollide::query::algorithms::VoronoiSimplex3;
+use ncollide::narrow_phase::{ContactGenerator, DefaultContactDispatcher,
+                             IncrementalContactManifoldGenerator,
+                             SupportMapSupportMapContactGenerator};
+
+#[test]
+fn contact_features_ball_ball() {
+    let ball = Ball::new(1.0f64);
+    let m1 = Isometry3::identity();
+    let m2 = Isometry3::new(Vector3::new(1.5, 0.0, 0.0), na::zero());
+
+    let c = query::contact(&m1, &ball, &m2, &ball, 0.0).unwrap();
+    assert_eq!(c.feature1, FeatureId::Vertex(0));
+    assert_eq!(c.feature2, FeatureId::Vertex(0));
+    assert!(c.has_same_features(&c));
+}
+
+#[test]
+fn contact_features_ball_plane_and_cuboid() {
+    let ball = Ball::new(1.0f64);
+    let plane = Plane::new(Vector3::y_axis());
+    let cuboid = Cuboid::new(Vector3::new(1.0f64, 1.0, 1.0));
+    let m1 = Isometry3::identity();
+    let m2 = Isometry3::new(Vector3::new(0.0, 0.9, 0.0), na::zero());
+
+    let c = query::contact(&m1, &plane, &m2, &ball, 0.0).unwrap();
+    assert_eq!(c.feature1, FeatureId::Face(0));
+    assert_eq!(c.feature2, FeatureId::Vertex(0));
+
+    let m2 = Isometry3::new(Vector3::new(0.0, 1.9, 0.0), na::zero());
+    let c = query::contact(&m1, &cuboid, &m2, &ball, 0.0).unwrap();
+    assert_eq!(c.feature1, FeatureId::Face(1));
+    assert_eq!(c.feature2, FeatureId::Vertex(0));
+}
+
+#[test]
+fn contact_features_plane_cuboid() {
+    let plane = Plane::new(Vector3::y_axis());
+    let cuboid = Cuboid::new(Vector3::new(1.0f64, 1.0, 1.0));
+    let m1 = Isometry3::identity();
+    // Only the vertex at the bottom of the cuboid touches the plane.
+    let m2 = Isometry3::new(Vector3::new(0.0, 0.9, 0.0), Vector3::new(0.1, 0.0, 0.2));
+
+    let mut c = query::contact(&m1, &plane, &m2, &cuboid, 0.0).unwrap();
+    assert_eq!(c.feature1, FeatureId::Face(0));
+    assert!(match c.feature2 {
+        FeatureId::Vertex(_) => true,
+        _ => false,
+    });
+
+    let feature2 = c.feature2;
+    c.flip();
+    assert_eq!(c.feature1, feature2);
+    assert_eq!(c.feature2, FeatureId::Face(0));
+}
+
+#[test]
+fn contact_features_trimesh_part() {
+    let vertices = vec![
+        Point3::new(-5.0, 0.0, -5.0),
+        Point3::new(5.0, 0.0, -5.0),
+        Point3::new(5.0, 0.0, 5.0),
+        Point3::new(-5.0, 0.0, 5.0),
+    ];
+    let indices = vec![Point3::new(0, 2, 1), Point3::new(0, 3, 2)];
+    let mesh = TriMesh::new(Arc::new(vertices), Arc::new(indices), None, None);
+
+    let mut world = CollisionWorld3::new(0.02);
+    let contact_query = GeometricQueryType::Contacts(0.0, 0.0);
+    let _ = world.add(
+        Isometry3::identity(),
+        ShapeHandle::new(mesh),
+        CollisionGroups::new(),
+        contact_query,
+        (),
+    );
+    let cuboid = world.add(
+        Isometry3::new(Vector3::new(-2.0, 0.45, 1.0), na::zero()),
+        ShapeHandle::new(Cuboid::new(Vector3::new(0.5f64, 0.5, 0.5))),
+        CollisionGroups::new(),
+        contact_query,
+        (),
+    );
+    world.update();
+
+    assert!(world.contacts().count() != 0);
+
+    for (o1, _, c) in world.contacts() {
+        // The cuboid lies above the second triangle of the mesh.
+        if o1.handle() == cuboid {
+            assert_eq!(c.part2, Some(1));
+        } else {
+            assert_eq!(c.part1, Some(1));
+        }
+    }
+
+    // Contacts between the same features are not duplicated.
+    world.update();
+    let num_contacts = world.contacts().count();
+    world.update();
+    assert_eq!(world.contacts().count(), num_contacts);
+}
+
+#[test]
+fn contact_features_cuboid_support_features() {
+    let cuboid = Cuboid::new(Vector3::new(1.0f64, 1.0, 1.0));
+    let m = Isometry3::identity();
+    let feature = |x, y, z| {
+        let dir = Unit::new_normalize(Vector3::new(x, y, z));
+        cuboid.support_feature_id_toward(&m, &dir)
+    };
+
+    assert_eq!(feature(0.0, -1.0, 0.0), FeatureId::Face(4));
+    assert_eq!(feature(0.0, 1.0, 0.0), FeatureId::Face(1));
+    assert_eq!(feature(1.0, 0.0, -1.0), FeatureId::Edge(4 + 2));
+    assert_eq!(feature(1.0, 1.0, 1.0), FeatureId::Vertex(7));
+}
+
+#[test]
+fn contact_features_rounded_support_features() {
+    let capsule = Capsule::new(1.0f64, 0.5);
+    let cylinder = Cylinder::new(1.0f64, 0.5);
+    let cone = Cone::new(1.0f64, 0.5);
+    let m = Isometry3::identity();
+    let dir = |x, y, z| Unit::new_normalize(Vector3::new(x, y, z));
+
+    assert_eq!(capsule.support_feature_id_toward(&m, &dir(1.0, 0.0, 0.0)), FeatureId::Edge(0));
+    assert_eq!(capsule.support_feature_id_toward(&m, &dir(1.0, -1.0, 0.0)), FeatureId::Vertex(0));
+    assert_eq!(capsule.support_feature_id_toward(&m, &dir(0.0, 1.0, 0.0)), FeatureId::Vertex(1));
+
+    assert_eq!(cylinder.support_feature_id_toward(&m, &dir(0.0, -1.0, 0.0)), FeatureId::Face(0));
+    assert_eq!(cylinder.support_feature_id_toward(&m, &dir(0.0, 1.0, 0.0)), FeatureId::Face(1));
+    assert_eq!(cylinder.support_feature_id_toward(&m, &dir(0.0, 0.0, 1.0)), FeatureId::Face(2));
+    assert_eq!(cylinder.support_feature_id_toward(&m, &dir(1.0, 1.0, 0.0)), FeatureId::Vertex(1));
+
+    assert_eq!(cone.support_feature_id_toward(&m, &dir(0.0, -1.0, 0.0)), FeatureId::Face(0));
+    assert_eq!(cone.support_feature_id_toward(&m, &dir(2.0, 0.5, 0.0)), FeatureId::Face(1));
+    assert_eq!(cone.support_feature_id_toward(&m, &dir(0.0, 1.0, 0.0)), FeatureId::Vertex(0));
+    assert_eq!(cone.support_feature_id_toward(&m, &dir(1.0, -1.0, 0.0)), FeatureId::Vertex(1));
+}
+
+#[test]
+fn contact_features_convex_hull_support_features() {
+    let points = vec![
+        Point3::new(0.0f64, 1.0, 0.0),
+        Point3::new(-1.0, 0.0, -1.0),
+        Point3::new(1.0, 0.0, -1.0),
+        Point3::new(0.0, 0.0, 1.0),
+    ];
+    let hull = ConvexHull::new(points);
+    let m = Isometry3::new(Vector3::new(5.0, 0.0, 0.0), na::zero());
+
+    let apex = Unit::new_normalize(Vector3::new(0.1, 1.0, 0.0));
+    assert_eq!(hull.support_feature_id_toward(&m, &apex), FeatureId::Vertex(0));
+
+    let corner = Unit::new_normalize(Vector3::new(1.0, -0.1, -1.0));
+    assert_eq!(hull.support_feature_id_toward(&m, &corner), FeatureId::Vertex(2));
+
+    // The base of the hull is a face, which is not identified.
+    let base = -Vector3::y_axis();
+    assert_eq!(hull.support_feature_id_toward(&m, &base), FeatureId::Unknown);
+}
+
+#[test]
+fn contact_features_box_on_box_manifold() {
+    let ground = Cuboid::new(Vector3::new(5.0f64, 1.0, 5.0));
+    let cuboid = Cuboid::new(Vector3::new(0.5f64, 0.5, 0.5));
+    let m1 = Isometry3::identity();
+    let dispatcher = DefaultContactDispatcher::<Point3<f64>, Isometry3<f64>>::new();
+    let prediction = ContactPrediction::new(0.0, 0.0, 0.0);
+    let generator = SupportMapSupportMapContactGenerator::new(VoronoiSimplex3::new());
+    let mut manifold = IncrementalContactManifoldGenerator::new(generator);
+
+    // A box sliding on a face: the face-face contacts are not identified by their features so
+    // they are not merged.
+    for i in 0..3 {
+        let m2 = Isometry3::new(Vector3::new(0.02 * i as f64, 1.45, 0.0), na::zero());
+        assert!(manifold.update(&dispatcher, &m1, &ground, &m2, &cuboid, &prediction));
+    }
+
+    let mut contacts = Vec::new();
+    manifold.contacts(&mut contacts);
+    assert_eq!(contacts.len(), 3);
+    assert!(contacts.iter().all(|c| c.feature1 == FeatureId::Unknown));
+
+    // A box resting on one of its vertices: this contact is identified by the vertex.
+    let generator = SupportMapSupportMapContactGenerator::new(VoronoiSimplex3::new());
+    let mut manifold = IncrementalContactManifoldGenerator::new(generator);
+    let rot = Vector3::new(0.3, 0.0, 0.4);
+
+    for i in 0..3 {
+        let m2 = Isometry3::new(Vector3::new(0.02 * i as f64, 1.75, 0.0), rot);
+        assert!(manifold.update(&dispatcher, &m1, &ground, &m2, &cuboid, &prediction));
+    }
+
+    contacts.clear();
+    manifold.contacts(&mut contacts);
+    assert_eq!(contacts.len(), 1);
+    assert!(match contacts[0].feature2 {
+        FeatureId::Vertex(_) => true,
+        _ => false,
+    });
+}
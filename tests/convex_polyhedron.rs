@@ -0,0 +1,65 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{Isometry2, Isometry3, Point2, Point3, Vector2, Vector3};
+use ncollide::shape::{Ball, SupportMap};
+use ncollide::query;
+use ncollide::transformation;
+
+#[test]
+fn convex_polyhedron_from_hull() {
+    let mut points = Vec::new();
+
+    for i in 0..8 {
+        let x = if i & 1 == 0 { -1.0f64 } else { 1.0 };
+        let y = if i & 2 == 0 { -1.0 } else { 1.0 };
+        let z = if i & 4 == 0 { -1.0 } else { 1.0 };
+        points.push(Point3::new(x, y, z));
+    }
+
+    points.push(Point3::new(0.1, 0.2, -0.3));
+
+    let cube = transformation::convex_polyhedron(&points).unwrap();
+    let m = Isometry3::identity();
+
+    assert_eq!(cube.points().len(), 8);
+    assert_eq!(cube.num_faces(), 6);
+    assert_eq!(cube.num_edges(), 12);
+
+    for i in 0..cube.num_faces() {
+        assert_eq!(cube.face_vertices(i).len(), 4);
+    }
+
+    let support = cube.support_point(&m, &Vector3::new(1.0, -2.0, 3.0));
+    assert_eq!(support, Point3::new(1.0, -1.0, 1.0));
+
+    let ball = Ball::new(0.5);
+    let m2 = Isometry3::new(Vector3::new(0.0, 1.4, 0.0), na::zero());
+    let contact = query::contact(&m, &cube, &m2, &ball, 0.0).unwrap();
+    assert!((contact.depth - 0.1).abs() < 1.0e-5);
+}
+
+#[test]
+fn convex_polygon_from_hull() {
+    let points = [
+        Point2::new(-1.0f64, -1.0),
+        Point2::new(0.0, -1.0),
+        Point2::new(1.0, -1.0),
+        Point2::new(1.0, 1.0),
+        Point2::new(-1.0, 1.0),
+        Point2::new(0.2, 0.3),
+    ];
+
+    let square = transformation::convex_polygon(&points).unwrap();
+    let m = Isometry2::identity();
+
+    assert_eq!(square.points().len(), 4);
+
+    let support = square.support_point(&m, &Vector2::new(-1.0, 2.0));
+    assert_eq!(support, Point2::new(-1.0, 1.0));
+
+    let ball = Ball::new(0.5);
+    let m2 = Isometry2::new(Vector2::new(1.4, 0.0), na::zero());
+    let contact = query::contact(&m, &square, &m2, &ball, 0.0).unwrap();
+    assert!((contact.depth - 0.1).abs() < 1.0e-5);
+}
@@ -0,0 +1,109 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use std::sync::Arc;
+
+use na::{Isometry3, Point3, Vector3};
+use ncollide::world::{CollisionGroups, CollisionWorld3, GeometricQueryType};
+use ncollide::shape::{Ball, ShapeHandle, TriMesh, TriMesh3};
+
+fn ground() -> TriMesh3<f64> {
+    let vertices = vec![
+        Point3::new(-5.0, 0.0, -5.0),
+        Point3::new(5.0, 0.0, -5.0),
+        Point3::new(5.0, 0.0, 5.0),
+        Point3::new(-5.0, 0.0, 5.0),
+    ];
+    let indices = vec![Point3::new(0, 1, 2), Point3::new(0, 2, 3)];
+
+    TriMesh::new(Arc::new(vertices), Arc::new(indices), None, None)
+}
+
+#[test]
+fn trimesh_update_vertices_refits_aabbs() {
+    let mut mesh = ground();
+
+    mesh.update_vertices(|vertices| {
+        for pt in vertices.iter_mut() {
+            pt.y = 2.0;
+        }
+    });
+
+    for aabb in mesh.bounding_volumes() {
+        assert_eq!(aabb.mins().y, 2.0);
+        assert_eq!(aabb.maxs().y, 2.0);
+    }
+}
+
+#[test]
+fn deformable_trimesh_in_world() {
+    let mut world = CollisionWorld3::new(0.02);
+    let contact_query = GeometricQueryType::Contacts(0.0, 0.0);
+
+    let ground = world.add(
+        Isometry3::identity(),
+        ShapeHandle::new(ground()),
+        CollisionGroups::new(),
+        contact_query,
+        (),
+    );
+    let ball = world.add(
+        Isometry3::new(Vector3::new(0.0, 1.5, 0.0), na::zero()),
+        ShapeHandle::new(Ball::new(1.0f64)),
+        CollisionGroups::new(),
+        contact_query,
+        (),
+    );
+    world.update();
+    assert_eq!(world.contacts().count(), 0);
+
+    // Raise the ground until it touches the ball.
+    let deformed = world.deform_shape(ground, |shape| {
+        let mesh = shape.as_shape_mut::<TriMesh3<f64>>().unwrap();
+        mesh.update_vertices(|vertices| {
+            for pt in vertices.iter_mut() {
+                pt.y = 0.75;
+            }
+        });
+    });
+    assert!(deformed.is_some());
+    world.update();
+    assert_eq!(world.contacts().count(), 1);
+
+    // Deforming the ground again keeps the contact pair, without any event.
+    let _ = world.deform_shape(ground, |shape| {
+        let mesh = shape.as_shape_mut::<TriMesh3<f64>>().unwrap();
+        mesh.update_vertices(|vertices| {
+            for pt in vertices.iter_mut() {
+                pt.y = 0.8;
+            }
+        });
+    });
+    world.update();
+    assert_eq!(world.contacts().count(), 1);
+    assert_eq!(world.contact_events().iter().count(), 0);
+
+    // Lower the ground again: the contact must not be computed from stale local points.
+    let deformed = world.deform_shape(ground, |shape| {
+        let mesh = shape.as_shape_mut::<TriMesh3<f64>>().unwrap();
+        mesh.update_vertices(|vertices| {
+            for pt in vertices.iter_mut() {
+                pt.y = 0.0;
+            }
+        });
+    });
+    assert!(deformed.is_some());
+    world.update();
+    assert_eq!(world.contacts().count(), 0);
+
+    // A shared shape cannot be deformed.
+    let shared = world.collision_object(ground).unwrap().shape().clone();
+    assert!(world.deform_shape(ground, |_| ()).is_none());
+    drop(shared);
+    assert!(world.deform_shape(ground, |_| ()).is_some());
+
+    // A smaller ball no longer touches the ground.
+    world.set_shape(ball, ShapeHandle::new(Ball::new(0.5f64)));
+    world.update();
+    assert_eq!(world.contacts().count(), 0);
+}
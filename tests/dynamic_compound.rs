@@ -0,0 +1,85 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{Isometry3, Point3, Vector3};
+use ncollide::world::{CollisionGroups, CollisionWorld3, GeometricQueryType};
+use ncollide::shape::{Ball, Cuboid, DynamicCompound, DynamicCompound3, ShapeHandle};
+use ncollide::query::{PointQuery, Ray, RayCast};
+
+fn cuboid() -> ShapeHandle<Point3<f64>, Isometry3<f64>> {
+    ShapeHandle::new(Cuboid::new(Vector3::new(0.5, 0.5, 0.5)))
+}
+
+#[test]
+fn dynamic_compound_edition() {
+    let mut compound = DynamicCompound::new();
+    let m = Isometry3::identity();
+    let ray = Ray::new(Point3::new(2.0, 10.0, 0.0), -Vector3::y());
+
+    let a = compound.add(Isometry3::identity(), cuboid());
+    let b = compound.add(Isometry3::new(Vector3::new(2.0, 0.0, 0.0), na::zero()), cuboid());
+    assert_eq!(compound.len(), 2);
+
+    let toi = compound.toi_with_ray(&m, &ray, true).unwrap();
+    assert!((toi - 9.5).abs() < 1.0e-7);
+
+    compound.set_part_position(b, Isometry3::new(Vector3::new(2.0, 1.0, 0.0), na::zero()));
+    let toi = compound.toi_with_ray(&m, &ray, true).unwrap();
+    assert!((toi - 8.5).abs() < 1.0e-7);
+
+    assert!(compound.remove(b).is_some());
+    assert!(compound.remove(b).is_none());
+    assert!(compound.toi_with_ray(&m, &ray, true).is_none());
+    assert!(compound.contains_point(&m, &Point3::origin()));
+
+    // Identifiers are never reused.
+    let c = compound.add(Isometry3::identity(), cuboid());
+    assert!(c != a && c != b);
+    assert_eq!(compound.parts().count(), 2);
+}
+
+#[test]
+fn dynamic_compound_in_world() {
+    let mut world = CollisionWorld3::new(0.02);
+    let contact_query = GeometricQueryType::Contacts(0.0, 0.0);
+
+    let mut compound = DynamicCompound::new();
+    let _ = compound.add(Isometry3::identity(), cuboid());
+
+    let compound = world.add(
+        Isometry3::identity(),
+        ShapeHandle::new(compound),
+        CollisionGroups::new(),
+        contact_query,
+        (),
+    );
+    let _ = world.add(
+        Isometry3::new(Vector3::new(2.0, 0.0, 0.0), na::zero()),
+        ShapeHandle::new(Ball::new(0.75f64)),
+        CollisionGroups::new(),
+        contact_query,
+        (),
+    );
+    world.update();
+    assert_eq!(world.contacts().count(), 0);
+
+    // Attach a part touching the ball.
+    let part = world.deform_shape(compound, |shape| {
+        let compound = shape.as_shape_mut::<DynamicCompound3<f64>>().unwrap();
+        compound.add(
+            Isometry3::new(Vector3::new(1.0, 0.0, 0.0), na::zero()),
+            cuboid(),
+        )
+    });
+    world.update();
+    assert_eq!(world.contacts().count(), 1);
+
+    // Break it off.
+    let removed = world.deform_shape(compound, |shape| {
+        let compound = shape.as_shape_mut::<DynamicCompound3<f64>>().unwrap();
+        compound.remove(part.unwrap())
+    });
+    assert!(removed.unwrap().is_some());
+    world.update();
+    assert_eq!(world.contacts().count(), 0);
+}
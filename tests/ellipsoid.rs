@@ -0,0 +1,68 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{Isometry3, Point3, Vector3};
+use ncollide::bounding_volume;
+use ncollide::shape::{Ball, Ellipsoid};
+use ncollide::query::{self, PointQuery, Ray, RayCast};
+use ncollide::transformation::ToTriMesh;
+
+fn on_surface(radii: &Vector3<f64>, pt: &Point3<f64>) -> bool {
+    let sum = (pt.x / radii.x).powi(2) + (pt.y / radii.y).powi(2) + (pt.z / radii.z).powi(2);
+
+    (sum - 1.0).abs() < 1.0e-6
+}
+
+#[test]
+fn ellipsoid_queries() {
+    let radii = Vector3::new(2.0f64, 1.0, 1.0);
+    let ellipsoid = Ellipsoid::new(radii);
+    let m = Isometry3::identity();
+
+    let aabb = bounding_volume::aabb(&ellipsoid, &m);
+    assert!((*aabb.mins() - Point3::new(-2.0, -1.0, -1.0)).norm() < 1.0e-7);
+    assert!((*aabb.maxs() - Point3::new(2.0, 1.0, 1.0)).norm() < 1.0e-7);
+
+    let ray = Ray::new(Point3::new(-5.0, 0.0, 0.0), Vector3::x());
+    let inter = ellipsoid.toi_and_normal_with_ray(&m, &ray, true).unwrap();
+    assert!((inter.toi - 3.0).abs() < 1.0e-7);
+    assert!((inter.normal + Vector3::x()).norm() < 1.0e-7);
+
+    let ray = Ray::new(Point3::new(0.0, 5.0, 0.0), -Vector3::y());
+    let inter = ellipsoid.toi_and_normal_with_ray(&m, &ray, true).unwrap();
+    assert!((inter.toi - 4.0).abs() < 1.0e-7);
+    assert!((inter.normal - Vector3::y()).norm() < 1.0e-7);
+
+    let proj = ellipsoid.project_point(&m, &Point3::new(4.0, 0.0, 0.0), true);
+    assert!((proj.point - Point3::new(2.0, 0.0, 0.0)).norm() < 1.0e-7);
+
+    // The projection direction is normal to the ellipsoid.
+    let pt = Point3::new(3.0, 3.0, 0.0);
+    let proj = ellipsoid.project_point(&m, &pt, true);
+    let normal = ellipsoid.normal_at(&proj.point.coords);
+    assert!(on_surface(&radii, &proj.point));
+    assert!(((pt - proj.point).normalize() - normal).norm() < 1.0e-6);
+
+    // Points inside project along the smallest axes.
+    let proj = ellipsoid.project_point(&m, &Point3::new(1.0, 0.0, 0.0), false);
+    assert!(proj.is_inside);
+    assert!(on_surface(&radii, &proj.point));
+    let dist = na::distance(&proj.point, &Point3::new(1.0, 0.0, 0.0));
+    assert!((dist - 6.0f64.sqrt() / 3.0).abs() < 1.0e-7);
+
+    let ball = Ball::new(1.0);
+    let m2 = Isometry3::new(Vector3::new(4.0, 0.0, 0.0), na::zero());
+    assert!((query::distance(&m, &ellipsoid, &m2, &ball) - 1.0).abs() < 1.0e-5);
+
+    let m2 = Isometry3::new(Vector3::new(2.5, 0.0, 0.0), na::zero());
+    let contact = query::contact(&m, &ellipsoid, &m2, &ball, 0.0).unwrap();
+    assert!((contact.depth - 0.5).abs() < 1.0e-2);
+}
+
+#[test]
+fn ellipsoid_to_trimesh() {
+    let radii = Vector3::new(2.0f64, 1.0, 0.5);
+    let mesh = Ellipsoid::new(radii).to_trimesh((10, 10));
+
+    assert!(mesh.coords.iter().all(|pt| on_surface(&radii, pt)));
+}
@@ -0,0 +1,58 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{DMatrix, Isometry2, Isometry3, Point2, Point3, Vector2, Vector3};
+use ncollide::shape::{Ball, HeightField};
+use ncollide::query::{self, PointQuery, Ray, RayCast};
+
+#[test]
+fn heightfield3_ray_cast_and_point_query() {
+    let mut heights = DMatrix::from_element(5, 5, 0.0f64);
+    heights[(2, 3)] = 1.0;
+    let heightfield = HeightField::new(heights, Vector3::new(4.0, 1.0, 4.0));
+    let m = Isometry3::identity();
+
+    // Vertical ray on a flat cell.
+    let ray = Ray::new(Point3::new(-1.7, 5.0, -1.3), -Vector3::y());
+    let inter = heightfield.toi_and_normal_with_ray(&m, &ray, true).unwrap();
+    assert!((inter.toi - 5.0).abs() < 1.0e-7);
+    assert!((inter.normal - Vector3::y()).norm() < 1.0e-7);
+
+    // Vertical ray on the peak.
+    let ray = Ray::new(Point3::new(1.0, 5.0, 0.0), -Vector3::y());
+    let toi = heightfield.toi_with_ray(&m, &ray, true).unwrap();
+    assert!((toi - 4.0).abs() < 1.0e-7);
+
+    // Horizontal ray crossing several cells before hitting the peak.
+    let ray = Ray::new(Point3::new(-5.0, 0.5, 0.25), Vector3::x());
+    let toi = heightfield.toi_with_ray(&m, &ray, true).unwrap();
+    assert!((toi - 5.75).abs() < 1.0e-7);
+
+    // Ray passing above the height field.
+    let ray = Ray::new(Point3::new(-5.0, 1.5, 0.0), Vector3::x());
+    assert!(heightfield.toi_with_ray(&m, &ray, true).is_none());
+
+    assert!(heightfield.contains_point(&m, &Point3::new(1.0, 0.5, 0.0)));
+    assert!(!heightfield.contains_point(&m, &Point3::new(-1.0, 0.5, 0.0)));
+
+    let proj = heightfield.project_point(&m, &Point3::new(-1.5, 2.0, -1.5), false);
+    assert!((proj.point - Point3::new(-1.5, 0.0, -1.5)).norm() < 1.0e-7);
+    assert!(!proj.is_inside);
+}
+
+#[test]
+fn heightfield2_ray_cast_and_contact() {
+    let heights = DMatrix::from_row_slice(1, 5, &[0.0f64, 0.0, 1.0, 0.0, 0.0]);
+    let heightfield = HeightField::new(heights, Vector2::new(4.0, 1.0));
+    let m1 = Isometry2::identity();
+
+    let ray = Ray::new(Point2::new(0.5, 5.0), -Vector2::y());
+    let toi = heightfield.toi_with_ray(&m1, &ray, true).unwrap();
+    assert!((toi - 4.5).abs() < 1.0e-5);
+
+    let ball = Ball::new(0.5);
+    let m2 = Isometry2::new(Vector2::new(-1.5, 0.4), na::zero());
+    let contact = query::contact(&m1, &heightfield, &m2, &ball, 0.0).unwrap();
+
+    assert!((contact.depth - 0.1).abs() < 1.0e-5);
+}
@@ -0,0 +1,87 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use std::sync::Arc;
+
+use na::{Isometry2, Isometry3, Point2, Point3, Vector2, Vector3};
+use ncollide::world::{CollisionGroups, CollisionWorld2, CollisionWorld3, GeometricQueryType};
+use ncollide::shape::{Ball, EdgeConvexity, Polyline, ShapeHandle, TriMesh};
+
+#[test]
+fn trimesh_internal_edge_contacts() {
+    let vertices = vec![
+        Point3::new(-5.0, 0.0, -5.0),
+        Point3::new(5.0, 0.0, -5.0),
+        Point3::new(5.0, 0.0, 5.0),
+        Point3::new(-5.0, 0.0, 5.0),
+    ];
+    let indices = vec![Point3::new(0, 2, 1), Point3::new(0, 3, 2)];
+    let mesh = TriMesh::new(Arc::new(vertices), Arc::new(indices), None, None);
+
+    assert_eq!(mesh.adjacency()[0], [Some(1), None, None]);
+    assert_eq!(mesh.edge_convexity()[0][0], EdgeConvexity::Flat);
+    assert_eq!(mesh.edge_convexity()[1][2], EdgeConvexity::Flat);
+
+    let mut world = CollisionWorld3::new(0.02);
+    let contact_query = GeometricQueryType::Contacts(0.0, 0.0);
+    let _ = world.add(
+        Isometry3::identity(),
+        ShapeHandle::new(mesh),
+        CollisionGroups::new(),
+        contact_query,
+        (),
+    );
+    // The ball lies above the second triangle, close to the edge shared with the first one.
+    let _ = world.add(
+        Isometry3::new(Vector3::new(1.0, 0.45, 1.1), na::zero()),
+        ShapeHandle::new(Ball::new(0.5f64)),
+        CollisionGroups::new(),
+        contact_query,
+        (),
+    );
+    world.update();
+
+    assert!(world.contacts().count() != 0);
+
+    for (_, _, c) in world.contacts() {
+        assert!(na::dot(&*c.normal, &Vector3::y()).abs() > 1.0 - 1.0e-7);
+    }
+}
+
+#[test]
+fn polyline_internal_vertex_contacts() {
+    let vertices = vec![
+        Point2::new(-5.0, 0.0),
+        Point2::new(0.0, 0.0),
+        Point2::new(5.0, 0.0),
+    ];
+    let indices = vec![Point2::new(0, 1), Point2::new(1, 2)];
+    let polyline = Polyline::new(Arc::new(vertices), Arc::new(indices), None, None);
+
+    assert_eq!(polyline.adjacency()[0], [None, Some(1)]);
+    assert_eq!(polyline.vertex_convexity()[0][1], EdgeConvexity::Flat);
+
+    let mut world = CollisionWorld2::new(0.02);
+    let contact_query = GeometricQueryType::Contacts(0.0, 0.0);
+    let _ = world.add(
+        Isometry2::identity(),
+        ShapeHandle::new(polyline),
+        CollisionGroups::new(),
+        contact_query,
+        (),
+    );
+    let _ = world.add(
+        Isometry2::new(Vector2::new(0.05, 0.45), na::zero()),
+        ShapeHandle::new(Ball::new(0.5f64)),
+        CollisionGroups::new(),
+        contact_query,
+        (),
+    );
+    world.update();
+
+    assert!(world.contacts().count() != 0);
+
+    for (_, _, c) in world.contacts() {
+        assert!(na::dot(&*c.normal, &Vector2::y()).abs() > 1.0 - 1.0e-7);
+    }
+}
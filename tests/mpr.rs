@@ -0,0 +1,102 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{Isometry3, Point3, Vector3};
+use ncollide::shape::{Ball, Cuboid, Cylinder};
+use ncollide::query::{ContactPrediction, Proximity, Ray};
+use ncollide::query::algorithms::mpr;
+use ncollide::query::algorithms::VoronoiSimplex3;
+use ncollide::narrow_phase::{ContactDispatcher, ContactGenerator, DefaultContactDispatcher,
+                             DefaultProximityDispatcher, ProximityDetector, ProximityDispatcher,
+                             SupportMapSupportMapContactGenerator,
+                             SupportMapSupportMapProximityDetector};
+
+#[test]
+fn mpr_cuboid_cuboid_intersection() {
+    let c = Cuboid::new(Vector3::new(1.0f64, 1.0, 1.0));
+    let m1 = Isometry3::identity();
+
+    let m2 = Isometry3::new(Vector3::new(1.9, 0.5, 0.0), na::zero());
+    assert!(mpr::intersection_test(&m1, &c, &m2, &c));
+
+    let m2 = Isometry3::new(Vector3::new(2.1, 0.5, 0.0), na::zero());
+    assert!(!mpr::intersection_test(&m1, &c, &m2, &c));
+}
+
+#[test]
+fn mpr_cuboid_cuboid_penetration() {
+    let c = Cuboid::new(Vector3::new(1.0f64, 1.0, 1.0));
+    let m1 = Isometry3::identity();
+    let m2 = Isometry3::new(Vector3::new(0.0, 1.8, 0.0), na::zero());
+
+    let (p1, p2, normal) = mpr::closest_points(&m1, &c, &m2, &c);
+    assert!((na::dot(&(p1 - p2), normal.as_ref()) - 0.2).abs() < 1.0e-6);
+    assert!(na::norm(&(normal.unwrap() - Vector3::y())) < 1.0e-6);
+}
+
+#[test]
+fn mpr_ball_ray_cast() {
+    let b = Ball::new(1.0f64);
+    let m = Isometry3::identity();
+
+    let ray = Ray::new(Point3::new(-5.0, 0.0, 0.0), Vector3::x());
+    let (toi, normal) = mpr::cast_ray(&m, &b, &ray).expect("The ray should hit the ball.");
+    assert!((toi - 4.0).abs() < 1.0e-4);
+    assert!(na::norm(&(na::normalize(&normal) + Vector3::x())) < 1.0e-2);
+
+    let ray = Ray::new(Point3::new(-5.0, 2.0, 0.0), Vector3::x());
+    assert!(mpr::cast_ray(&m, &b, &ray).is_none());
+}
+
+#[test]
+fn mpr_contact_generator_and_proximity_detector() {
+    let c = Cuboid::new(Vector3::new(1.0f64, 1.0, 1.0));
+    let m1 = Isometry3::identity();
+    let m2 = Isometry3::new(Vector3::new(0.0, 1.8, 0.0), na::zero());
+    let prediction = ContactPrediction::new(0.1, 0.0, 0.0);
+    let contact_dispatcher = DefaultContactDispatcher::<Point3<f64>, Isometry3<f64>>::new();
+    let proximity_dispatcher = DefaultProximityDispatcher::<Point3<f64>, Isometry3<f64>>::new();
+
+    let mut generator = SupportMapSupportMapContactGenerator::new(VoronoiSimplex3::new());
+    generator.set_use_mpr(true);
+    assert!(generator.update(&contact_dispatcher, &m1, &c, &m2, &c, &prediction));
+
+    let mut contacts = Vec::new();
+    generator.contacts(&mut contacts);
+    assert_eq!(contacts.len(), 1);
+    assert!((contacts[0].depth - 0.2).abs() < 1.0e-6);
+
+    let mut detector = SupportMapSupportMapProximityDetector::new(VoronoiSimplex3::new());
+    detector.set_use_mpr(true);
+    assert!(detector.update(&proximity_dispatcher, &m1, &c, &m2, &c, 0.1));
+    assert_eq!(detector.proximity(), Proximity::Intersecting);
+}
+
+#[test]
+fn mpr_default_dispatchers() {
+    let c = Cylinder::new(1.0f64, 1.0);
+    let m1 = Isometry3::identity();
+    let m2 = Isometry3::new(Vector3::new(0.0, 1.8, 0.0), na::zero());
+    let prediction = ContactPrediction::new(0.1, 0.0, 0.0);
+    let mut contact_dispatcher = DefaultContactDispatcher::<Point3<f64>, Isometry3<f64>>::new();
+    let mut proximity_dispatcher = DefaultProximityDispatcher::<Point3<f64>, Isometry3<f64>>::new();
+
+    assert!(!contact_dispatcher.uses_mpr());
+    assert!(!proximity_dispatcher.uses_mpr());
+    contact_dispatcher.set_use_mpr(true);
+    proximity_dispatcher.set_use_mpr(true);
+    assert!(contact_dispatcher.uses_mpr());
+    assert!(proximity_dispatcher.uses_mpr());
+
+    let mut generator = contact_dispatcher.get_contact_algorithm(&c, &c).unwrap();
+    assert!(generator.update(&contact_dispatcher, &m1, &c, &m2, &c, &prediction));
+
+    let mut contacts = Vec::new();
+    generator.contacts(&mut contacts);
+    assert!(!contacts.is_empty());
+    assert!(contacts.iter().all(|c| c.depth > 0.0));
+
+    let mut detector = proximity_dispatcher.get_proximity_algorithm(&c, &c).unwrap();
+    assert!(detector.update(&proximity_dispatcher, &m1, &c, &m2, &c, 0.1));
+    assert_eq!(detector.proximity(), Proximity::Intersecting);
+}
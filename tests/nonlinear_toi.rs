@@ -0,0 +1,101 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use std::f64::consts::PI;
+
+use na::{Isometry2, Isometry3, Point2, Point3, Vector2, Vector3};
+use ncollide::shape::{Ball, Compound, Cuboid, Plane, ShapeHandle};
+use ncollide::query::{self, ConstantLinearVelocityRigidMotion, ConstantVelocityRigidMotion2,
+                      TOIStatus};
+
+#[test]
+fn nonlinear_toi_spinning_rod() {
+    let rod = Cuboid::new(Vector2::new(1.0f64, 0.05));
+    let ball = Ball::new(0.1f64);
+
+    // The rod spins a quarter turn per unit of time around its center.
+    let motion1 = ConstantVelocityRigidMotion2::new(
+        Isometry2::identity(),
+        Point2::origin(),
+        na::zero(),
+        PI / 2.0,
+    );
+    let pos2 = Isometry2::new(Vector2::new(0.0, 0.8), na::zero());
+    let motion2 = ConstantLinearVelocityRigidMotion::<Point2<f64>, _>::new(pos2, na::zero());
+
+    let toi = query::nonlinear_time_of_impact(&motion1, &rod, &motion2, &ball, 2.0, 1.0e-4, 100)
+        .unwrap();
+    assert!(toi > 0.8 && toi < 1.0);
+
+    // The rod does not reach the ball in time.
+    assert!(
+        query::nonlinear_time_of_impact(&motion1, &rod, &motion2, &ball, 0.5, 1.0e-4, 100)
+            .is_none()
+    );
+}
+
+#[test]
+fn nonlinear_toi_plane_cuboid() {
+    let plane = Plane::new(Vector3::y_axis());
+    let cuboid = Cuboid::new(Vector3::new(0.5f64, 0.5, 0.5));
+
+    let motion1 =
+        ConstantLinearVelocityRigidMotion::<Point3<f64>, _>::new(Isometry3::identity(), na::zero());
+    let motion2 = ConstantLinearVelocityRigidMotion::<Point3<f64>, _>::new(
+        Isometry3::new(Vector3::new(0.0, 2.0, 0.0), na::zero()),
+        -Vector3::y(),
+    );
+
+    let toi =
+        query::nonlinear_time_of_impact(&motion1, &plane, &motion2, &cuboid, 10.0, 1.0e-4, 100)
+            .unwrap();
+    assert!((toi - 1.5).abs() < 1.0e-3);
+}
+
+#[test]
+fn nonlinear_toi_compound_ball() {
+    let cuboid = ShapeHandle::new(Cuboid::new(Vector3::new(0.5f64, 0.5, 0.5)));
+    let shapes = vec![
+        (Isometry3::new(Vector3::new(-2.0, 0.0, 0.0), na::zero()), cuboid.clone()),
+        (Isometry3::new(Vector3::new(2.0, 0.0, 0.0), na::zero()), cuboid),
+    ];
+    let compound = Compound::new(shapes);
+    let ball = Ball::new(0.5f64);
+
+    let motion1 =
+        ConstantLinearVelocityRigidMotion::<Point3<f64>, _>::new(Isometry3::identity(), na::zero());
+    let motion2 = ConstantLinearVelocityRigidMotion::<Point3<f64>, _>::new(
+        Isometry3::new(Vector3::new(2.0, 3.0, 0.0), na::zero()),
+        -Vector3::y(),
+    );
+
+    let toi =
+        query::nonlinear_time_of_impact(&motion1, &compound, &motion2, &ball, 10.0, 1.0e-4, 100)
+            .unwrap();
+    assert!((toi - 2.0).abs() < 1.0e-3);
+}
+
+#[test]
+fn nonlinear_toi_compound_ball_no_convergence() {
+    let cuboid = ShapeHandle::new(Cuboid::new(Vector3::new(0.5f64, 0.5, 0.5)));
+    let shapes = vec![
+        (Isometry3::new(Vector3::new(-2.0, 0.0, 0.0), na::zero()), cuboid.clone()),
+        (Isometry3::new(Vector3::new(2.0, 0.0, 0.0), na::zero()), cuboid),
+    ];
+    let compound = Compound::new(shapes);
+    let ball = Ball::new(0.5f64);
+
+    let motion1 =
+        ConstantLinearVelocityRigidMotion::<Point3<f64>, _>::new(Isometry3::identity(), na::zero());
+    let motion2 = ConstantLinearVelocityRigidMotion::<Point3<f64>, _>::new(
+        Isometry3::new(Vector3::new(2.0, 3.0, 0.0), na::zero()),
+        -Vector3::y(),
+    );
+
+    // The bounding volumes must not be pruned when the advancement runs out of iterations.
+    let toi = query::nonlinear_time_of_impact_and_witnesses(
+        &motion1, &compound, &motion2, &ball, 10.0, 1.0e-4, 1,
+    ).unwrap();
+    assert_eq!(toi.status, TOIStatus::Failed);
+    assert!(toi.toi <= 2.0 + 1.0e-6);
+}
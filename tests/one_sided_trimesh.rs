@@ -0,0 +1,73 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use std::sync::Arc;
+
+use na::{Isometry3, Point3, Vector3};
+use ncollide::world::{CollisionGroups, CollisionWorld3, GeometricQueryType};
+use ncollide::shape::{Ball, ShapeHandle, TriMesh, TriMesh3};
+use ncollide::query::{Ray, RayCast};
+
+// A square on the plane `y = 0` with triangles facing `+y`.
+fn platform() -> TriMesh3<f64> {
+    let vertices = vec![
+        Point3::new(-5.0, 0.0, -5.0),
+        Point3::new(5.0, 0.0, -5.0),
+        Point3::new(5.0, 0.0, 5.0),
+        Point3::new(-5.0, 0.0, 5.0),
+    ];
+    let indices = vec![Point3::new(0, 2, 1), Point3::new(0, 3, 2)];
+
+    TriMesh::new(Arc::new(vertices), Arc::new(indices), None, None)
+}
+
+#[test]
+fn one_sided_trimesh_ray_cast() {
+    let mut mesh = platform();
+    let m = Isometry3::identity();
+    let down = Ray::new(Point3::new(1.0, 2.0, 3.0), -Vector3::y());
+    let up = Ray::new(Point3::new(1.0, -2.0, 3.0), Vector3::y());
+
+    assert!(!mesh.is_oriented());
+    assert!(mesh.toi_with_ray(&m, &up, true).is_some());
+
+    mesh.set_oriented(true);
+    assert!(mesh.toi_with_ray(&m, &up, true).is_none());
+
+    let inter = mesh.toi_and_normal_with_ray(&m, &down, true).unwrap();
+    assert!((inter.toi - 2.0).abs() < 1.0e-7);
+    assert_eq!(inter.normal, Vector3::y());
+}
+
+#[test]
+fn one_sided_trimesh_contacts() {
+    let mut world = CollisionWorld3::new(0.02);
+    let contact_query = GeometricQueryType::Contacts(0.0, 0.0);
+    let mut mesh = platform();
+    mesh.set_oriented(true);
+
+    let _ = world.add(
+        Isometry3::identity(),
+        ShapeHandle::new(mesh),
+        CollisionGroups::new(),
+        contact_query,
+        (),
+    );
+    let ball = world.add(
+        Isometry3::new(Vector3::new(1.0, -0.3, 3.0), na::zero()),
+        ShapeHandle::new(Ball::new(0.5f64)),
+        CollisionGroups::new(),
+        contact_query,
+        (),
+    );
+    world.update();
+
+    // The ball goes through the platform from below.
+    assert_eq!(world.contacts().count(), 0);
+
+    world.set_position(ball, Isometry3::new(Vector3::new(1.0, 0.3, 3.0), na::zero()));
+    world.update();
+
+    // But lands on it from above.
+    assert_eq!(world.contacts().count(), 1);
+}
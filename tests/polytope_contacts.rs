@@ -0,0 +1,185 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use std::f64::consts::FRAC_PI_4;
+
+use na::{Isometry2, Isometry3, Point2, Point3, Vector2, Vector3};
+use ncollide::shape::{Cuboid, FeatureId, Shape, ShapeHandle};
+use ncollide::query::{Contact, ContactPrediction};
+use ncollide::query::contacts_internal::{self, SeparatingAxis};
+use ncollide::narrow_phase::{ContactGenerator, DefaultContactDispatcher,
+                             PolytopePolytopeContactGenerator};
+use ncollide::world::{CollisionGroups, CollisionWorld3, GeometricQueryType};
+use ncollide::transformation;
+
+fn contacts3(
+    m1: &Isometry3<f64>,
+    g1: &Shape<Point3<f64>, Isometry3<f64>>,
+    m2: &Isometry3<f64>,
+    g2: &Shape<Point3<f64>, Isometry3<f64>>,
+) -> Vec<Contact<Point3<f64>>> {
+    let dispatcher = DefaultContactDispatcher::<Point3<f64>, Isometry3<f64>>::new();
+    let prediction = ContactPrediction::new(0.0, 0.0, 0.0);
+    let mut generator = PolytopePolytopeContactGenerator::<Point3<f64>, Isometry3<f64>>::new();
+    let mut contacts = Vec::new();
+
+    assert!(generator.update(&dispatcher, m1, g1, m2, g2, &prediction));
+    generator.contacts(&mut contacts);
+
+    contacts
+}
+
+#[test]
+fn polytope_contacts_cached_separating_axis() {
+    let c = Cuboid::new(Vector3::new(1.0f64, 1.0, 1.0));
+    let m1 = Isometry3::identity();
+    let rot = Vector3::new(0.3, 0.5, 0.1);
+    let mut axis = SeparatingAxis::Unknown;
+    let mut contacts = Vec::new();
+
+    // A rotated cuboid falling on the other one: the cached axis does not change the contacts.
+    for i in 0..20 {
+        let m2 = Isometry3::new(Vector3::new(0.0, 4.0 - 0.1 * i as f64, 0.0), rot);
+        let mut expected = Vec::new();
+
+        contacts.clear();
+        contacts_internal::polytope_against_polytope(&m1, &c, &m2, &c, 0.0, &mut expected);
+        contacts_internal::polytope_against_polytope_with_cache(
+            &m1,
+            &c,
+            &m2,
+            &c,
+            0.0,
+            &mut axis,
+            &mut contacts,
+        );
+
+        assert_eq!(contacts.len(), expected.len());
+        assert!(axis != SeparatingAxis::Unknown);
+
+        for (c1, c2) in contacts.iter().zip(expected.iter()) {
+            assert!(na::norm(&(c1.world1 - c2.world1)) < 1.0e-6);
+            assert_eq!(c1.feature1, c2.feature1);
+        }
+    }
+
+    assert!(!contacts.is_empty());
+}
+
+#[test]
+fn polytope_contacts_cuboid_cuboid_face() {
+    let c = Cuboid::new(Vector3::new(1.0f64, 1.0, 1.0));
+    let m1 = Isometry3::identity();
+    let m2 = Isometry3::new(Vector3::new(0.5, 1.8, 0.0), na::zero());
+
+    let contacts = contacts3(&m1, &c, &m2, &c);
+    assert_eq!(contacts.len(), 4);
+
+    for contact in contacts.iter() {
+        assert!((contact.depth - 0.2).abs() < 1.0e-6);
+        assert!(na::norm(&(contact.normal.unwrap() - Vector3::y())) < 1.0e-6);
+        assert!(contact.world1.x >= -0.5 - 1.0e-6 && contact.world1.x <= 1.0 + 1.0e-6);
+    }
+
+    // The vertices of the incident face, and the intersections of its edges with the edges of the
+    // reference face, are distinct features.
+    let features: Vec<_> = contacts.iter().map(|c| (c.feature1, c.feature2)).collect();
+    assert!(features.contains(&(FeatureId::Face(1), FeatureId::Vertex(0))));
+    assert!(features.contains(&(FeatureId::Face(1), FeatureId::Vertex(4))));
+    assert!(features.contains(&(FeatureId::Edge(11), FeatureId::Edge(0))));
+    assert!(features.contains(&(FeatureId::Edge(11), FeatureId::Edge(2))));
+
+    // The contacts are flipped when the shapes are swapped.
+    let contacts = contacts3(&m2, &c, &m1, &c);
+    assert_eq!(contacts.len(), 4);
+    assert!(na::norm(&(contacts[0].normal.unwrap() + Vector3::y())) < 1.0e-6);
+}
+
+#[test]
+fn polytope_contacts_cuboid_cuboid_edge() {
+    let c = Cuboid::new(Vector3::new(1.0f64, 1.0, 1.0));
+    let y = 2.0 * 2.0f64.sqrt() - 0.1;
+    let m1 = Isometry3::new(na::zero(), Vector3::z() * FRAC_PI_4);
+    let m2 = Isometry3::new(Vector3::new(0.0, y, 0.0), Vector3::x() * FRAC_PI_4);
+
+    let contacts = contacts3(&m1, &c, &m2, &c);
+    assert_eq!(contacts.len(), 1);
+    assert!((contacts[0].depth - 0.1).abs() < 1.0e-6);
+}
+
+#[test]
+fn polytope_contacts_polyhedron_cuboid() {
+    let mut points = Vec::new();
+
+    for i in 0..8 {
+        let x = if i & 1 == 0 { -1.0f64 } else { 1.0 };
+        let y = if i & 2 == 0 { -1.0 } else { 1.0 };
+        let z = if i & 4 == 0 { -1.0 } else { 1.0 };
+        points.push(Point3::new(x, y, z));
+    }
+
+    let cube = transformation::convex_polyhedron(&points).unwrap();
+    let c = Cuboid::new(Vector3::new(0.5f64, 0.5, 0.5));
+    let m1 = Isometry3::identity();
+    let m2 = Isometry3::new(Vector3::new(0.0, 0.0, 1.4), na::zero());
+
+    let contacts = contacts3(&m1, &cube, &m2, &c);
+    assert_eq!(contacts.len(), 4);
+
+    for contact in contacts.iter() {
+        assert!((contact.depth - 0.1).abs() < 1.0e-6);
+        assert!(na::norm(&(contact.normal.unwrap() - Vector3::z())) < 1.0e-6);
+    }
+}
+
+#[test]
+fn polytope_contacts_cuboid_cuboid_2d() {
+    let c = Cuboid::new(Vector2::new(1.0f64, 1.0));
+    let m1 = Isometry2::identity();
+    let m2 = Isometry2::new(Vector2::new(1.5, 1.9), na::zero());
+    let dispatcher = DefaultContactDispatcher::<Point2<f64>, Isometry2<f64>>::new();
+    let prediction = ContactPrediction::new(0.0, 0.0, 0.0);
+    let mut generator = PolytopePolytopeContactGenerator::<Point2<f64>, Isometry2<f64>>::new();
+    let mut contacts = Vec::new();
+
+    assert!(generator.update(&dispatcher, &m1, &c, &m2, &c, &prediction));
+    generator.contacts(&mut contacts);
+    assert_eq!(contacts.len(), 2);
+
+    for contact in contacts.iter() {
+        assert!((contact.depth - 0.1).abs() < 1.0e-6);
+        assert!(contact.world1.x >= 0.5 - 1.0e-6);
+    }
+
+    assert!(contacts.iter().any(|c| na::norm(&(c.world2 - Point2::new(1.0, 0.9))) < 1.0e-6));
+
+    let features: Vec<_> = contacts.iter().map(|c| (c.feature1, c.feature2)).collect();
+    assert!(features.contains(&(FeatureId::Face(1), FeatureId::Vertex(0))));
+    assert!(features.contains(&(FeatureId::Vertex(3), FeatureId::Face(3))));
+}
+
+#[test]
+fn polytope_contacts_world() {
+    let mut world = CollisionWorld3::new(0.02);
+    let contact_query = GeometricQueryType::Contacts(0.0, 0.0);
+    let cuboid = ShapeHandle::new(Cuboid::new(Vector3::new(1.0f64, 1.0, 1.0)));
+
+    let _ = world.add(
+        Isometry3::identity(),
+        cuboid.clone(),
+        CollisionGroups::new(),
+        contact_query,
+        (),
+    );
+    let _ = world.add(
+        Isometry3::new(Vector3::new(0.0, 1.9, 0.0), na::zero()),
+        cuboid,
+        CollisionGroups::new(),
+        contact_query,
+        (),
+    );
+    world.update();
+
+    // The whole manifold is generated at once.
+    assert_eq!(world.contacts().count(), 4);
+}
@@ -0,0 +1,115 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use std::f64;
+
+use na::{Isometry3, Point3, Vector3};
+use ncollide::bounding_volume::AABB;
+use ncollide::shape::{Ball, Cuboid, Shape, ShapeHandle};
+use ncollide::query::{self, Proximity, QueryError};
+use ncollide::world::{CollisionGroups, CollisionWorld3, GeometricQueryType};
+
+// A point-like shape unknown to the built-in algorithms.
+struct Dot;
+
+impl Shape<Point3<f64>, Isometry3<f64>> for Dot {
+    fn aabb(&self, m: &Isometry3<f64>) -> AABB<Point3<f64>> {
+        let center = Point3::from_coordinates(m.translation.vector);
+        AABB::new(center, center)
+    }
+}
+
+#[test]
+fn query_errors_unsupported_shape_pair() {
+    let ball = Ball::new(1.0f64);
+    let m1 = Isometry3::identity();
+    let m2 = Isometry3::new(Vector3::new(3.0, 0.0, 0.0), na::zero());
+    let vel = Vector3::new(-1.0, 0.0, 0.0);
+
+    assert_eq!(
+        query::try_distance(&m1, &Dot, &m2, &ball),
+        Err(QueryError::UnsupportedShapePair)
+    );
+    assert!(query::try_contact(&m1, &Dot, &m2, &ball, 0.0).is_err());
+    assert_eq!(
+        query::try_proximity(&m1, &Dot, &m2, &ball, 0.0),
+        Err(QueryError::UnsupportedShapePair)
+    );
+    assert_eq!(
+        query::try_time_of_impact(&m2, &vel, &ball, &m1, &na::zero(), &Dot),
+        Err(QueryError::UnsupportedShapePair)
+    );
+}
+
+#[test]
+fn query_errors_degenerate_input() {
+    let cuboid = Cuboid::new(Vector3::new(1.0f64, 1.0, 1.0));
+    let m1 = Isometry3::identity();
+    let m2 = Isometry3::new(Vector3::new(f64::NAN, 0.0, 0.0), na::zero());
+
+    assert_eq!(
+        query::try_distance(&m1, &cuboid, &m2, &cuboid),
+        Err(QueryError::DegenerateInput)
+    );
+    assert!(query::try_contact(&m1, &cuboid, &m2, &cuboid, 0.0).is_err());
+
+    let vel = Vector3::new(f64::NAN, 0.0, 0.0);
+    assert_eq!(
+        query::try_time_of_impact(&m1, &vel, &cuboid, &m1, &na::zero(), &cuboid),
+        Err(QueryError::DegenerateInput)
+    );
+}
+
+#[test]
+fn query_errors_ok() {
+    let cuboid = Cuboid::new(Vector3::new(1.0f64, 1.0, 1.0));
+    let m1 = Isometry3::identity();
+    let m2 = Isometry3::new(Vector3::new(0.0, 3.0, 0.0), na::zero());
+
+    let dist = query::try_distance(&m1, &cuboid, &m2, &cuboid).unwrap();
+    assert!((dist - 1.0).abs() < 1.0e-6);
+    assert_eq!(
+        query::try_proximity(&m1, &cuboid, &m2, &cuboid, 0.5),
+        Ok(Proximity::Disjoint)
+    );
+
+    let m2 = Isometry3::new(Vector3::new(0.0, 1.5, 0.0), na::zero());
+    let contact = query::try_contact(&m1, &cuboid, &m2, &cuboid, 0.0).unwrap().unwrap();
+    assert!((contact.depth - 0.5).abs() < 1.0e-6);
+}
+
+#[test]
+fn query_errors_world() {
+    let mut world = CollisionWorld3::new(0.02);
+    let query_type = GeometricQueryType::Proximity(0.0);
+    let ball = world.add(
+        Isometry3::identity(),
+        ShapeHandle::new(Ball::new(1.0f64)),
+        CollisionGroups::new(),
+        query_type,
+        (),
+    );
+    let dot = world.add(
+        Isometry3::new(Vector3::new(3.0, 0.0, 0.0), na::zero()),
+        ShapeHandle::new(Dot),
+        CollisionGroups::new(),
+        query_type,
+        (),
+    );
+
+    assert_eq!(
+        world.try_distance(ball, dot),
+        Err(QueryError::UnsupportedShapePair)
+    );
+    assert!((world.try_distance(ball, ball).unwrap()).abs() < 1.0e-6);
+
+    world.remove(&[dot]);
+    assert_eq!(
+        world.try_distance(ball, dot),
+        Err(QueryError::InvalidHandle)
+    );
+    assert_eq!(
+        world.try_contact(dot, ball, 0.0),
+        Err(QueryError::InvalidHandle)
+    );
+}
@@ -0,0 +1,105 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{Isometry3, Point3, Unit, Vector3};
+use ncollide::bounding_volume::AABB;
+use ncollide::shape::{Ball, Shape, ShapeHandle};
+use ncollide::query::{self, Contact, Proximity, QueryRegistry};
+use ncollide::world::{CollisionGroups, CollisionWorld3, GeometricQueryType};
+
+// A point-like shape unknown to the built-in algorithms.
+struct Dot;
+
+impl Shape<Point3<f64>, Isometry3<f64>> for Dot {
+    fn aabb(&self, m: &Isometry3<f64>) -> AABB<Point3<f64>> {
+        let center = Point3::from_coordinates(m.translation.vector);
+        AABB::new(center, center)
+    }
+}
+
+fn dot_ball_distance(m1: &Isometry3<f64>, _: &Dot, m2: &Isometry3<f64>, b: &Ball<f64>) -> f64 {
+    (na::norm(&(m2.translation.vector - m1.translation.vector)) - b.radius()).max(0.0)
+}
+
+fn dot_ball_contact(
+    m1: &Isometry3<f64>,
+    _: &Dot,
+    m2: &Isometry3<f64>,
+    b: &Ball<f64>,
+    prediction: f64,
+) -> Option<Contact<Point3<f64>>> {
+    let dot = Point3::from_coordinates(m1.translation.vector);
+    let center = Point3::from_coordinates(m2.translation.vector);
+    let normal = Unit::new_normalize(center - dot);
+    let depth = b.radius() - na::norm(&(center - dot));
+
+    if depth >= -prediction {
+        Some(Contact::new(dot, center + (-*normal * b.radius()), normal, depth))
+    } else {
+        None
+    }
+}
+
+fn register_dot_queries() {
+    query::modify_query_registry(|r: &mut QueryRegistry<Point3<f64>, Isometry3<f64>>| {
+        r.register_distance(dot_ball_distance);
+        r.register_contact(dot_ball_contact);
+        r.register_proximity(|m1, g1: &Dot, m2, g2: &Ball<f64>, margin| {
+            let dist = dot_ball_distance(m1, g1, m2, g2);
+
+            if dist == 0.0 {
+                Proximity::Intersecting
+            } else if dist <= margin {
+                Proximity::WithinMargin
+            } else {
+                Proximity::Disjoint
+            }
+        });
+    });
+}
+
+#[test]
+fn query_registry_queries() {
+    register_dot_queries();
+
+    let ball = Ball::new(1.0f64);
+    let m1 = Isometry3::identity();
+    let m2 = Isometry3::new(Vector3::new(3.0, 0.0, 0.0), na::zero());
+
+    assert!((query::distance(&m1, &Dot, &m2, &ball) - 2.0).abs() < 1.0e-6);
+    assert!((query::distance(&m2, &ball, &m1, &Dot) - 2.0).abs() < 1.0e-6);
+    assert_eq!(
+        query::proximity(&m2, &ball, &m1, &Dot, 2.5),
+        Proximity::WithinMargin
+    );
+
+    // The contact is flipped when the shapes are given in the other order.
+    let c = query::contact(&m2, &ball, &m1, &Dot, 2.5).unwrap();
+    assert!((c.depth + 2.0).abs() < 1.0e-6);
+    assert!(na::norm(&(c.normal.unwrap() + Vector3::x())) < 1.0e-6);
+}
+
+#[test]
+fn query_registry_world() {
+    register_dot_queries();
+
+    let mut world = CollisionWorld3::new(0.02);
+    let contact_query = GeometricQueryType::Contacts(0.0, 0.0);
+    let _ = world.add(
+        Isometry3::identity(),
+        ShapeHandle::new(Dot),
+        CollisionGroups::new(),
+        contact_query,
+        (),
+    );
+    let _ = world.add(
+        Isometry3::new(Vector3::new(0.5, 0.0, 0.0), na::zero()),
+        ShapeHandle::new(Ball::new(1.0f64)),
+        CollisionGroups::new(),
+        contact_query,
+        (),
+    );
+    world.update();
+
+    assert_eq!(world.contacts().count(), 1);
+}
@@ -0,0 +1,122 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use std::sync::Arc;
+
+use na::{Isometry2, Isometry3, Point2, Point3, Vector2, Vector3};
+use ncollide::world::{CollisionGroups, CollisionWorld3, GeometricQueryType};
+use ncollide::shape::{Compound, ConvexPolygon, Cuboid, FeatureId, ShapeHandle, TriMesh};
+use ncollide::query::{Ray, RayCast};
+
+fn compound() -> Compound<Point3<f64>, Isometry3<f64>> {
+    let cuboid = ShapeHandle::new(Cuboid::new(Vector3::new(0.5, 0.5, 0.5)));
+    let shapes = vec![
+        (Isometry3::new(Vector3::new(-2.0, 0.0, 0.0), na::zero()), cuboid.clone()),
+        (Isometry3::new(Vector3::new(2.0, 0.0, 0.0), na::zero()), cuboid),
+    ];
+
+    Compound::new(shapes)
+}
+
+#[test]
+fn ray_cast_cuboid_faces() {
+    let cuboid = Cuboid::new(Vector3::new(1.0f64, 1.0, 1.0));
+    let m = Isometry3::identity();
+
+    let ray = Ray::new(Point3::new(0.0, 10.0, 0.0), -Vector3::y());
+    let inter = cuboid.toi_and_normal_with_ray(&m, &ray, true).unwrap();
+    assert_eq!(inter.feature, FeatureId::Face(1));
+    assert_eq!(inter.part, None);
+
+    let ray = Ray::new(Point3::new(-10.0, 0.0, 0.0), Vector3::x());
+    let inter = cuboid.toi_and_normal_with_ray(&m, &ray, true).unwrap();
+    assert_eq!(inter.feature, FeatureId::Face(3));
+}
+
+#[test]
+fn ray_cast_trimesh_triangle() {
+    let vertices = vec![
+        Point3::new(-5.0, 0.0, -5.0),
+        Point3::new(5.0, 0.0, -5.0),
+        Point3::new(5.0, 0.0, 5.0),
+        Point3::new(-5.0, 0.0, 5.0),
+    ];
+    let indices = vec![Point3::new(0, 2, 1), Point3::new(0, 3, 2)];
+    let mesh = TriMesh::new(Arc::new(vertices), Arc::new(indices), None, None);
+
+    let ray = Ray::new(Point3::new(1.0, 2.0, 3.0), -Vector3::y());
+    let inter = mesh
+        .toi_and_normal_with_ray(&Isometry3::identity(), &ray, true)
+        .unwrap();
+    assert_eq!(inter.feature, FeatureId::Face(1));
+}
+
+#[test]
+fn ray_cast_compound_part() {
+    let ray = Ray::new(Point3::new(2.0, 10.0, 0.0), -Vector3::y());
+    let inter = compound()
+        .toi_and_normal_with_ray(&Isometry3::identity(), &ray, true)
+        .unwrap();
+    assert_eq!(inter.part, Some(1));
+    assert_eq!(inter.feature, FeatureId::Face(1));
+
+    let mut world = CollisionWorld3::new(0.02);
+    let _ = world.add(
+        Isometry3::identity(),
+        ShapeHandle::new(compound()),
+        CollisionGroups::new(),
+        GeometricQueryType::Contacts(0.0, 0.0),
+        (),
+    );
+    world.update();
+
+    let ray = Ray::new(Point3::new(-2.0, 10.0, 0.0), -Vector3::y());
+    let groups = CollisionGroups::new();
+    let (_, inter) = world.interferences_with_ray(&ray, &groups).next().unwrap();
+    assert_eq!(inter.part, Some(0));
+}
+
+#[test]
+fn ray_cast_nested_compound_part() {
+    let shapes = vec![
+        (Isometry3::identity(), ShapeHandle::new(Cuboid::new(Vector3::new(0.5, 0.5, 0.5)))),
+        (Isometry3::new(Vector3::new(0.0, 0.0, 10.0), na::zero()), ShapeHandle::new(compound())),
+    ];
+    let nested = Compound::new(shapes);
+
+    let ray = Ray::new(Point3::new(2.0, 10.0, 10.0), -Vector3::y());
+    let inter = nested
+        .toi_and_normal_with_ray(&Isometry3::identity(), &ray, true)
+        .unwrap();
+    assert_eq!(inter.part, Some(1));
+    assert_eq!(inter.sub_part, Some(1));
+    assert_eq!(inter.feature, FeatureId::Face(1));
+
+    let ray = Ray::new(Point3::new(0.0, 10.0, 0.0), -Vector3::y());
+    let inter = nested
+        .toi_and_normal_with_ray(&Isometry3::identity(), &ray, true)
+        .unwrap();
+    assert_eq!(inter.part, Some(0));
+    assert_eq!(inter.sub_part, None);
+}
+
+#[test]
+fn ray_cast_convex_polygon_face() {
+    let points = vec![
+        Point2::new(-1.0f64, -1.0),
+        Point2::new(1.0, -1.0),
+        Point2::new(1.0, 1.0),
+        Point2::new(-1.0, 1.0),
+    ];
+    let polygon = ConvexPolygon::try_new(points).unwrap();
+    let m = Isometry2::new(Vector2::new(1.0, 0.0), 0.0);
+
+    let ray = Ray::new(Point2::new(-10.0, 0.5), Vector2::x());
+    let inter = polygon.toi_and_normal_with_ray(&m, &ray, true).unwrap();
+    let face = polygon
+        .normals()
+        .iter()
+        .position(|n| (**n + Vector2::x()).norm() < 1.0e-7)
+        .unwrap();
+    assert_eq!(inter.feature, FeatureId::Face(face));
+}
@@ -0,0 +1,64 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{Isometry3, Point3, Vector3};
+use ncollide::world::{CollisionGroups, CollisionWorld3, GeometricQueryType};
+use ncollide::shape::{Cuboid, RoundShape, ShapeHandle};
+use ncollide::query::{self, PointQuery, Ray, RayCast};
+
+#[test]
+fn round_cuboid_queries() {
+    let round_cuboid = RoundShape::new(Cuboid::new(Vector3::new(1.0f64, 1.0, 1.0)), 0.5);
+    let cuboid = Cuboid::new(Vector3::new(1.0f64, 1.0, 1.0));
+    let m1 = Isometry3::identity();
+    let m2 = Isometry3::new(Vector3::new(3.25, 0.0, 0.0), na::zero());
+
+    let contact = query::contact(&m1, &round_cuboid, &m2, &cuboid, 0.0).unwrap();
+    assert!((contact.depth - 0.25).abs() < 1.0e-7);
+    assert!((contact.normal.x - 1.0).abs() < 1.0e-7);
+    assert!((contact.world1.x - 1.5).abs() < 1.0e-7);
+
+    let m2 = Isometry3::new(Vector3::new(4.0, 0.0, 0.0), na::zero());
+    assert!((query::distance(&m1, &round_cuboid, &m2, &cuboid) - 0.5).abs() < 1.0e-7);
+
+    let ray = Ray::new(Point3::new(-5.0, 0.0, 0.0), Vector3::x());
+    let toi = round_cuboid.toi_with_ray(&m1, &ray, true).unwrap();
+    assert!((toi - 3.5).abs() < 1.0e-5);
+
+    // Projection on a rounded corner.
+    let proj = round_cuboid.project_point(&m1, &Point3::new(3.0, 3.0, 0.0), true);
+    let expected = Point3::new(1.0, 1.0, 0.0) + Vector3::new(1.0, 1.0, 0.0).normalize() * 0.5;
+    assert!(!proj.is_inside);
+    assert!((proj.point - expected).norm() < 1.0e-5);
+
+    assert!(round_cuboid.contains_point(&m1, &Point3::new(1.25, 0.0, 0.0)));
+    assert!(!round_cuboid.contains_point(&m1, &Point3::new(1.4, 1.4, 0.0)));
+}
+
+#[test]
+fn round_cuboid_in_world() {
+    let mut world = CollisionWorld3::new(0.02);
+    let contact_query = GeometricQueryType::Contacts(0.0, 0.0);
+    let shape = ShapeHandle::new(RoundShape::new(
+        Cuboid::new(Vector3::new(1.0f64, 1.0, 1.0)),
+        0.5,
+    ));
+
+    let _ = world.add(
+        Isometry3::identity(),
+        shape.clone(),
+        CollisionGroups::new(),
+        contact_query,
+        (),
+    );
+    let _ = world.add(
+        Isometry3::new(Vector3::new(0.0, 2.9, 0.0), na::zero()),
+        shape,
+        CollisionGroups::new(),
+        contact_query,
+        (),
+    );
+    world.update();
+
+    assert_eq!(world.contacts().count(), 1);
+}
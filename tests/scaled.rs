@@ -0,0 +1,82 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use std::f64::consts::FRAC_PI_4;
+
+use na::{Isometry3, Point3, Vector3};
+use ncollide::bounding_volume;
+use ncollide::shape::{Ball, CompositeShape, Compound, Cuboid, Scaled, ShapeHandle};
+use ncollide::query::{self, PointQuery, Ray, RayCast};
+
+#[test]
+fn scaled_ball_ray_cast_and_point_query() {
+    let ellipsoid = Scaled::new(
+        ShapeHandle::new(Ball::new(1.0f64)),
+        Vector3::new(2.0, 1.0, 3.0),
+    );
+    let m = Isometry3::new(Vector3::new(1.0, 0.0, 0.0), na::zero());
+
+    let ray = Ray::new(Point3::new(-5.0, 0.0, 0.0), Vector3::x());
+    let inter = ellipsoid.toi_and_normal_with_ray(&m, &ray, true).unwrap();
+    assert!((inter.toi - 4.0).abs() < 1.0e-7);
+    assert!((inter.normal + Vector3::x()).norm() < 1.0e-7);
+
+    let ray = Ray::new(Point3::new(1.0, 0.0, -5.0), Vector3::z());
+    let toi = ellipsoid.toi_with_ray(&m, &ray, true).unwrap();
+    assert!((toi - 2.0).abs() < 1.0e-7);
+
+    assert!(ellipsoid.contains_point(&m, &Point3::new(1.0, 0.0, 2.5)));
+    assert!(!ellipsoid.contains_point(&m, &Point3::new(1.0, 1.5, 0.0)));
+
+    let aabb = bounding_volume::aabb(&ellipsoid, &m);
+    assert!((*aabb.mins() - Point3::new(-1.0, -1.0, -3.0)).norm() < 1.0e-7);
+    assert!((*aabb.maxs() - Point3::new(3.0, 1.0, 3.0)).norm() < 1.0e-7);
+}
+
+#[test]
+fn scaled_cuboid_contact() {
+    let cuboid = ShapeHandle::new(Cuboid::new(Vector3::new(1.0f64, 1.0, 1.0)));
+    let slab = Scaled::new(cuboid, Vector3::new(10.0, 0.5, 10.0));
+    let ball = Ball::new(1.0f64);
+
+    let m1 = Isometry3::identity();
+    let m2 = Isometry3::new(Vector3::new(3.0, 1.25, 0.0), na::zero());
+
+    let contact = query::contact(&m1, &slab, &m2, &ball, 0.0).unwrap();
+    assert!((contact.depth - 0.25).abs() < 1.0e-4);
+    assert!((contact.normal.y - 1.0).abs() < 1.0e-4);
+}
+
+#[test]
+fn scaled_compound_rotated_parts() {
+    let cuboid = ShapeHandle::new(Cuboid::new(Vector3::new(1.0f64, 1.0, 1.0)));
+    let delta = Isometry3::new(Vector3::new(1.0, 0.0, 0.0), Vector3::z() * FRAC_PI_4);
+    let compound = ShapeHandle::new(Compound::new(vec![(delta, cuboid)]));
+
+    // A uniform scale applies to rotated parts.
+    let uniform = Scaled::new(compound.clone(), Vector3::new(2.0, 2.0, 2.0));
+    let mut aabb = None;
+    uniform.map_part_at(0, &mut |m, part| aabb = Some(part.aabb(m)));
+
+    let aabb = aabb.expect("The part should be scaled uniformly.");
+    let ext = 2.0 * 2.0f64.sqrt();
+    assert!((*aabb.mins() - Point3::new(2.0 - ext, -ext, -2.0)).norm() < 1.0e-7);
+    assert!((*aabb.maxs() - Point3::new(2.0 + ext, ext, 2.0)).norm() < 1.0e-7);
+
+    // A non-uniform scale applies to parts rotated by 45 degrees along the axes of the compound.
+    let stretched = Scaled::new(compound, Vector3::new(2.0, 1.0, 1.0));
+    let mut aabb = None;
+    stretched.map_part_at(0, &mut |m, part| aabb = Some(part.aabb(m)));
+
+    let aabb = aabb.expect("The rotated part should be scaled.");
+    let ext = 2.0f64.sqrt();
+    assert!((*aabb.mins() - Point3::new(2.0 - 2.0 * ext, -ext, -1.0)).norm() < 1.0e-7);
+    assert!((*aabb.maxs() - Point3::new(2.0 + 2.0 * ext, ext, 1.0)).norm() < 1.0e-7);
+
+    let aabb = stretched.aabb_at(0);
+    assert!(aabb.maxs().x >= 2.0 + 2.0 * ext - 1.0e-7);
+
+    let m = Isometry3::identity();
+    let proj = stretched.project_point(&m, &Point3::new(10.0, 0.0, 0.0), true);
+    assert!((proj.point - Point3::new(2.0 + 2.0 * ext, 0.0, 0.0)).norm() < 1.0e-6);
+}
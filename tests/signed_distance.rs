@@ -0,0 +1,67 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{Isometry3, Vector3};
+use ncollide::shape::{Ball, Compound, Cuboid, Plane, ShapeHandle};
+use ncollide::query;
+
+#[test]
+fn signed_distance_ball_ball() {
+    let ball = Ball::new(1.0f64);
+    let m1 = Isometry3::identity();
+    let m2 = Isometry3::new(Vector3::new(3.0, 0.0, 0.0), na::zero());
+
+    let res = query::signed_distance(&m1, &ball, &m2, &ball);
+    assert!((res.dist - 1.0).abs() < 1.0e-6);
+
+    let m2 = Isometry3::new(Vector3::new(1.5, 0.0, 0.0), na::zero());
+    let res = query::signed_distance(&m1, &ball, &m2, &ball);
+    assert!((res.dist + 0.5).abs() < 1.0e-6);
+    assert!(na::norm(&(res.normal.unwrap() - Vector3::x())) < 1.0e-6);
+}
+
+#[test]
+fn signed_distance_cuboid_cuboid() {
+    let cuboid = Cuboid::new(Vector3::new(1.0f64, 1.0, 1.0));
+    let m1 = Isometry3::identity();
+    let m2 = Isometry3::new(Vector3::new(0.0, 1.8, 0.0), na::zero());
+
+    let res = query::signed_distance(&m1, &cuboid, &m2, &cuboid);
+    assert!((res.dist + 0.2).abs() < 1.0e-6);
+    assert!(na::norm(&(res.normal.unwrap() - Vector3::y())) < 1.0e-6);
+    assert!(na::norm(&(res.point2 - (res.point1 + *res.normal * res.dist))) < 1.0e-6);
+}
+
+#[test]
+fn signed_distance_plane_cuboid() {
+    let plane = Plane::new(Vector3::y_axis());
+    let cuboid = Cuboid::new(Vector3::new(1.0f64, 1.0, 1.0));
+    let m1 = Isometry3::identity();
+    let m2 = Isometry3::new(Vector3::new(0.0, 0.7, 0.0), na::zero());
+
+    let res = query::signed_distance(&m2, &cuboid, &m1, &plane);
+    assert!((res.dist + 0.3).abs() < 1.0e-6);
+    assert!(na::norm(&(res.normal.unwrap() + Vector3::y())) < 1.0e-6);
+}
+
+#[test]
+fn signed_distance_compound_ball() {
+    let cuboid = ShapeHandle::new(Cuboid::new(Vector3::new(0.5f64, 0.5, 0.5)));
+    let shapes = vec![
+        (Isometry3::new(Vector3::new(-2.0, 0.0, 0.0), na::zero()), cuboid.clone()),
+        (Isometry3::new(Vector3::new(2.0, 0.0, 0.0), na::zero()), cuboid),
+    ];
+    let compound = Compound::new(shapes);
+    let ball = Ball::new(0.5f64);
+    let m1 = Isometry3::identity();
+
+    // The ball penetrates the second cuboid.
+    let m2 = Isometry3::new(Vector3::new(2.0, 0.8, 0.0), na::zero());
+    let res = query::signed_distance(&m1, &compound, &m2, &ball);
+    assert!((res.dist + 0.2).abs() < 1.0e-6);
+
+    // The ball is between both cuboids.
+    let m2 = Isometry3::identity();
+    let res = query::signed_distance(&m2, &ball, &m1, &compound);
+    assert!((res.dist - 1.0).abs() < 1.0e-6);
+}
@@ -0,0 +1,185 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{Isometry3, Point3, Unit, Vector3};
+use ncollide::shape::{Capsule, ConvexHull, Cuboid, Cylinder, FeatureId, Plane, RoundShape,
+                      Segment, ShapeHandle, SupportAreaFeatures, SupportMap};
+use ncollide::world::{CollisionGroups, CollisionWorld3, GeometricQueryType};
+
+fn area3<G>(g: &G, dir: Vector3<f64>) -> Vec<Point3<f64>>
+where
+    G: SupportMap<Point3<f64>, Isometry3<f64>>,
+{
+    let mut out = Vec::new();
+    g.support_area_toward(&Isometry3::identity(), &Unit::new_normalize(dir), 0.1, &mut out);
+
+    out
+}
+
+#[test]
+fn support_area_cuboid() {
+    let c = Cuboid::new(Vector3::new(1.0f64, 1.0, 1.0));
+
+    let face = area3(&c, Vector3::new(0.05, 1.0, 0.0));
+    assert_eq!(face.len(), 4);
+    assert!(face.iter().all(|pt| (pt.y - 1.0).abs() < 1.0e-6));
+
+    let edge = area3(&c, Vector3::new(1.0, 1.0, 0.0));
+    assert_eq!(edge.len(), 2);
+    assert!(edge.iter().all(|pt| pt.x == 1.0 && pt.y == 1.0));
+
+    let vertex = area3(&c, Vector3::new(1.0, 1.0, 1.0));
+    assert_eq!(vertex, vec![Point3::new(1.0, 1.0, 1.0)]);
+}
+
+#[test]
+fn support_area_features_cuboid() {
+    let c = Cuboid::new(Vector3::new(1.0f64, 1.0, 1.0));
+    let m = Isometry3::identity();
+    let mut area = Vec::new();
+    let mut features = SupportAreaFeatures::new();
+
+    c.support_area_features_toward(&m, &Vector3::y_axis(), 0.1, &mut area, &mut features);
+    assert_eq!(features.area, FeatureId::Face(1));
+    assert_eq!(features.vertices.len(), 4);
+    assert_eq!(features.edges.len(), 4);
+
+    for (pt, vertex) in area.iter().zip(features.vertices.iter()) {
+        // The `k`-th bit of the index of a vertex is set if its `k`-th coordinate is positive.
+        let id = (0..3).fold(0, |id, i| if pt[i] > 0.0 { id | (1 << i) } else { id });
+        assert_eq!(*vertex, FeatureId::Vertex(id));
+    }
+
+    area.clear();
+    features.clear();
+    let dir = Unit::new_normalize(Vector3::new(1.0, 1.0, 0.0));
+    c.support_area_features_toward(&m, &dir, 0.1, &mut area, &mut features);
+    assert_eq!(features.area, c.support_feature_id_toward(&m, &dir));
+    assert_eq!(features.vertices, vec![FeatureId::Vertex(7), FeatureId::Vertex(3)]);
+}
+
+#[test]
+fn support_area_round_cuboid() {
+    let c = RoundShape::new(Cuboid::new(Vector3::new(1.0f64, 1.0, 1.0)), 0.1);
+
+    let face = area3(&c, Vector3::y());
+    assert_eq!(face.len(), 4);
+    assert!(face.iter().all(|pt| (pt.y - 1.1).abs() < 1.0e-6));
+}
+
+#[test]
+fn support_area_cylinder_capsule() {
+    let cylinder = Cylinder::new(1.0f64, 0.5);
+
+    let cap = area3(&cylinder, Vector3::y());
+    assert_eq!(cap.len(), 8);
+    assert!(cap.iter().all(|pt| (pt.y - 1.0).abs() < 1.0e-6));
+
+    let side = area3(&cylinder, Vector3::x());
+    assert_eq!(side.len(), 2);
+    assert!(side.iter().all(|pt| (pt.x - 0.5).abs() < 1.0e-6));
+
+    let capsule = Capsule::new(1.0f64, 0.5);
+
+    let side = area3(&capsule, Vector3::x());
+    assert_eq!(side.len(), 2);
+    assert!(side.iter().all(|pt| (pt.x - 0.5).abs() < 1.0e-6));
+
+    assert_eq!(area3(&capsule, Vector3::y()).len(), 1);
+}
+
+#[test]
+fn support_area_segment() {
+    let s = Segment::new(Point3::origin(), Point3::new(1.0f64, 0.0, 0.0));
+
+    assert_eq!(area3(&s, Vector3::y()).len(), 2);
+    assert_eq!(area3(&s, Vector3::new(1.0, 1.0, 0.0)).len(), 1);
+}
+
+#[test]
+fn support_area_convex_hull() {
+    let mut points = Vec::new();
+
+    for i in 0..8 {
+        let x = if i & 1 == 0 { -1.0f64 } else { 1.0 };
+        let y = if i & 2 == 0 { -1.0 } else { 1.0 };
+        let z = if i & 4 == 0 { -1.0 } else { 1.0 };
+        points.push(Point3::new(x, y, z));
+    }
+
+    let hull = ConvexHull::new(points);
+
+    let face = area3(&hull, Vector3::z());
+    assert_eq!(face.len(), 4);
+    assert!(face.iter().all(|pt| pt.z == 1.0));
+
+    let edge = area3(&hull, Vector3::new(0.0, 1.0, 1.0));
+    assert_eq!(edge.len(), 2);
+}
+
+#[test]
+fn support_area_cylinder_on_plane_manifold() {
+    let mut world = CollisionWorld3::new(0.02);
+    let contact_query = GeometricQueryType::Contacts(0.0, 0.0);
+
+    let _ = world.add(
+        Isometry3::identity(),
+        ShapeHandle::new(Plane::new(Vector3::y_axis())),
+        CollisionGroups::new(),
+        contact_query,
+        (),
+    );
+    let _ = world.add(
+        Isometry3::new(Vector3::new(0.0, 0.9, 0.0), na::zero()),
+        ShapeHandle::new(Cylinder::new(1.0f64, 0.5)),
+        CollisionGroups::new(),
+        contact_query,
+        (),
+    );
+    world.update();
+
+    // The cap of the cylinder yields a full manifold at once.
+    assert_eq!(world.contacts().count(), 4);
+
+    for (_, _, c) in world.contacts() {
+        assert!((c.depth - 0.1).abs() < 1.0e-6);
+    }
+}
+
+#[test]
+fn support_area_cuboid_on_plane_manifold_features() {
+    let mut world = CollisionWorld3::new(0.02);
+    let contact_query = GeometricQueryType::Contacts(0.0, 0.0);
+
+    let _ = world.add(
+        Isometry3::identity(),
+        ShapeHandle::new(Plane::new(Vector3::y_axis())),
+        CollisionGroups::new(),
+        contact_query,
+        (),
+    );
+    let _ = world.add(
+        Isometry3::new(Vector3::new(0.0, 0.9, 0.0), na::zero()),
+        ShapeHandle::new(Cuboid::new(Vector3::new(1.0f64, 1.0, 1.0))),
+        CollisionGroups::new(),
+        contact_query,
+        (),
+    );
+    world.update();
+
+    let mut vertices = Vec::new();
+
+    for (_, _, c) in world.contacts() {
+        let (face, vertex) = match (c.feature1, c.feature2) {
+            (FeatureId::Vertex(v), f) | (f, FeatureId::Vertex(v)) => (f, v),
+            _ => panic!("Each contact should involve a vertex of the cuboid."),
+        };
+
+        assert_eq!(face, FeatureId::Face(0));
+        vertices.push(vertex);
+    }
+
+    // The vertices of the bottom face of the cuboid.
+    vertices.sort();
+    assert_eq!(vertices, vec![0, 1, 4, 5]);
+}
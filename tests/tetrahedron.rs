@@ -0,0 +1,64 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{Isometry3, Point3, Vector3};
+use ncollide::shape::{Ball, SupportMap, Tetrahedron};
+use ncollide::query::{ContactPrediction, Ray, RayCast};
+use ncollide::narrow_phase::{ContactDispatcher, DefaultContactDispatcher};
+
+fn tetrahedron() -> Tetrahedron<Point3<f64>> {
+    Tetrahedron::new(
+        Point3::origin(),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+        Point3::new(0.0, 0.0, 1.0),
+    )
+}
+
+#[test]
+fn tetrahedron_support_points() {
+    let t = tetrahedron();
+    let m = Isometry3::new(Vector3::new(1.0, 2.0, 3.0), na::zero());
+
+    assert_eq!(t.support_point(&m, &Vector3::x()), Point3::new(2.0, 2.0, 3.0));
+    assert_eq!(t.support_point(&m, &Vector3::z()), Point3::new(1.0, 2.0, 4.0));
+    assert_eq!(
+        t.support_point(&m, &Vector3::new(-1.0, -1.0, -1.0)),
+        Point3::new(1.0, 2.0, 3.0)
+    );
+}
+
+#[test]
+fn tetrahedron_ray_cast() {
+    let t = tetrahedron();
+    let m = Isometry3::identity();
+
+    let ray = Ray::new(Point3::new(-5.0, 0.2, 0.2), Vector3::x());
+    let inter = t.toi_and_normal_with_ray(&m, &ray, true).unwrap();
+    assert!((inter.toi - 5.0).abs() < 1.0e-6);
+    assert!(na::norm(&(inter.normal + Vector3::x())) < 1.0e-6);
+
+    let ray = Ray::new(Point3::new(-5.0, 2.0, 2.0), Vector3::x());
+    assert!(t.toi_and_normal_with_ray(&m, &ray, true).is_none());
+}
+
+#[test]
+fn tetrahedron_ball_contact() {
+    let t = tetrahedron();
+    let ball = Ball::new(0.5f64);
+    let m1 = Isometry3::identity();
+    let m2 = Isometry3::new(Vector3::new(-0.4, 0.2, 0.2), na::zero());
+    let dispatcher = DefaultContactDispatcher::<Point3<f64>, Isometry3<f64>>::new();
+    let prediction = ContactPrediction::new(0.0, 0.0, 0.0);
+
+    let mut algorithm = dispatcher
+        .get_contact_algorithm(&t, &ball)
+        .expect("A tetrahedron is a support map.");
+    assert!(algorithm.update(&dispatcher, &m1, &t, &m2, &ball, &prediction));
+
+    let mut contacts = Vec::new();
+    algorithm.contacts(&mut contacts);
+    assert_eq!(contacts.len(), 1);
+    assert!((contacts[0].depth - 0.1).abs() < 1.0e-6);
+    assert!(na::norm(&(contacts[0].normal.unwrap() + Vector3::x())) < 1.0e-6);
+}
@@ -0,0 +1,106 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{Isometry3, Point3, Vector3};
+use ncollide::shape::{Ball, Compound, Cuboid, Plane, ShapeHandle};
+use ncollide::query::{self, ConstantLinearVelocityRigidMotion, TOIStatus};
+
+#[test]
+fn toi_witnesses_ball_ball() {
+    let b = Ball::new(0.5f64);
+    let m1 = Isometry3::identity();
+    let m2 = Isometry3::new(Vector3::new(0.0, 10.0, 0.0), na::zero());
+    let vel1 = Vector3::new(0.0, 10.0, 0.0);
+
+    let toi = query::time_of_impact_and_witnesses(&m1, &vel1, &b, &m2, &na::zero(), &b).unwrap();
+
+    assert!((toi.toi - 0.9).abs() < 1.0e-6);
+    assert_eq!(toi.status, TOIStatus::Converged);
+    assert!(na::norm(&(toi.witness1 - Point3::new(0.0, 9.5, 0.0))) < 1.0e-6);
+    assert!(na::norm(&(toi.witness2 - Point3::new(0.0, 9.5, 0.0))) < 1.0e-6);
+    assert!(na::norm(&(toi.normal.unwrap() - Vector3::y())) < 1.0e-6);
+}
+
+#[test]
+fn toi_witnesses_penetrating() {
+    let b = Ball::new(0.5f64);
+    let m1 = Isometry3::identity();
+    let m2 = Isometry3::new(Vector3::new(0.0, 0.5, 0.0), na::zero());
+    let vel1 = Vector3::new(0.0, 1.0, 0.0);
+
+    let toi = query::time_of_impact_and_witnesses(&m1, &vel1, &b, &m2, &na::zero(), &b).unwrap();
+
+    assert_eq!(toi.toi, 0.0);
+    assert_eq!(toi.status, TOIStatus::Penetrating);
+}
+
+#[test]
+fn toi_witnesses_match_time_of_impact() {
+    let cuboid = Cuboid::new(Vector3::new(0.5f64, 0.5, 0.5));
+    let compound = Compound::new(vec![
+        (
+            Isometry3::new(Vector3::new(-2.0, 0.0, 0.0), na::zero()),
+            ShapeHandle::new(cuboid.clone()),
+        ),
+        (
+            Isometry3::new(Vector3::new(2.0, 0.0, 0.0), na::zero()),
+            ShapeHandle::new(cuboid.clone()),
+        ),
+    ]);
+    let m1 = Isometry3::identity();
+    let m2 = Isometry3::new(Vector3::new(2.0, 5.0, 0.0), na::zero());
+    let vel2 = -Vector3::y();
+
+    let toi = query::time_of_impact(&m1, &na::zero(), &cuboid, &m2, &vel2, &cuboid);
+    assert!(toi.is_none());
+
+    let toi = query::time_of_impact(&m1, &na::zero(), &compound, &m2, &vel2, &cuboid).unwrap();
+    let res = query::time_of_impact_and_witnesses(&m1, &na::zero(), &compound, &m2, &vel2, &cuboid)
+        .unwrap();
+
+    assert!((toi - 4.0).abs() < 1.0e-4);
+    assert!((res.toi - toi).abs() < 1.0e-6);
+    assert!((res.witness1.y - 0.5).abs() < 1.0e-4);
+}
+
+#[test]
+fn toi_witnesses_nonlinear_failed() {
+    let plane = Plane::new(Vector3::y_axis());
+    let cuboid = Cuboid::new(Vector3::new(0.5f64, 0.5, 0.5));
+
+    let motion1 =
+        ConstantLinearVelocityRigidMotion::<Point3<f64>, _>::new(Isometry3::identity(), na::zero());
+    let motion2 = ConstantLinearVelocityRigidMotion::<Point3<f64>, _>::new(
+        Isometry3::new(Vector3::new(0.0, 2.0, 0.0), na::zero()),
+        -Vector3::y(),
+    );
+
+    // A single iteration is not enough to check that the shapes are touching.
+    let toi = query::nonlinear_time_of_impact_and_witnesses(
+        &motion1,
+        &plane,
+        &motion2,
+        &cuboid,
+        10.0,
+        1.0e-4,
+        1,
+    ).unwrap();
+    assert_eq!(toi.status, TOIStatus::Failed);
+    assert!(
+        query::nonlinear_time_of_impact(&motion1, &plane, &motion2, &cuboid, 10.0, 1.0e-4, 1)
+            .is_none()
+    );
+
+    let toi = query::nonlinear_time_of_impact_and_witnesses(
+        &motion1,
+        &plane,
+        &motion2,
+        &cuboid,
+        10.0,
+        1.0e-4,
+        100,
+    ).unwrap();
+    assert_eq!(toi.status, TOIStatus::Converged);
+    assert!(toi.witness1.y.abs() < 1.0e-3);
+    assert!(toi.witness2.y.abs() < 1.0e-3);
+}
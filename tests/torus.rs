@@ -0,0 +1,73 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{Isometry3, Point3, Vector3};
+use ncollide::shape::{Ball, Cuboid, Torus};
+use ncollide::query::{self, Proximity, Ray, RayCast};
+
+#[test]
+fn torus_ray_cast() {
+    let torus = Torus::new(2.0f64, 0.5);
+    let m = Isometry3::identity();
+
+    // Through the tube.
+    let ray = Ray::new(Point3::new(-5.0, 0.0, 0.0), Vector3::x());
+    let inter = torus.toi_and_normal_with_ray(&m, &ray, true).unwrap();
+    assert!((inter.toi - 2.5).abs() < 1.0e-7);
+    assert!((inter.normal - -Vector3::x()).norm() < 1.0e-7);
+
+    // Through the hole.
+    let ray = Ray::new(Point3::new(0.0, 5.0, 0.0), -Vector3::y());
+    assert!(torus.toi_with_ray(&m, &ray, true).is_none());
+}
+
+#[test]
+fn torus_cuboid_contact() {
+    let torus = Torus::new(2.0f64, 0.5);
+    let cuboid = Cuboid::new(Vector3::new(3.0, 1.0, 3.0));
+    let m1 = Isometry3::identity();
+    let m2 = Isometry3::new(Vector3::new(0.0, -1.4, 0.0), na::zero());
+
+    let contact = query::contact(&m1, &torus, &m2, &cuboid, 0.0).unwrap();
+
+    assert!((contact.depth - 0.1).abs() < 1.0e-5);
+}
+
+#[test]
+fn torus_tangent_ray_cast() {
+    let torus = Torus::new(2.0f64, 0.5);
+    let m = Isometry3::identity();
+
+    // Tangent to the top of the tube.
+    let ray = Ray::new(Point3::new(-5.0, 0.5, 0.0), Vector3::x());
+    let toi = torus.toi_with_ray(&m, &ray, true).unwrap();
+    assert!((toi - 3.0).abs() < 1.0e-5);
+}
+
+#[test]
+fn torus_ball_distance_proximity_toi() {
+    let torus = Torus::new(2.0f64, 0.5);
+    let ball = Ball::new(0.5f64);
+    let m1 = Isometry3::identity();
+
+    let m2 = Isometry3::new(Vector3::new(4.0, 0.0, 0.0), na::zero());
+    assert!((query::distance(&m1, &torus, &m2, &ball) - 1.0).abs() < 1.0e-5);
+    assert!((query::distance(&m2, &ball, &m1, &torus) - 1.0).abs() < 1.0e-5);
+
+    let m2 = Isometry3::new(Vector3::new(3.2, 0.0, 0.0), na::zero());
+    let prox = query::proximity(&m1, &torus, &m2, &ball, 0.5);
+    assert_eq!(prox, Proximity::WithinMargin);
+
+    let m2 = Isometry3::new(Vector3::new(2.9, 0.0, 0.0), na::zero());
+    let prox = query::proximity(&m2, &ball, &m1, &torus, 0.5);
+    assert_eq!(prox, Proximity::Intersecting);
+
+    let m2 = Isometry3::new(Vector3::new(-5.0, 0.0, 0.0), na::zero());
+    let toi = query::time_of_impact(&m1, &na::zero(), &torus, &m2, &Vector3::x(), &ball);
+    assert!((toi.unwrap() - 2.0).abs() < 1.0e-5);
+
+    // Through the hole.
+    let m2 = Isometry3::new(Vector3::new(0.0, 5.0, 0.0), na::zero());
+    let toi = query::time_of_impact(&m1, &na::zero(), &torus, &m2, &-Vector3::y(), &ball);
+    assert!(toi.is_none());
+}
@@ -0,0 +1,135 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{Isometry2, Isometry3, Point2, Point3, Vector2, Vector3};
+use ncollide::bounding_volume;
+use ncollide::world::{CollisionGroups, CollisionWorld3, GeometricQueryType};
+use ncollide::shape::{Ball, ShapeHandle, Voxels};
+use ncollide::query::{PointQuery, Ray, RayCast};
+
+#[test]
+fn voxels_queries() {
+    let mut voxels = Voxels::new(&[4, 4, 4], 1.0f64);
+    let m = Isometry3::identity();
+
+    // A full 4x4x2 slab on the bottom of the grid.
+    for k in 0..4 {
+        for j in 0..2 {
+            for i in 0..4 {
+                let id = voxels.voxel_id(&[i, j, k]);
+                voxels.set_occupied(id, true);
+            }
+        }
+    }
+
+    let aabb = bounding_volume::aabb(&voxels, &m);
+    assert_eq!(*aabb.mins(), Point3::new(0.0, 0.0, 0.0));
+    assert_eq!(*aabb.maxs(), Point3::new(4.0, 2.0, 4.0));
+
+    // All the voxels of the slab touch the border of the grid.
+    assert!(voxels.is_boundary(voxels.voxel_id(&[1, 1, 1])));
+
+    let ray = Ray::new(Point3::new(1.5, 10.0, 1.5), -Vector3::y());
+    let inter = voxels.toi_and_normal_with_ray(&m, &ray, true).unwrap();
+    assert!((inter.toi - 8.0).abs() < 1.0e-7);
+    assert_eq!(inter.normal, Vector3::y());
+
+    // Digging a hole lets the ray go deeper.
+    let id = voxels.voxel_id(&[1, 1, 1]);
+    voxels.set_occupied(id, false);
+    let inter = voxels.toi_and_normal_with_ray(&m, &ray, true).unwrap();
+    assert!((inter.toi - 9.0).abs() < 1.0e-7);
+
+    assert!(voxels.contains_point(&m, &Point3::new(0.5, 0.5, 0.5)));
+    assert!(!voxels.contains_point(&m, &Point3::new(1.5, 1.5, 1.5)));
+
+    let proj = voxels.project_point(&m, &Point3::new(3.5, 5.0, 3.5), true);
+    assert!((proj.point - Point3::new(3.5, 2.0, 3.5)).norm() < 1.0e-7);
+
+    // Removing the top layer shrinks the AABB.
+    for k in 0..4 {
+        for i in 0..4 {
+            let id = voxels.voxel_id(&[i, 1, k]);
+            voxels.set_occupied(id, false);
+        }
+    }
+
+    let aabb = bounding_volume::aabb(&voxels, &m);
+    assert_eq!(*aabb.maxs(), Point3::new(4.0, 1.0, 4.0));
+}
+
+#[test]
+fn voxels_2d_ray_cast() {
+    let mut voxels = Voxels::new(&[8, 8], 0.5f64);
+    let m = Isometry2::identity();
+    let id = voxels.voxel_id(&[5, 2]);
+    voxels.set_occupied(id, true);
+
+    let ray = Ray::new(Point2::new(0.0, 1.25), Vector2::x());
+    let inter = voxels.toi_and_normal_with_ray(&m, &ray, true).unwrap();
+    assert!((inter.toi - 2.5).abs() < 1.0e-7);
+    assert_eq!(inter.normal, -Vector2::x());
+
+    // Non-solid ray cast from inside of the voxel.
+    let ray = Ray::new(Point2::new(2.6, 1.25), Vector2::x());
+    let inter = voxels.toi_and_normal_with_ray(&m, &ray, false).unwrap();
+    assert!((inter.toi - 0.4).abs() < 1.0e-7);
+    assert_eq!(inter.normal, Vector2::x());
+}
+
+#[test]
+fn voxels_at_point_and_empty_grid() {
+    let mut voxels = Voxels::new(&[5, 3, 7], 0.5f64);
+    let m = Isometry3::identity();
+
+    assert_eq!(voxels.voxel_at_point(&Point3::new(0.0, 0.0, 0.0)), Some([0, 0, 0]));
+    assert_eq!(voxels.voxel_at_point(&Point3::new(2.5, 1.5, 3.5)), Some([4, 2, 6]));
+    assert_eq!(voxels.voxel_at_point(&Point3::new(2.75, 0.0, 0.0)), None);
+    assert_eq!(voxels.voxel_at_point(&Point3::new(0.0, -0.1, 0.0)), None);
+
+    // Projecting on an empty grid falls back to the grid AABB.
+    let proj = voxels.project_point(&m, &Point3::new(1.0, 5.0, 1.0), true);
+    assert!(!proj.is_inside);
+    assert!((proj.point - Point3::new(1.0, 1.5, 1.0)).norm() < 1.0e-7);
+
+    // Every voxel of a grid with non-power-of-two dimensions can be filled.
+    for id in 0..voxels.num_voxels() {
+        voxels.set_occupied(id, true);
+    }
+
+    assert!(voxels.contains_point(&m, &Point3::new(2.5, 1.5, 3.5)));
+    assert!(!voxels.is_boundary(voxels.voxel_id(&[2, 1, 3])));
+}
+
+#[test]
+fn voxels_in_world() {
+    let mut world = CollisionWorld3::new(0.02);
+    let contact_query = GeometricQueryType::Contacts(0.0, 0.0);
+    let mut voxels = Voxels::new(&[4, 1, 4], 1.0f64);
+
+    for k in 0..4 {
+        for i in 0..4 {
+            let id = voxels.voxel_id(&[i, 0, k]);
+            voxels.set_occupied(id, true);
+        }
+    }
+
+    let _ = world.add(
+        Isometry3::identity(),
+        ShapeHandle::new(voxels),
+        CollisionGroups::new(),
+        contact_query,
+        (),
+    );
+    let _ = world.add(
+        Isometry3::new(Vector3::new(2.0, 1.4, 2.0), na::zero()),
+        ShapeHandle::new(Ball::new(0.5f64)),
+        CollisionGroups::new(),
+        contact_query,
+        (),
+    );
+    world.update();
+
+    // The ball touches the four voxels around its center.
+    assert_eq!(world.contacts().count(), 4);
+}